@@ -15,15 +15,37 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{helpers::AccountModel, CurrentNetwork};
-use aleo_rust::account::Encryptor;
-use snarkvm::prelude::{Address, Ciphertext, PrivateKey, ViewKey};
+use aleo_wasm::{
+    native::{
+        account_path,
+        decrypt_keystore,
+        decrypt_private_key,
+        derive_private_key,
+        encrypt_keystore,
+        private_key_from_mnemonic,
+        Address,
+        ExtendedPrivateKey,
+        KdfParams,
+        Keystore,
+        Mnemonic,
+        NetworkTrait,
+        PrivateKey,
+        PrivateKeyCiphertext,
+        ViewKey,
+    },
+    Network,
+};
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use clap::Parser;
 use colored::*;
 use rand::SeedableRng;
 use rand_chacha::ChaChaRng;
-use std::{convert::TryFrom, fs::File};
+use std::{
+    convert::TryFrom,
+    fs::{self, File},
+    str::FromStr,
+};
 
 /// Commands to manage Aleo account creation, import, and encryption/decryption
 #[derive(Debug, Parser)]
@@ -33,6 +55,13 @@ pub enum Account {
         /// Seed the RNG with a numeric value
         #[clap(short = 's', long)]
         seed: Option<u64>,
+        /// Generate the account from a new BIP-39 mnemonic with the given number of words (12, 15,
+        /// 18, 21 or 24)
+        #[clap(short = 'm', long, conflicts_with = "seed", num_args = 0..=1, default_missing_value = "24")]
+        mnemonic: Option<usize>,
+        /// Passphrase protecting the mnemonic
+        #[clap(long, requires = "mnemonic")]
+        passphrase: Option<String>,
         /// Flag to encrypt the private key (will prompt for an encryption password)
         #[clap(short = 'e', long)]
         encrypt: bool,
@@ -42,15 +71,46 @@ pub enum Account {
         /// password to encrypt the private key
         #[clap(short = 'p', long)]
         password: Option<String>,
+        /// Network of the keystore the private key is encrypted into
+        #[clap(short = 'n', long, default_value_t = Network::MainnetV0)]
+        network: Network,
     },
     /// Derive view key and address from a private key plaintext
     Import {
         /// Private key plaintext
-        private_key: PrivateKey<CurrentNetwork>,
+        #[clap(required_unless_present = "mnemonic")]
+        private_key: Option<PrivateKey<CurrentNetwork>>,
+        /// Recover the account from a BIP-39 mnemonic phrase
+        #[clap(short = 'm', long, conflicts_with = "private_key")]
+        mnemonic: Option<String>,
+        /// Passphrase protecting the mnemonic
+        #[clap(long, requires = "mnemonic")]
+        passphrase: Option<String>,
         /// Write key data to disk
         #[clap(short = 'w', long)]
         write: bool,
     },
+    /// Derive accounts from a BIP-39 mnemonic with hierarchical deterministic derivation
+    Derive {
+        /// Mnemonic phrase of the master key
+        #[clap(short = 'm', long)]
+        mnemonic: String,
+        /// Passphrase protecting the mnemonic
+        #[clap(long)]
+        passphrase: Option<String>,
+        /// Index of the first account to derive
+        #[clap(short = 'i', long, default_value_t = 0)]
+        index: u32,
+        /// Number of accounts to derive
+        #[clap(short = 'c', long, default_value_t = 1)]
+        count: u32,
+        /// Derive the account at a hardened derivation path instead, e.g. "m/44'/683'/0'/0'"
+        #[clap(long, conflicts_with_all = &["index", "count"])]
+        path: Option<String>,
+        /// Only print the view keys and addresses of the accounts, for watch-only scanning
+        #[clap(long)]
+        watch_only: bool,
+    },
     /// Encrypt a private key plaintext
     Encrypt {
         /// Provide private key plaintext to command line
@@ -59,19 +119,22 @@ pub enum Account {
         /// Get private key plaintext from file
         #[clap(short = 'f', long)]
         file: Option<String>,
-        /// Write private key ciphertext and address to disk
+        /// Write a keystore of the private key to disk
         #[clap(short = 'w', long)]
         write: bool,
         /// password to encrypt the private key
         #[clap(short = 'p', long)]
         password: Option<String>,
+        /// Network of the keystore the private key is encrypted into
+        #[clap(short = 'n', long, default_value_t = Network::MainnetV0)]
+        network: Network,
     },
     /// Decrypt a private key ciphertext
     Decrypt {
         /// Provide ciphertext directly to command line
         #[clap(short = 'k', long)]
-        ciphertext: Option<Ciphertext<CurrentNetwork>>,
-        /// Get ciphertext from file
+        ciphertext: Option<PrivateKeyCiphertext>,
+        /// Get the keystore or ciphertext from file
         #[clap(short = 'f', long)]
         file: Option<String>,
         /// Write account plaintext data to disk
@@ -86,21 +149,37 @@ pub enum Account {
 impl Account {
     pub fn parse(self) -> Result<String> {
         match self {
-            Self::New { seed, encrypt, write, password } => {
-                // Sample a new Aleo account.
-                let private_key = match seed {
-                    Some(seed) => PrivateKey::<CurrentNetwork>::new(&mut ChaChaRng::seed_from_u64(seed))?,
-                    None => PrivateKey::new(&mut rand::thread_rng())?,
+            Self::New { seed, mnemonic, passphrase, encrypt, write, password, network } => {
+                // Sample a new Aleo account, or a new mnemonic to derive the account from.
+                let mnemonic =
+                    mnemonic.map(|word_count| Mnemonic::new(Some(word_count))).transpose().map_err(|e| anyhow!(e))?;
+                let private_key = match (seed, &mnemonic) {
+                    (_, Some(mnemonic)) => {
+                        private_key_from_mnemonic(mnemonic, passphrase.as_deref().unwrap_or_default())?
+                    }
+                    (Some(seed), None) => PrivateKey::<CurrentNetwork>::new(&mut ChaChaRng::seed_from_u64(seed))?,
+                    (None, None) => PrivateKey::new(&mut rand::thread_rng())?,
                 };
 
                 // Create success message
                 let mut key_output = format!("\n{:>12}", "✅ Account keys successfully generated:\n".green().bold(),);
 
+                // Add the mnemonic to the output, it is not written to disk
+                if let Some(mnemonic) = mnemonic {
+                    key_output += format!(
+                        "\n {:>1}  {mnemonic}\n {:>1}",
+                        "Mnemonic".cyan().bold(),
+                        "⚠️  Write down the mnemonic and keep it safe, it recovers the account".yellow().bold(),
+                    )
+                    .as_str();
+                }
+
                 // If encryption flag is specified encrypt private key and add it to the output
-                let private_key_ciphertext = if encrypt {
-                    let ciphertext = Self::encrypt_with_password(&private_key, password)?;
+                let keystore = if encrypt {
+                    let keystore = Self::encrypt_with_password(&private_key, network, password)?;
+                    let ciphertext = keystore.to_private_key_ciphertext().map_err(|e| anyhow!(e))?;
                     key_output += format!("\n {:>1}  {ciphertext}", "Private Key Ciphertext".cyan().bold()).as_str();
-                    Some(ciphertext)
+                    Some(keystore)
                 } else {
                     None
                 };
@@ -118,17 +197,21 @@ impl Account {
                 .as_str();
 
                 // Save output to file if specified
-                let save_output = Self::write_account_to_file(
-                    write,
-                    private_key_ciphertext,
-                    private_key.into(),
-                    view_key.into(),
-                    address.into(),
-                )?;
+                let save_output =
+                    Self::write_account_to_file(write, keystore, private_key.into(), view_key.into(), address.into())?;
 
                 Ok(format!("{key_output}{save_output}"))
             }
-            Self::Import { private_key, write } => {
+            Self::Import { private_key, mnemonic, passphrase, write } => {
+                let private_key = match (private_key, mnemonic) {
+                    (_, Some(mnemonic)) => {
+                        let mnemonic = Mnemonic::from_str(&mnemonic).map_err(|e| anyhow!("❌ {e}"))?;
+                        private_key_from_mnemonic(&mnemonic, passphrase.as_deref().unwrap_or_default())?
+                    }
+                    (Some(private_key), None) => private_key,
+                    (None, None) => bail!("❌ Please provide either a private key or a mnemonic"),
+                };
+
                 let view_key = ViewKey::try_from(&private_key)?;
                 let address = Address::try_from(&view_key)?;
 
@@ -147,7 +230,35 @@ impl Account {
 
                 Ok(format!("{key_output}{save_output}"))
             }
-            Self::Encrypt { private_key, file, write, password } => {
+            Self::Derive { mnemonic, passphrase, index, count, path, watch_only } => {
+                let master = ExtendedPrivateKey::from_mnemonic(&mnemonic, passphrase).map_err(|e| anyhow!("❌ {e}"))?;
+                let paths = match path {
+                    Some(path) => vec![path],
+                    None => (index..index.saturating_add(count)).map(account_path).collect(),
+                };
+
+                // Print the accounts at each derivation path
+                let mut key_output = format!("\n{:>12}", "✅ Account keys successfully derived:".green().bold());
+                for path in paths {
+                    let private_key = derive_private_key::<CurrentNetwork>(&master, &path)?;
+                    let view_key = ViewKey::try_from(&private_key)?;
+                    let address = Address::try_from(&view_key)?;
+
+                    key_output += format!("\n\n {:>1}  {path}", "Path".cyan().bold()).as_str();
+                    if !watch_only {
+                        key_output += format!("\n {:>1}  {private_key}", "Private Key".cyan().bold()).as_str();
+                    }
+                    key_output += format!(
+                        "\n {:>1}  {view_key}\n {:>1}  {address}",
+                        "View Key".cyan().bold(),
+                        "Address".cyan().bold(),
+                    )
+                    .as_str();
+                }
+
+                Ok(key_output)
+            }
+            Self::Encrypt { private_key, file, write, password, network } => {
                 // Check for ambiguous input
                 if private_key.is_some() && file.is_some() {
                     bail!("❌ Please provide either a private key or a filepath, not both");
@@ -167,7 +278,8 @@ impl Account {
                 };
 
                 // Use the provided password or prompt for a password and encrypt private key
-                let private_key_ciphertext = Self::encrypt_with_password(&private_key, password)?;
+                let keystore = Self::encrypt_with_password(&private_key, network, password)?;
+                let private_key_ciphertext = keystore.to_private_key_ciphertext().map_err(|e| anyhow!(e))?;
                 let address = Address::try_from(&private_key)?;
 
                 // Display private key ciphertext and public address
@@ -179,8 +291,7 @@ impl Account {
                 );
 
                 // Save output to file if specified
-                let save_output =
-                    Self::write_account_to_file(write, private_key_ciphertext.into(), None, None, address.into())?;
+                let save_output = Self::write_account_to_file(write, keystore.into(), None, None, address.into())?;
 
                 Ok(format!("{key_output}{save_output}"))
             }
@@ -190,17 +301,23 @@ impl Account {
                     bail!("❌ Please provide either a private key or a filepath, not both");
                 }
 
-                // Get the ciphertext from file or command line
-                let private_key_ciphertext = match file {
+                // Get the keystore or the ciphertext from file or command line. Files written before
+                // keystores were introduced hold the ciphertext in an account model.
+                let encrypted_key = match file {
                     Some(file) => {
-                        let mut file = File::open(file)?;
-                        let account_keys: AccountModel = serde_json::from_reader(&mut file)?;
-                        account_keys
-                            .private_key_ciphertext
-                            .ok_or_else(|| anyhow::anyhow!("❌ No private key ciphertext found in file"))?
+                        let contents = fs::read_to_string(file)?;
+                        match Keystore::from_str(&contents) {
+                            Ok(keystore) => EncryptedKey::Keystore(keystore),
+                            Err(_) => {
+                                let account_keys: AccountModel = serde_json::from_str(&contents)?;
+                                EncryptedKey::Ciphertext(account_keys.private_key_ciphertext.ok_or_else(|| {
+                                    anyhow::anyhow!("❌ No keystore or private key ciphertext found in file")
+                                })?)
+                            }
+                        }
                     }
                     None => match ciphertext {
-                        Some(ciphertext) => ciphertext,
+                        Some(ciphertext) => EncryptedKey::Ciphertext(ciphertext),
                         None => bail!("❌ Please provide either a ciphertext or a filepath"),
                     },
                 };
@@ -211,8 +328,8 @@ impl Account {
                 } else {
                     rpassword::prompt_password("Enter decryption password: ")?
                 };
-                let private_key = Encryptor::decrypt_private_key_with_secret(&private_key_ciphertext, &secret)
-                    .map_err(|_| anyhow::anyhow!("❌ Incorrect password"))?;
+                let private_key =
+                    encrypted_key.decrypt(&secret).map_err(|_| anyhow::anyhow!("❌ Incorrect password"))?;
 
                 let view_key = ViewKey::try_from(&private_key)?;
                 let address = Address::try_from(&view_key)?;
@@ -235,13 +352,14 @@ impl Account {
         }
     }
 
-    // Encrypt the private key with a password specified at the command line
+    // Encrypt the private key into a keystore of the network with a password specified at the command line
     fn encrypt_with_password(
         private_key: &PrivateKey<CurrentNetwork>,
+        network: Network,
         password: Option<String>,
-    ) -> Result<Ciphertext<CurrentNetwork>> {
+    ) -> Result<Keystore> {
         if let Some(password) = password {
-            Ok(Encryptor::encrypt_private_key_with_secret(private_key, &password)?)
+            Self::encrypt_on_network(private_key, network, &password)
         } else {
             let password = rpassword::prompt_password("Enter encryption password: ")?;
             let password_confirm = rpassword::prompt_password("Confirm encryption password: ")?;
@@ -250,14 +368,31 @@ impl Account {
                 bail!("❌ Passwords do not match");
             }
 
-            Ok(Encryptor::encrypt_private_key_with_secret(private_key, &password)?)
+            Self::encrypt_on_network(private_key, network, &password)
+        }
+    }
+
+    // Encrypt the private key into a keystore tagged with the network
+    fn encrypt_on_network(
+        private_key: &PrivateKey<CurrentNetwork>,
+        network: Network,
+        password: &str,
+    ) -> Result<Keystore> {
+        fn encrypt<N: NetworkTrait>(private_key: &PrivateKey<CurrentNetwork>, password: &str) -> Result<Keystore> {
+            let private_key = PrivateKey::<N>::from_str(&private_key.to_string())?;
+            Ok(encrypt_keystore(&private_key, password, &KdfParams::default())?)
+        }
+        match network {
+            Network::MainnetV0 => encrypt::<aleo_wasm::native::mainnet::CurrentNetwork>(private_key, password),
+            Network::TestnetV0 => encrypt::<aleo_wasm::native::testnet::CurrentNetwork>(private_key, password),
+            Network::CanaryV0 => encrypt::<aleo_wasm::native::canary::CurrentNetwork>(private_key, password),
         }
     }
 
     // Write the account keys to a file or return if write flag is not specified
     fn write_account_to_file(
         write: bool,
-        private_key_ciphertext: Option<Ciphertext<CurrentNetwork>>,
+        keystore: Option<Keystore>,
         private_key: Option<PrivateKey<CurrentNetwork>>,
         view_key: Option<ViewKey<CurrentNetwork>>,
         address: Option<Address<CurrentNetwork>>,
@@ -267,20 +402,16 @@ impl Account {
         }
 
         // Get file name and account serialization
-        let (preamble, filename, account_keys) = if private_key_ciphertext.is_some() {
-            ("✅ Account key ciphertext written to", "account-ciphertext.json", AccountModel {
-              private_key_ciphertext,
-              private_key: None,
-              view_key: None,
-              address,
-            })
-        } else {
-            ("✅ Account key plaintexts written to", "account-plaintext.json", AccountModel {
-              private_key_ciphertext,
-              private_key,
-              view_key,
-              address,
-            })
+        let (preamble, filename, contents) = match keystore {
+            Some(keystore) => ("✅ Account keystore written to", "account-ciphertext.json", keystore.to_string()),
+            None => {
+                let account_keys = AccountModel { private_key_ciphertext: None, private_key, view_key, address };
+                (
+                    "✅ Account key plaintexts written to",
+                    "account-plaintext.json",
+                    serde_json::to_string_pretty(&account_keys)?,
+                )
+            }
         };
 
         // Check if file already exists if so error, else write to file
@@ -294,29 +425,125 @@ impl Account {
                 "file already exists in this directory".red().bold()
             ))
         } else {
-            let mut file = File::create(path)?;
-
-            serde_json::to_writer_pretty(&mut file, &account_keys)?;
+            fs::write(path, contents)?;
             Ok(format!("\n\n{} {}", preamble.green().bold(), filename.green().bold()))
         }
     }
 }
 
+/// A private key encrypted into a keystore or into a bare private key ciphertext
+enum EncryptedKey {
+    Keystore(Keystore),
+    Ciphertext(PrivateKeyCiphertext),
+}
+
+impl EncryptedKey {
+    fn decrypt(&self, secret: &str) -> Result<PrivateKey<CurrentNetwork>> {
+        match self {
+            Self::Keystore(keystore) => {
+                // Decrypt the keystore with the types of the network it was written for
+                fn decrypt<N: NetworkTrait>(keystore: &Keystore, secret: &str) -> Result<PrivateKey<CurrentNetwork>> {
+                    let private_key = decrypt_keystore::<N>(keystore, secret)?;
+                    PrivateKey::from_str(&private_key.to_string())
+                }
+                match keystore.network() {
+                    Network::MainnetV0 => decrypt::<aleo_wasm::native::mainnet::CurrentNetwork>(keystore, secret),
+                    Network::TestnetV0 => decrypt::<aleo_wasm::native::testnet::CurrentNetwork>(keystore, secret),
+                    Network::CanaryV0 => decrypt::<aleo_wasm::native::canary::CurrentNetwork>(keystore, secret),
+                }
+            }
+            Self::Ciphertext(ciphertext) => Ok(decrypt_private_key(ciphertext, secret)?),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::{fs, str::FromStr};
 
-    use snarkvm::prelude::TestRng;
-
     #[test]
     fn test_account_new() {
         for _ in 0..3 {
-            let account = Account::New { seed: None, encrypt: false, write: false, password: None };
+            let account = Account::New {
+                seed: None,
+                mnemonic: None,
+                passphrase: None,
+                encrypt: false,
+                write: false,
+                password: None,
+                network: Network::MainnetV0,
+            };
             assert!(account.parse().is_ok());
         }
     }
 
+    #[test]
+    fn test_account_new_and_import_with_mnemonic() {
+        let account = Account::New {
+            seed: None,
+            mnemonic: Some(12),
+            passphrase: Some("passphrase".to_string()),
+            encrypt: false,
+            write: false,
+            password: None,
+            network: Network::MainnetV0,
+        };
+        assert!(account.parse().unwrap().contains("Mnemonic"));
+
+        // Ensure a mnemonic and passphrase always recover the same account
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let import = |passphrase: Option<&str>| {
+            Account::Import {
+                private_key: None,
+                mnemonic: Some(phrase.to_string()),
+                passphrase: passphrase.map(str::to_string),
+                write: false,
+            }
+            .parse()
+            .unwrap()
+        };
+        assert_eq!(import(Some("passphrase")), import(Some("passphrase")));
+        assert_ne!(import(Some("passphrase")), import(None));
+
+        let invalid_import = Account::Import {
+            private_key: None,
+            mnemonic: Some("abandon about".to_string()),
+            passphrase: None,
+            write: false,
+        };
+        assert!(invalid_import.parse().is_err());
+    }
+
+    #[test]
+    fn test_account_derive() {
+        let mnemonic = "legal winner thank year wave sausage worth useful legal winner thank yellow".to_string();
+        let derive = |index, count, path: Option<&str>, watch_only| {
+            Account::Derive {
+                mnemonic: mnemonic.clone(),
+                passphrase: None,
+                index,
+                count,
+                path: path.map(str::to_string),
+                watch_only,
+            }
+            .parse()
+            .unwrap()
+        };
+
+        // Ensure the accounts are derived at the account paths
+        let accounts = derive(2, 3, None, false);
+        assert!(accounts.contains("m/44'/683'/2'/0'") && accounts.contains("m/44'/683'/4'/0'"));
+        assert!(!accounts.contains("m/44'/683'/5'/0'"));
+        let account = derive(0, 1, Some("m/44'/683'/3'/0'"), false);
+        assert!(accounts.contains(account.split("Path").nth(1).unwrap()));
+
+        // Ensure watch-only output leaves out the private keys
+        let watch_only = derive(2, 3, None, true);
+        assert!(!watch_only.contains("APrivateKey1"));
+        assert_eq!(watch_only.matches("AViewKey1").count(), 3);
+    }
+
     #[test]
     fn test_account_create_import_encrypt_and_decrypt_from_file() {
         // Create a new account in a file
@@ -324,7 +551,15 @@ mod tests {
         let _ = fs::remove_file(temp_dir.join("account-plaintext.json"));
         let _ = fs::remove_file(temp_dir.join("account-ciphertext.json"));
         std::env::set_current_dir(&temp_dir).unwrap();
-        let account = Account::New { seed: None, encrypt: false, write: true, password: None };
+        let account = Account::New {
+            seed: None,
+            mnemonic: None,
+            passphrase: None,
+            encrypt: false,
+            write: true,
+            password: None,
+            network: Network::MainnetV0,
+        };
 
         // Ensure it was created correctly
         let new_account_parse_attempt = account.parse().unwrap();
@@ -345,16 +580,14 @@ mod tests {
             file: Some("account-plaintext.json".to_string()),
             write: true,
             password: Some("mypassword".to_string()),
+            network: Network::MainnetV0,
         };
         assert!(encrypted_account.parse().is_ok());
-        let account_ciphertext: AccountModel =
-            serde_json::from_reader(&mut File::open(temp_dir.join("account-ciphertext.json")).unwrap()).unwrap();
+        let account_keystore =
+            Keystore::from_str(&fs::read_to_string(temp_dir.join("account-ciphertext.json")).unwrap()).unwrap();
 
-        // Ensure the ciphertext exists
-        assert!(account_ciphertext.private_key.is_none());
-        assert!(account_ciphertext.view_key.is_none());
-        assert_eq!(account_ciphertext.address, account.address);
-        assert!(account_ciphertext.private_key_ciphertext.is_some());
+        // Ensure the keystore is for the account
+        assert_eq!(Some(*account_keystore.address()), account.address);
 
         // Ensure creating, encrypting and decrypting to file fails to write file if file already exists
         let encrypt_parse_attempt = Account::Encrypt {
@@ -362,6 +595,7 @@ mod tests {
             file: Some("account-plaintext.json".to_string()),
             write: true,
             password: Some("mypassword".to_string()),
+            network: Network::MainnetV0,
         };
         let encrypt_parse_attempt_result = encrypt_parse_attempt.parse().unwrap();
         assert!(encrypt_parse_attempt_result.contains("✅ Account private key successfully encrypted"));
@@ -398,7 +632,15 @@ mod tests {
         assert_eq!(recovered_account.address, account.address);
 
         // Assert no new plaintext accounts can be written to file
-        let new_account_plaintext = Account::New { seed: None, encrypt: false, write: true, password: None };
+        let new_account_plaintext = Account::New {
+            seed: None,
+            mnemonic: None,
+            passphrase: None,
+            encrypt: false,
+            write: true,
+            password: None,
+            network: Network::MainnetV0,
+        };
         let new_plaintext_account_parse_attempt_result = new_account_plaintext.parse().unwrap();
 
         // Ensure a not written to disk message is emitted
@@ -412,8 +654,15 @@ mod tests {
         assert_eq!(plaintext_recovered_account_check.address, account.address);
 
         // Assert no new encrypted accounts can be written to file
-        let new_account_ciphertext =
-            Account::New { seed: None, encrypt: false, write: true, password: Some("mypassword".to_string()) };
+        let new_account_ciphertext = Account::New {
+            seed: None,
+            mnemonic: None,
+            passphrase: None,
+            encrypt: false,
+            write: true,
+            password: Some("mypassword".to_string()),
+            network: Network::MainnetV0,
+        };
         let new_account_ciphertext_parse_attempt_result = new_account_ciphertext.parse().unwrap();
 
         // Ensure a not written to disk message is emitted
@@ -428,19 +677,24 @@ mod tests {
 
         // Ensure new encrypted accounts can be written to file if the file does not exist
         fs::remove_file(temp_dir.join("account-ciphertext.json")).unwrap();
-        let new_account_ciphertext =
-            Account::New { seed: None, encrypt: true, write: true, password: Some("mypassword".to_string()) };
+        let new_account_ciphertext = Account::New {
+            seed: None,
+            mnemonic: None,
+            passphrase: None,
+            encrypt: true,
+            write: true,
+            password: Some("mypassword".to_string()),
+            network: Network::MainnetV0,
+        };
         let new_account_ciphertext_parse_attempt_result_2 = new_account_ciphertext.parse().unwrap();
-        let ciphertext_recovered_account_check_2: AccountModel =
-            serde_json::from_reader(&mut File::open(temp_dir.join("account-ciphertext.json")).unwrap()).unwrap();
+        let keystore_recovered_account_check_2 =
+            Keystore::from_str(&fs::read_to_string(temp_dir.join("account-ciphertext.json")).unwrap()).unwrap();
         // Assert a write confirmation is printed
         assert!(new_account_ciphertext_parse_attempt_result_2.contains("written to"));
 
         // Assert we've created a different account and that data was written properly
-        assert_ne!(ciphertext_recovered_account_check_2.private_key_ciphertext, account.private_key_ciphertext);
-        assert_ne!(ciphertext_recovered_account_check_2.address, account.address);
-        assert!(ciphertext_recovered_account_check_2.private_key.is_none());
-        assert!(ciphertext_recovered_account_check_2.view_key.is_none());
+        assert_ne!(keystore_recovered_account_check_2, account_keystore);
+        assert_ne!(Some(*keystore_recovered_account_check_2.address()), account.address);
 
         fs::remove_file(temp_dir.join("account-ciphertext.json")).unwrap();
         fs::remove_file(temp_dir.join("account-plaintext.json")).unwrap();
@@ -451,7 +705,8 @@ mod tests {
                 .unwrap();
         let import_view_key = ViewKey::<CurrentNetwork>::try_from(import_private_key).unwrap();
         let import_address = Address::<CurrentNetwork>::try_from(import_private_key).unwrap();
-        let import_parse_attempt = Account::Import { private_key: import_private_key, write: true };
+        let import_parse_attempt =
+            Account::Import { private_key: Some(import_private_key), mnemonic: None, passphrase: None, write: true };
         let import_parse_attempt_result = import_parse_attempt.parse().unwrap();
 
         assert!(import_parse_attempt_result.contains("written to"));
@@ -470,23 +725,21 @@ mod tests {
             file: None,
             write: true,
             password: Some("mypassword".to_string()),
+            network: Network::MainnetV0,
         };
         let encrypted_from_console_parse_attempt_result = encrypted_from_console_parse_attempt.parse().unwrap();
 
         // Ensure a write message is emitted
         assert!(encrypted_from_console_parse_attempt_result.contains("written to"));
-        let encrypted_from_console_account: AccountModel =
-            serde_json::from_reader(&mut File::open(temp_dir.join("account-ciphertext.json")).unwrap()).unwrap();
+        let encrypted_from_console_account =
+            Keystore::from_str(&fs::read_to_string(temp_dir.join("account-ciphertext.json")).unwrap()).unwrap();
 
         // Ensure the result is well formed
-        assert!(encrypted_from_console_account.private_key.is_none());
-        assert!(encrypted_from_console_account.view_key.is_none());
-        assert_eq!(encrypted_from_console_account.address, Some(import_address));
-        assert!(encrypted_from_console_account.private_key_ciphertext.is_some());
+        assert_eq!(*encrypted_from_console_account.address(), import_address);
 
-        // Ensure we can decrypt the ciphertext to file from the command line
+        // Ensure we can decrypt the ciphertext of the keystore to file from the command line
         let encrypted_from_console_parse_attempt = Account::Decrypt {
-            ciphertext: encrypted_from_console_account.private_key_ciphertext,
+            ciphertext: encrypted_from_console_account.to_private_key_ciphertext().ok(),
             file: None,
             write: true,
             password: Some("mypassword".to_string()),
@@ -508,22 +761,59 @@ mod tests {
 
     #[test]
     fn test_account_encrypt_fails_with_invalid_inputs() {
-        let account_no_inputs = Account::Encrypt { private_key: None, file: None, write: false, password: None };
+        let account_no_inputs = Account::Encrypt {
+            private_key: None,
+            file: None,
+            write: false,
+            password: None,
+            network: Network::MainnetV0,
+        };
         assert!(account_no_inputs.parse().is_err());
 
-        let private_key = Some(PrivateKey::<CurrentNetwork>::new(&mut TestRng::default()).unwrap());
-        let account_ambiguous_inputs =
-            Account::Encrypt { private_key, file: Some("test.json".to_string()), write: false, password: None };
+        let private_key = Some(PrivateKey::<CurrentNetwork>::new(&mut ChaChaRng::seed_from_u64(3)).unwrap());
+        let account_ambiguous_inputs = Account::Encrypt {
+            private_key,
+            file: Some("test.json".to_string()),
+            write: false,
+            password: None,
+            network: Network::MainnetV0,
+        };
         assert!(account_ambiguous_inputs.parse().is_err());
     }
 
+    #[test]
+    fn test_account_encrypt_for_network() {
+        let temp_dir = std::env::temp_dir().join("aleo-account-encrypt-network");
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(&temp_dir).unwrap();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut ChaChaRng::seed_from_u64(7)).unwrap();
+
+        // Ensure the keystore is tagged with the network it was encrypted for
+        let keystore =
+            Account::encrypt_with_password(&private_key, Network::TestnetV0, Some("mypassword".to_string())).unwrap();
+        assert_eq!(keystore.network(), Network::TestnetV0);
+        assert_eq!(keystore.address().to_string(), Address::try_from(&private_key).unwrap().to_string());
+
+        // Ensure keystores of other networks decrypt to the same private key
+        let path = temp_dir.join("account-ciphertext.json");
+        fs::write(&path, keystore.to_string()).unwrap();
+        let decrypt = Account::Decrypt {
+            ciphertext: None,
+            file: Some(path.to_string_lossy().to_string()),
+            write: false,
+            password: Some("mypassword".to_string()),
+        };
+        assert!(decrypt.parse().unwrap().contains(&private_key.to_string()));
+        fs::remove_dir_all(&temp_dir).unwrap();
+    }
+
     #[test]
     fn test_account_decrypt_fails_with_invalid_inputs() {
         let account_no_inputs = Account::Decrypt { ciphertext: None, file: None, write: false, password: None };
         assert!(account_no_inputs.parse().is_err());
 
         let private_key = PrivateKey::<CurrentNetwork>::new(&mut ChaChaRng::seed_from_u64(5)).unwrap();
-        let ciphertext = Some(Encryptor::encrypt_private_key_with_secret(&private_key, "password").unwrap());
+        let ciphertext = Some(encrypt_private_key(&private_key, "password").unwrap());
         let account_ambiguous_inputs =
             Account::Decrypt { ciphertext, file: Some("test.json".to_string()), write: false, password: None };
         assert!(account_ambiguous_inputs.parse().is_err());
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::helpers::{
+    load_programs,
+    read_program,
+    run_on_network,
+    BroadcastOptions,
+    FeeArgs,
+    KeyOptions,
+    QueryOptions,
+};
+use aleo_wasm::native::{Aleo, ProgramManager};
+
+use anyhow::Result;
+use clap::Parser;
+use colored::*;
+use std::path::PathBuf;
+
+/// Deploys a program
+#[derive(Debug, Parser)]
+pub struct Deploy {
+    /// Source file of the program
+    program: PathBuf,
+    /// Source files of the imports of the program, listed so every import comes after its own imports
+    #[clap(long = "import")]
+    imports: Vec<PathBuf>,
+    #[clap(flatten)]
+    key: KeyOptions,
+    #[clap(flatten)]
+    fee: FeeArgs,
+    #[clap(flatten)]
+    query: QueryOptions,
+    #[clap(flatten)]
+    broadcast: BroadcastOptions,
+}
+
+impl Deploy {
+    pub async fn parse(self) -> Result<String> {
+        run_on_network!(self.query.network, self)
+    }
+
+    async fn run<A: Aleo>(self) -> Result<String> {
+        let private_key = self.key.private_key::<A>()?;
+        let query = self.query.query()?;
+
+        let program = read_program::<A::Network>(&self.program)?;
+
+        // Only the imports are added to the process, the deployment synthesizes the keys of the
+        // program itself
+        let mut program_manager = ProgramManager::<A>::new()?;
+        load_programs(&mut program_manager, &self.imports)?;
+
+        println!("{}", format!("Deploying {} on {}...", program.id(), self.query.network).bright_blue());
+        let transaction = program_manager.deploy(&private_key, &program, self.fee.fee_options::<A>()?, query).await?;
+
+        println!("{}", "Deployment successful!".to_string().bright_green().bold());
+        self.broadcast.output(&self.query, &transaction).await
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::helpers::{credits_to_microcredits, load_programs, read_program, run_on_network, KeyOptions, QueryOptions};
+use aleo_wasm::{
+    native::{Aleo, ProgramManager},
+    Network,
+};
+
+use anyhow::Result;
+use clap::Parser;
+use colored::*;
+use std::path::PathBuf;
+
+/// Estimates the fee of a transaction in microcredits without building it
+#[derive(Debug, Parser)]
+pub enum Estimate {
+    /// Estimate the fee of a program function execution, the execution is proven to measure its size
+    Execute {
+        /// Id of the program, e.g. credits.aleo
+        program_id: String,
+        /// Name of the function to execute
+        function: String,
+        /// Inputs of the function
+        inputs: Vec<String>,
+        /// Source file of the program, not needed for credits.aleo
+        #[clap(long)]
+        program: Option<PathBuf>,
+        /// Source files of the imports of the program, listed so every import comes after its own imports
+        #[clap(long = "import")]
+        imports: Vec<PathBuf>,
+        /// Priority fee in credits paid on top of the base fee
+        #[clap(long, default_value_t = 0.0)]
+        priority_fee: f64,
+        #[clap(flatten)]
+        key: KeyOptions,
+        #[clap(flatten)]
+        query: QueryOptions,
+    },
+    /// Estimate the fee of a program deployment
+    Deploy {
+        /// Source file of the program
+        program: PathBuf,
+        /// Source files of the imports of the program, listed so every import comes after its own imports
+        #[clap(long = "import")]
+        imports: Vec<PathBuf>,
+        /// Network to estimate the deployment fee on
+        #[clap(short = 'n', long, default_value_t = Network::MainnetV0)]
+        network: Network,
+    },
+}
+
+impl Estimate {
+    pub async fn parse(self) -> Result<String> {
+        let network = match &self {
+            Self::Execute { query, .. } => query.network,
+            Self::Deploy { network, .. } => *network,
+        };
+        run_on_network!(network, self)
+    }
+
+    async fn run<A: Aleo>(self) -> Result<String> {
+        match self {
+            Self::Execute { program_id, function, inputs, program, imports, priority_fee, key, query } => {
+                let private_key = key.private_key::<A>()?;
+                let priority_fee = credits_to_microcredits(priority_fee)?;
+
+                let mut program_manager = ProgramManager::<A>::new()?;
+                load_programs(&mut program_manager, &imports)?;
+                if let Some(path) = &program {
+                    program_manager.add_program(&read_program(path)?)?;
+                }
+
+                println!("{}", format!("Estimating the fee of {program_id}/{function}...").bright_blue());
+                let cost = program_manager
+                    .estimate_execution_fee(
+                        &private_key,
+                        &program_id,
+                        &function,
+                        inputs.iter(),
+                        priority_fee,
+                        query.query()?,
+                    )
+                    .await?;
+                Ok(format!(
+                    "\n {:>1}  {}\n {:>1}  {}\n {:>1}  {}\n {:>1}  {}",
+                    "Storage Cost".cyan().bold(),
+                    cost.storage_cost(),
+                    "Finalize Cost".cyan().bold(),
+                    cost.finalize_cost(),
+                    "Priority Fee".cyan().bold(),
+                    cost.priority_fee(),
+                    "Total Fee".cyan().bold(),
+                    cost.total(),
+                ))
+            }
+            Self::Deploy { program, imports, .. } => {
+                let program = read_program::<A::Network>(&program)?;
+                let mut program_manager = ProgramManager::<A>::new()?;
+                load_programs(&mut program_manager, &imports)?;
+
+                println!("{}", format!("Estimating the fee of deploying {}...", program.id()).bright_blue());
+                let cost = program_manager.estimate_deployment_fee(&program)?;
+                Ok(format!(
+                    "\n {:>1}  {}\n {:>1}  {}\n {:>1}  {}\n {:>1}  {}",
+                    "Storage Cost".cyan().bold(),
+                    cost.storage_cost(),
+                    "Synthesis Cost".cyan().bold(),
+                    cost.synthesis_cost(),
+                    "Namespace Cost".cyan().bold(),
+                    cost.namespace_cost(),
+                    "Total Fee".cyan().bold(),
+                    cost.total(),
+                ))
+            }
+        }
+    }
+}
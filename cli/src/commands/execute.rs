@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::helpers::{
+    load_programs,
+    read_program,
+    run_on_network,
+    BroadcastOptions,
+    FeeArgs,
+    KeyOptions,
+    QueryOptions,
+};
+use aleo_wasm::native::{Aleo, ProgramManager};
+
+use anyhow::Result;
+use clap::Parser;
+use colored::*;
+use std::path::PathBuf;
+
+/// Executes a program function
+#[derive(Debug, Parser)]
+pub struct Execute {
+    /// Id of the program, e.g. credits.aleo
+    program_id: String,
+    /// Name of the function to execute
+    function: String,
+    /// Inputs of the function
+    inputs: Vec<String>,
+    /// Source file of the program, not needed for credits.aleo
+    #[clap(long)]
+    program: Option<PathBuf>,
+    /// Source files of the imports of the program, listed so every import comes after its own imports
+    #[clap(long = "import")]
+    imports: Vec<PathBuf>,
+    #[clap(flatten)]
+    key: KeyOptions,
+    #[clap(flatten)]
+    fee: FeeArgs,
+    #[clap(flatten)]
+    query: QueryOptions,
+    #[clap(flatten)]
+    broadcast: BroadcastOptions,
+}
+
+impl Execute {
+    pub async fn parse(self) -> Result<String> {
+        run_on_network!(self.query.network, self)
+    }
+
+    async fn run<A: Aleo>(self) -> Result<String> {
+        let private_key = self.key.private_key::<A>()?;
+        let query = self.query.query()?;
+
+        let mut program_manager = ProgramManager::<A>::new()?;
+        load_programs(&mut program_manager, &self.imports)?;
+        if let Some(path) = &self.program {
+            program_manager.add_program(&read_program(path)?)?;
+        }
+
+        println!(
+            "{}",
+            format!("Executing {}/{} on {}...", self.program_id, self.function, self.query.network).bright_blue()
+        );
+        let transaction = program_manager
+            .execute(
+                &private_key,
+                &self.program_id,
+                &self.function,
+                self.inputs.iter(),
+                self.fee.fee_options::<A>()?,
+                query,
+            )
+            .await?;
+
+        println!("{}", "Execution successful!".to_string().bright_green().bold());
+        self.broadcast.output(&self.query, &transaction).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_execute_arguments() {
+        let execute = Execute::try_parse_from([
+            "aleo",
+            "hello.aleo",
+            "hello",
+            "5u32",
+            "6u32",
+            "--program",
+            "hello.aleo",
+            "--import",
+            "a.aleo",
+            "--import",
+            "b.aleo",
+            "-k",
+            "APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6",
+            "-n",
+            "testnet",
+        ])
+        .unwrap();
+        assert_eq!(execute.inputs, ["5u32", "6u32"]);
+        assert_eq!(execute.imports, [PathBuf::from("a.aleo"), PathBuf::from("b.aleo")]);
+        assert_eq!(execute.query.network, aleo_wasm::Network::TestnetV0);
+
+        // Unknown networks are rejected
+        let execute = Execute::try_parse_from(["aleo", "credits.aleo", "join", "-n", "devnet"]);
+        assert_eq!(execute.unwrap_err().kind(), clap::error::ErrorKind::ValueValidation);
+
+        // Waiting for confirmation requires broadcasting, which requires querying the network
+        let execute = Execute::try_parse_from(["aleo", "hello.aleo", "hello", "--broadcast", "--wait"]).unwrap();
+        assert!(execute.broadcast.broadcast && execute.broadcast.wait);
+        let execute = Execute::try_parse_from(["aleo", "hello.aleo", "hello", "--wait"]);
+        assert_eq!(execute.unwrap_err().kind(), clap::error::ErrorKind::MissingRequiredArgument);
+        let execute =
+            Execute::try_parse_from(["aleo", "hello.aleo", "hello", "--broadcast", "--offline-query", "query.json"]);
+        assert_eq!(execute.unwrap_err().kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+}
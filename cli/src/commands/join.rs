@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::helpers::{parse, run_on_network, BroadcastOptions, FeeArgs, KeyOptions, QueryOptions};
+use aleo_wasm::native::{Aleo, Plaintext, ProgramManager, Record};
+
+use anyhow::Result;
+use clap::Parser;
+use colored::*;
+
+/// Joins two credits records into one
+#[derive(Debug, Parser)]
+pub struct Join {
+    /// First record to join
+    first: String,
+    /// Second record to join
+    second: String,
+    #[clap(flatten)]
+    key: KeyOptions,
+    #[clap(flatten)]
+    fee: FeeArgs,
+    #[clap(flatten)]
+    query: QueryOptions,
+    #[clap(flatten)]
+    broadcast: BroadcastOptions,
+}
+
+impl Join {
+    pub async fn parse(self) -> Result<String> {
+        run_on_network!(self.query.network, self)
+    }
+
+    async fn run<A: Aleo>(self) -> Result<String> {
+        let private_key = self.key.private_key::<A>()?;
+        let query = self.query.query()?;
+        let first = parse::<Record<A::Network, Plaintext<A::Network>>>("first record", &self.first)?;
+        let second = parse::<Record<A::Network, Plaintext<A::Network>>>("second record", &self.second)?;
+
+        println!("{}", format!("Joining records on {}...", self.query.network).bright_blue());
+        let mut program_manager = ProgramManager::<A>::new()?;
+        let transaction =
+            program_manager.join(&private_key, &first, &second, self.fee.fee_options::<A>()?, query).await?;
+
+        println!("{}", "Join successful!".to_string().bright_green().bold());
+        self.broadcast.output(&self.query, &transaction).await
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::helpers::{credits_to_microcredits, parse, run_on_network, BroadcastOptions, KeyOptions, QueryOptions};
+use aleo_wasm::native::{Aleo, Credits, Plaintext, ProgramManager, Record};
+
+use anyhow::{ensure, Result};
+use clap::Parser;
+use colored::*;
+
+/// Splits a credits record in two, splits do not pay a fee
+#[derive(Debug, Parser)]
+pub struct Split {
+    /// Record to split
+    record: String,
+    /// Number of credits to split off into the first record
+    #[clap(short, long)]
+    amount: f64,
+    #[clap(flatten)]
+    key: KeyOptions,
+    #[clap(flatten)]
+    query: QueryOptions,
+    #[clap(flatten)]
+    broadcast: BroadcastOptions,
+}
+
+impl Split {
+    pub async fn parse(self) -> Result<String> {
+        ensure!(self.amount > 0f64, "Split amount must be greater than 0 credits");
+        run_on_network!(self.query.network, self)
+    }
+
+    async fn run<A: Aleo>(self) -> Result<String> {
+        let private_key = self.key.private_key::<A>()?;
+        let query = self.query.query()?;
+        let record = parse::<Record<A::Network, Plaintext<A::Network>>>("record", &self.record)?;
+        let amount = credits_to_microcredits(self.amount)?;
+        ensure!(record.microcredits()? > amount, "The record must have more microcredits than the split amount");
+
+        println!(
+            "{}",
+            format!("Splitting {} credits off a record on {}...", self.amount, self.query.network).bright_blue()
+        );
+        let mut program_manager = ProgramManager::<A>::new()?;
+        let transaction = program_manager.split(&private_key, &record, amount, query).await?;
+
+        println!("{}", "Split successful!".to_string().bright_green().bold());
+        self.broadcast.output(&self.query, &transaction).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECORD: &str = "{  owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private,  microcredits: 1500000000000000u64.private,  _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public}";
+    const PRIVATE_KEY: &str = "APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6";
+
+    #[tokio::test]
+    async fn test_split_config_errors() {
+        // Assert the split amount must be positive
+        let split = Split::try_parse_from(["aleo", RECORD, "-a", "0", "-k", PRIVATE_KEY]).unwrap();
+        assert!(split.parse().await.is_err());
+
+        // Assert the split amount must be smaller than the record
+        let split = Split::try_parse_from(["aleo", RECORD, "-a", "1500000000", "-k", PRIVATE_KEY]).unwrap();
+        assert!(split.parse().await.is_err());
+
+        // Assert the record must be valid
+        let split = Split::try_parse_from(["aleo", "{ owner: aleo1 }", "-a", "1", "-k", PRIVATE_KEY]).unwrap();
+        assert!(split.parse().await.is_err());
+    }
+}
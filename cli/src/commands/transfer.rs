@@ -0,0 +1,210 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::helpers::{
+    credits_to_microcredits,
+    parse,
+    run_on_network,
+    BroadcastOptions,
+    FeeArgs,
+    KeyOptions,
+    QueryOptions,
+};
+use aleo_wasm::native::{Address, Aleo, Credits, Plaintext, ProgramManager, Record};
+
+use anyhow::{ensure, Result};
+use clap::Parser;
+use colored::*;
+
+/// Executes a transfer of Aleo credits
+#[derive(Debug, Parser)]
+pub struct Transfer {
+    /// Recipient address
+    #[clap(short, long)]
+    recipient: String,
+    /// Number of credits to transfer
+    #[clap(short, long)]
+    amount: f64,
+    /// Record used to fund the transfer privately, the amount is transferred from the public
+    /// balance if it is not set
+    #[clap(long)]
+    amount_record: Option<String>,
+    #[clap(flatten)]
+    key: KeyOptions,
+    #[clap(flatten)]
+    fee: FeeArgs,
+    #[clap(flatten)]
+    query: QueryOptions,
+    #[clap(flatten)]
+    broadcast: BroadcastOptions,
+}
+
+impl Transfer {
+    pub async fn parse(self) -> Result<String> {
+        // Check for config errors
+        ensure!(self.amount > 0f64, "Transfer amount must be greater than 0 credits");
+        run_on_network!(self.query.network, self)
+    }
+
+    async fn run<A: Aleo>(self) -> Result<String> {
+        let private_key = self.key.private_key::<A>()?;
+        let query = self.query.query()?;
+        let recipient = parse::<Address<A::Network>>("recipient", &self.recipient)?;
+        let amount = credits_to_microcredits(self.amount)?;
+        let amount_record = self
+            .amount_record
+            .as_deref()
+            .map(|record| parse::<Record<A::Network, Plaintext<A::Network>>>("amount record", record))
+            .transpose()?;
+        if let Some(record) = &amount_record {
+            ensure!(
+                record.microcredits()? >= amount,
+                "Amount record must have at least as many microcredits as the transfer amount specified"
+            );
+        }
+
+        println!(
+            "{}",
+            format!("Attempting to transfer {} credits to {} on {}...", self.amount, recipient, self.query.network)
+                .bright_blue()
+        );
+
+        let mut program_manager = ProgramManager::<A>::new()?;
+        let transaction = program_manager
+            .transfer(&private_key, &recipient, amount, amount_record, self.fee.fee_options::<A>()?, query)
+            .await?;
+
+        println!("{}", "Transfer successful!".to_string().bright_green().bold());
+        self.broadcast.output(&self.query, &transaction).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CurrentNetwork;
+    use aleo_wasm::native::{encrypt_private_key, PrivateKey};
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    #[tokio::test]
+    async fn test_transfer_config_errors() {
+        let recipient_private_key = PrivateKey::<CurrentNetwork>::new(&mut ChaChaRng::seed_from_u64(5)).unwrap();
+        let recipient_address = Address::<CurrentNetwork>::try_from(&recipient_private_key).unwrap();
+        let ciphertext = encrypt_private_key(&recipient_private_key, "password").unwrap();
+
+        // Assert that the transfer fails without a private key or private key ciphertext
+        let transfer_missing_key_material =
+            Transfer::try_parse_from(["aleo", "-r", &recipient_address.to_string(), "-a", "1.0"]);
+        assert!(transfer_missing_key_material.unwrap().parse().await.is_err());
+
+        // Assert transfer fails if both a private key and ciphertext are provided
+        let transfer_conflicting_inputs = Transfer::try_parse_from([
+            "aleo",
+            "-r",
+            &recipient_address.to_string(),
+            "-a",
+            "2.0",
+            "-k",
+            &recipient_private_key.to_string(),
+            "--ciphertext",
+            &ciphertext.to_string(),
+            "--password",
+            "password",
+        ]);
+        assert_eq!(transfer_conflicting_inputs.unwrap_err().kind(), clap::error::ErrorKind::ArgumentConflict);
+
+        // Assert transfer fails if a ciphertext is provided without a password
+        let transfer_no_password = Transfer::try_parse_from([
+            "aleo",
+            "-r",
+            &recipient_address.to_string(),
+            "-a",
+            "3.0",
+            "--ciphertext",
+            &ciphertext.to_string(),
+        ]);
+        assert_eq!(transfer_no_password.unwrap_err().kind(), clap::error::ErrorKind::MissingRequiredArgument);
+
+        // Assert transfer fails if a password is provided without a ciphertext
+        let transfer_password_only = Transfer::try_parse_from([
+            "aleo",
+            "-r",
+            &recipient_address.to_string(),
+            "-a",
+            "4.0",
+            "--password",
+            "password",
+        ]);
+        assert_eq!(transfer_password_only.unwrap_err().kind(), clap::error::ErrorKind::MissingRequiredArgument);
+
+        // Assert transfer fails if the endpoint and an offline query are both provided
+        let transfer_conflicting_queries = Transfer::try_parse_from([
+            "aleo",
+            "-r",
+            &recipient_address.to_string(),
+            "-k",
+            &recipient_private_key.to_string(),
+            "-a",
+            "5.0",
+            "-e",
+            "http://localhost:3030",
+            "--offline-query",
+            "query.json",
+        ]);
+        assert_eq!(transfer_conflicting_queries.unwrap_err().kind(), clap::error::ErrorKind::ArgumentConflict);
+
+        // Assert transfer fails if the endpoint is not a url
+        let transfer_bad_peer = Transfer::try_parse_from([
+            "aleo",
+            "-r",
+            &recipient_address.to_string(),
+            "-k",
+            &recipient_private_key.to_string(),
+            "-a",
+            "5.0",
+            "-e",
+            "localhost:3030",
+        ]);
+        assert!(transfer_bad_peer.unwrap().parse().await.is_err());
+
+        // Assert transfer fails if the transfer amount is zero
+        let transfer_zero_amount = Transfer::try_parse_from([
+            "aleo",
+            "-r",
+            &recipient_address.to_string(),
+            "-k",
+            &recipient_private_key.to_string(),
+            "-a",
+            "0.0",
+            "-e",
+            "http://localhost:3030",
+        ]);
+        assert!(transfer_zero_amount.unwrap().parse().await.is_err());
+
+        // Assert transfer fails if the recipient is not an address
+        let transfer_bad_recipient = Transfer::try_parse_from([
+            "aleo",
+            "-r",
+            "aleo1notanaddress",
+            "-k",
+            &recipient_private_key.to_string(),
+            "-a",
+            "1.0",
+        ]);
+        assert!(transfer_bad_recipient.unwrap().parse().await.is_err());
+    }
+}
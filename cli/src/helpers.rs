@@ -0,0 +1,275 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::CurrentNetwork;
+use aleo_wasm::{
+    native::{
+        decrypt_private_key,
+        Address,
+        Aleo,
+        FeeOptions,
+        NetworkQuery,
+        NetworkTrait,
+        OfflineQuery,
+        PrivateKey,
+        PrivateKeyCiphertext,
+        Program,
+        ProgramManager,
+        StateQuery,
+        Transaction,
+        TransactionStatus,
+        ViewKey,
+        CONFIRMATION_INTERVAL,
+        CONFIRMATION_TIMEOUT,
+    },
+    Network,
+};
+
+use anyhow::{anyhow, ensure, Result};
+use clap::Args;
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Account keys written to and read from disk by the account commands
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccountModel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key_ciphertext: Option<PrivateKeyCiphertext>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<PrivateKey<CurrentNetwork>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub view_key: Option<ViewKey<CurrentNetwork>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address<CurrentNetwork>>,
+}
+
+/// Network the transaction is built for and where the state it is proven against comes from
+#[derive(Debug, Args)]
+pub struct QueryOptions {
+    /// Network to build the transaction for
+    #[clap(short = 'n', long, default_value_t = Network::MainnetV0)]
+    pub network: Network,
+    /// Node endpoint to fetch the state root and record state paths from. When repeated, the
+    /// endpoints are tried in order.
+    #[clap(short = 'e', long = "endpoint", conflicts_with = "offline_query")]
+    pub endpoints: Vec<String>,
+    /// Header sent with every request to the endpoints, e.g. an API key
+    #[clap(long = "header", value_name = "NAME:VALUE", conflicts_with = "offline_query")]
+    pub headers: Vec<String>,
+    /// Build the transaction offline against the state root and state paths of an offline query file
+    #[clap(long)]
+    pub offline_query: Option<PathBuf>,
+}
+
+impl QueryOptions {
+    /// Get the query proofs of inclusion are created with
+    pub fn query(&self) -> Result<StateQuery> {
+        if let Some(path) = &self.offline_query {
+            let query = OfflineQuery::from_string(&fs::read_to_string(path)?).map_err(|e| anyhow!(e))?;
+            return Ok(StateQuery::Offline(query));
+        }
+        Ok(StateQuery::Network(self.network_query()?))
+    }
+
+    /// Get the query of the endpoints, sending the headers with every request
+    pub fn network_query(&self) -> Result<NetworkQuery> {
+        let mut query = match self.endpoints.is_empty() {
+            true => NetworkQuery::default(),
+            false => NetworkQuery::from_endpoints(&self.endpoints).map_err(|e| anyhow!(e))?,
+        };
+        for header in &self.headers {
+            let (name, value) =
+                header.split_once(':').ok_or_else(|| anyhow!("Header {header} must be formatted as NAME:VALUE"))?;
+            query.set_header(name.trim(), value.trim()).map_err(|e| anyhow!(e))?;
+        }
+        Ok(query)
+    }
+}
+
+/// Whether the transaction is broadcast to the endpoints and its confirmation awaited
+#[derive(Debug, Args)]
+pub struct BroadcastOptions {
+    /// Broadcast the transaction to the endpoints instead of printing it
+    #[clap(long, conflicts_with = "offline_query")]
+    pub broadcast: bool,
+    /// Wait until the broadcast transaction is accepted, rejected or aborted in a block
+    #[clap(long, requires = "broadcast")]
+    pub wait: bool,
+}
+
+impl BroadcastOptions {
+    /// Get the output of a command building a transaction: the transaction itself, or its id once
+    /// it was broadcast. Rejected and aborted transactions fail the command.
+    pub async fn output<N: NetworkTrait>(&self, query: &QueryOptions, transaction: &Transaction<N>) -> Result<String> {
+        if !self.broadcast {
+            return Ok(transaction.to_string());
+        }
+        let query = query.network_query()?;
+        let id = query.submit_native_transaction(transaction).await?;
+        println!("{}", format!("Broadcast transaction {id}").bright_green());
+        if !self.wait {
+            return Ok(id.to_string());
+        }
+
+        println!("{}", format!("Waiting for transaction {id} to be confirmed...").bright_blue());
+        let confirmation =
+            query.wait_for_native_confirmation::<N>(&id, CONFIRMATION_TIMEOUT, CONFIRMATION_INTERVAL).await?;
+        let height = confirmation.block_height();
+        match confirmation.status() {
+            TransactionStatus::Accepted => {
+                println!("{}", format!("Transaction accepted in block {height}").bright_green().bold());
+                Ok(id.to_string())
+            }
+            TransactionStatus::Rejected => Err(anyhow!(
+                "Transaction {id} was rejected in block {height}, its fee was paid by transaction {}",
+                confirmation.fee_transaction_id().unwrap_or_default()
+            )),
+            TransactionStatus::Aborted => Err(anyhow!("Transaction {id} was aborted in block {height}")),
+        }
+    }
+}
+
+/// Private key of the account signing the transaction, in plaintext or encrypted with a password
+#[derive(Debug, Args)]
+pub struct KeyOptions {
+    /// Private key plaintext
+    #[clap(short = 'k', long, conflicts_with_all = &["ciphertext", "password"])]
+    pub private_key: Option<String>,
+    /// Private key ciphertext
+    #[clap(short = 'c', long, conflicts_with = "private_key", requires = "password")]
+    pub ciphertext: Option<String>,
+    /// Password to decrypt the private key ciphertext
+    #[clap(short = 'p', long, conflicts_with = "private_key", requires = "ciphertext")]
+    pub password: Option<String>,
+}
+
+impl KeyOptions {
+    /// Get the private key, decrypting it if a ciphertext was provided
+    pub fn private_key<A: Aleo>(&self) -> Result<PrivateKey<A::Network>> {
+        match (&self.private_key, &self.ciphertext, &self.password) {
+            (Some(private_key), _, _) => parse("private key", private_key),
+            (None, Some(ciphertext), Some(password)) => {
+                let ciphertext = parse::<PrivateKeyCiphertext>("private key ciphertext", ciphertext)?;
+                decrypt_private_key(&ciphertext, password).map_err(|_| anyhow!("❌ Incorrect password"))
+            }
+            _ => Err(anyhow!("Private key or private key ciphertext required")),
+        }
+    }
+}
+
+/// Fee paid on top of the base fee and the record it is paid from
+#[derive(Debug, Args)]
+pub struct FeeArgs {
+    /// Priority fee in credits paid on top of the base fee
+    #[clap(long, default_value_t = 0.0)]
+    pub priority_fee: f64,
+    /// Record to pay the fee from privately, the fee is paid from the public balance if it is not set
+    #[clap(long)]
+    pub fee_record: Option<String>,
+}
+
+impl FeeArgs {
+    /// Get the fee options of the program manager
+    pub fn fee_options<A: Aleo>(&self) -> Result<FeeOptions<A::Network>> {
+        Ok(FeeOptions {
+            priority_fee: credits_to_microcredits(self.priority_fee)?,
+            record: self.fee_record.as_deref().map(|record| parse("fee record", record)).transpose()?,
+        })
+    }
+}
+
+/// Parse a value of the network of the command, naming the argument if it is invalid
+pub fn parse<T: FromStr>(name: &str, value: &str) -> Result<T>
+where
+    T::Err: Display,
+{
+    T::from_str(value).map_err(|e| anyhow!("❌ Invalid {name} '{value}': {e}"))
+}
+
+/// Convert an amount of credits into microcredits
+pub fn credits_to_microcredits(credits: f64) -> Result<u64> {
+    ensure!(credits.is_finite() && credits >= 0.0, "Credit amounts must be non-negative numbers");
+    let microcredits = (credits * 1_000_000.0).round();
+    // `u64::MAX as f64` rounds up to 2^64, which is already out of range
+    ensure!(microcredits < u64::MAX as f64, "{credits} credits exceeds the maximum amount of microcredits");
+    Ok(microcredits as u64)
+}
+
+/// Add programs to the program manager in order, so every program comes after its imports
+pub fn load_programs<A: Aleo>(program_manager: &mut ProgramManager<A>, paths: &[PathBuf]) -> Result<()> {
+    for path in paths {
+        program_manager.add_program(&read_program(path)?)?;
+    }
+    Ok(())
+}
+
+/// Read and parse a program from a file
+pub fn read_program<N: NetworkTrait>(path: &Path) -> Result<Program<N>> {
+    parse(&format!("program {}", path.display()), &fs::read_to_string(path)?)
+}
+
+/// Run a command with the program manager of the network it was given
+macro_rules! run_on_network {
+    ($network:expr, $command:expr) => {
+        match $network {
+            aleo_wasm::Network::MainnetV0 => $command.run::<aleo_wasm::native::mainnet::CurrentAleo>().await,
+            aleo_wasm::Network::TestnetV0 => $command.run::<aleo_wasm::native::testnet::CurrentAleo>().await,
+            aleo_wasm::Network::CanaryV0 => $command.run::<aleo_wasm::native::canary::CurrentAleo>().await,
+        }
+    };
+}
+pub(crate) use run_on_network;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_credits_to_microcredits() {
+        assert_eq!(credits_to_microcredits(0.0).unwrap(), 0);
+        assert_eq!(credits_to_microcredits(1.5).unwrap(), 1_500_000);
+        assert_eq!(credits_to_microcredits(0.000001).unwrap(), 1);
+        assert!(credits_to_microcredits(-1.0).is_err());
+        assert!(credits_to_microcredits(f64::NAN).is_err());
+        assert!(credits_to_microcredits(f64::MAX).is_err());
+        assert!(credits_to_microcredits(u64::MAX as f64 / 1_000_000.0).is_err());
+    }
+
+    #[test]
+    fn test_query_options() {
+        let options = |endpoints: &[&str], headers: &[&str]| QueryOptions {
+            network: Network::MainnetV0,
+            endpoints: endpoints.iter().map(|endpoint| endpoint.to_string()).collect(),
+            headers: headers.iter().map(|header| header.to_string()).collect(),
+            offline_query: None,
+        };
+
+        let mut expected = NetworkQuery::from_endpoints(["https://a.example", "https://b.example"]).unwrap();
+        expected.set_header("X-API-Key", "secret").unwrap();
+        let query = options(&["https://a.example/", "https://b.example"], &["X-API-Key: secret"]).query().unwrap();
+        assert_eq!(query, StateQuery::Network(expected));
+        assert_eq!(options(&[], &[]).query().unwrap(), StateQuery::Network(NetworkQuery::default()));
+
+        assert!(options(&["localhost:3030"], &[]).query().is_err());
+        assert!(options(&[], &["X-API-Key"]).query().is_err());
+    }
+}
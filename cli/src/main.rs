@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+//! `aleo` command line interface for managing accounts and building transactions with the native
+//! program manager of the SDK
+
+mod commands;
+use commands::*;
+
+mod helpers;
+
+use clap::Parser;
+use colored::*;
+
+/// Account keys are network agnostic and use the types of the default network
+pub(crate) type CurrentNetwork = aleo_wasm::native::mainnet::CurrentNetwork;
+
+/// Aleo command line interface
+#[derive(Debug, Parser)]
+#[clap(name = "aleo", author = "The Provable Team", version)]
+pub struct Cli {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Parser)]
+pub enum Command {
+    #[clap(subcommand)]
+    Account(Account),
+    Deploy(Deploy),
+    #[clap(subcommand)]
+    Estimate(Estimate),
+    Execute(Execute),
+    Join(Join),
+    Split(Split),
+    Transfer(Transfer),
+}
+
+impl Command {
+    pub async fn parse(self) -> anyhow::Result<String> {
+        match self {
+            Self::Account(command) => command.parse(),
+            Self::Deploy(command) => command.parse().await,
+            Self::Estimate(command) => command.parse().await,
+            Self::Execute(command) => command.parse().await,
+            Self::Join(command) => command.parse().await,
+            Self::Split(command) => command.parse().await,
+            Self::Transfer(command) => command.parse().await,
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+    match cli.command.parse().await {
+        Ok(output) => println!("{output}"),
+        Err(error) => {
+            eprintln!("{} {error}", "Error:".red().bold());
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_subcommands() {
+        let cli = Cli::try_parse_from(["aleo", "account", "new", "--seed", "1"]).unwrap();
+        assert!(matches!(cli.command, Command::Account(Account::New { seed: Some(1), .. })));
+
+        let cli = Cli::try_parse_from(["aleo", "account", "new", "--mnemonic"]).unwrap();
+        assert!(matches!(cli.command, Command::Account(Account::New { mnemonic: Some(24), .. })));
+        assert!(Cli::try_parse_from(["aleo", "account", "new", "--mnemonic", "12", "--seed", "1"]).is_err());
+        assert!(Cli::try_parse_from(["aleo", "account", "import"]).is_err());
+
+        let cli = Cli::try_parse_from(["aleo", "estimate", "deploy", "program.aleo", "-n", "canary"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Estimate(Estimate::Deploy { network: aleo_wasm::Network::CanaryV0, .. })
+        ));
+
+        assert!(Cli::try_parse_from(["aleo", "stake"]).is_err());
+    }
+}
@@ -2,6 +2,7 @@ import {
   Address,
   PrivateKey,
   Signature,
+  SignedValue,
   ViewKey,
   PrivateKeyCiphertext,
   RecordCiphertext,
@@ -77,6 +78,39 @@ export class Account {
     }
   }
 
+  /**
+   * Attempts to create an account from a JSON keystore written by the SDK or the Aleo CLI
+   * @param {string} keystore
+   * @param {string} password
+   * @returns {Account}
+   *
+   * @example
+   * const keystore = new Account().toKeystore("password");
+   * const account = Account.fromKeystore(keystore, "password");
+   */
+  public static fromKeystore(keystore: string, password: string) {
+    try {
+      const _privateKey = PrivateKey.fromKeystore(keystore, password);
+      return new Account({ privateKey: _privateKey.to_string() });
+    } catch(e) {
+      throw new Error("Wrong password or invalid keystore");
+    }
+  }
+
+  /**
+   * Attempts to create an account from a BIP-39 mnemonic phrase and optional passphrase
+   * @param {string} phrase
+   * @param {string} passphrase
+   * @returns {Account}
+   *
+   * @example
+   * const account = Account.fromMnemonic("abandon abandon ... about", "passphrase");
+   */
+  public static fromMnemonic(phrase: string, passphrase?: string) {
+    const _privateKey = PrivateKey.fromMnemonic(phrase, passphrase);
+    return new Account({ privateKey: _privateKey.to_string() });
+  }
+
   private privateKeyFromParams(params: AccountParam) {
     if (params.seed) {
       return PrivateKey.from_seed_unchecked(params.seed);
@@ -116,6 +150,20 @@ export class Account {
     return this._privateKey.toCiphertext(password);
   }
 
+  /**
+   * Encrypt the account's private key with a password into a JSON keystore which can be read by
+   * the Aleo CLI and other tools supporting the keystore format
+   * @param {string} password
+   * @returns {string}
+   *
+   * @example
+   * const account = new Account();
+   * const keystore = account.toKeystore("password");
+   */
+  toKeystore(password: string) {
+    return this._privateKey.toKeystore(password);
+  }
+
   /**
    * Decrypts a Record in ciphertext form into plaintext
    * @param {string} ciphertext
@@ -212,4 +260,38 @@ export class Account {
     return this._address.verify(message, signature);
   }
 
+  /**
+   * Signs an Aleo value (a literal, struct or array) so that the signature is accepted by
+   * `signature::verify` in Leo. The signing nonce is sampled from the seed, so the signature is
+   * deterministic for a seed.
+   *
+   * @param {string} value
+   * @param {Uint8Array} seed 32 random bytes
+   * @returns {SignedValue}
+   *
+   * @example
+   * const account = new Account();
+   * const signed = account.signValue("{ amount: 5u64 }", crypto.getRandomValues(new Uint8Array(32)));
+   * const signature = signed.signature().to_string();
+   */
+  signValue(value: string, seed: Uint8Array): SignedValue {
+    return Signature.signValue(this._privateKey, value, seed);
+  }
+
+  /**
+   * Verifies a signature of an Aleo value made with `signValue`.
+   *
+   * @param {string} value
+   * @param {Signature} signature
+   * @returns {boolean}
+   *
+   * @example
+   * const account = new Account();
+   * const signed = account.signValue("{ amount: 5u64 }", crypto.getRandomValues(new Uint8Array(32)));
+   * account.verifyValue("{ amount: 5u64 }", signed.signature());
+   */
+  verifyValue(value: string, signature: Signature): boolean {
+    return signature.verifyValue(this._address, value);
+  }
+
 }
@@ -6,6 +6,7 @@ import { Block } from "./models/block";
 import { Execution } from "./models/execution";
 import { Input } from "./models/input";
 import { Output } from "./models/output";
+import { SdkError, SdkErrorCode, isSdkError } from "./models/sdkError";
 import { TransactionModel } from "./models/transactionModel";
 import { Transition } from "./models/transition";
 import {
@@ -41,19 +42,37 @@ export { logAndThrow } from "./utils";
 
 export {
     Address,
+    Authorization,
+    DeploymentCost,
     Execution as FunctionExecution,
+    ExecutionCost,
     ExecutionResponse,
+    ExtendedPrivateKey,
     Field,
+    FunctionCost,
+    KdfParams,
+    Keystore,
+    LocalLedger,
+    Mnemonic,
+    Network,
+    NetworkQuery,
     OfflineQuery,
+    OwnedRecord,
     PrivateKey,
     PrivateKeyCiphertext,
     Program,
     ProgramManager as ProgramManagerBase,
+    ProgressStage,
     ProvingKey,
     RecordCiphertext,
     RecordPlaintext,
     Signature,
+    SignedValue,
     Transaction,
+    TransactionConfirmation,
+    TransactionStatus,
+    Transition as FunctionTransition,
+    TransitionEntry,
     VerifyingKey,
     ViewKey,
     initThreadPool,
@@ -66,6 +85,7 @@ export {
     Key,
     CREDITS_PROGRAM_KEYS,
     KEY_STORE,
+    NETWORK,
     PRIVATE_TRANSFER,
     PRIVATE_TO_PUBLIC_TRANSFER,
     PRIVATE_TRANSFER_TYPES,
@@ -96,6 +116,9 @@ export {
     Output,
     RecordProvider,
     RecordSearchParams,
+    SdkError,
+    SdkErrorCode,
     TransactionModel,
     Transition,
+    isSdkError,
 };
@@ -1,6 +1,9 @@
-import {VerifyingKey, Metadata} from "./wasm";
+import {VerifyingKey, Metadata, Network} from "./wasm";
 
-export const KEY_STORE = Metadata.baseUrl();
+// The network this build of the SDK targets
+export const NETWORK: Network = "%%NETWORK%%" === "mainnet" ? Network.MainnetV0 : Network.TestnetV0;
+
+export const KEY_STORE = Metadata.baseUrl(NETWORK);
 
 export interface Key {
     name: string,
@@ -12,9 +15,9 @@ export interface Key {
 
 function convert(metadata: Metadata): Key {
     // This looks up the method name in VerifyingKey
-    const verifyingKey = (VerifyingKey as any)[metadata.verifyingKey];
+    const getVerifyingKey = (VerifyingKey as any)[metadata.verifyingKey];
 
-    if (!verifyingKey) {
+    if (!getVerifyingKey) {
         throw new Error("Invalid method name: " + metadata.verifyingKey);
     }
 
@@ -23,26 +26,26 @@ function convert(metadata: Metadata): Key {
         locator: metadata.locator,
         prover: metadata.prover,
         verifier: metadata.verifier,
-        verifyingKey,
+        verifyingKey: () => getVerifyingKey(NETWORK),
     };
 }
 
 export const CREDITS_PROGRAM_KEYS = {
-    bond_public: convert(Metadata.bond_public()),
-    bond_validator: convert(Metadata.bond_validator()),
-    claim_unbond_public: convert(Metadata.claim_unbond_public()),
-    fee_private: convert(Metadata.fee_private()),
-    fee_public: convert(Metadata.fee_public()),
-    inclusion: convert(Metadata.inclusion()),
-    join: convert(Metadata.join()),
-    set_validator_state: convert(Metadata.set_validator_state()),
-    split: convert(Metadata.split()),
-    transfer_private: convert(Metadata.transfer_private()),
-    transfer_private_to_public: convert(Metadata.transfer_private_to_public()),
-    transfer_public: convert(Metadata.transfer_public()),
-    transfer_public_as_signer: convert(Metadata.transfer_public_as_signer()),
-    transfer_public_to_private: convert(Metadata.transfer_public_to_private()),
-    unbond_public: convert(Metadata.unbond_public()),
+    bond_public: convert(Metadata.bond_public(NETWORK)),
+    bond_validator: convert(Metadata.bond_validator(NETWORK)),
+    claim_unbond_public: convert(Metadata.claim_unbond_public(NETWORK)),
+    fee_private: convert(Metadata.fee_private(NETWORK)),
+    fee_public: convert(Metadata.fee_public(NETWORK)),
+    inclusion: convert(Metadata.inclusion(NETWORK)),
+    join: convert(Metadata.join(NETWORK)),
+    set_validator_state: convert(Metadata.set_validator_state(NETWORK)),
+    split: convert(Metadata.split(NETWORK)),
+    transfer_private: convert(Metadata.transfer_private(NETWORK)),
+    transfer_private_to_public: convert(Metadata.transfer_private_to_public(NETWORK)),
+    transfer_public: convert(Metadata.transfer_public(NETWORK)),
+    transfer_public_as_signer: convert(Metadata.transfer_public_as_signer(NETWORK)),
+    transfer_public_to_private: convert(Metadata.transfer_public_to_private(NETWORK)),
+    unbond_public: convert(Metadata.unbond_public(NETWORK)),
     getKey: function(key: string): Key {
         if (this.hasOwnProperty(key)) {
             return (this as any)[key] as Key;
@@ -0,0 +1,30 @@
+export type SdkErrorCode =
+    | "INSUFFICIENT_FEE"
+    | "INVALID_INPUT"
+    | "INVALID_ARGUMENT"
+    | "PROGRAM_NOT_FOUND"
+    | "PROGRAM_MISMATCH"
+    | "KEY_MISMATCH"
+    | "IMPORT_MISSING"
+    | "QUERY_FAILED"
+    | "PROOF_FAILED"
+    | "NETWORK_MISMATCH"
+    | "ABORTED"
+    | "BUSY"
+    | "REJECTED"
+    | "UNKNOWN";
+
+export type SdkError = Error & {
+    name: "SdkError";
+    code: SdkErrorCode;
+    required?: number;
+    available?: number;
+    index?: number;
+    programId?: string;
+    expected?: string;
+    found?: string;
+}
+
+export function isSdkError(error: unknown): error is SdkError {
+    return error instanceof Error && error.name === "SdkError" && "code" in error;
+}
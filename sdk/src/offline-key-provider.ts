@@ -12,6 +12,7 @@ import {
 
 import {
     CREDITS_PROGRAM_KEYS,
+    NETWORK,
     PRIVATE_TRANSFER,
     PRIVATE_TO_PUBLIC_TRANSFER,
     PUBLIC_TRANSFER,
@@ -311,31 +312,31 @@ class OfflineKeyProvider implements FunctionKeyProvider {
     verifyCreditsKeys(locator: string, provingKey: ProvingKey, verifyingKey: VerifyingKey): boolean {
         switch (locator) {
             case CREDITS_PROGRAM_KEYS.bond_public.locator:
-                return provingKey.isBondPublicProver() && verifyingKey.isBondPublicVerifier();
+                return provingKey.isBondPublicProver(NETWORK) && verifyingKey.isBondPublicVerifier(NETWORK);
             case CREDITS_PROGRAM_KEYS.claim_unbond_public.locator:
-                return provingKey.isClaimUnbondPublicProver() && verifyingKey.isClaimUnbondPublicVerifier();
+                return provingKey.isClaimUnbondPublicProver(NETWORK) && verifyingKey.isClaimUnbondPublicVerifier(NETWORK);
             case CREDITS_PROGRAM_KEYS.fee_private.locator:
-                return provingKey.isFeePrivateProver() && verifyingKey.isFeePrivateVerifier();
+                return provingKey.isFeePrivateProver(NETWORK) && verifyingKey.isFeePrivateVerifier(NETWORK);
             case CREDITS_PROGRAM_KEYS.fee_public.locator:
-                return provingKey.isFeePublicProver() && verifyingKey.isFeePublicVerifier();
+                return provingKey.isFeePublicProver(NETWORK) && verifyingKey.isFeePublicVerifier(NETWORK);
             case CREDITS_PROGRAM_KEYS.inclusion.locator:
-                return provingKey.isInclusionProver() && verifyingKey.isInclusionVerifier();
+                return provingKey.isInclusionProver(NETWORK) && verifyingKey.isInclusionVerifier(NETWORK);
             case CREDITS_PROGRAM_KEYS.join.locator:
-                return provingKey.isJoinProver() && verifyingKey.isJoinVerifier();
+                return provingKey.isJoinProver(NETWORK) && verifyingKey.isJoinVerifier(NETWORK);
             case CREDITS_PROGRAM_KEYS.set_validator_state.locator:
-                return provingKey.isSetValidatorStateProver() && verifyingKey.isSetValidatorStateVerifier();
+                return provingKey.isSetValidatorStateProver(NETWORK) && verifyingKey.isSetValidatorStateVerifier(NETWORK);
             case CREDITS_PROGRAM_KEYS.split.locator:
-                return provingKey.isSplitProver() && verifyingKey.isSplitVerifier();
+                return provingKey.isSplitProver(NETWORK) && verifyingKey.isSplitVerifier(NETWORK);
             case CREDITS_PROGRAM_KEYS.transfer_private.locator:
-                return provingKey.isTransferPrivateProver() && verifyingKey.isTransferPrivateVerifier();
+                return provingKey.isTransferPrivateProver(NETWORK) && verifyingKey.isTransferPrivateVerifier(NETWORK);
             case CREDITS_PROGRAM_KEYS.transfer_private_to_public.locator:
-                return provingKey.isTransferPrivateToPublicProver() && verifyingKey.isTransferPrivateToPublicVerifier();
+                return provingKey.isTransferPrivateToPublicProver(NETWORK) && verifyingKey.isTransferPrivateToPublicVerifier(NETWORK);
             case CREDITS_PROGRAM_KEYS.transfer_public.locator:
-                return provingKey.isTransferPublicProver() && verifyingKey.isTransferPublicVerifier();
+                return provingKey.isTransferPublicProver(NETWORK) && verifyingKey.isTransferPublicVerifier(NETWORK);
             case CREDITS_PROGRAM_KEYS.transfer_public_to_private.locator:
-                return provingKey.isTransferPublicToPrivateProver() && verifyingKey.isTransferPublicToPrivateVerifier();
+                return provingKey.isTransferPublicToPrivateProver(NETWORK) && verifyingKey.isTransferPublicToPrivateVerifier(NETWORK);
             case CREDITS_PROGRAM_KEYS.unbond_public.locator:
-                return provingKey.isUnbondPublicProver() && verifyingKey.isUnbondPublicVerifier();
+                return provingKey.isUnbondPublicProver(NETWORK) && verifyingKey.isUnbondPublicVerifier(NETWORK);
             default:
                 return false;
         }
@@ -436,8 +437,8 @@ class OfflineKeyProvider implements FunctionKeyProvider {
      * @param provingKey
      */
     insertBondPublicKeys(provingKey: ProvingKey) {
-        if (provingKey.isBondPublicProver()) {
-            this.cache.set(CREDITS_PROGRAM_KEYS.bond_public.locator, [provingKey.toBytes(), VerifyingKey.bondPublicVerifier().toBytes()]);
+        if (provingKey.isBondPublicProver(NETWORK)) {
+            this.cache.set(CREDITS_PROGRAM_KEYS.bond_public.locator, [provingKey.toBytes(), VerifyingKey.bondPublicVerifier(NETWORK).toBytes()]);
         } else {
             throw new Error("Attempted to insert invalid proving keys for bond_public");
         }
@@ -451,8 +452,8 @@ class OfflineKeyProvider implements FunctionKeyProvider {
      * @param provingKey
      */
     insertClaimUnbondPublicKeys(provingKey: ProvingKey) {
-        if (provingKey.isClaimUnbondPublicProver()) {
-            this.cache.set(CREDITS_PROGRAM_KEYS.claim_unbond_public.locator, [provingKey.toBytes(), VerifyingKey.claimUnbondPublicVerifier().toBytes()]);
+        if (provingKey.isClaimUnbondPublicProver(NETWORK)) {
+            this.cache.set(CREDITS_PROGRAM_KEYS.claim_unbond_public.locator, [provingKey.toBytes(), VerifyingKey.claimUnbondPublicVerifier(NETWORK).toBytes()]);
         } else {
             throw new Error("Attempted to insert invalid proving keys for claim_unbond_public");
         }
@@ -466,8 +467,8 @@ class OfflineKeyProvider implements FunctionKeyProvider {
      * @param provingKey
      */
     insertFeePrivateKeys(provingKey: ProvingKey) {
-        if (provingKey.isFeePrivateProver()) {
-            this.cache.set(CREDITS_PROGRAM_KEYS.fee_private.locator, [provingKey.toBytes(), VerifyingKey.feePrivateVerifier().toBytes()]);
+        if (provingKey.isFeePrivateProver(NETWORK)) {
+            this.cache.set(CREDITS_PROGRAM_KEYS.fee_private.locator, [provingKey.toBytes(), VerifyingKey.feePrivateVerifier(NETWORK).toBytes()]);
         } else {
             throw new Error("Attempted to insert invalid proving keys for fee_private");
         }
@@ -481,8 +482,8 @@ class OfflineKeyProvider implements FunctionKeyProvider {
      * @param provingKey
      */
     insertFeePublicKeys(provingKey: ProvingKey) {
-        if (provingKey.isFeePublicProver()) {
-            this.cache.set(CREDITS_PROGRAM_KEYS.fee_public.locator, [provingKey.toBytes(), VerifyingKey.feePublicVerifier().toBytes()]);
+        if (provingKey.isFeePublicProver(NETWORK)) {
+            this.cache.set(CREDITS_PROGRAM_KEYS.fee_public.locator, [provingKey.toBytes(), VerifyingKey.feePublicVerifier(NETWORK).toBytes()]);
         } else {
             throw new Error("Attempted to insert invalid proving keys for fee_public");
         }
@@ -496,8 +497,8 @@ class OfflineKeyProvider implements FunctionKeyProvider {
      * @param provingKey
      */
     insertJoinKeys(provingKey: ProvingKey) {
-        if (provingKey.isJoinProver()) {
-            this.cache.set(CREDITS_PROGRAM_KEYS.join.locator, [provingKey.toBytes(), VerifyingKey.joinVerifier().toBytes()]);
+        if (provingKey.isJoinProver(NETWORK)) {
+            this.cache.set(CREDITS_PROGRAM_KEYS.join.locator, [provingKey.toBytes(), VerifyingKey.joinVerifier(NETWORK).toBytes()]);
         } else {
             throw new Error("Attempted to insert invalid proving keys for join");
         }
@@ -511,8 +512,8 @@ class OfflineKeyProvider implements FunctionKeyProvider {
      * @param provingKey
      */
     insertSetValidatorStateKeys(provingKey: ProvingKey) {
-        if (provingKey.isSetValidatorStateProver()) {
-            this.cache.set(CREDITS_PROGRAM_KEYS.set_validator_state.locator, [provingKey.toBytes(), VerifyingKey.setValidatorStateVerifier().toBytes()]);
+        if (provingKey.isSetValidatorStateProver(NETWORK)) {
+            this.cache.set(CREDITS_PROGRAM_KEYS.set_validator_state.locator, [provingKey.toBytes(), VerifyingKey.setValidatorStateVerifier(NETWORK).toBytes()]);
         } else {
             throw new Error("Attempted to insert invalid proving keys for set_validator_state");
         }
@@ -526,8 +527,8 @@ class OfflineKeyProvider implements FunctionKeyProvider {
      * @param provingKey
      */
     insertSplitKeys(provingKey: ProvingKey) {
-        if (provingKey.isSplitProver()) {
-            this.cache.set(CREDITS_PROGRAM_KEYS.split.locator, [provingKey.toBytes(), VerifyingKey.splitVerifier().toBytes()]);
+        if (provingKey.isSplitProver(NETWORK)) {
+            this.cache.set(CREDITS_PROGRAM_KEYS.split.locator, [provingKey.toBytes(), VerifyingKey.splitVerifier(NETWORK).toBytes()]);
         } else {
             throw new Error("Attempted to insert invalid proving keys for split");
         }
@@ -541,8 +542,8 @@ class OfflineKeyProvider implements FunctionKeyProvider {
      * @param provingKey
      */
     insertTransferPrivateKeys(provingKey: ProvingKey) {
-        if (provingKey.isTransferPrivateProver()) {
-            this.cache.set(CREDITS_PROGRAM_KEYS.transfer_private.locator, [provingKey.toBytes(), VerifyingKey.transferPrivateVerifier().toBytes()]);
+        if (provingKey.isTransferPrivateProver(NETWORK)) {
+            this.cache.set(CREDITS_PROGRAM_KEYS.transfer_private.locator, [provingKey.toBytes(), VerifyingKey.transferPrivateVerifier(NETWORK).toBytes()]);
         } else {
             throw new Error("Attempted to insert invalid proving keys for transfer_private");
         }
@@ -556,8 +557,8 @@ class OfflineKeyProvider implements FunctionKeyProvider {
      * @param provingKey
      */
     insertTransferPrivateToPublicKeys(provingKey: ProvingKey) {
-        if (provingKey.isTransferPrivateToPublicProver()) {
-            this.cache.set(CREDITS_PROGRAM_KEYS.transfer_private_to_public.locator, [provingKey.toBytes(), VerifyingKey.transferPrivateToPublicVerifier().toBytes()]);
+        if (provingKey.isTransferPrivateToPublicProver(NETWORK)) {
+            this.cache.set(CREDITS_PROGRAM_KEYS.transfer_private_to_public.locator, [provingKey.toBytes(), VerifyingKey.transferPrivateToPublicVerifier(NETWORK).toBytes()]);
         } else {
             throw new Error("Attempted to insert invalid proving keys for transfer_private_to_public");
         }
@@ -571,8 +572,8 @@ class OfflineKeyProvider implements FunctionKeyProvider {
      * @param provingKey
      */
     insertTransferPublicKeys(provingKey: ProvingKey) {
-        if (provingKey.isTransferPublicProver()) {
-            this.cache.set(CREDITS_PROGRAM_KEYS.transfer_public.locator, [provingKey.toBytes(), VerifyingKey.transferPublicVerifier().toBytes()]);
+        if (provingKey.isTransferPublicProver(NETWORK)) {
+            this.cache.set(CREDITS_PROGRAM_KEYS.transfer_public.locator, [provingKey.toBytes(), VerifyingKey.transferPublicVerifier(NETWORK).toBytes()]);
         } else {
             throw new Error("Attempted to insert invalid proving keys for transfer_public");
         }
@@ -586,16 +587,16 @@ class OfflineKeyProvider implements FunctionKeyProvider {
      * @param provingKey
      */
     insertTransferPublicToPrivateKeys(provingKey: ProvingKey) {
-        if (provingKey.isTransferPublicToPrivateProver()) {
-            this.cache.set(CREDITS_PROGRAM_KEYS.transfer_public_to_private.locator, [provingKey.toBytes(), VerifyingKey.transferPublicToPrivateVerifier().toBytes()]);
+        if (provingKey.isTransferPublicToPrivateProver(NETWORK)) {
+            this.cache.set(CREDITS_PROGRAM_KEYS.transfer_public_to_private.locator, [provingKey.toBytes(), VerifyingKey.transferPublicToPrivateVerifier(NETWORK).toBytes()]);
         } else {
             throw new Error("Attempted to insert invalid proving keys for transfer_public_to_private");
         }
     }
 
     insertUnbondPublicKeys(provingKey: ProvingKey) {
-        if (provingKey.isUnbondPublicProver()) {
-            this.cache.set(CREDITS_PROGRAM_KEYS.unbond_public.locator, [provingKey.toBytes(), VerifyingKey.unbondPublicVerifier().toBytes()]);
+        if (provingKey.isUnbondPublicProver(NETWORK)) {
+            this.cache.set(CREDITS_PROGRAM_KEYS.unbond_public.locator, [provingKey.toBytes(), VerifyingKey.unbondPublicVerifier(NETWORK).toBytes()]);
         } else {
             throw new Error("Attempted to insert invalid proving keys for unbond_public");
         }
@@ -25,17 +25,30 @@ import {
     VerifyingKey,
     Transaction,
     ProgramManager as WasmProgramManager,
+    ProgressStage,
     verifyFunctionExecution,
 } from "./wasm";
 
 import {
     CREDITS_PROGRAM_KEYS,
+    NETWORK,
     PRIVATE_TRANSFER_TYPES,
     VALID_TRANSFER_TYPES,
 } from "./constants";
 
 import { logAndThrow } from "./utils";
 
+/**
+ * Represents the options for following and stopping the build of a transaction.
+ *
+ * @property {(stage: ProgressStage) => void} [onProgress] - Optional callback called with each stage of the transaction build as it starts.
+ * @property {AbortSignal} [signal] - Optional signal which stops the transaction build before its next stage once aborted.
+ */
+interface ProgressOptions {
+    onProgress?: (stage: ProgressStage) => void;
+    signal?: AbortSignal;
+}
+
 /**
  * Represents the options for executing a transaction in the Aleo network.
  * This interface is used to specify the parameters required for building and submitting an execution transaction.
@@ -54,8 +67,10 @@ import { logAndThrow } from "./utils";
  * @property {OfflineQuery} [offlineQuery] - Optional offline query if creating transactions in an offline environment.
  * @property {string | Program} [program] - Optional program source code to use for the transaction.
  * @property {ProgramImports} [imports] - Optional programs that the program being executed imports.
+ * @property {(stage: ProgressStage) => void} [onProgress] - Optional callback called with each stage of the transaction build as it starts.
+ * @property {AbortSignal} [signal] - Optional signal which stops the transaction build before its next stage once aborted.
  */
-interface ExecuteOptions {
+interface ExecuteOptions extends ProgressOptions {
     programName: string;
     functionName: string;
     fee: number;
@@ -81,6 +96,7 @@ class ProgramManager {
     host: string;
     networkClient: AleoNetworkClient;
     recordProvider: RecordProvider | undefined;
+    wasmProgramManager: WasmProgramManager;
 
     /** Create a new instance of the ProgramManager
      *
@@ -94,6 +110,7 @@ class ProgramManager {
 
         this.keyProvider = keyProvider ? keyProvider : new AleoKeyProvider();
         this.recordProvider = recordProvider;
+        this.wasmProgramManager = new WasmProgramManager(NETWORK);
     }
 
     /**
@@ -143,6 +160,7 @@ class ProgramManager {
      * pay the deployment fee
      * @param {string | RecordPlaintext | undefined} feeRecord Optional Fee record to use for the transaction
      * @param {PrivateKey | undefined} privateKey Optional private key to use for the transaction
+     * @param {ProgressOptions} progressOptions Optional progress callback and abort signal for the deployment build
      * @returns {string} The transaction id of the deployed program or a failure message from the network
      *
      * @example
@@ -171,6 +189,7 @@ class ProgramManager {
         recordSearchParams?: RecordSearchParams,
         feeRecord?: string | RecordPlaintext,
         privateKey?: PrivateKey,
+        progressOptions: ProgressOptions = {},
     ): Promise<string> {
         // Ensure the program is valid and does not exist on the network
         try {
@@ -224,7 +243,7 @@ class ProgramManager {
         }
 
         // Build a deployment transaction and submit it to the network
-        const tx = await WasmProgramManager.buildDeploymentTransaction(deploymentPrivateKey, program, fee, feeRecord, this.host, imports, feeProvingKey, feeVerifyingKey);
+        const tx = await this.wasmProgramManager.buildDeploymentTransaction(deploymentPrivateKey, program, fee, feeRecord, this.host, imports, feeProvingKey, feeVerifyingKey, undefined, undefined, progressOptions.onProgress, progressOptions.signal);
         return await this.networkClient.submitTransaction(tx);
     }
 
@@ -266,7 +285,9 @@ class ProgramManager {
             recordSearchParams,
             keySearchParams,
             privateKey,
-            offlineQuery
+            offlineQuery,
+            onProgress,
+            signal
         } = options;
 
         let feeRecord = options.feeRecord;
@@ -332,7 +353,7 @@ class ProgramManager {
         }
 
         // Build an execution transaction and submit it to the network
-        return await WasmProgramManager.buildExecutionTransaction(executionPrivateKey, program, functionName, inputs, fee, feeRecord, this.host, imports, provingKey, verifyingKey, feeProvingKey, feeVerifyingKey, offlineQuery);
+        return await this.wasmProgramManager.buildExecutionTransaction(executionPrivateKey, program, functionName, inputs, fee, feeRecord, this.host, imports, provingKey, verifyingKey, feeProvingKey, feeVerifyingKey, offlineQuery, undefined, onProgress, signal);
     }
 
     /**
@@ -435,7 +456,7 @@ class ProgramManager {
         console.log("Running program offline")
         console.log("Proving key: ", provingKey);
         console.log("Verifying key: ", verifyingKey);
-        return WasmProgramManager.executeFunctionOffline(executionPrivateKey, program, function_name, inputs, proveExecution, false, imports, provingKey, verifyingKey, this.host, offlineQuery);
+        return this.wasmProgramManager.executeFunctionOffline(executionPrivateKey, program, function_name, inputs, proveExecution, false, imports, provingKey, verifyingKey, this.host, offlineQuery);
     }
 
     /**
@@ -450,6 +471,7 @@ class ProgramManager {
      * @param {RecordPlaintext | string | undefined} feeRecord Fee record to use for the join transaction
      * @param {PrivateKey | undefined} privateKey Private key to use for the join transaction
      * @param {OfflineQuery | undefined} offlineQuery Optional offline query if creating transactions in an offline environment
+     * @param {ProgressOptions} progressOptions Optional progress callback and abort signal for the join transaction build
      * @returns {Promise<string>}
      */
     async join(
@@ -461,6 +483,7 @@ class ProgramManager {
         feeRecord?: RecordPlaintext | string | undefined,
         privateKey?: PrivateKey,
         offlineQuery?: OfflineQuery,
+        progressOptions: ProgressOptions = {},
     ): Promise<string> {
         // Get the private key from the account if it is not provided in the parameters
         let executionPrivateKey = privateKey;
@@ -500,7 +523,7 @@ class ProgramManager {
         }
 
         // Build an execution transaction and submit it to the network
-        const tx = await WasmProgramManager.buildJoinTransaction(executionPrivateKey, recordOne, recordTwo, fee, feeRecord, this.host, joinProvingKey, joinVerifyingKey, feeProvingKey, feeVerifyingKey, offlineQuery);
+        const tx = await this.wasmProgramManager.buildJoinTransaction(executionPrivateKey, recordOne, recordTwo, fee, feeRecord, this.host, joinProvingKey, joinVerifyingKey, feeProvingKey, feeVerifyingKey, offlineQuery, undefined, progressOptions.onProgress, progressOptions.signal);
         return await this.networkClient.submitTransaction(tx);
     }
 
@@ -511,6 +534,7 @@ class ProgramManager {
      * @param {RecordPlaintext | string} amountRecord Amount record to use for the split transaction
      * @param {PrivateKey | undefined} privateKey Optional private key to use for the split transaction
      * @param {OfflineQuery | undefined} offlineQuery Optional offline query if creating transactions in an offline environment
+     * @param {ProgressOptions} progressOptions Optional progress callback and abort signal for the split transaction build
      * @returns {Promise<string>}
      *
      * @example
@@ -526,7 +550,7 @@ class ProgramManager {
      * const tx_id = await programManager.split(25000000, record);
      * const transaction = await programManager.networkClient.getTransaction(tx_id);
      */
-    async split(splitAmount: number, amountRecord: RecordPlaintext | string, privateKey?: PrivateKey, offlineQuery?: OfflineQuery): Promise<string> {
+    async split(splitAmount: number, amountRecord: RecordPlaintext | string, privateKey?: PrivateKey, offlineQuery?: OfflineQuery, progressOptions: ProgressOptions = {}): Promise<string> {
         // Get the private key from the account if it is not provided in the parameters
         let executionPrivateKey = privateKey;
         if (typeof executionPrivateKey === "undefined" && typeof this.account !== "undefined") {
@@ -554,7 +578,7 @@ class ProgramManager {
         }
 
         // Build an execution transaction and submit it to the network
-        const tx = await WasmProgramManager.buildSplitTransaction(executionPrivateKey, splitAmount, amountRecord, this.host, splitProvingKey, splitVerifyingKey, offlineQuery);
+        const tx = await this.wasmProgramManager.buildSplitTransaction(executionPrivateKey, splitAmount, amountRecord, this.host, splitProvingKey, splitVerifyingKey, offlineQuery, progressOptions.onProgress, progressOptions.signal);
         return await this.networkClient.submitTransaction(tx);
     }
 
@@ -589,7 +613,7 @@ class ProgramManager {
         // Attempt to run an offline execution of the program and extract the proving and verifying keys
         try {
             imports = await this.networkClient.getProgramImports(program);
-            const keyPair = await WasmProgramManager.synthesizeKeyPair(
+            const keyPair = await this.wasmProgramManager.synthesizeKeyPair(
                 executionPrivateKey,
                 program,
                 function_id,
@@ -616,6 +640,7 @@ class ProgramManager {
      * @param {RecordPlaintext | string} feeRecord Optional fee record to use for the transfer
      * @param {PrivateKey | undefined} privateKey Optional private key to use for the transfer transaction
      * @param {OfflineQuery | undefined} offlineQuery Optional offline query if creating transactions in an offline environment
+     * @param {ProgressOptions} progressOptions Optional progress callback and abort signal for the transfer transaction build
      * @returns {Promise<string>} The transaction id of the transfer transaction
      *
      * @example
@@ -641,7 +666,8 @@ class ProgramManager {
         amountRecord?: RecordPlaintext | string,
         feeRecord?: RecordPlaintext | string,
         privateKey?: PrivateKey,
-        offlineQuery?: OfflineQuery
+        offlineQuery?: OfflineQuery,
+        progressOptions: ProgressOptions = {}
     ): Promise<Transaction> {
         // Validate the transfer type
         transferType = <string>validateTransferType(transferType);
@@ -685,7 +711,7 @@ class ProgramManager {
         }
 
         // Build an execution transaction and submit it to the network
-        return await WasmProgramManager.buildTransferTransaction(executionPrivateKey, amount, recipient, transferType, amountRecord, fee, feeRecord, this.host, transferProvingKey, transferVerifyingKey, feeProvingKey, feeVerifyingKey, offlineQuery);
+        return await this.wasmProgramManager.buildTransferTransaction(executionPrivateKey, amount, recipient, transferType, amountRecord, fee, feeRecord, this.host, transferProvingKey, transferVerifyingKey, feeProvingKey, feeVerifyingKey, offlineQuery, undefined, progressOptions.onProgress, progressOptions.signal);
     }
 
     /**
@@ -702,6 +728,7 @@ class ProgramManager {
      * @param {RecordPlaintext | string} feeRecord Optional fee record to use for the transfer
      * @param {PrivateKey | undefined} privateKey Optional private key to use for the transfer transaction
      * @param {OfflineQuery | undefined} offlineQuery Optional offline query if creating transactions in an offline environment
+     * @param {ProgressOptions} progressOptions Optional progress callback and abort signal for the transfer transaction build
      * @returns {Promise<string>} The transaction id of the transfer transaction
      */
     async buildTransferPublicTransaction(
@@ -709,9 +736,10 @@ class ProgramManager {
         recipient: string,
         fee: number,
         privateKey?: PrivateKey,
-        offlineQuery?: OfflineQuery
+        offlineQuery?: OfflineQuery,
+        progressOptions: ProgressOptions = {}
     ): Promise<Transaction> {
-        return this.buildTransferTransaction(amount, recipient, "public", fee, false, undefined, undefined, undefined, privateKey, offlineQuery);
+        return this.buildTransferTransaction(amount, recipient, "public", fee, false, undefined, undefined, undefined, privateKey, offlineQuery, progressOptions);
     }
 
     /**
@@ -728,6 +756,7 @@ class ProgramManager {
      * @param {RecordPlaintext | string} feeRecord Optional fee record to use for the transfer
      * @param {PrivateKey | undefined} privateKey Optional private key to use for the transfer transaction
      * @param {OfflineQuery | undefined} offlineQuery Optional offline query if creating transactions in an offline environment
+     * @param {ProgressOptions} progressOptions Optional progress callback and abort signal for the transfer transaction build
      * @returns {Promise<string>} The transaction id of the transfer transaction
      */
     async buildTransferPublicAsSignerTransaction(
@@ -735,9 +764,10 @@ class ProgramManager {
         recipient: string,
         fee: number,
         privateKey?: PrivateKey,
-        offlineQuery?: OfflineQuery
+        offlineQuery?: OfflineQuery,
+        progressOptions: ProgressOptions = {}
     ): Promise<Transaction> {
-        return this.buildTransferTransaction(amount, recipient, "public", fee, false, undefined, undefined, undefined, privateKey, offlineQuery);
+        return this.buildTransferTransaction(amount, recipient, "public", fee, false, undefined, undefined, undefined, privateKey, offlineQuery, progressOptions);
     }
 
     /**
@@ -754,6 +784,7 @@ class ProgramManager {
      * @param {RecordPlaintext | string} feeRecord Optional fee record to use for the transfer
      * @param {PrivateKey | undefined} privateKey Optional private key to use for the transfer transaction
      * @param {OfflineQuery | undefined} offlineQuery Optional offline query if creating transactions in an offline environment
+     * @param {ProgressOptions} progressOptions Optional progress callback and abort signal for the transfer transaction build
      * @returns {Promise<string>} The transaction id of the transfer transaction
      *
      * @example
@@ -778,9 +809,10 @@ class ProgramManager {
         amountRecord?: RecordPlaintext | string,
         feeRecord?: RecordPlaintext | string,
         privateKey?: PrivateKey,
-        offlineQuery?: OfflineQuery
+        offlineQuery?: OfflineQuery,
+        progressOptions: ProgressOptions = {}
     ): Promise<string> {
-        const tx = <Transaction>await this.buildTransferTransaction(amount, recipient, transferType, fee, privateFee, recordSearchParams, amountRecord, feeRecord, privateKey, offlineQuery);
+        const tx = <Transaction>await this.buildTransferTransaction(amount, recipient, transferType, fee, privateFee, recordSearchParams, amountRecord, feeRecord, privateKey, offlineQuery, progressOptions);
         return await this.networkClient.submitTransaction(tx);
     }
 
@@ -1,19 +1,37 @@
 export {
     Address,
+    Authorization,
+    DeploymentCost,
     Execution,
+    ExecutionCost,
     ExecutionResponse,
+    ExtendedPrivateKey,
     Field,
+    FunctionCost,
+    KdfParams,
+    Keystore,
+    LocalLedger,
+    Mnemonic,
+    Network,
+    NetworkQuery,
     OfflineQuery,
+    OwnedRecord,
     Metadata,
     PrivateKey,
     PrivateKeyCiphertext,
     Program,
     ProgramManager,
+    ProgressStage,
     ProvingKey,
     RecordCiphertext,
     RecordPlaintext,
     Signature,
+    SignedValue,
     Transaction,
+    TransactionConfirmation,
+    TransactionStatus,
+    Transition,
+    TransitionEntry,
     VerifyingKey,
     ViewKey,
     initThreadPool,
@@ -1,6 +1,6 @@
 import sinon from "sinon";
 import { expect } from "chai";
-import { Account, Address, PrivateKey, RecordCiphertext, ViewKey } from "../src/node";
+import { Account, Address, ExtendedPrivateKey, Mnemonic, PrivateKey, RecordCiphertext, ViewKey } from "../src/node";
 import { seed, message, beaconPrivateKeyString, beaconViewKeyString, beaconAddressString, recordCiphertextString, foreignCiphertextString, recordPlaintextString } from "./data/account-data";
 
 describe('Account', () => {
@@ -83,6 +83,45 @@ describe('Account', () => {
             }
         });
 
+        it('can write an account to a keystore and read it back', () => {
+            const newAccount = new Account();
+            const keystore = newAccount.toKeystore("mypassword");
+            const keystoreJson = JSON.parse(keystore);
+
+            // Ensure the keystore exposes the account address and is versioned
+            expect(keystoreJson.version).equal(1);
+            expect(keystoreJson.address).equal(newAccount.toString());
+
+            const account = Account.fromKeystore(keystore, "mypassword");
+            expect(account.privateKey().to_string()).equal(newAccount.privateKey().to_string());
+            expect(() => Account.fromKeystore(keystore, "badpassword")).throw("Wrong password or invalid keystore");
+        });
+
+        it('recovers the same account from a mnemonic', () => {
+            const mnemonic = new Mnemonic(12);
+            const phrase = mnemonic.toString();
+            expect(phrase.split(" ").length).equal(12);
+            expect(Mnemonic.isValid(phrase)).equal(true);
+
+            const account = Account.fromMnemonic(phrase, "passphrase");
+            expect(Account.fromMnemonic(phrase, "passphrase").toString()).equal(account.toString());
+            expect(Account.fromMnemonic(phrase).toString()).not.equal(account.toString());
+            expect(() => Account.fromMnemonic("abandon abandon abandon")).throw();
+        });
+
+        it('derives accounts and watch-only view keys from a master key', () => {
+            const phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+            const master = ExtendedPrivateKey.fromMnemonic(phrase);
+            const account = new Account({ privateKey: master.deriveAccount(3).to_string() });
+
+            expect(master.derivePath("m/44'/683'/3'/0'").address().to_string()).equal(account.toString());
+            const viewKeys = master.deriveViewKeys(2, 3);
+            expect(viewKeys.length).equal(3);
+            expect(viewKeys[1].to_string()).equal(account.viewKey().to_string());
+            expect(master.deriveAddresses(3, 1)[0].to_string()).equal(account.toString());
+            expect(() => master.derivePath("m/0")).throw();
+        });
+
         it('fails to create an account from a bad password', () => {
             const newAccount = new Account();
             const privateKeyCiphertext = newAccount.encryptAccount("mypassword");
@@ -183,5 +222,20 @@ describe('Account', () => {
             // Ensure a signature & message mismatch is invalid
             expect(isSigValidForWrongMessage).equal(false);
         });
+
+        it("verifies the signature on an Aleo value", () => {
+            const account = new Account();
+            const value = "{ amount: 5u64, memo: 7field }";
+            const seed = new Uint8Array(32).fill(1);
+            const signed = account.signValue(value, seed);
+
+            // Ensure the signature is deterministic for a seed and covers the fields of the value
+            expect(account.signValue(value, seed).signature().to_string()).equal(signed.signature().to_string());
+            expect(signed.fields().length).greaterThan(0);
+            // Ensure the signature is valid for the value and invalid for another value
+            expect(signed.verify(account.address())).equal(true);
+            expect(account.verifyValue(value, signed.signature())).equal(true);
+            expect(account.verifyValue("{ amount: 6u64, memo: 7field }", signed.signature())).equal(false);
+        });
     });
 });
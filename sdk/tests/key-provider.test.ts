@@ -1,5 +1,5 @@
 import { expect } from "chai";
-import {AleoKeyProvider, CachedKeyPair, CREDITS_PROGRAM_KEYS, FunctionKeyPair, OfflineKeyProvider, ProvingKey, VerifyingKey} from "../src/node";
+import {AleoKeyProvider, CachedKeyPair, CREDITS_PROGRAM_KEYS, FunctionKeyPair, NETWORK, OfflineKeyProvider, ProvingKey, VerifyingKey} from "../src/node";
 
 describe('KeyProvider', () => {
     let keyProvider: AleoKeyProvider;
@@ -117,28 +117,28 @@ describe('KeyProvider', () => {
             expect(unbondPublicVerifier.checksum()).equal(unbondPublicVerifierLocal.checksum());
 
             // Ensure the recovered keys are of the correct type
-            expect(bondPublicProverLocal.isBondPublicProver()).equal(true);
-            expect(bondPublicVerifierLocal.isBondPublicVerifier()).equal(true);
-            expect(claimUnbondPublicProverLocal.isClaimUnbondPublicProver()).equal(true);
-            expect(claimUnbondVerifierLocal.isClaimUnbondPublicVerifier()).equal(true);
-            expect(feePrivateProverLocal.isFeePrivateProver()).equal(true);
-            expect(feePrivateVerifierLocal.isFeePrivateVerifier()).equal(true);
-            expect(feePublicProverLocal.isFeePublicProver()).equal(true);
-            expect(feePublicVerifierLocal.isFeePublicVerifier()).equal(true);
-            expect(joinProverLocal.isJoinProver()).equal(true);
-            expect(joinVerifierLocal.isJoinVerifier()).equal(true);
-            expect(splitProverLocal.isSplitProver()).equal(true);
-            expect(splitVerifierLocal.isSplitVerifier()).equal(true);
-            expect(transferPrivateProverLocal.isTransferPrivateProver()).equal(true);
-            expect(transferPrivateVerifierLocal.isTransferPrivateVerifier()).equal(true);
-            expect(transferPrivateToPublicProverLocal.isTransferPrivateToPublicProver()).equal(true);
-            expect(transferPrivateToPublicVerifierLocal.isTransferPrivateToPublicVerifier()).equal(true);
-            expect(transferPublicProverLocal.isTransferPublicProver()).equal(true);
-            expect(transferPublicVerifierLocal.isTransferPublicVerifier()).equal(true);
-            expect(transferPublicToPrivateProverLocal.isTransferPublicToPrivateProver()).equal(true);
-            expect(transferPublicToPrivateVerifierLocal.isTransferPublicToPrivateVerifier()).equal(true);
-            expect(unbondPublicProverLocal.isUnbondPublicProver()).equal(true);
-            expect(unbondPublicVerifierLocal.isUnbondPublicVerifier()).equal(true);
+            expect(bondPublicProverLocal.isBondPublicProver(NETWORK)).equal(true);
+            expect(bondPublicVerifierLocal.isBondPublicVerifier(NETWORK)).equal(true);
+            expect(claimUnbondPublicProverLocal.isClaimUnbondPublicProver(NETWORK)).equal(true);
+            expect(claimUnbondVerifierLocal.isClaimUnbondPublicVerifier(NETWORK)).equal(true);
+            expect(feePrivateProverLocal.isFeePrivateProver(NETWORK)).equal(true);
+            expect(feePrivateVerifierLocal.isFeePrivateVerifier(NETWORK)).equal(true);
+            expect(feePublicProverLocal.isFeePublicProver(NETWORK)).equal(true);
+            expect(feePublicVerifierLocal.isFeePublicVerifier(NETWORK)).equal(true);
+            expect(joinProverLocal.isJoinProver(NETWORK)).equal(true);
+            expect(joinVerifierLocal.isJoinVerifier(NETWORK)).equal(true);
+            expect(splitProverLocal.isSplitProver(NETWORK)).equal(true);
+            expect(splitVerifierLocal.isSplitVerifier(NETWORK)).equal(true);
+            expect(transferPrivateProverLocal.isTransferPrivateProver(NETWORK)).equal(true);
+            expect(transferPrivateVerifierLocal.isTransferPrivateVerifier(NETWORK)).equal(true);
+            expect(transferPrivateToPublicProverLocal.isTransferPrivateToPublicProver(NETWORK)).equal(true);
+            expect(transferPrivateToPublicVerifierLocal.isTransferPrivateToPublicVerifier(NETWORK)).equal(true);
+            expect(transferPublicProverLocal.isTransferPublicProver(NETWORK)).equal(true);
+            expect(transferPublicVerifierLocal.isTransferPublicVerifier(NETWORK)).equal(true);
+            expect(transferPublicToPrivateProverLocal.isTransferPublicToPrivateProver(NETWORK)).equal(true);
+            expect(transferPublicToPrivateVerifierLocal.isTransferPublicToPrivateVerifier(NETWORK)).equal(true);
+            expect(unbondPublicProverLocal.isUnbondPublicProver(NETWORK)).equal(true);
+            expect(unbondPublicVerifierLocal.isUnbondPublicVerifier(NETWORK)).equal(true);
         });
     });
 });
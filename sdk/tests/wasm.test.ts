@@ -1,5 +1,5 @@
 import { expect } from "chai";
-import { Address, PrivateKey, ViewKey, Signature, RecordCiphertext, RecordPlaintext, PrivateKeyCiphertext } from "../src/node";
+import { Address, LocalLedger, NetworkQuery, PrivateKey, ViewKey, Signature, RecordCiphertext, RecordPlaintext, PrivateKeyCiphertext, isSdkError } from "../src/node";
 import {
     seed,
     message,
@@ -364,5 +364,73 @@ describe('WASM Objects', () => {
         });
     });
 
+    describe('NetworkQuery', () => {
+        it('can be configured with endpoints and headers', () => {
+            const query = new NetworkQuery(["https://node-a.example/v1/", "https://node-b.example/v1"]);
+            expect(query.endpoints()).deep.equal(["https://node-a.example/v1", "https://node-b.example/v1"]);
+            query.setHeader("X-API-Key", "secret");
+            query.setTimeout(5000);
+            query.setRetries(2, 250);
+            expect(query.clone().endpoints()).deep.equal(query.endpoints());
+
+            expect(() => query.setHeader("Invalid Name", "secret")).throw();
+            expect(() => new NetworkQuery(["node.example"])).throw();
+        });
+    });
+
+    describe('LocalLedger', () => {
+        it('applies the finalize logic of executions and rejects aborting ones', () => {
+            const program = `program ledger_test.aleo;
+
+mapping counter:
+    key as u8.public;
+    value as u64.public;
+
+function increment:
+    input r0 as u64.public;
+    async increment r0 into r1;
+    output r1 as ledger_test.aleo/increment.future;
+
+finalize increment:
+    input r0 as u64.public;
+    get.or_use counter[0u8] 0u64 into r1;
+    add r1 r0 into r2;
+    lte r2 10u64 into r3;
+    assert.eq r3 true;
+    set r2 into counter[0u8];
+`;
+            const ledger = new LocalLedger();
+            const privateKey = PrivateKey.from_string(privateKeyString);
+            ledger.addProgram(program);
+
+            ledger.execute(privateKey, "ledger_test.aleo", "increment", ["6u64"]);
+            expect(ledger.getMappingValue("ledger_test.aleo", "counter", "0u8")).equal("6u64");
+
+            // Ensure an execution aborting in finalize is rejected and leaves the mapping unchanged
+            try {
+                ledger.execute(privateKey, "ledger_test.aleo", "increment", ["6u64"]);
+                expect.fail("The execution should have been rejected");
+            } catch (error) {
+                expect(isSdkError(error) && error.code).equal("REJECTED");
+            }
+            expect(ledger.getMappingValue("ledger_test.aleo", "counter", "0u8")).equal("6u64");
+            expect(ledger.height()).equal(1);
+        });
+
+        it('mints records with state paths for offline queries', () => {
+            const ledger = new LocalLedger();
+            const address = Address.from_string(addressString);
+            const record = ledger.mint(address, 1000000);
+            expect(record.microcredits()).equal(1000000);
+            expect(ledger.height()).equal(1);
+
+            // Ensure the offline query contains the state root and the state path of the record
+            const commitment = record.commitment("credits.aleo", "credits").toString();
+            const offlineQuery = JSON.parse(ledger.offlineQuery([commitment]).toString());
+            expect(offlineQuery.state_root).equal(ledger.stateRoot());
+            expect(offlineQuery.state_paths[commitment]).equal(ledger.statePath(commitment));
+        });
+    });
+
 
 });
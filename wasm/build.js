@@ -16,7 +16,7 @@ async function buildRollup(input, output) {
 }
 
 
-async function buildWasm(network) {
+async function buildWasm() {
     await buildRollup({
         input: {
             "aleo_wasm": "entry",
@@ -31,17 +31,17 @@ async function buildWasm(network) {
                     // This enables multi-threading
                     "--config", `build.rustflags=["-C", "target-feature=+atomics,+bulk-memory,+mutable-globals", "-C", "link-arg=--max-memory=4294967296"]`,
                     "--no-default-features",
-                    "--features", `browser,${network}`,
+                    "--features", "browser",
                     "-Z", "build-std=panic_abort,std",
                 ],
 
                 experimental: {
-                    typescriptDeclarationDir: `dist/${network}`,
+                    typescriptDeclarationDir: "dist",
                 },
             }),
         ],
     }, {
-        dir: `dist/${network}`,
+        dir: "dist",
         format: "es",
         sourcemap: true,
         assetFileNames: `[name][extname]`,
@@ -49,17 +49,29 @@ async function buildWasm(network) {
 }
 
 
-async function buildJS(network) {
-    const js = `import wasm from "./dist/${network}/aleo_wasm.js";
+async function buildJS() {
+    const js = `import wasm from "./dist/aleo_wasm.js";
 
 const {
     initThreadPool: wasmInitThreadPool,
     Address,
+    Authorization,
+    DeploymentCost,
     Execution,
+    ExecutionCost,
     ExecutionResponse,
+    ExtendedPrivateKey,
     Field,
+    FunctionCost,
+    KdfParams,
+    Keystore,
+    LocalLedger,
+    Mnemonic,
     Metadata,
+    Network,
+    NetworkQuery,
     OfflineQuery,
+    OwnedRecord,
     Private,
     PrivateKey,
     PrivateKeyCiphertext,
@@ -68,8 +80,14 @@ const {
     RecordCiphertext,
     RecordPlaintext,
     ProgramManager,
+    ProgressStage,
     Signature,
+    SignedValue,
     Transaction,
+    TransactionConfirmation,
+    TransactionStatus,
+    Transition,
+    TransitionEntry,
     ViewKey,
     VerifyingKey,
     verifyFunctionExecution,
@@ -92,11 +110,23 @@ async function initThreadPool(threads) {
 export {
     initThreadPool,
     Address,
+    Authorization,
+    DeploymentCost,
     Execution,
+    ExecutionCost,
     ExecutionResponse,
+    ExtendedPrivateKey,
     Field,
+    FunctionCost,
+    KdfParams,
+    Keystore,
+    LocalLedger,
+    Mnemonic,
     Metadata,
+    Network,
+    NetworkQuery,
     OfflineQuery,
+    OwnedRecord,
     PrivateKey,
     PrivateKeyCiphertext,
     Program,
@@ -104,8 +134,14 @@ export {
     RecordCiphertext,
     RecordPlaintext,
     ProgramManager,
+    ProgressStage,
     Signature,
+    SignedValue,
     Transaction,
+    TransactionConfirmation,
+    TransactionStatus,
+    Transition,
+    TransitionEntry,
     ViewKey,
     VerifyingKey,
     verifyFunctionExecution,
@@ -121,15 +157,15 @@ export {
             }),
         ],
     }, {
-        dir: `dist/${network}`,
+        dir: "dist",
         format: "es",
         sourcemap: true,
     });
 }
 
 
-async function buildWorker(network) {
-    const worker = `import wasm from "./dist/${network}/aleo_wasm.js";
+async function buildWorker() {
+    const worker = `import wasm from "./dist/aleo_wasm.js";
 
 async function initializeWorker(wasm) {
     // Wait for the main thread to send us the Module, Memory, and Rayon thread pointer.
@@ -176,14 +212,14 @@ await initializeWorker(wasm);`;
             }),
         ],
     }, {
-        dir: `dist/${network}`,
+        dir: "dist",
         format: "es",
         sourcemap: true,
     });
 }
 
 
-async function buildTypes(network) {
+async function buildTypes() {
     const js = `/**
  * Initializes a thread pool of Workers. This enables multi-threading, which significantly improves performance.
  *
@@ -193,11 +229,23 @@ export function initThreadPool(threads?: number): Promise<void>;
 
 export {
     Address,
+    Authorization,
+    DeploymentCost,
     Execution,
+    ExecutionCost,
     ExecutionResponse,
+    ExtendedPrivateKey,
     Field,
+    FunctionCost,
+    KdfParams,
+    Keystore,
+    LocalLedger,
+    Mnemonic,
     Metadata,
+    Network,
+    NetworkQuery,
     OfflineQuery,
+    OwnedRecord,
     PrivateKey,
     PrivateKeyCiphertext,
     Program,
@@ -205,8 +253,14 @@ export {
     RecordCiphertext,
     RecordPlaintext,
     ProgramManager,
+    ProgressStage,
     Signature,
+    SignedValue,
     Transaction,
+    TransactionConfirmation,
+    TransactionStatus,
+    Transition,
+    TransitionEntry,
     ViewKey,
     VerifyingKey,
     verifyFunctionExecution,
@@ -214,11 +268,11 @@ export {
 
     const worker = `export {};`;
 
-    await $fs.mkdir(`dist/${network}`, { recursive: true })
+    await $fs.mkdir("dist", { recursive: true })
 
     await Promise.all([
-        $fs.writeFile(`dist/${network}/index.d.ts`, js),
-        $fs.writeFile(`dist/${network}/worker.d.ts`, worker),
+        $fs.writeFile("dist/index.d.ts", js),
+        $fs.writeFile("dist/worker.d.ts", worker),
     ]);
 }
 
@@ -235,22 +289,17 @@ export {
 // But we want to share the Wasm build between the `index.js`
 // and `worker.js` builds, so we build the Wasm, and then
 // build the `index.js` and `worker.js` separately.
-async function build(network) {
+async function build() {
     await Promise.all([
-        buildTypes(network),
-        buildWasm(network),
+        buildTypes(),
+        buildWasm(),
     ]);
 
     await Promise.all([
-        buildJS(network),
-        buildWorker(network),
+        buildJS(),
+        buildWorker(),
     ]);
 }
 
 
-const networks = [
-    "testnet",
-    "mainnet",
-];
-
-await Promise.all(networks.map(build));
+await build();
@@ -18,14 +18,15 @@ use crate::account::{PrivateKey, Signature, ViewKey};
 
 use crate::types::native::AddressNative;
 use core::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::prelude::*;
 
 /// Public address of an Aleo account
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Address(AddressNative);
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl Address {
     /// Derive an Aleo address from a private key
     ///
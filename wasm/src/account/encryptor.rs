@@ -14,47 +14,209 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::native::{
-    CiphertextNative,
-    CurrentNetwork,
-    FieldNative,
-    IdentifierNative,
-    LiteralNative,
-    Network,
-    PlaintextNative,
-    PrivateKeyNative,
-    Uniform,
+use crate::{
+    account::PrivateKeyCiphertext,
+    types::native::{
+        CiphertextNative,
+        CurrentNetwork,
+        FieldNative,
+        FromBits,
+        IdentifierNative,
+        LiteralNative,
+        Network,
+        PlaintextNative,
+        PrivateKeyNative,
+        SizeInDataBits,
+        ToBits,
+        Uniform,
+    },
 };
 
+use argon2::{Algorithm, Argon2, Params, Version};
 use once_cell::sync::OnceCell;
-use std::str::FromStr;
+use rand::Rng;
+use std::{fmt, str::FromStr};
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::prelude::*;
+
+/// Size in bytes of the salt of the key derivation
+pub(crate) const SALT_SIZE: usize = 16;
+/// Size in bytes of the key derived from a secret
+pub(crate) const KEY_SIZE: usize = 32;
+/// Maximum memory in KiB of a key derivation (256 MiB), so an untrusted keystore cannot exhaust the
+/// memory of a wasm32 instance, which is limited to 4 GiB in total
+const MAX_MEMORY_KIB: u32 = 256 * 1024;
+/// Maximum number of passes over the memory of a key derivation, so an untrusted keystore cannot
+/// stall decryption
+const MAX_ITERATIONS: u32 = 64;
+/// Maximum number of lanes of a key derivation
+const MAX_PARALLELISM: u32 = 16;
+
+/// Parameters of the Argon2id key derivation used to derive the key of a private key ciphertext
+/// from its secret. Raising them makes every guess of the secret more expensive.
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KdfParams {
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl KdfParams {
+    /// Create key derivation parameters
+    ///
+    /// @param {number} memory_kib Memory used by the key derivation in KiB
+    /// @param {number} iterations Number of passes over the memory
+    /// @param {number} parallelism Number of lanes
+    /// @returns {KdfParams} Key derivation parameters
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(constructor))]
+    pub fn new(memory_kib: u32, iterations: u32, parallelism: u32) -> Result<KdfParams, String> {
+        if memory_kib > MAX_MEMORY_KIB || iterations > MAX_ITERATIONS || parallelism > MAX_PARALLELISM {
+            return Err(format!(
+                "Key derivation parameters may use at most {MAX_MEMORY_KIB} KiB of memory, {MAX_ITERATIONS} iterations and {MAX_PARALLELISM} lanes"
+            ));
+        }
+        let params = Self { memory_kib, iterations, parallelism };
+        params.argon2()?;
+        Ok(params)
+    }
+
+    /// Get the memory used by the key derivation in KiB
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(getter, js_name = memoryKib))]
+    pub fn memory_kib(&self) -> u32 {
+        self.memory_kib
+    }
+
+    /// Get the number of passes over the memory
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(getter))]
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// Get the number of lanes
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(getter))]
+    pub fn parallelism(&self) -> u32 {
+        self.parallelism
+    }
+}
+
+impl Default for KdfParams {
+    /// The OWASP recommended minimum for Argon2id: 19 MiB of memory, 2 iterations and 1 lane
+    fn default() -> Self {
+        Self { memory_kib: 19 * 1024, iterations: 2, parallelism: 1 }
+    }
+}
+
+impl KdfParams {
+    fn argon2(&self) -> Result<Argon2<'static>, String> {
+        let params = Params::new(self.memory_kib, self.iterations, self.parallelism, Some(KEY_SIZE))
+            .map_err(|e| format!("Invalid key derivation parameters: {e}"))?;
+        Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+    }
+
+    /// Derive the 32 byte key of a secret and a salt
+    pub(crate) fn derive_key(&self, secret: &str, salt: &[u8; SALT_SIZE]) -> Result<[u8; KEY_SIZE], String> {
+        let mut key = [0u8; KEY_SIZE];
+        self.argon2()?.hash_password_into(secret.as_bytes(), salt, &mut key).map_err(|e| e.to_string())?;
+        Ok(key)
+    }
+}
+
+impl fmt::Display for KdfParams {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "m={},t={},p={}", self.memory_kib, self.iterations, self.parallelism)
+    }
+}
+
+impl FromStr for KdfParams {
+    type Err = String;
+
+    fn from_str(params: &str) -> Result<Self, Self::Err> {
+        let mut values = [None; 3];
+        for param in params.split(',') {
+            let (name, value) = param.split_once('=').ok_or(format!("Invalid key derivation parameter {param}"))?;
+            let index = match name {
+                "m" => 0,
+                "t" => 1,
+                "p" => 2,
+                _ => return Err(format!("Unknown key derivation parameter {name}")),
+            };
+            values[index] = Some(value.parse::<u32>().map_err(|e| e.to_string())?);
+        }
+        match values {
+            [Some(memory_kib), Some(iterations), Some(parallelism)] => Self::new(memory_kib, iterations, parallelism),
+            _ => Err("Missing key derivation parameters".to_string()),
+        }
+    }
+}
 
 /// Tool for encrypting and decrypting Aleo key material into ciphertext
 pub struct Encryptor;
 
 impl Encryptor {
-    /// Encrypt a private key into ciphertext using a secret
+    /// Encrypt a private key into ciphertext using a secret, deriving the encryption key with
+    /// Argon2id from the secret and a random salt
     pub(crate) fn encrypt_private_key_with_secret(
         private_key: &PrivateKeyNative,
         secret: &str,
-    ) -> Result<CiphertextNative, String> {
-        Self::encrypt_field(&private_key.seed(), secret, "private_key")
+        params: &KdfParams,
+    ) -> Result<PrivateKeyCiphertext, String> {
+        let salt: [u8; SALT_SIZE] = rand::thread_rng().gen();
+        let key = params.derive_key(secret, &salt)?;
+        Self::encrypt_private_key_with_key(private_key, &key, params, salt)
+    }
+
+    /// Encrypt a private key into ciphertext using a key derived with the parameters and salt
+    pub(crate) fn encrypt_private_key_with_key(
+        private_key: &PrivateKeyNative,
+        key: &[u8; KEY_SIZE],
+        params: &KdfParams,
+        salt: [u8; SALT_SIZE],
+    ) -> Result<PrivateKeyCiphertext, String> {
+        let ciphertext = Self::encrypt_field(&private_key.seed(), Self::key_to_field(key)?, "private_key")?;
+        Ok(PrivateKeyCiphertext::new(ciphertext, Some((*params, salt))))
     }
 
-    /// Decrypt a private key from ciphertext using a secret
+    /// Decrypt a private key from ciphertext using a secret. Ciphertexts created before the key
+    /// derivation was introduced are decrypted with the legacy key.
     pub(crate) fn decrypt_private_key_with_secret(
-        ciphertext: &CiphertextNative,
+        ciphertext: &PrivateKeyCiphertext,
         secret: &str,
     ) -> Result<PrivateKeyNative, String> {
-        let seed = Self::decrypt_field(ciphertext, secret, "private_key")?;
+        let key = match ciphertext.kdf() {
+            Some((params, salt)) => Self::key_to_field(&params.derive_key(secret, salt)?)?,
+            None => Self::legacy_key(secret),
+        };
+        let seed = Self::decrypt_field(ciphertext.ciphertext(), key, "private_key")?;
+        PrivateKeyNative::try_from(seed).map_err(|e| e.to_string())
+    }
+
+    /// Decrypt a private key from ciphertext using a key derived with the parameters and salt of
+    /// the ciphertext
+    pub(crate) fn decrypt_private_key_with_key(
+        ciphertext: &PrivateKeyCiphertext,
+        key: &[u8; KEY_SIZE],
+    ) -> Result<PrivateKeyNative, String> {
+        let seed = Self::decrypt_field(ciphertext.ciphertext(), Self::key_to_field(key)?, "private_key")?;
         PrivateKeyNative::try_from(seed).map_err(|e| e.to_string())
     }
 
+    /// Convert a derived key into the field element used as the encryption key, truncating it to
+    /// the data bits of a field so it never exceeds the modulus
+    fn key_to_field(key: &[u8; KEY_SIZE]) -> Result<FieldNative, String> {
+        FieldNative::from_bits_le(&key.to_bits_le()[..FieldNative::size_in_data_bits()]).map_err(|e| e.to_string())
+    }
+
+    /// Derive the key of a legacy ciphertext, which is a hash of the secret
+    fn legacy_key(secret: &str) -> FieldNative {
+        FieldNative::new_domain_separator(secret)
+    }
+
     // Encrypted a field element into a ciphertext representation
-    fn encrypt_field(field: &FieldNative, secret: &str, domain: &str) -> Result<CiphertextNative, String> {
-        // Derive the domain separators and the secret.
+    fn encrypt_field(field: &FieldNative, secret: FieldNative, domain: &str) -> Result<CiphertextNative, String> {
+        // Derive the domain separator.
         let domain = FieldNative::new_domain_separator(domain);
-        let secret = FieldNative::new_domain_separator(secret);
 
         // Generate a nonce
         let mut rng = rand::thread_rng();
@@ -80,9 +242,8 @@ impl Encryptor {
     }
 
     // Recover a field element encrypted within ciphertext
-    fn decrypt_field(ciphertext: &CiphertextNative, secret: &str, domain: &str) -> Result<FieldNative, String> {
+    fn decrypt_field(ciphertext: &CiphertextNative, secret: FieldNative, domain: &str) -> Result<FieldNative, String> {
         let domain = FieldNative::new_domain_separator(domain);
-        let secret = FieldNative::new_domain_separator(secret);
         let decrypted = ciphertext.decrypt_symmetric(secret).map_err(|e| e.to_string())?;
         let recovered_key = Self::extract_value(&decrypted, "key")?;
         let recovered_nonce = Self::extract_value(&decrypted, "nonce")?;
@@ -112,11 +273,16 @@ mod tests {
     use snarkvm_console::prelude::TestRng;
     use wasm_bindgen_test::*;
 
+    /// Cheap key derivation parameters which keep the tests fast
+    fn params() -> KdfParams {
+        KdfParams::new(64, 1, 1).unwrap()
+    }
+
     #[wasm_bindgen_test]
     fn test_encryptor_encrypt_and_decrypt() {
         let mut rng = TestRng::default();
         let private_key = PrivateKeyNative::new(&mut rng).unwrap();
-        let enc = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword").unwrap();
+        let enc = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword", &params()).unwrap();
         let recovered_private_key = Encryptor::decrypt_private_key_with_secret(&enc, "mypassword").unwrap();
         assert_eq!(private_key, recovered_private_key);
     }
@@ -125,7 +291,7 @@ mod tests {
     fn test_encryptor_wrong_private_key_doesnt_decrypt() {
         let mut rng = TestRng::default();
         let private_key = PrivateKeyNative::new(&mut rng).unwrap();
-        let enc = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword").unwrap();
+        let enc = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword", &params()).unwrap();
         let recovered_private_key = Encryptor::decrypt_private_key_with_secret(&enc, "wrong_password");
         assert!(recovered_private_key.is_err())
     }
@@ -134,8 +300,8 @@ mod tests {
     fn test_encryptor_same_secret_doesnt_produce_same_ciphertext_on_different_runs() {
         let mut rng = TestRng::default();
         let private_key = PrivateKeyNative::new(&mut rng).unwrap();
-        let enc = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword").unwrap();
-        let enc2 = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword").unwrap();
+        let enc = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword", &params()).unwrap();
+        let enc2 = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword", &params()).unwrap();
         assert_ne!(enc, enc2);
 
         // Assert that we can decrypt both ciphertexts with the same secret despite being different
@@ -148,8 +314,8 @@ mod tests {
     fn test_encryptor_private_keys_encrypted_with_different_passwords_match() {
         let mut rng = TestRng::default();
         let private_key = PrivateKeyNative::new(&mut rng).unwrap();
-        let enc = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword").unwrap();
-        let enc2 = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword2").unwrap();
+        let enc = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword", &params()).unwrap();
+        let enc2 = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword2", &params()).unwrap();
         assert_ne!(enc, enc2);
 
         // Assert that we can decrypt both ciphertexts with to the same key
@@ -163,8 +329,8 @@ mod tests {
         let mut rng = TestRng::default();
         let private_key = PrivateKeyNative::new(&mut rng).unwrap();
         let private_key2 = PrivateKeyNative::new(&mut rng).unwrap();
-        let enc = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword").unwrap();
-        let enc2 = Encryptor::encrypt_private_key_with_secret(&private_key2, "mypassword").unwrap();
+        let enc = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword", &params()).unwrap();
+        let enc2 = Encryptor::encrypt_private_key_with_secret(&private_key2, "mypassword", &params()).unwrap();
         assert_ne!(enc, enc2);
 
         // Assert that private key plaintexts don't match
@@ -172,4 +338,53 @@ mod tests {
         let recovered_key_2 = Encryptor::decrypt_private_key_with_secret(&enc2, "mypassword").unwrap();
         assert_ne!(recovered_key_1, recovered_key_2);
     }
+
+    #[wasm_bindgen_test]
+    fn test_encryptor_decrypts_legacy_ciphertexts() {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKeyNative::new(&mut rng).unwrap();
+        let key = Encryptor::legacy_key("mypassword");
+        let legacy =
+            PrivateKeyCiphertext::new(Encryptor::encrypt_field(&private_key.seed(), key, "private_key").unwrap(), None);
+        let recovered_private_key = Encryptor::decrypt_private_key_with_secret(&legacy, "mypassword").unwrap();
+        assert_eq!(private_key, recovered_private_key);
+        assert!(Encryptor::decrypt_private_key_with_secret(&legacy, "wrong_password").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_kdf_derives_distinct_keys() {
+        let params = params();
+        let salt = [1u8; SALT_SIZE];
+        let key = params.derive_key("mypassword", &salt).unwrap();
+        // The key is deterministic for a secret, salt and parameters.
+        assert_eq!(key, params.derive_key("mypassword", &salt).unwrap());
+        // Changing any of them changes the key.
+        assert_ne!(key, params.derive_key("mypassword2", &salt).unwrap());
+        assert_ne!(key, params.derive_key("mypassword", &[2u8; SALT_SIZE]).unwrap());
+        assert_ne!(key, KdfParams::new(64, 2, 1).unwrap().derive_key("mypassword", &salt).unwrap());
+        // The encryption key differs from the legacy key.
+        assert_ne!(Encryptor::key_to_field(&key).unwrap(), Encryptor::legacy_key("mypassword"));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_kdf_params_to_and_from_string() {
+        let params = KdfParams::default();
+        assert_eq!(params.to_string(), "m=19456,t=2,p=1");
+        assert_eq!(KdfParams::from_str(&params.to_string()).unwrap(), params);
+        assert!(KdfParams::from_str("m=19456,t=2").is_err());
+        assert!(KdfParams::from_str("m=19456,t=2,q=1").is_err());
+        // Argon2 requires at least 8 KiB of memory per lane.
+        assert!(KdfParams::new(8, 1, 2).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_kdf_params_are_capped() {
+        assert!(KdfParams::new(MAX_MEMORY_KIB, MAX_ITERATIONS, MAX_PARALLELISM).is_ok());
+        assert!(KdfParams::new(MAX_MEMORY_KIB + 1, 2, 1).is_err());
+        assert!(KdfParams::new(19 * 1024, MAX_ITERATIONS + 1, 1).is_err());
+        assert!(KdfParams::new(19 * 1024, 2, MAX_PARALLELISM + 1).is_err());
+        // Parameters parsed from untrusted keystores are capped as well.
+        assert!(KdfParams::from_str(&format!("m={},t=2,p=1", u32::MAX)).is_err());
+        assert!(KdfParams::from_str("m=4194304,t=2,p=1").unwrap_err().starts_with("Key derivation parameters"));
+    }
 }
@@ -0,0 +1,289 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::account::{Address, Mnemonic, PrivateKey, ViewKey};
+
+use hmac::{Hmac, Mac};
+#[cfg(not(feature = "native"))]
+use js_sys::Array;
+use sha2::Sha512;
+use std::{fmt, ops::Range};
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::prelude::*;
+
+/// Key of the HMAC deriving the master key from a seed
+const MASTER_KEY: &[u8] = b"Aleo seed";
+/// Offset of the hardened child indices
+const HARDENED_OFFSET: u32 = 1 << 31;
+/// SLIP-44 coin type of Aleo
+const COIN_TYPE: u32 = 683;
+/// Maximum number of accounts derived at once, so a single call cannot stall on an unbounded range
+pub const MAX_DERIVED_ACCOUNTS: u32 = 1000;
+
+/// Hierarchical deterministic key deriving many accounts from one master seed, following SLIP-10
+/// with hardened derivation only
+///
+/// `I = HMAC-SHA512(key = "Aleo seed", data = seed)` is the master key `I[0..32]` and chain code
+/// `I[32..64]`, and each child `i` is derived from its parent with
+/// `I = HMAC-SHA512(key = chain code, data = 0x00 || key || ser32(i + 2^31))`. The private key of a
+/// node is recovered from its key the same way as `PrivateKey.from_seed_unchecked`.
+///
+/// Aleo private keys are recovered from a seed and view keys are hashes of them, so there is no
+/// public derivation of child view keys from a parent view key. Watch-only services are instead
+/// handed the view keys or addresses derived up front, e.g. with `deriveViewKeys`.
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone, PartialEq, Eq)]
+pub struct ExtendedPrivateKey {
+    key: [u8; 32],
+    chain_code: [u8; 32],
+    path: Vec<u32>,
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl ExtendedPrivateKey {
+    /// Get the master key of a seed
+    ///
+    /// @param {Uint8Array} seed Seed of 16 to 64 bytes, e.g. the seed of a mnemonic
+    /// @returns {ExtendedPrivateKey} Master key
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromSeed))]
+    pub fn from_seed(seed: &[u8]) -> Result<ExtendedPrivateKey, String> {
+        if !(16..=64).contains(&seed.len()) {
+            return Err(format!("The seed must be 16 to 64 bytes long, not {}", seed.len()));
+        }
+        let (key, chain_code) = hmac_sha512(MASTER_KEY, &[seed]);
+        Ok(Self { key, chain_code, path: Vec::new() })
+    }
+
+    /// Get the master key of a BIP-39 mnemonic phrase and passphrase
+    ///
+    /// @param {string} phrase Mnemonic phrase with the words separated by spaces
+    /// @param {string | undefined} passphrase (optional) Passphrase protecting the mnemonic
+    /// @returns {ExtendedPrivateKey} Master key
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromMnemonic))]
+    pub fn from_mnemonic(phrase: &str, passphrase: Option<String>) -> Result<ExtendedPrivateKey, String> {
+        let mnemonic = Mnemonic::from_phrase(phrase)?;
+        Self::from_seed(&mnemonic.seed(passphrase.as_deref().unwrap_or_default()))
+    }
+
+    /// Derive the hardened child of the key at an index
+    ///
+    /// @param {number} index Index of the child below 2^31, derived as the hardened index
+    /// @returns {ExtendedPrivateKey} Child key
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = deriveChild))]
+    pub fn derive_child(&self, index: u32) -> Result<ExtendedPrivateKey, String> {
+        if index >= HARDENED_OFFSET {
+            return Err(format!("The child index must be below 2^31, not {index}"));
+        }
+        let index = (index + HARDENED_OFFSET).to_be_bytes();
+        let (key, chain_code) = hmac_sha512(&self.chain_code, &[&[0u8], &self.key, &index]);
+        let mut path = self.path.clone();
+        path.push(u32::from_be_bytes(index));
+        Ok(Self { key, chain_code, path })
+    }
+
+    /// Derive the key at a path, e.g. "m/44'/683'/0'/0'" from the master key or "0'/1'" relative to
+    /// the key. Every index of the path must be hardened, marked with ' or h
+    ///
+    /// @param {string} path Derivation path
+    /// @returns {ExtendedPrivateKey} Derived key
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = derivePath))]
+    pub fn derive_path(&self, path: &str) -> Result<ExtendedPrivateKey, String> {
+        let mut segments = path.split('/').peekable();
+        if segments.peek() == Some(&"m") {
+            if !self.path.is_empty() {
+                return Err(format!("The path {path} starts at the master key but the key is at {}", self.path()));
+            }
+            segments.next();
+        }
+        segments.try_fold(self.clone(), |key, segment| {
+            let index = segment
+                .strip_suffix(['\'', 'h'])
+                .ok_or_else(|| format!("Index {segment} of the path {path} is not hardened"))?
+                .parse::<u32>()
+                .map_err(|_| format!("Index {segment} of the path {path} is invalid"))?;
+            key.derive_child(index)
+        })
+    }
+
+    /// Derive the private key of the account at an index, at the path "m/44'/683'/{index}'/0'"
+    /// from the master key
+    ///
+    /// @param {number} index Index of the account
+    /// @returns {PrivateKey} Private key of the account
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = deriveAccount))]
+    pub fn derive_account(&self, index: u32) -> Result<PrivateKey, String> {
+        self.derive_path(&account_path(index))?.private_key()
+    }
+
+    /// Derive the view keys of consecutive accounts for watch-only scanning
+    ///
+    /// @param {number} start Index of the first account
+    /// @param {number} count Number of accounts, at most 1000
+    /// @returns {Array<ViewKey>} View keys of the accounts
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen(js_name = deriveViewKeys)]
+    pub fn derive_view_keys(&self, start: u32, count: u32) -> Result<Array, String> {
+        let view_keys = Array::new();
+        for index in account_range(start, count)? {
+            view_keys.push(&ViewKey::from_private_key(&self.derive_account(index)?).into());
+        }
+        Ok(view_keys)
+    }
+
+    /// Derive the addresses of consecutive accounts, e.g. to hand out deposit addresses
+    ///
+    /// @param {number} start Index of the first account
+    /// @param {number} count Number of accounts, at most 1000
+    /// @returns {Array<Address>} Addresses of the accounts
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen(js_name = deriveAddresses)]
+    pub fn derive_addresses(&self, start: u32, count: u32) -> Result<Array, String> {
+        let addresses = Array::new();
+        for index in account_range(start, count)? {
+            addresses.push(&Address::from_private_key(&self.derive_account(index)?).into());
+        }
+        Ok(addresses)
+    }
+
+    /// Get the private key of the account of the key
+    ///
+    /// @returns {PrivateKey} Private key
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = privateKey))]
+    pub fn private_key(&self) -> Result<PrivateKey, String> {
+        Ok(PrivateKey::from(PrivateKey::from_seed_bytes(&self.key)?))
+    }
+
+    /// Get the view key of the account of the key
+    ///
+    /// @returns {ViewKey} View key
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = viewKey))]
+    pub fn view_key(&self) -> Result<ViewKey, String> {
+        Ok(ViewKey::from_private_key(&self.private_key()?))
+    }
+
+    /// Get the address of the account of the key
+    ///
+    /// @returns {Address} Address
+    pub fn address(&self) -> Result<Address, String> {
+        Ok(Address::from_private_key(&self.private_key()?))
+    }
+
+    /// Get the derivation path of the key from the master key
+    ///
+    /// @returns {string} Derivation path, e.g. "m/44'/683'/0'/0'"
+    pub fn path(&self) -> String {
+        self.path.iter().fold("m".to_string(), |path, index| format!("{path}/{}'", index - HARDENED_OFFSET))
+    }
+}
+
+impl fmt::Debug for ExtendedPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ExtendedPrivateKey").field("path", &self.path()).finish_non_exhaustive()
+    }
+}
+
+/// Derivation path "m/44'/683'/{index}'/0'" of the account at an index
+pub fn account_path(index: u32) -> String {
+    format!("m/44'/{COIN_TYPE}'/{index}'/0'")
+}
+
+/// Get the indices of `count` consecutive accounts from `start`. The range is rejected if it has
+/// more than [`MAX_DERIVED_ACCOUNTS`] accounts or runs past the last hardened index.
+pub(crate) fn account_range(start: u32, count: u32) -> Result<Range<u32>, String> {
+    if count > MAX_DERIVED_ACCOUNTS {
+        return Err(format!("At most {MAX_DERIVED_ACCOUNTS} accounts can be derived at once"));
+    }
+    match start.checked_add(count) {
+        Some(end) if end <= HARDENED_OFFSET => Ok(start..end),
+        _ => Err(format!("The range of {count} accounts from index {start} exceeds the last account index")),
+    }
+}
+
+/// Split the HMAC-SHA512 of the data into a key and a chain code
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> ([u8; 32], [u8; 32]) {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    data.iter().for_each(|data| mac.update(data));
+    let output = mac.finalize().into_bytes();
+
+    let (mut key, mut chain_code) = ([0u8; 32], [0u8; 32]);
+    key.copy_from_slice(&output[..32]);
+    chain_code.copy_from_slice(&output[32..]);
+    (key, chain_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const SEED: &str = "000102030405060708090a0b0c0d0e0f";
+
+    #[wasm_bindgen_test]
+    fn test_derivation_vectors() {
+        let master = ExtendedPrivateKey::from_seed(&hex::decode(SEED).unwrap()).unwrap();
+        assert_eq!(hex::encode(master.key), "f17f00aacc384e27747eda10eda2216d48921f01bc2dcbd022f7e13e91627c7a");
+        assert_eq!(hex::encode(master.chain_code), "176119d872cde0f463915cfb0db3380a9c099c8d8faae8a4107a43c40b53797d");
+
+        let child = master.derive_path("m/0'/1h").unwrap();
+        assert_eq!(child.path(), "m/0'/1'");
+        assert_eq!(hex::encode(child.key), "5d78a3aac40181cdf30a4f90e8772eafb058babfe04be86b8eb0c73146ec57da");
+        assert_eq!(hex::encode(child.chain_code), "3e4d16f817d73b5c9b243812fad57c0cd95745f94cdcc674afef93f813f8f6fc");
+        assert_eq!(child, master.derive_child(0).unwrap().derive_path("1'").unwrap());
+
+        // Ensure the private key of a node is recovered from its key
+        assert_eq!(child.private_key().unwrap(), PrivateKey::from_seed_unchecked(&child.key));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_derive_accounts() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let master = ExtendedPrivateKey::from_mnemonic(phrase, None).unwrap();
+        assert_eq!(master, ExtendedPrivateKey::from_seed(&Mnemonic::from_phrase(phrase).unwrap().seed("")).unwrap());
+
+        let account = master.derive_account(7).unwrap();
+        let node = master.derive_path("m/44'/683'/7'/0'").unwrap();
+        assert_eq!(account, node.private_key().unwrap());
+        assert_eq!(node.view_key().unwrap(), ViewKey::from_private_key(&account));
+        assert_eq!(node.address().unwrap(), account.to_address());
+        assert_ne!(account, master.derive_account(8).unwrap());
+
+        // Ensure a passphrase derives different accounts
+        let protected = ExtendedPrivateKey::from_mnemonic(phrase, Some("passphrase".to_string())).unwrap();
+        assert_ne!(account, protected.derive_account(7).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_invalid_derivations() {
+        let master = ExtendedPrivateKey::from_seed(&hex::decode(SEED).unwrap()).unwrap();
+        assert!(ExtendedPrivateKey::from_seed(&[0u8; 15]).is_err());
+        assert!(ExtendedPrivateKey::from_seed(&[0u8; 65]).is_err());
+        assert!(master.derive_path("m/0").is_err());
+        assert!(master.derive_path("m/x'").is_err());
+        assert!(master.derive_child(HARDENED_OFFSET).is_err());
+        assert!(master.derive_child(0).unwrap().derive_path("m/0'").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_account_range() {
+        assert_eq!(account_range(5, 3).unwrap(), 5..8);
+        assert_eq!(account_range(HARDENED_OFFSET - 1, 1).unwrap(), HARDENED_OFFSET - 1..HARDENED_OFFSET);
+        assert!(account_range(0, MAX_DERIVED_ACCOUNTS).is_ok());
+        assert!(account_range(0, MAX_DERIVED_ACCOUNTS + 1).is_err());
+        assert!(account_range(HARDENED_OFFSET, 1).is_err());
+        assert!(account_range(u32::MAX, 2).is_err());
+    }
+}
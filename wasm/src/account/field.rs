@@ -15,163 +15,133 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    account::PrivateKey,
-    types::native::{CurrentNetwork, ComputeKey, FieldNative, Field, FromBits, Literal, Network, Scalar, SizeInDataBits, ToFields, Value}
+    account::{signing_prefix, PrivateKey},
+    types::native::{
+        CurrentNetwork,
+        Field,
+        FieldNative,
+        FromBits,
+        Literal,
+        Network,
+        SizeInDataBits,
+        ToBits,
+        ToFields,
+        Value,
+    },
+    SdkError,
 };
 
-use anyhow::Error;
-use rand::{rngs::StdRng, SeedableRng};
+use core::str::FromStr;
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::prelude::*;
-use std::collections::HashMap;
-use snarkvm_wasm::utilities::{Uniform, ToBits};
-use itertools::Itertools;
 
-
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 pub struct JsField(FieldNative);
 
-
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl JsField {
-
-    // gen_message(nonce, message_plaintext) → msg_Leo_verify: { field_1: 123125field, field_2:123131field },
-    // msg_client_signing: ["field_1: 123125field, field_2:123131".to_fields()]
-    pub fn generate_message_leo (
-        private_key: &PrivateKey,
-        message: &[u8],
-        seed: &[u8],
-    ) -> String {
-
-        // {record} -> encode(record) -> generate_message
-        // question what are we  are we sending in?
-        // all we need is message verify to be the same as message sign
-        // Ensure the number of field elements does not exceed the maximum allowed size.
-        // Sample a random nonce from the scalar field.
-
-        let seed_array = <[u8; 32]>::try_from(seed).expect("Invalid seed length");
-        let mut rng = StdRng::from_seed(seed_array);
-
-        let msg_bits: &[bool] = &message.to_bits_le();
-
-        let message_fields: Vec<Field<CurrentNetwork>> =
-            msg_bits.chunks(Field::<CurrentNetwork>::size_in_data_bits()).map(Field::from_bits_le).collect::<Result<Vec<_>, Error>>().unwrap();
-
-        let vec_msg_first_field = vec![message_fields[0]];
-
-        let nonce = Scalar::rand(&mut rng);
-
-        let g_r = Network::g_scalar_multiply(&nonce);
-
-        let pk_sig = Network::g_scalar_multiply(&private_key.sk_sig());
-
-        let pr_sig = Network::g_scalar_multiply(&private_key.r_sig());
-
-        let compute_key = ComputeKey::try_from((pk_sig, pr_sig)).unwrap();
-
-        let address = compute_key.to_address();
-
-        let mut msg = Vec::with_capacity(4 + message_fields.len());
-        msg.extend([g_r, pk_sig, pr_sig, *address].map(|point| point.to_x_coordinate()));
-        msg.extend(vec_msg_first_field);
-
-        let mut my_dict: HashMap<String, Value<CurrentNetwork>> = HashMap::new();
-
-        for (index, field) in msg.clone().into_iter().enumerate() {
-            let lit = Literal::Field(field);
-            let val = Value::from(&lit); // assuming the conversion takes a reference
-            let key = format!("field_{}", index + 1);  // generate key in the format "field_i"
-            my_dict.insert(key, val);
-        }
-
-        let string_representation: String = my_dict.iter()
-        .map(|(k, v)| (k, k.trim_start_matches("field_").parse::<usize>().unwrap_or(0), v)) // extract numeric part
-        .sorted_by(|(_, a_num, _), (_, b_num, _)| a_num.cmp(b_num)) // sort by the numeric part
-        .map(|(key, _, value)| format!("{}: {:?}", key, value)) // Use Debug trait for formatting
-        .collect::<Vec<String>>()
-        .join(",\n");
-
-        let result = format!("{{\n{}\n}}", string_representation);
-
-        return result;
-
+    /// Generate the message a Leo program verifies a signature against, as a struct of the
+    /// fields `{ field_1: ..., field_2: ... }` made of the nonce, the compute key, the address and
+    /// the field elements of the message. To sign an arbitrary Aleo value use
+    /// `Signature.signValue` instead.
+    ///
+    /// @param {PrivateKey} private_key The private key the message is signed with
+    /// @param {Uint8Array} message Byte representation of the message
+    /// @param {Uint8Array} seed 32 byte seed the signing nonce is sampled from
+    /// @returns {string} String representation of the struct of fields
+    pub fn generate_message_leo(private_key: &PrivateKey, message: &[u8], seed: &[u8]) -> Result<String, SdkError> {
+        let fields = Self::message_fields(private_key, message, seed)?;
+        Ok(Self::fields_to_string(&fields, ""))
     }
 
+    /// Generate the message a client signs for a Leo program to verify, which is the field
+    /// encoding of the struct returned by `generate_message_leo`
+    ///
+    /// @param {PrivateKey} private_key The private key the message is signed with
+    /// @param {Uint8Array} message Byte representation of the message
+    /// @param {Uint8Array} seed 32 byte seed the signing nonce is sampled from
+    /// @returns {string} String representation of the struct of fields
+    pub fn generate_message_clients(private_key: &PrivateKey, message: &[u8], seed: &[u8]) -> Result<String, SdkError> {
+        let fields = Self::message_fields(private_key, message, seed)?;
+        let message = Value::<CurrentNetwork>::from_str(&Self::fields_to_string(&fields, ""))
+            .map_err(|error| SdkError::Other(error.to_string()))?;
+        let message_fields = message.to_fields().map_err(|error| SdkError::Other(error.to_string()))?;
+        Ok(Self::fields_to_string(&message_fields, "  "))
+    }
+}
 
-    pub fn generate_message_clients (
-        private_key: &PrivateKey,
-        message: &[u8],
-        seed: &[u8]
-    ) -> String {
-
-        let seed_array = <[u8; 32]>::try_from(seed).expect("Invalid seed length");
-        let mut rng = StdRng::from_seed(seed_array);
-
-        let msg_bits: &[bool] = &message.to_bits_le();
-
-        let message_fields: Vec<Field<CurrentNetwork>> =
-            msg_bits.chunks(Field::<CurrentNetwork>::size_in_data_bits()).map(Field::from_bits_le).collect::<Result<Vec<_>, Error>>().unwrap();
-
-        let vec_msg_first_field = vec![message_fields[0]];
-
-        let nonce = Scalar::rand(&mut rng);
-
-        let g_r = Network::g_scalar_multiply(&nonce);
-
-        let pk_sig = Network::g_scalar_multiply(&private_key.sk_sig());
-
-        let pr_sig = Network::g_scalar_multiply(&private_key.r_sig());
-
-        let compute_key = ComputeKey::try_from((pk_sig, pr_sig)).unwrap();
-
-        let address = compute_key.to_address();
-
-        let mut msg = Vec::with_capacity(4 + message_fields.len());
-        msg.extend([g_r, pk_sig, pr_sig, *address].map(|point| point.to_x_coordinate()));
-        msg.extend(vec_msg_first_field);
-
-        let mut my_dict: HashMap<String, Value<CurrentNetwork>> = HashMap::new();
-
-        for (index, field) in msg.clone().into_iter().enumerate() {
-            let lit = Literal::Field(field);
-            let val = Value::from(&lit); // assuming the conversion takes a reference
-            let key = format!("field_{}", index + 1);  // generate key in the format "field_i"
-            my_dict.insert(key, val);
+impl JsField {
+    /// Prepend the signing prefix for the seed to the field elements of the message
+    fn message_fields(private_key: &PrivateKey, message: &[u8], seed: &[u8]) -> Result<Vec<FieldNative>, SdkError> {
+        if message.is_empty() {
+            return Err(SdkError::InvalidArgument("The message must not be empty".to_string()));
         }
-
-        let string_representation: String = my_dict.iter()
-        .map(|(k, v)| (k, k.trim_start_matches("field_").parse::<usize>().unwrap_or(0), v)) // extract numeric part
-        .sorted_by(|(_, a_num, _), (_, b_num, _)| a_num.cmp(b_num)) // sort by the numeric part
-        .map(|(key, _, value)| format!("{}: {:?}", key, value)) // Use Debug trait for formatting
-        .collect::<Vec<String>>()
-        .join(",\n");
-
-        let result = format!("{{\n{}\n}}", string_representation);
-
-        let val_of_dict: Value<CurrentNetwork> = Value::try_from(&result).unwrap();
-
-        let val_unwrapped = val_of_dict;
-
-        let msg_to_fields = val_unwrapped.to_fields().unwrap();
-
-        let mut dict_of_fields: HashMap<String, Value<CurrentNetwork>> = HashMap::new();
-
-        for (index, field) in msg_to_fields.clone().into_iter().enumerate() {
-            let lit = Literal::Field(field);
-            let val = Value::from(&lit); // assuming the conversion takes a reference
-            let key = format!("field_{}", index + 1);  // generate key in the format "field_i"
-            dict_of_fields.insert(key, val);
+        let message_fields = message
+            .to_bits_le()
+            .chunks(Field::<CurrentNetwork>::size_in_data_bits())
+            .map(FieldNative::from_bits_le)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| SdkError::InvalidArgument(error.to_string()))?;
+
+        let mut fields = signing_prefix(private_key, seed)?;
+        // Every field is a member of the struct the message is formatted as.
+        if fields.len() + message_fields.len() > CurrentNetwork::MAX_STRUCT_ENTRIES {
+            return Err(SdkError::InvalidArgument(format!(
+                "The message is {} fields long which exceeds the maximum of {}",
+                message_fields.len(),
+                CurrentNetwork::MAX_STRUCT_ENTRIES - fields.len()
+            )));
         }
+        fields.extend(message_fields);
+        Ok(fields)
+    }
 
-        let fields_string_representation: String = dict_of_fields.iter()
-        .map(|(k, v)| (k, k.trim_start_matches("field_").parse::<usize>().unwrap_or(0), v)) // extract numeric part
-        .sorted_by(|(_, a_num, _), (_, b_num, _)| a_num.cmp(b_num)) // sort by the numeric part
-        .map(|(key, _, value)| format!("  {}: {:?}", key, value)) // Use Debug trait for formatting
-        .collect::<Vec<String>>()
-        .join(",\n");
-
-        let msg_to_fields_str = format!("{{\n{}\n}}", fields_string_representation);
-
-        return msg_to_fields_str;
-
+    /// Format fields as a struct with the members `field_1`, `field_2`, ...
+    fn fields_to_string(fields: &[FieldNative], indent: &str) -> String {
+        let members = fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| format!("{indent}field_{}: {:?}", index + 1, Value::from(&Literal::Field(*field))))
+            .collect::<Vec<String>>()
+            .join(",\n");
+        format!("{{\n{members}\n}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use wasm_bindgen_test::*;
+
+    const ITERATIONS: u64 = 100;
+
+    #[wasm_bindgen_test]
+    pub fn test_generate_message_random_input() {
+        let rng = &mut StdRng::from_entropy();
+        let private_key = PrivateKey::new();
+        for _ in 0..ITERATIONS {
+            // Sample arbitrary bytes and a seed of arbitrary length.
+            let message: Vec<u8> = (0..rng.gen_range(0..64)).map(|_| rng.gen()).collect();
+            let seed: Vec<u8> = (0..rng.gen_range(0..40)).map(|_| rng.gen()).collect();
+
+            // Generating the messages must return an error rather than panic.
+            let leo = JsField::generate_message_leo(&private_key, &message, &seed);
+            let clients = JsField::generate_message_clients(&private_key, &message, &seed);
+            if message.is_empty() || seed.len() != 32 {
+                assert!(matches!(leo, Err(SdkError::InvalidArgument(_))));
+                assert!(matches!(clients, Err(SdkError::InvalidArgument(_))));
+            } else {
+                // The Leo message is a struct of the signing prefix and every message field.
+                let leo = leo.unwrap();
+                let message_fields = (message.len() * 8).div_ceil(Field::<CurrentNetwork>::size_in_data_bits());
+                assert!(Value::<CurrentNetwork>::from_str(&leo).is_ok());
+                assert_eq!(leo.matches("field_").count(), 4 + message_fields);
+                assert!(clients.is_ok());
+                // The messages are deterministic for a seed.
+                assert_eq!(leo, JsField::generate_message_leo(&private_key, &message, &seed).unwrap());
+            }
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,321 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::{Address, Encryptor, KdfParams, PrivateKey, PrivateKeyCiphertext, KEY_SIZE, SALT_SIZE},
+    types::native::{AddressNative, CiphertextNative, PrivateKeyNative},
+    Network,
+};
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{fmt, str::FromStr};
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::prelude::*;
+
+/// Version of the keystore format
+const KEYSTORE_VERSION: u32 = 1;
+/// Name of the key derivation function of the keystore format
+const ARGON2ID: &str = "argon2id";
+/// Domain separator of the MAC key derived from the encryption key
+const MAC_DOMAIN: &[u8] = b"aleo-keystore-mac";
+
+/// Private key encrypted into a JSON keystore file, similar in spirit to Ethereum's keystore v3
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "network": "mainnet",
+///   "address": "aleo1...",
+///   "crypto": {
+///     "kdf": "argon2id",
+///     "kdfparams": { "m": 19456, "t": 2, "p": 1, "salt": "<hex>" },
+///     "ciphertext": "ciphertext1...",
+///     "mac": "<hex>"
+///   }
+/// }
+/// ```
+///
+/// The MAC is the SHA-256 hash of a MAC key followed by the ciphertext, and is checked before
+/// decrypting. It fails for both an incorrect password and a modified ciphertext, which cannot be
+/// told apart. The MAC key is a domain separated hash of the derived key, so it reveals nothing
+/// about the encryption key.
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    version: u32,
+    network: Network,
+    address: AddressNative,
+    crypto: KeystoreCrypto,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KeystoreCrypto {
+    kdf: String,
+    kdfparams: KeystoreKdfParams,
+    ciphertext: CiphertextNative,
+    mac: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KeystoreKdfParams {
+    m: u32,
+    t: u32,
+    p: u32,
+    salt: String,
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl Keystore {
+    /// Encrypt a private key into a keystore using a secret. The secret is sensitive and will be
+    /// needed to decrypt the private key later, so it should be stored securely
+    ///
+    /// @param {PrivateKey} private_key Private key to encrypt
+    /// @param {string} secret Secret to encrypt the private key with
+    /// @param {Network | undefined} network (optional) The network the account is used on,
+    /// defaults to mainnet
+    /// @param {KdfParams | undefined} params (optional) Parameters of the key derivation, defaults
+    /// to 19 MiB of memory, 2 iterations and 1 lane
+    /// @returns {Keystore} Keystore of the private key
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromPrivateKey))]
+    pub fn from_private_key(
+        private_key: &PrivateKey,
+        secret: &str,
+        network: Option<Network>,
+        params: Option<KdfParams>,
+    ) -> Result<Keystore, String> {
+        Self::encrypt(private_key, secret, network.unwrap_or_default(), &params.unwrap_or_default())
+    }
+
+    /// Decrypt the private key of the keystore using the secret it was encrypted with
+    ///
+    /// @param {string} secret Secret used to encrypt the private key
+    /// @returns {PrivateKey} Private key
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = decryptToPrivateKey))]
+    pub fn decrypt_to_private_key(&self, secret: &str) -> Result<PrivateKey, String> {
+        Ok(PrivateKey::from(self.decrypt(secret)?))
+    }
+
+    /// Get the private key ciphertext of the keystore, which is encrypted with the same key and
+    /// decrypts with the same secret
+    ///
+    /// @returns {PrivateKeyCiphertext} Private key ciphertext
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toPrivateKeyCiphertext))]
+    pub fn to_private_key_ciphertext(&self) -> Result<PrivateKeyCiphertext, String> {
+        let (params, salt) = self.kdf()?;
+        Ok(PrivateKeyCiphertext::new(self.crypto.ciphertext.clone(), Some((params, salt))))
+    }
+
+    /// Get the address of the encrypted private key
+    ///
+    /// @returns {Address} Address
+    pub fn address(&self) -> Address {
+        Address::from(self.address)
+    }
+
+    /// Get the network the account is used on
+    ///
+    /// @returns {Network} Network
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Get the JSON representation of the keystore
+    ///
+    /// @returns {string} JSON representation of the keystore
+    #[allow(clippy::inherent_to_string)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toString))]
+    pub fn to_string(&self) -> String {
+        format!("{self}")
+    }
+
+    /// Parse a keystore from its JSON representation
+    ///
+    /// @param {string} keystore JSON representation of the keystore
+    /// @returns {Keystore} Keystore
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromString))]
+    pub fn from_string(keystore: &str) -> Result<Keystore, String> {
+        Self::from_str(keystore)
+    }
+}
+
+impl Keystore {
+    /// Encrypt a private key into a keystore using a secret
+    pub(crate) fn encrypt(
+        private_key: &PrivateKeyNative,
+        secret: &str,
+        network: Network,
+        params: &KdfParams,
+    ) -> Result<Self, String> {
+        let salt: [u8; SALT_SIZE] = rand::thread_rng().gen();
+        let key = params.derive_key(secret, &salt)?;
+        let ciphertext = Encryptor::encrypt_private_key_with_key(private_key, &key, params, salt)?;
+        let ciphertext = ciphertext.ciphertext().clone();
+        let address = AddressNative::try_from(private_key).map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            version: KEYSTORE_VERSION,
+            network,
+            address,
+            crypto: KeystoreCrypto {
+                kdf: ARGON2ID.to_string(),
+                kdfparams: KeystoreKdfParams {
+                    m: params.memory_kib(),
+                    t: params.iterations(),
+                    p: params.parallelism(),
+                    salt: hex::encode(salt),
+                },
+                mac: Self::mac(&key, &ciphertext),
+                ciphertext,
+            },
+        })
+    }
+
+    /// Decrypt the private key of the keystore using a secret
+    pub(crate) fn decrypt(&self, secret: &str) -> Result<PrivateKeyNative, String> {
+        let (params, salt) = self.kdf()?;
+        let key = params.derive_key(secret, &salt)?;
+        if !constant_time_eq(Self::mac(&key, &self.crypto.ciphertext).as_bytes(), self.crypto.mac.as_bytes()) {
+            return Err("Incorrect password".to_string());
+        }
+
+        let ciphertext = PrivateKeyCiphertext::new(self.crypto.ciphertext.clone(), Some((params, salt)));
+        let private_key = Encryptor::decrypt_private_key_with_key(&ciphertext, &key)?;
+        if AddressNative::try_from(&private_key).map_err(|e| e.to_string())? != self.address {
+            return Err("The address of the keystore does not match its private key".to_string());
+        }
+        Ok(private_key)
+    }
+
+    /// Get the parameters and the salt of the key derivation
+    fn kdf(&self) -> Result<(KdfParams, [u8; SALT_SIZE]), String> {
+        let kdfparams = &self.crypto.kdfparams;
+        let params = KdfParams::new(kdfparams.m, kdfparams.t, kdfparams.p)?;
+        let salt = hex::decode(&kdfparams.salt)
+            .ok()
+            .and_then(|salt| <[u8; SALT_SIZE]>::try_from(salt).ok())
+            .ok_or("Invalid key derivation salt".to_string())?;
+        Ok((params, salt))
+    }
+
+    /// Compute the MAC of a ciphertext with a MAC key derived from the key it was encrypted with
+    fn mac(key: &[u8; KEY_SIZE], ciphertext: &CiphertextNative) -> String {
+        let mac_key = Sha256::new().chain_update(MAC_DOMAIN).chain_update(key).finalize();
+        let mut hasher = Sha256::new();
+        hasher.update(mac_key);
+        hasher.update(ciphertext.to_string().as_bytes());
+        hex::encode(hasher.finalize())
+    }
+}
+
+/// Compare two byte strings in time independent of where they differ
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |difference, (a, b)| difference | (a ^ b)) == 0
+}
+
+impl FromStr for Keystore {
+    type Err = String;
+
+    fn from_str(keystore: &str) -> Result<Self, Self::Err> {
+        let keystore: Self = serde_json::from_str(keystore).map_err(|e| format!("Invalid keystore: {e}"))?;
+        if keystore.version != KEYSTORE_VERSION {
+            return Err(format!("Unsupported keystore version {}", keystore.version));
+        }
+        if keystore.crypto.kdf != ARGON2ID {
+            return Err(format!("Unsupported key derivation function {}", keystore.crypto.kdf));
+        }
+        Ok(keystore)
+    }
+}
+
+impl fmt::Display for Keystore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string_pretty(self).map_err(|_| fmt::Error)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    /// Cheap key derivation parameters which keep the tests fast
+    fn params() -> KdfParams {
+        KdfParams::new(64, 1, 1).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_keystore_encrypt_and_decrypt() {
+        let private_key = PrivateKey::new();
+        let keystore =
+            Keystore::from_private_key(&private_key, "mypassword", Some(Network::TestnetV0), Some(params())).unwrap();
+        assert_eq!(keystore.address(), private_key.to_address());
+        assert_eq!(keystore.network(), Network::TestnetV0);
+
+        // Round trip the keystore through its JSON representation
+        let json = keystore.to_string();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], 1);
+        assert_eq!(value["network"], "testnet");
+        assert_eq!(value["address"], private_key.to_address().to_string());
+        assert_eq!(value["crypto"]["kdf"], "argon2id");
+        assert_eq!(value["crypto"]["kdfparams"]["m"], 64);
+        let keystore = Keystore::from_string(&json).unwrap();
+        assert_eq!(private_key, keystore.decrypt_to_private_key("mypassword").unwrap());
+
+        // Ensure decryption fails with an incorrect password
+        assert_eq!(keystore.decrypt_to_private_key("badpassword").unwrap_err(), "Incorrect password");
+
+        // The private key ciphertext of the keystore decrypts with the same secret
+        let ciphertext = keystore.to_private_key_ciphertext().unwrap();
+        assert_eq!(private_key, ciphertext.decrypt_to_private_key("mypassword").unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_keystore_rejects_tampered_files() {
+        let private_key = PrivateKey::new();
+        let keystore = Keystore::from_private_key(&private_key, "mypassword", None, Some(params())).unwrap();
+        let json = keystore.to_string();
+
+        // Unsupported versions and key derivation functions fail to parse
+        assert!(Keystore::from_string(&json.replacen("\"version\": 1", "\"version\": 2", 1)).is_err());
+        assert!(Keystore::from_string(&json.replacen("argon2id", "scrypt", 1)).is_err());
+
+        // A different ciphertext fails the MAC check
+        let mut tampered = keystore.clone();
+        let ciphertext = PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword", Some(params())).unwrap();
+        tampered.crypto.ciphertext = ciphertext.ciphertext().clone();
+        assert!(tampered.decrypt_to_private_key("mypassword").is_err());
+
+        // The MAC key is derived from the whole key rather than from a slice of it
+        let mut key = [7u8; KEY_SIZE];
+        let mac = Keystore::mac(&key, &keystore.crypto.ciphertext);
+        key[0] = 8;
+        assert_ne!(mac, Keystore::mac(&key, &keystore.crypto.ciphertext));
+
+        // A different address is detected after decryption
+        let mut tampered = keystore.clone();
+        tampered.address = *PrivateKey::new().to_address();
+        assert!(tampered.decrypt_to_private_key("mypassword").is_err());
+
+        // Key derivation parameters beyond the caps are rejected before any memory is allocated
+        let mut tampered = keystore;
+        tampered.crypto.kdfparams.m = u32::MAX;
+        assert!(tampered.decrypt_to_private_key("mypassword").unwrap_err().starts_with("Key derivation parameters"));
+    }
+}
@@ -0,0 +1,253 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::account::PrivateKey;
+
+use bip39::Language;
+use rand::{rngs::StdRng, SeedableRng};
+use std::{fmt, str::FromStr};
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::prelude::*;
+
+/// Size of the seed derived from a mnemonic in bytes
+pub(crate) const MNEMONIC_SEED_SIZE: usize = 64;
+
+/// BIP-39 mnemonic phrase backing up an Aleo account with a list of English words
+///
+/// The private key of the account is derived from the 64 byte BIP-39 seed of the mnemonic and an
+/// optional passphrase, by reducing the seed little-endian modulo the order of the base field `Field`:
+///
+/// `seed = PBKDF2-HMAC-SHA512(mnemonic, "mnemonic" || passphrase, 2048)`
+///
+/// `private_key = PrivateKey::try_from(Field::from_bytes_le_mod_order(seed))`
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mnemonic(bip39::Mnemonic);
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl Mnemonic {
+    /// Generate a new mnemonic using a cryptographically secure random number generator
+    ///
+    /// @param {number | undefined} word_count (optional) Number of words of the mnemonic, one of
+    /// 12, 15, 18, 21 or 24, defaults to 24
+    /// @returns {Mnemonic} Mnemonic
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(constructor))]
+    pub fn new(word_count: Option<usize>) -> Result<Mnemonic, String> {
+        let word_count = word_count.unwrap_or(24);
+        bip39::Mnemonic::generate_in_with(&mut StdRng::from_entropy(), Language::English, word_count)
+            .map(Self)
+            .map_err(|_| format!("A mnemonic must have 12, 15, 18, 21 or 24 words, not {word_count}"))
+    }
+
+    /// Parse a mnemonic from its phrase, checking that every word is in the English BIP-39
+    /// wordlist and that the checksum is valid
+    ///
+    /// @param {string} phrase Mnemonic phrase with the words separated by spaces
+    /// @returns {Mnemonic} Mnemonic
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromPhrase))]
+    pub fn from_phrase(phrase: &str) -> Result<Mnemonic, String> {
+        Self::from_str(phrase)
+    }
+
+    /// Get the mnemonic encoding entropy bytes
+    ///
+    /// @param {Uint8Array} entropy 16, 20, 24, 28 or 32 bytes of entropy
+    /// @returns {Mnemonic} Mnemonic
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromEntropy))]
+    pub fn from_entropy(entropy: &[u8]) -> Result<Mnemonic, String> {
+        bip39::Mnemonic::from_entropy_in(Language::English, entropy).map(Self).map_err(|error| error.to_string())
+    }
+
+    /// Check whether a phrase is a valid mnemonic
+    ///
+    /// @param {string} phrase Mnemonic phrase with the words separated by spaces
+    /// @returns {boolean} True if every word is in the wordlist and the checksum is valid
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = isValid))]
+    pub fn is_valid(phrase: &str) -> bool {
+        Self::from_str(phrase).is_ok()
+    }
+
+    /// Get the number of words of the mnemonic
+    ///
+    /// @returns {number} Number of words
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = wordCount))]
+    pub fn word_count(&self) -> usize {
+        self.0.word_count()
+    }
+
+    /// Get the BIP-39 seed of the mnemonic. This function should be used very carefully as the
+    /// seed gives access to the account
+    ///
+    /// @param {string | undefined} passphrase (optional) Passphrase protecting the mnemonic
+    /// @returns {Uint8Array} 64 byte seed
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toSeed))]
+    pub fn to_seed(&self, passphrase: Option<String>) -> Vec<u8> {
+        self.seed(passphrase.as_deref().unwrap_or_default()).to_vec()
+    }
+
+    /// Derive the private key of the account backed up by the mnemonic
+    ///
+    /// @param {string | undefined} passphrase (optional) Passphrase protecting the mnemonic
+    /// @returns {PrivateKey} Private key
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toPrivateKey))]
+    pub fn to_private_key(&self, passphrase: Option<String>) -> Result<PrivateKey, String> {
+        let seed = self.seed(passphrase.as_deref().unwrap_or_default());
+        Ok(PrivateKey::from(PrivateKey::from_seed_bytes(&seed)?))
+    }
+
+    /// Get the phrase of the mnemonic. This function should be used very carefully as the phrase
+    /// gives access to the account
+    ///
+    /// @returns {string} Mnemonic phrase with the words separated by spaces
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toString))]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl Mnemonic {
+    /// Get the BIP-39 seed of the mnemonic and a passphrase
+    pub(crate) fn seed(&self, passphrase: &str) -> [u8; MNEMONIC_SEED_SIZE] {
+        self.0.to_seed(passphrase)
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = String;
+
+    fn from_str(phrase: &str) -> Result<Self, Self::Err> {
+        bip39::Mnemonic::parse_in(Language::English, phrase).map(Self).map_err(|error| match error {
+            bip39::Error::BadWordCount(count) => {
+                format!("A mnemonic must have 12, 15, 18, 21 or 24 words, not {count}")
+            }
+            bip39::Error::UnknownWord(index) => format!("Word {} of the mnemonic is not in the wordlist", index + 1),
+            bip39::Error::InvalidChecksum => "The checksum of the mnemonic is invalid".to_string(),
+            error => error.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    // Test vectors published with BIP-39, using the passphrase "TREZOR"
+    const VECTORS: [(&str, &str, &str); 5] = [
+        (
+            "00000000000000000000000000000000",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+        ),
+        (
+            "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+        ),
+        (
+            "80808080808080808080808080808080",
+            "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+            "d71de856f81a8acc65e6fc851a38d4d7ec216fd0796d0a6827a3ad6ed5511a30fa280f12eb2e47ed2ac03b5c462a0358d18d69fe4f985ec81778c1b370b652a8",
+        ),
+        (
+            "ffffffffffffffffffffffffffffffff",
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+            "ac27495480225222079d7be181583751e86f571027b0497b5b5d11218e0a8a13332572917f0f8e5a589620c6f15b11c61dee327651a14c34e18231052e48c069",
+        ),
+        (
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+            "bda85446c68413707090a52022edd26a1c9462295029f2e60cd7c4f2bbd3097170af7a4d73245cafa9c3cca8d561a7c3de6f5d4a10be8ed2a5e608d68f92fcc8",
+        ),
+    ];
+
+    #[wasm_bindgen_test]
+    fn test_bip39_vectors() {
+        for (entropy, phrase, seed) in VECTORS {
+            let mnemonic = Mnemonic::from_entropy(&hex::decode(entropy).unwrap()).unwrap();
+            assert_eq!(mnemonic.to_string(), phrase);
+            assert_eq!(Mnemonic::from_phrase(phrase).unwrap(), mnemonic);
+            assert_eq!(hex::encode(mnemonic.to_seed(Some("TREZOR".to_string()))), seed);
+        }
+    }
+
+    // Private keys derived from the first two BIP-39 vectors without and with the passphrase "TREZOR"
+    const PRIVATE_KEY_VECTORS: [(&str, &str, &str); 4] = [
+        (VECTORS[0].1, "", "APrivateKey1zkpBNNJDBu8ur7qVzEacfYwGAc8P7i7v3n5j7rDK2QSAnvK"),
+        (VECTORS[0].1, "TREZOR", "APrivateKey1zkp5LrZSWAKJraB6DEvS9YF2MthuF2xWDb8karih6obS4jR"),
+        (VECTORS[1].1, "", "APrivateKey1zkp7QbuTzALre9dgzZMhifNp5cx7GBcaUFjQXshmbsE5TPo"),
+        (VECTORS[1].1, "TREZOR", "APrivateKey1zkpEFAv6ByUnZTQnzYCyYZwJRgqDuWtem6wh1EjsxmzN6dN"),
+    ];
+
+    #[wasm_bindgen_test]
+    fn test_private_key_vectors() {
+        for (phrase, passphrase, private_key) in PRIVATE_KEY_VECTORS {
+            let mnemonic = Mnemonic::from_phrase(phrase).unwrap();
+            let derived = mnemonic.to_private_key(Some(passphrase.to_string())).unwrap();
+            assert_eq!(derived.to_string(), private_key);
+            assert_eq!(derived.to_address(), PrivateKey::from_string(private_key).unwrap().to_address());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_new_and_private_key_derivation() {
+        for word_count in [12, 24] {
+            let mnemonic = Mnemonic::new(Some(word_count)).unwrap();
+            assert_eq!(mnemonic.word_count(), word_count);
+
+            // Ensure the phrase recovers the same account
+            let recovered = Mnemonic::from_phrase(&mnemonic.to_string()).unwrap();
+            let private_key = mnemonic.to_private_key(None).unwrap();
+            assert_eq!(recovered.to_private_key(None).unwrap(), private_key);
+            assert_eq!(recovered.to_private_key(Some(String::new())).unwrap(), private_key);
+
+            // Ensure the passphrase changes the account
+            let protected = recovered.to_private_key(Some("passphrase".to_string())).unwrap();
+            assert_ne!(protected, private_key);
+            assert_eq!(mnemonic.to_private_key(Some("passphrase".to_string())).unwrap(), protected);
+        }
+        assert!(Mnemonic::new(Some(13)).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_invalid_phrases() {
+        // The checksum of the last word is invalid
+        assert!(!Mnemonic::is_valid(&["abandon"; 12].join(" ")));
+        assert_eq!(
+            Mnemonic::from_phrase(&["abandon"; 12].join(" ")).unwrap_err(),
+            "The checksum of the mnemonic is invalid"
+        );
+        // The second word is not in the wordlist
+        assert_eq!(
+            Mnemonic::from_phrase(
+                "abandon aleo abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+            )
+            .unwrap_err(),
+            "Word 2 of the mnemonic is not in the wordlist"
+        );
+        // The phrase has too few words
+        assert!(Mnemonic::from_phrase("abandon abandon about").is_err());
+        assert!(Mnemonic::is_valid(VECTORS[0].1));
+    }
+}
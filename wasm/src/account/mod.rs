@@ -17,12 +17,21 @@
 pub mod address;
 pub use address::*;
 
+pub mod extended_private_key;
+pub use extended_private_key::*;
+
 pub mod field;
 pub use field::*;
 
 pub mod encryptor;
 pub use encryptor::*;
 
+pub mod keystore;
+pub use keystore::*;
+
+pub mod mnemonic;
+pub use mnemonic::*;
+
 pub mod private_key;
 pub use private_key::*;
 
@@ -32,5 +41,8 @@ pub use private_key_ciphertext::*;
 pub mod signature;
 pub use signature::*;
 
+pub mod signed_value;
+pub use signed_value::*;
+
 pub mod view_key;
 pub use view_key::*;
@@ -15,25 +15,27 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    account::{Address, Encryptor, PrivateKeyCiphertext, Signature, ViewKey},
-    types::native::{CurrentNetwork, Environment, FromBytes, PrimeField, PrivateKeyNative, ToBytes},
+    account::{Address, Encryptor, KdfParams, Keystore, Mnemonic, PrivateKeyCiphertext, Signature, ViewKey},
+    types::native::{CurrentNetwork, Environment, FieldNative, FromBytes, PrimeField, PrivateKeyNative, ToBytes},
+    Network,
 };
 
 use core::{convert::TryInto, fmt, ops::Deref, str::FromStr};
 use rand::{rngs::StdRng, SeedableRng};
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::prelude::*;
 
 /// Private key of an Aleo account
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PrivateKey(PrivateKeyNative);
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl PrivateKey {
     /// Generate a new private key using a cryptographically secure random number generator
     ///
     /// @returns {PrivateKey}
-    #[wasm_bindgen(constructor)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(constructor))]
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         Self(PrivateKeyNative::new(&mut StdRng::from_entropy()).unwrap())
@@ -46,10 +48,17 @@ impl PrivateKey {
     pub fn from_seed_unchecked(seed: &[u8]) -> PrivateKey {
         // Cast into a fixed-size byte array. Note: This is a **hard** requirement for security.
         let seed: [u8; 32] = seed.try_into().unwrap();
-        // Recover the field element deterministically.
-        let field = <CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(&seed);
-        // Cast and recover the private key from the seed.
-        Self(PrivateKeyNative::try_from(FromBytes::read_le(&*field.to_bytes_le().unwrap()).unwrap()).unwrap())
+        Self(Self::from_seed_bytes(&seed).unwrap())
+    }
+
+    /// Derive a private key deterministically from a BIP-39 mnemonic phrase
+    ///
+    /// @param {string} phrase Mnemonic phrase with the words separated by spaces
+    /// @param {string | undefined} passphrase (optional) Passphrase protecting the mnemonic
+    /// @returns {PrivateKey}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromMnemonic))]
+    pub fn from_mnemonic(phrase: &str, passphrase: Option<String>) -> Result<PrivateKey, String> {
+        Mnemonic::from_phrase(phrase)?.to_private_key(passphrase)
     }
 
     /// Get a private key from a string representation of a private key
@@ -105,12 +114,11 @@ impl PrivateKey {
     ///
     /// @param {string} secret Secret used to encrypt the private key
     /// @returns {PrivateKeyCiphertext} Ciphertext representation of the private key
-    #[wasm_bindgen(js_name = newEncrypted)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = newEncrypted))]
     pub fn new_encrypted(secret: &str) -> Result<PrivateKeyCiphertext, String> {
         let key = Self::new();
-        let ciphertext =
-            Encryptor::encrypt_private_key_with_secret(&key, secret).map_err(|_| "Encryption failed".to_string())?;
-        Ok(PrivateKeyCiphertext::from(ciphertext))
+        Encryptor::encrypt_private_key_with_secret(&key, secret, &KdfParams::default())
+            .map_err(|_| "Encryption failed".to_string())
     }
 
     /// Encrypt an existing private key with a secret. The secret is sensitive and will be needed to
@@ -118,11 +126,10 @@ impl PrivateKey {
     ///
     /// @param {string} secret Secret used to encrypt the private key
     /// @returns {PrivateKeyCiphertext} Ciphertext representation of the private key
-    #[wasm_bindgen(js_name = toCiphertext)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toCiphertext))]
     pub fn to_ciphertext(&self, secret: &str) -> Result<PrivateKeyCiphertext, String> {
-        let ciphertext =
-            Encryptor::encrypt_private_key_with_secret(self, secret).map_err(|_| "Encryption failed".to_string())?;
-        Ok(PrivateKeyCiphertext::from(ciphertext))
+        Encryptor::encrypt_private_key_with_secret(self, secret, &KdfParams::default())
+            .map_err(|_| "Encryption failed".to_string())
     }
 
     /// Get private key from a private key ciphertext and secret originally used to encrypt it
@@ -130,12 +137,46 @@ impl PrivateKey {
     /// @param {PrivateKeyCiphertext} ciphertext Ciphertext representation of the private key
     /// @param {string} secret Secret originally used to encrypt the private key
     /// @returns {PrivateKey} Private key
-    #[wasm_bindgen(js_name = fromPrivateKeyCiphertext)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromPrivateKeyCiphertext))]
     pub fn from_private_key_ciphertext(ciphertext: &PrivateKeyCiphertext, secret: &str) -> Result<PrivateKey, String> {
         let private_key = Encryptor::decrypt_private_key_with_secret(ciphertext, secret)
             .map_err(|_| "Decryption failed".to_string())?;
         Ok(Self::from(private_key))
     }
+
+    /// Encrypt the private key into a JSON keystore using a secret. The keystore can be read by
+    /// the Aleo CLI and any other tool supporting the keystore format
+    ///
+    /// @param {string} secret Secret used to encrypt the private key
+    /// @param {Network | undefined} network (optional) The network the account is used on,
+    /// defaults to mainnet
+    /// @returns {string} JSON representation of the keystore
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toKeystore))]
+    pub fn to_keystore(&self, secret: &str, network: Option<Network>) -> Result<String, String> {
+        Ok(Keystore::from_private_key(self, secret, network, None)?.to_string())
+    }
+
+    /// Get a private key from a JSON keystore and the secret originally used to encrypt it
+    ///
+    /// @param {string} keystore JSON representation of the keystore
+    /// @param {string} secret Secret originally used to encrypt the private key
+    /// @returns {PrivateKey} Private key
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromKeystore))]
+    pub fn from_keystore(keystore: &str, secret: &str) -> Result<PrivateKey, String> {
+        Keystore::from_string(keystore)?.decrypt_to_private_key(secret)
+    }
+}
+
+impl PrivateKey {
+    /// Recover a private key from seed bytes by reducing them into a field element
+    pub(crate) fn from_seed_bytes(seed: &[u8]) -> Result<PrivateKeyNative, String> {
+        // Recover the field element deterministically.
+        let field = <CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(seed);
+        let field: FieldNative =
+            FromBytes::read_le(&*field.to_bytes_le().map_err(|e| e.to_string())?).map_err(|e| e.to_string())?;
+        // Cast and recover the private key from the seed.
+        PrivateKeyNative::try_from(field).map_err(|e| e.to_string())
+    }
 }
 
 impl From<PrivateKeyNative> for PrivateKey {
@@ -255,4 +296,25 @@ mod tests {
             assert!(signature.verify_bytes(&private_key.to_address(), &message));
         }
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_mnemonic() {
+        let phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        let mnemonic = Mnemonic::from_phrase(phrase).unwrap();
+
+        // Ensure the private key is derived from the BIP-39 seed of the mnemonic and passphrase.
+        let private_key = PrivateKey::from_mnemonic(phrase, Some("TREZOR".to_string())).unwrap();
+        assert_eq!(private_key, PrivateKey::from(PrivateKey::from_seed_bytes(&mnemonic.seed("TREZOR")).unwrap()));
+        assert_ne!(private_key, PrivateKey::from_mnemonic(phrase, None).unwrap());
+        assert!(PrivateKey::from_mnemonic("legal winner thank year", None).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_keystore_round_trip() {
+        let private_key = PrivateKey::new();
+        let keystore = private_key.to_keystore("mypassword", Some(Network::TestnetV0)).unwrap();
+        assert!(keystore.contains("\"network\": \"testnet\""));
+        assert_eq!(private_key, PrivateKey::from_keystore(&keystore, "mypassword").unwrap());
+        assert!(PrivateKey::from_keystore(&keystore, "badpassword").is_err());
+    }
 }
@@ -14,30 +14,48 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::account::{Encryptor, PrivateKey};
+use crate::account::{Encryptor, KdfParams, PrivateKey, SALT_SIZE};
 
 use crate::types::native::CiphertextNative;
-use std::{ops::Deref, str::FromStr};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::prelude::*;
 
+/// Version tag of ciphertexts whose key is derived with a salted Argon2id KDF
+const VERSION_1: &str = "v1";
+/// Name of the key derivation function of version 1 ciphertexts
+const ARGON2ID: &str = "argon2id";
+
 /// Private Key in ciphertext form
-#[wasm_bindgen]
+///
+/// Ciphertexts are written as `v1$argon2id$m=<memory KiB>,t=<iterations>,p=<lanes>$<salt>$ciphertext1...`.
+/// Legacy ciphertexts, whose key is a plain hash of the secret, are written as `ciphertext1...`.
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PrivateKeyCiphertext(CiphertextNative);
+pub struct PrivateKeyCiphertext {
+    ciphertext: CiphertextNative,
+    kdf: Option<(KdfParams, [u8; SALT_SIZE])>,
+}
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl PrivateKeyCiphertext {
     /// Encrypt a private key using a secret string. The secret is sensitive and will be needed to
     /// decrypt the private key later, so it should be stored securely
     ///
     /// @param {PrivateKey} private_key Private key to encrypt
     /// @param {string} secret Secret to encrypt the private key with
+    /// @param {KdfParams | undefined} params (optional) Parameters of the key derivation, defaults
+    /// to 19 MiB of memory, 2 iterations and 1 lane
     /// @returns {PrivateKeyCiphertext} Private key ciphertext
-    #[wasm_bindgen(js_name = encryptPrivateKey)]
-    pub fn encrypt_private_key(private_key: &PrivateKey, secret: &str) -> Result<PrivateKeyCiphertext, String> {
-        let ciphertext = Encryptor::encrypt_private_key_with_secret(private_key, secret)
-            .map_err(|_| "Encryption failed".to_string())?;
-        Ok(Self::from(ciphertext))
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = encryptPrivateKey))]
+    pub fn encrypt_private_key(
+        private_key: &PrivateKey,
+        secret: &str,
+        params: Option<KdfParams>,
+    ) -> Result<PrivateKeyCiphertext, String> {
+        Encryptor::encrypt_private_key_with_secret(private_key, secret, &params.unwrap_or_default())
+            .map_err(|_| "Encryption failed".to_string())
     }
 
     /// Decrypts a private ciphertext using a secret string. This must be the same secret used to
@@ -45,35 +63,99 @@ impl PrivateKeyCiphertext {
     ///
     /// @param {string} secret Secret used to encrypt the private key
     /// @returns {PrivateKey} Private key
-    #[wasm_bindgen(js_name = decryptToPrivateKey)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = decryptToPrivateKey))]
     pub fn decrypt_to_private_key(&self, secret: &str) -> Result<PrivateKey, String> {
-        let private_key = Encryptor::decrypt_private_key_with_secret(&self.0, secret)
+        let private_key = Encryptor::decrypt_private_key_with_secret(self, secret)
             .map_err(|_| "Decryption failed - ciphertext was not a private key")?;
         Ok(PrivateKey::from(private_key))
     }
 
+    /// Decrypt the private key and encrypt it again with the current key derivation. Use this to
+    /// migrate legacy ciphertexts, to change the secret or to raise the key derivation parameters
+    ///
+    /// @param {string} secret Secret used to encrypt the private key
+    /// @param {string | undefined} new_secret (optional) Secret to encrypt the private key with,
+    /// defaults to the current secret
+    /// @param {KdfParams | undefined} params (optional) Parameters of the key derivation, defaults
+    /// to 19 MiB of memory, 2 iterations and 1 lane
+    /// @returns {PrivateKeyCiphertext} Private key ciphertext
+    pub fn reencrypt(
+        &self,
+        secret: &str,
+        new_secret: Option<String>,
+        params: Option<KdfParams>,
+    ) -> Result<PrivateKeyCiphertext, String> {
+        let private_key = self.decrypt_to_private_key(secret)?;
+        Self::encrypt_private_key(&private_key, new_secret.as_deref().unwrap_or(secret), params)
+    }
+
+    /// Check whether the ciphertext uses the legacy key derivation and should be migrated with
+    /// `reencrypt`
+    ///
+    /// @returns {boolean} True if the key of the ciphertext is a plain hash of the secret
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = isLegacy))]
+    pub fn is_legacy(&self) -> bool {
+        self.kdf.is_none()
+    }
+
     /// Returns the ciphertext string
     ///
     /// @returns {string} Ciphertext string
     #[allow(clippy::inherent_to_string)]
-    #[wasm_bindgen(js_name = toString)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toString))]
     pub fn to_string(&self) -> String {
-        self.0.to_string()
+        format!("{self}")
     }
 
     /// Creates a PrivateKeyCiphertext from a string
     ///
     /// @param {string} ciphertext Ciphertext string
     /// @returns {PrivateKeyCiphertext} Private key ciphertext
-    #[wasm_bindgen(js_name = fromString)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromString))]
     pub fn from_string(ciphertext: String) -> Result<PrivateKeyCiphertext, String> {
         Self::try_from(ciphertext).map_err(|_| "Invalid ciphertext".to_string())
     }
 }
 
+impl PrivateKeyCiphertext {
+    pub(crate) fn new(ciphertext: CiphertextNative, kdf: Option<(KdfParams, [u8; SALT_SIZE])>) -> Self {
+        Self { ciphertext, kdf }
+    }
+
+    pub(crate) fn ciphertext(&self) -> &CiphertextNative {
+        &self.ciphertext
+    }
+
+    pub(crate) fn kdf(&self) -> Option<&(KdfParams, [u8; SALT_SIZE])> {
+        self.kdf.as_ref()
+    }
+}
+
 impl From<CiphertextNative> for PrivateKeyCiphertext {
+    /// Wrap a legacy ciphertext
     fn from(ciphertext: CiphertextNative) -> Self {
-        Self(ciphertext)
+        Self::new(ciphertext, None)
+    }
+}
+
+impl FromStr for PrivateKeyCiphertext {
+    type Err = String;
+
+    fn from_str(ciphertext: &str) -> Result<Self, Self::Err> {
+        let parse_ciphertext =
+            |ciphertext: &str| CiphertextNative::from_str(ciphertext).map_err(|_| "Invalid ciphertext".to_string());
+        match ciphertext.split('$').collect::<Vec<_>>()[..] {
+            [legacy] => Ok(Self::from(parse_ciphertext(legacy)?)),
+            [VERSION_1, ARGON2ID, params, salt, ciphertext] => {
+                let params = KdfParams::from_str(params)?;
+                let salt = hex::decode(salt)
+                    .ok()
+                    .and_then(|salt| <[u8; SALT_SIZE]>::try_from(salt).ok())
+                    .ok_or("Invalid key derivation salt".to_string())?;
+                Ok(Self::new(parse_ciphertext(ciphertext)?, Some((params, salt))))
+            }
+            _ => Err("Unsupported ciphertext version".to_string()),
+        }
     }
 }
 
@@ -81,15 +163,30 @@ impl TryFrom<String> for PrivateKeyCiphertext {
     type Error = String;
 
     fn try_from(ciphertext: String) -> Result<Self, Self::Error> {
-        Ok(Self(CiphertextNative::from_str(&ciphertext).map_err(|_| "Invalid ciphertext".to_string())?))
+        Self::from_str(&ciphertext)
+    }
+}
+
+impl fmt::Display for PrivateKeyCiphertext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kdf {
+            Some((params, salt)) => {
+                write!(f, "{VERSION_1}${ARGON2ID}${params}${}${}", hex::encode(salt), self.ciphertext)
+            }
+            None => write!(f, "{}", self.ciphertext),
+        }
     }
 }
 
-impl Deref for PrivateKeyCiphertext {
-    type Target = CiphertextNative;
+impl Serialize for PrivateKeyCiphertext {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+impl<'de> Deserialize<'de> for PrivateKeyCiphertext {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom)
     }
 }
 
@@ -102,7 +199,8 @@ mod tests {
     #[wasm_bindgen_test]
     fn test_private_key_ciphertext_to_and_from_string() {
         let private_key = PrivateKey::new();
-        let private_key_ciphertext = PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypasword").unwrap();
+        let private_key_ciphertext =
+            PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypasword", None).unwrap();
         let private_key_ciphertext_2 = PrivateKeyCiphertext::from_string(private_key_ciphertext.to_string()).unwrap();
 
         // Assert the round trip to and from string journey results in the same key
@@ -129,7 +227,8 @@ mod tests {
     #[wasm_bindgen_test]
     fn test_private_key_ciphertext_encrypt_and_decrypt() {
         let private_key = PrivateKey::new();
-        let private_key_ciphertext = PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword").unwrap();
+        let private_key_ciphertext =
+            PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword", None).unwrap();
         let recovered_private_key = private_key_ciphertext.decrypt_to_private_key("mypassword").unwrap();
         assert_eq!(private_key, recovered_private_key);
     }
@@ -137,7 +236,8 @@ mod tests {
     #[wasm_bindgen_test]
     fn test_private_key_ciphertext_doesnt_decrypt_with_wrong_password() {
         let private_key = PrivateKey::new();
-        let private_key_ciphertext = PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword").unwrap();
+        let private_key_ciphertext =
+            PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword", None).unwrap();
         let recovered_private_key = private_key_ciphertext.decrypt_to_private_key("wrong_password");
         assert!(recovered_private_key.is_err())
     }
@@ -145,8 +245,10 @@ mod tests {
     #[wasm_bindgen_test]
     fn test_private_key_ciphertext_doesnt_produce_same_ciphertext_on_different_runs() {
         let private_key = PrivateKey::new();
-        let private_key_ciphertext = PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword").unwrap();
-        let private_key_ciphertext_2 = PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword").unwrap();
+        let private_key_ciphertext =
+            PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword", None).unwrap();
+        let private_key_ciphertext_2 =
+            PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword", None).unwrap();
         assert_ne!(private_key_ciphertext, private_key_ciphertext_2);
 
         // Assert that we can decrypt both ciphertexts with the same secret despite being different
@@ -158,8 +260,10 @@ mod tests {
     #[wasm_bindgen_test]
     fn test_private_key_ciphertext_encrypted_with_different_passwords_match() {
         let private_key = PrivateKey::new();
-        let private_key_ciphertext = PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword").unwrap();
-        let private_key_ciphertext_2 = PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword2").unwrap();
+        let private_key_ciphertext =
+            PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword", None).unwrap();
+        let private_key_ciphertext_2 =
+            PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword2", None).unwrap();
         assert_ne!(private_key_ciphertext, private_key_ciphertext_2);
 
         // Assert that we can decrypt both ciphertexts with the same secret despite being different
@@ -172,8 +276,10 @@ mod tests {
     fn test_private_key_ciphertext_different_private_keys_encrypted_with_same_password_dont_match() {
         let private_key = PrivateKey::new();
         let private_key_2 = PrivateKey::new();
-        let private_key_ciphertext = PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword").unwrap();
-        let private_key_ciphertext_2 = PrivateKeyCiphertext::encrypt_private_key(&private_key_2, "mypassword").unwrap();
+        let private_key_ciphertext =
+            PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword", None).unwrap();
+        let private_key_ciphertext_2 =
+            PrivateKeyCiphertext::encrypt_private_key(&private_key_2, "mypassword", None).unwrap();
         assert_ne!(private_key_ciphertext, private_key_ciphertext_2);
 
         // Assert that private key plaintexts dont match
@@ -227,4 +333,53 @@ mod tests {
         let bad_secret_attempt = PrivateKey::from_private_key_ciphertext(&private_key_ciphertext, "badpassword");
         assert!(bad_secret_attempt.is_err());
     }
+
+    #[wasm_bindgen_test]
+    fn test_private_key_ciphertext_reencrypt_migrates_legacy_ciphertexts() {
+        let private_key =
+            PrivateKey::from_string("APrivateKey1zkpAYS46Dq4rnt9wdohyWMwdmjmTeMJKPZdp5AhvjXZDsVG").unwrap();
+        let ciphertext = "ciphertext1qvqg7rgvam3xdcu55pwu6sl8rxwefxaj5gwthk0yzln6jv5fastzup0qn0qftqlqq7jcckyx03fzv9kke0z9puwd7cl7jzyhxfy2f2juplz39dkqs6p24urhxymhv364qm3z8mvyklv5gr52n4fxr2z59jgqytyddj8";
+        let legacy = PrivateKeyCiphertext::from_string(ciphertext.to_string()).unwrap();
+        assert!(legacy.is_legacy());
+        assert_eq!(legacy.to_string(), ciphertext);
+
+        // Migrate the legacy ciphertext and change the secret while doing so
+        let params = KdfParams::new(64, 1, 1).unwrap();
+        let migrated = legacy.reencrypt("mypassword", Some("newpassword".to_string()), Some(params)).unwrap();
+        assert!(!migrated.is_legacy());
+        assert!(migrated.to_string().starts_with("v1$argon2id$m=64,t=1,p=1$"));
+        assert_eq!(private_key, migrated.decrypt_to_private_key("newpassword").unwrap());
+        assert!(migrated.decrypt_to_private_key("mypassword").is_err());
+
+        // Ensure reencryption fails with an incorrect secret
+        assert!(legacy.reencrypt("badpassword", None, Some(params)).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_private_key_ciphertext_versioned_string_round_trip() {
+        let private_key = PrivateKey::new();
+        let params = KdfParams::new(64, 1, 1).unwrap();
+        let private_key_ciphertext =
+            PrivateKeyCiphertext::encrypt_private_key(&private_key, "mypassword", Some(params)).unwrap();
+        let private_key_ciphertext_2 = PrivateKeyCiphertext::from_string(private_key_ciphertext.to_string()).unwrap();
+        assert_eq!(private_key_ciphertext, private_key_ciphertext_2);
+        assert_eq!(private_key, private_key_ciphertext_2.decrypt_to_private_key("mypassword").unwrap());
+
+        // Ensure unknown versions, functions, parameters and salts fail to parse
+        let ciphertext = private_key_ciphertext.to_string();
+        for bad_ciphertext in [
+            ciphertext.replacen("v1$", "v2$", 1),
+            ciphertext.replacen("argon2id", "scrypt", 1),
+            ciphertext.replacen("m=64,", "", 1),
+            ciphertext.replacen("$ciphertext1", "00$ciphertext1", 1),
+        ] {
+            assert!(PrivateKeyCiphertext::from_string(bad_ciphertext).is_err());
+        }
+
+        // Ensure a header asking for more memory than the cap is rejected when parsed, before the
+        // key derivation allocates it
+        let bad_ciphertext = ciphertext.replacen("m=64,", "m=4194304,", 1);
+        let error = bad_ciphertext.parse::<PrivateKeyCiphertext>().unwrap_err();
+        assert!(error.starts_with("Key derivation parameters"));
+    }
 }
@@ -15,19 +15,32 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    account::{Address, PrivateKey},
-    types::native::{CurrentNetwork, ComputeKey, Network, Scalar, SignatureNative, ToFields, Value}
+    account::{Address, PrivateKey, SignedValue},
+    types::native::{
+        ComputeKey,
+        CurrentNetwork,
+        FieldNative,
+        Network,
+        PrivateKeyNative,
+        Scalar,
+        SignatureNative,
+        ToFields,
+        Uniform,
+        ValueNative,
+    },
+    SdkError,
 };
-use snarkvm_wasm::utilities::Uniform;
+
 use core::{fmt, ops::Deref, str::FromStr};
 use rand::{rngs::StdRng, SeedableRng};
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::prelude::*;
 
 /// Cryptographic signature of a message signed by an Aleo account
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 pub struct Signature(SignatureNative);
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl Signature {
     /// Sign a message with a private key
     ///
@@ -38,77 +51,46 @@ impl Signature {
         Self(SignatureNative::sign_bytes(private_key, message, &mut StdRng::from_entropy()).unwrap())
     }
 
+    /// Sign the field elements of an Aleo value with a private key, using a nonce sampled from
+    /// the seed provided
+    ///
+    /// @param {PrivateKey} private_key The private key to sign the message with
+    /// @param {Uint8Array} message UTF-8 bytes of the Aleo value to sign, e.g. "{ amount: 5u64 }"
+    /// @param {Uint8Array} seed 32 byte seed the signing nonce is sampled from
+    /// @returns {Signature} Signature of the message
+    pub fn sign_message(private_key: &PrivateKey, message: &[u8], seed: &[u8]) -> Result<Signature, SdkError> {
+        let message = core::str::from_utf8(message)
+            .map_err(|error| SdkError::InvalidArgument(format!("The message is not valid UTF-8: {error}")))?;
+        Ok(Self::sign_value(private_key, message, seed)?.signature())
+    }
 
-    pub fn sign_message(private_key: &PrivateKey, message: &[u8], seed: &[u8]) -> Self {
-        // seed used for generating rng
-        let seed_array = <[u8; 32]>::try_from(seed).expect("Invalid seed length");
-        let mut rng = StdRng::from_seed(seed_array);
-
-        let message_slice: &[u8] = &message;
-        let message_str = match std::str::from_utf8(message_slice) {
-            Ok(v) => v.to_string(),
-            Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
-        };
-        let message_value: Value<CurrentNetwork> = Value::from_str(&message_str).unwrap();
-        let mut message_to_fields = message_value.to_fields().unwrap();
-        if message_to_fields.len() > CurrentNetwork::MAX_DATA_SIZE_IN_FIELDS as usize {
-            panic!("Cannot sign the message: the message exceeds maximum allowed size");
-        };
-
-        let nonce = Scalar::rand(&mut rng);
-        let g_r = Network::g_scalar_multiply(&nonce);
-        let pk_sig = Network::g_scalar_multiply(&private_key.sk_sig());
-        let pr_sig = Network::g_scalar_multiply(&private_key.r_sig());
-        let compute_key = ComputeKey::try_from((pk_sig, pr_sig)).unwrap();
-        let address = compute_key.to_address();
-
-        // need to splice in g_r, pk_sig, pr_sig, address ... and sign against that
-        let prepend_items: Vec<_> = [g_r, pk_sig, pr_sig, *address]
-        .iter() // Convert the array to an iterator
-        .map(|&point| point.to_x_coordinate())
-        .collect();
-        message_to_fields.splice(0..0, prepend_items);
-
-        // Compute the verifier challenge.
-        let challenge = Network::hash_to_scalar_psd8(&message_to_fields).unwrap();
-
-        // Compute the prover response.
-        let response = nonce - (challenge * private_key.sk_sig());
-
-        let sig = SignatureNative::from((challenge, response, compute_key));
-
-        // note: keeping result, dict, etc below here for debugging message that one is signing against.
-        // to see message one is signing against, change return to string and return result
-
-        // let result = format!("{{\n{}\n}} + {}", string_representation, sig);
-
-        // let mut my_dict: HashMap<String, Value<CurrentNetwork>> = HashMap::new();
-
-        // for (index, field) in message.clone().into_iter().enumerate() {
-        //     let lit = Literal::Field(field);
-        //     let val = Value::from(&lit); // assuming the conversion takes a reference
-        //     let key = format!("field_{}", index + 1);  // generate key in the format "field_i"
-        //     my_dict.insert(key, val);
-        // }
-
-        // Output the signature.
-        // let string_representation: String = my_dict.iter()
-        // .map(|(k, v)| (k, k.trim_start_matches("field_").parse::<usize>().unwrap_or(0), v)) // extract numeric part
-        // .sorted_by(|(_, a_num, _), (_, b_num, _)| a_num.cmp(b_num)) // sort by the numeric part
-        // .map(|(key, _, value)| format!("  {}: {:?}", key, value)) // Use Debug trait for formatting
-        // .collect::<Vec<String>>()
-        // .join(",\n");
-
-        // Verify the signature.
-        // let address = Address::try_from(&private_key).unwrap();
-        // assert!(signature.verify(&address, &message));
-
-        // // Print the results.
-        // print!("{signature}");
-        // print!(" {address}");
-        // print!(" \"{value}\"")
+    /// Sign an Aleo value (a literal, struct or array) so that the signature is accepted by
+    /// `signature::verify` in Leo. The signature is over the field elements of the value and its
+    /// nonce is sampled from the seed, so signing is deterministic for a seed.
+    ///
+    /// @param {PrivateKey} private_key The private key to sign the value with
+    /// @param {string} value String representation of the value, e.g. "{ amount: 5u64, to: aleo1... }"
+    /// @param {Uint8Array} seed 32 byte seed the signing nonce is sampled from
+    /// @returns {SignedValue} The signature together with the value and its field elements
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = signValue))]
+    pub fn sign_value(private_key: &PrivateKey, value: &str, seed: &[u8]) -> Result<SignedValue, SdkError> {
+        let (value, fields) = value_to_fields(value)?;
+        let rng = &mut StdRng::from_seed(parse_seed(seed)?);
+        let signature =
+            SignatureNative::sign(private_key, &fields, rng).map_err(|error| SdkError::Other(error.to_string()))?;
+        Ok(SignedValue::new(signature, value, fields))
+    }
 
-        Self(sig)
+    /// Verify a signature of an Aleo value with an address, checking the same field elements as
+    /// `signature::verify` in Leo
+    ///
+    /// @param {Address} address The address to verify the signature with
+    /// @param {string} value String representation of the signed value
+    /// @returns {boolean} True if the signature is valid, false otherwise
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = verifyValue))]
+    pub fn verify_value(&self, address: &Address, value: &str) -> Result<bool, SdkError> {
+        let (_, fields) = value_to_fields(value)?;
+        Ok(self.0.verify(address, &fields))
     }
 
     /// Verify a signature of a message with an address
@@ -141,7 +123,50 @@ impl FromStr for Signature {
     type Err = anyhow::Error;
 
     fn from_str(signature: &str) -> Result<Self, Self::Err> {
-        Ok(Self(SignatureNative::from_str(signature).unwrap()))
+        Ok(Self(SignatureNative::from_str(signature)?))
+    }
+}
+
+/// Parse a value and compute the field elements a signature over it covers, which are the field
+/// elements `signature::verify` in Leo checks
+pub(crate) fn value_to_fields(value: &str) -> Result<(ValueNative, Vec<FieldNative>), SdkError> {
+    let value = ValueNative::from_str(value)
+        .map_err(|error| SdkError::InvalidArgument(format!("The message is not a valid Aleo value: {error}")))?;
+    let fields = value.to_fields().map_err(|error| SdkError::InvalidArgument(error.to_string()))?;
+    if fields.len() > CurrentNetwork::MAX_DATA_SIZE_IN_FIELDS as usize {
+        return Err(SdkError::InvalidArgument(format!(
+            "The message is {} fields long which exceeds the maximum of {}",
+            fields.len(),
+            CurrentNetwork::MAX_DATA_SIZE_IN_FIELDS
+        )));
+    }
+    Ok((value, fields))
+}
+
+/// Parse the 32 byte seed the signing nonce is sampled from
+pub(crate) fn parse_seed(seed: &[u8]) -> Result<[u8; 32], SdkError> {
+    <[u8; 32]>::try_from(seed).map_err(|_| {
+        SdkError::InvalidArgument(format!("The seed must be 32 bytes but {} bytes were provided", seed.len()))
+    })
+}
+
+/// Compute the fields a signature with the nonce sampled from a seed is bound to: the
+/// x-coordinates of `g^r`, `pk_sig`, `pr_sig` and the address of the signer
+pub(crate) fn signing_prefix(private_key: &PrivateKeyNative, seed: &[u8]) -> Result<Vec<FieldNative>, SdkError> {
+    let nonce = Scalar::<CurrentNetwork>::rand(&mut StdRng::from_seed(parse_seed(seed)?));
+
+    let g_r = CurrentNetwork::g_scalar_multiply(&nonce);
+    let pk_sig = CurrentNetwork::g_scalar_multiply(&private_key.sk_sig());
+    let pr_sig = CurrentNetwork::g_scalar_multiply(&private_key.r_sig());
+    let compute_key = ComputeKey::try_from((pk_sig, pr_sig)).map_err(|error| SdkError::Other(error.to_string()))?;
+    let address = compute_key.to_address();
+
+    Ok([g_r, pk_sig, pr_sig, *address].iter().map(|point| point.to_x_coordinate()).collect())
+}
+
+impl From<SignatureNative> for Signature {
+    fn from(signature: SignatureNative) -> Self {
+        Self(signature)
     }
 }
 
@@ -186,4 +211,59 @@ mod tests {
             assert!(!signature.verify(&private_key.to_address(), &bad_message));
         }
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_message_and_verify() {
+        let rng = &mut StdRng::from_entropy();
+        for _ in 0..ITERATIONS {
+            // Sample a private key, a message and a seed.
+            let private_key = PrivateKey::new();
+            let message = format!("{{ amount: {}u64, id: {} }}", rng.gen::<u64>(), FieldNative::rand(rng));
+            let seed: [u8; 32] = rng.gen();
+
+            // Sign the message and check the signature is valid for its fields.
+            let signature = Signature::sign_message(&private_key, message.as_bytes(), &seed).unwrap();
+            let fields = ValueNative::from_str(&message).unwrap().to_fields().unwrap();
+            assert!(signature.0.verify(&private_key.to_address(), &fields));
+
+            // Check signing is deterministic for a seed.
+            let again = Signature::sign_message(&private_key, message.as_bytes(), &seed).unwrap();
+            assert_eq!(signature.to_string(), again.to_string());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_message_random_input() {
+        let rng = &mut StdRng::from_entropy();
+        let private_key = PrivateKey::new();
+        for _ in 0..ITERATIONS {
+            // Sample arbitrary bytes and a seed of arbitrary length.
+            let message: Vec<u8> = (0..rng.gen_range(0..64)).map(|_| rng.gen()).collect();
+            let seed: Vec<u8> = (0..rng.gen_range(0..40)).map(|_| rng.gen()).collect();
+
+            // Signing must return an error rather than panic.
+            let result = Signature::sign_message(&private_key, &message, &seed);
+            if seed.len() != 32 {
+                assert!(matches!(result, Err(SdkError::InvalidArgument(_))));
+            }
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_message_invalid() {
+        let private_key = PrivateKey::new();
+        let seed = [0u8; 32];
+
+        // Invalid UTF-8.
+        assert!(Signature::sign_message(&private_key, &[0xff, 0xfe], &seed).is_err());
+        // Not an Aleo value.
+        assert!(Signature::sign_message(&private_key, b"not a value", &seed).is_err());
+        // Too many fields.
+        let row = format!("[{}]", vec!["0field"; 32].join(", "));
+        let table = format!("[{}]", vec![row; 32].join(", "));
+        let message = format!("[{}]", vec![table; 8].join(", "));
+        assert!(Signature::sign_message(&private_key, message.as_bytes(), &seed).is_err());
+        // Seed of the wrong length.
+        assert!(Signature::sign_message(&private_key, b"1u64", &[0u8; 31]).is_err());
+    }
 }
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::{Address, Signature},
+    types::native::{FieldNative, SignatureNative, ValueNative},
+};
+
+#[cfg(not(feature = "native"))]
+use js_sys::Array;
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::prelude::*;
+
+/// An Aleo value together with a signature over its field elements. The signature is accepted by
+/// `signature::verify` in Leo when called with the signer's address and the same value.
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone, Debug)]
+pub struct SignedValue {
+    signature: SignatureNative,
+    value: ValueNative,
+    fields: Vec<FieldNative>,
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl SignedValue {
+    /// Get the signature of the value
+    ///
+    /// @returns {Signature} Signature of the value
+    pub fn signature(&self) -> Signature {
+        Signature::from(self.signature)
+    }
+
+    /// Get the signed value as a literal which can be passed as the input of a Leo or Aleo
+    /// function that verifies the signature
+    ///
+    /// @returns {string} String representation of the value
+    pub fn value(&self) -> String {
+        self.value.to_string()
+    }
+
+    /// Get the field elements of the value the signature covers
+    ///
+    /// @returns {Array<string>} String representations of the field elements
+    #[cfg(not(feature = "native"))]
+    pub fn fields(&self) -> Array {
+        self.fields.iter().map(|field| JsValue::from_str(&field.to_string())).collect()
+    }
+
+    /// Verify the signature of the value with an address
+    ///
+    /// @param {Address} address The address to verify the signature with
+    /// @returns {boolean} True if the value was signed by the address, false otherwise
+    pub fn verify(&self, address: &Address) -> bool {
+        self.signature.verify(address, &self.fields)
+    }
+}
+
+impl SignedValue {
+    pub(crate) fn new(signature: SignatureNative, value: ValueNative, fields: Vec<FieldNative>) -> Self {
+        Self { signature, value, fields }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        account::PrivateKey,
+        types::native::{ToFields, Uniform},
+    };
+
+    use core::str::FromStr;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use wasm_bindgen_test::*;
+
+    const ITERATIONS: u64 = 100;
+
+    /// Sample a literal, a struct or a nested array of fields
+    fn sample_value(rng: &mut StdRng) -> String {
+        match rng.gen_range(0..3) {
+            0 => format!("{}u128", rng.gen::<u128>()),
+            1 => format!(
+                "{{ owner: {}, amount: {}u64, memo: {{ id: {}, flag: {} }} }}",
+                PrivateKey::new().to_address(),
+                rng.gen::<u64>(),
+                FieldNative::rand(rng),
+                rng.gen::<bool>()
+            ),
+            _ => {
+                let row =
+                    format!("[{}]", (0..4).map(|_| FieldNative::rand(rng).to_string()).collect::<Vec<_>>().join(", "));
+                format!("[{}]", vec![row; rng.gen_range(1..4)].join(", "))
+            }
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_value_matches_snarkvm() {
+        let rng = &mut StdRng::from_entropy();
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::new();
+            let address = private_key.to_address();
+            let value = sample_value(rng);
+            let seed: [u8; 32] = rng.gen();
+
+            let signed = Signature::sign_value(&private_key, &value, &seed).unwrap();
+
+            // The signed fields are the fields of the value, which is what Leo's `signature::verify` checks.
+            let fields = ValueNative::from_str(&value).unwrap().to_fields().unwrap();
+            assert_eq!(signed.fields, fields);
+            #[cfg(not(feature = "native"))]
+            {
+                let fields_js: Vec<String> = signed.fields().iter().map(|field| field.as_string().unwrap()).collect();
+                assert_eq!(fields_js, fields.iter().map(|field| field.to_string()).collect::<Vec<_>>());
+            }
+            assert_eq!(ValueNative::from_str(&signed.value()).unwrap(), ValueNative::from_str(&value).unwrap());
+
+            // The signature round trips against snarkVM's own signing and verification.
+            let expected = SignatureNative::sign(&private_key, &fields, &mut StdRng::from_seed(seed)).unwrap();
+            assert_eq!(signed.signature().to_string(), expected.to_string());
+            assert!(expected.verify(&address, &fields));
+            assert!(signed.verify(&address));
+            assert!(signed.signature().verify_value(&address, &value).unwrap());
+
+            // The signature does not verify for another signer or another value.
+            let other = PrivateKey::new().to_address();
+            assert!(!signed.verify(&other));
+            assert!(!signed.signature().verify_value(&address, &sample_value(rng)).unwrap());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_value_invalid() {
+        let private_key = PrivateKey::new();
+        assert!(Signature::sign_value(&private_key, "{ amount: 5u64", &[0u8; 32]).is_err());
+        assert!(Signature::sign_value(&private_key, "5u64", &[0u8; 16]).is_err());
+        let signature = Signature::sign_value(&private_key, "5u64", &[0u8; 32]).unwrap().signature();
+        assert!(signature.verify_value(&private_key.to_address(), "not a value").is_err());
+    }
+}
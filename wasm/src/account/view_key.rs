@@ -15,24 +15,36 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{Address, PrivateKey};
-use crate::record::RecordCiphertext;
-use snarkvm_console::{
-  account::ViewKey as RustViewKey,
-  network::{Testnet3, Network},
-  types::{Group, U16, Field},
-  program::{Ciphertext, Identifier}
+use crate::{
+    programs::{entry_view_key, transition_view_key},
+    record::RecordCiphertext,
+    types::{
+        native::{
+            canary,
+            mainnet,
+            testnet,
+            Ciphertext,
+            Group,
+            Identifier,
+            Network as NetworkTrait,
+            ProgramID,
+            ViewKey as ViewKeyGeneric,
+            ViewKeyNative,
+        },
+        to_network,
+    },
+    Network,
 };
-use snarkvm_utilities::ToBits;
 
-use crate::types::native::ViewKeyNative;
 use core::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::prelude::*;
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ViewKey(ViewKeyNative);
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl ViewKey {
     /// Create a new view key from a private key
     ///
@@ -77,34 +89,79 @@ impl ViewKey {
         }
     }
 
-    // // Decrypt ciphertext (non-record), usually program inputs and outputs, ex: "cipher1as1l2jj32392390fh2eif02h02f20f0h"
-    // pub fn decrypt_ciphertext(
-    //   &self,
-    //   ciphertext: &str,
-    //   tpk: &str,
-    //   program_name: &str,
-    //   function_name: &str,
-    //   index: u16,
-    // ) -> Result<String, String> {
-    //     let vk: RustViewKey<Testnet3> =
-    //         RustViewKey::<Testnet3>::from_str(&self.to_string()).map_err(|error| error.to_string())?;
-    //     let tpk = Group::<Testnet3>::from_str(tpk).unwrap();
-    //     let tvk = (tpk * *vk).to_x_coordinate();
-    //     let bits = &(
-    //         U16::<Testnet3>::new(3).to_bits_le(),
-    //         &Identifier::<Testnet3>::from_str(program_name).unwrap().to_bits_le(),
-    //         &Identifier::<Testnet3>::from_str("aleo").unwrap().to_bits_le(),
-    //         &Identifier::<Testnet3>::from_str(function_name).unwrap().to_bits_le(),
-    //     ).collect();
-
-    //     let function_id = <Testnet3 as Network>::hash_bhp1024(bits).unwrap();
-    //     let ivk = <Testnet3 as Network>::hash_psd4(&[function_id, tvk, Field::from_u16(index)]).unwrap();
-    //     let ciphertext = Ciphertext::<Testnet3>::from_str(ciphertext).unwrap();
-    //     match ciphertext.decrypt_symmetric(ivk) {
-    //         Ok(plaintext) => Ok(plaintext.to_string()),
-    //         Err(error) => Err(error.to_string()),
-    //     }
-    // }
+    /// Decrypt a private (non-record) input or output of a transition created by the account of
+    /// the view key
+    ///
+    /// @param {string} ciphertext String representation of the ciphertext, e.g. "ciphertext1..."
+    /// @param {string} tpk Transition public key of the transition the ciphertext belongs to
+    /// @param {string} program_id Id of the program the transition belongs to
+    /// @param {string} function_name Name of the function the transition executed
+    /// @param {number} index Index of the input, or number of inputs plus the index of the output
+    /// @param {Network | undefined} network (optional) The network the transition belongs to,
+    /// defaults to mainnet
+    /// @returns {string} String representation of the plaintext
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = decryptCiphertext))]
+    pub fn decrypt_ciphertext(
+        &self,
+        ciphertext: &str,
+        tpk: &str,
+        program_id: &str,
+        function_name: &str,
+        index: u16,
+        network: Option<Network>,
+    ) -> Result<String, String> {
+        match network.unwrap_or_default() {
+            Network::MainnetV0 => self.decrypt_ciphertext_native::<mainnet::CurrentNetwork>(
+                ciphertext,
+                tpk,
+                program_id,
+                function_name,
+                index,
+            ),
+            Network::TestnetV0 => self.decrypt_ciphertext_native::<testnet::CurrentNetwork>(
+                ciphertext,
+                tpk,
+                program_id,
+                function_name,
+                index,
+            ),
+            Network::CanaryV0 => self.decrypt_ciphertext_native::<canary::CurrentNetwork>(
+                ciphertext,
+                tpk,
+                program_id,
+                function_name,
+                index,
+            ),
+        }
+    }
+}
+
+impl ViewKey {
+    fn decrypt_ciphertext_native<N: NetworkTrait>(
+        &self,
+        ciphertext: &str,
+        tpk: &str,
+        program_id: &str,
+        function_name: &str,
+        index: u16,
+    ) -> Result<String, String> {
+        let view_key: ViewKeyGeneric<N> = to_network(self.0)?;
+        let tpk =
+            Group::<N>::from_str(tpk).map_err(|_| "The transition public key provided was invalid".to_string())?;
+        let program_id =
+            ProgramID::<N>::from_str(program_id).map_err(|_| format!("{program_id} is an invalid program name"))?;
+        let function_name = Identifier::<N>::from_str(function_name)
+            .map_err(|_| format!("{function_name} is an invalid identifier"))?;
+        let ciphertext = Ciphertext::<N>::from_str(ciphertext)
+            .map_err(|_| "The ciphertext string provided was invalid".to_string())?;
+
+        let tvk = transition_view_key(&view_key, &tpk);
+        let entry_key = entry_view_key(tvk, &program_id, &function_name, index)?;
+        let plaintext = ciphertext
+            .decrypt_symmetric(entry_key)
+            .map_err(|_| "Decryption failed - view key did not create the transition".to_string())?;
+        Ok(plaintext.to_string())
+    }
 }
 
 impl FromStr for ViewKey {
@@ -169,4 +226,4 @@ mod tests {
         let plaintext = ciphertext.decrypt(&incorrect_view_key);
         assert!(plaintext.is_err());
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::NetworkMismatch;
+
+#[cfg(not(feature = "native"))]
+use js_sys::Reflect;
+use std::fmt;
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::JsValue;
+
+/// Errors returned by the program manager. They are thrown to javascript as `Error` objects
+/// whose `code` property identifies the kind of failure independently of the message, along with
+/// the details of the failure such as the input `index` or the `required` fee.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SdkError {
+    /// The fee or the record paying an amount does not cover the microcredits required
+    InsufficientFee { required: u64, available: u64 },
+    /// A function input could not be parsed as the type the function expects
+    InvalidInput { index: usize, expected: String },
+    /// An argument such as a program, function name or amount was invalid
+    InvalidArgument(String),
+    /// A program is not loaded in the program manager
+    ProgramNotFound(String),
+    /// A program differs from the program with the same id loaded in the program manager
+    ProgramMismatch(String),
+    /// Proving and verifying keys are missing their counterpart or do not match the function
+    KeyMismatch(String),
+    /// An import of a program was neither provided nor loaded in the program manager
+    ImportMissing(String),
+    /// Fetching the state root or state paths needed for inclusion proofs failed
+    QueryFailed(String),
+    /// Creating or verifying a proof failed
+    ProofFailed(String),
+    /// A value created for one network was used with another
+    NetworkMismatch(NetworkMismatch),
+    /// The operation was aborted through its abort signal
+    Aborted,
+    /// The program manager is building a transaction and cannot be used synchronously until the
+    /// build finishes
+    Busy,
+    /// A transaction would be rejected by the network, e.g. because its finalize logic aborts
+    Rejected(String),
+    /// Any other failure
+    Other(String),
+}
+
+impl SdkError {
+    /// Get the stable code identifying the kind of failure
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InsufficientFee { .. } => "INSUFFICIENT_FEE",
+            Self::InvalidInput { .. } => "INVALID_INPUT",
+            Self::InvalidArgument(_) => "INVALID_ARGUMENT",
+            Self::ProgramNotFound(_) => "PROGRAM_NOT_FOUND",
+            Self::ProgramMismatch(_) => "PROGRAM_MISMATCH",
+            Self::KeyMismatch(_) => "KEY_MISMATCH",
+            Self::ImportMissing(_) => "IMPORT_MISSING",
+            Self::QueryFailed(_) => "QUERY_FAILED",
+            Self::ProofFailed(_) => "PROOF_FAILED",
+            Self::NetworkMismatch(_) => "NETWORK_MISMATCH",
+            Self::Aborted => "ABORTED",
+            Self::Busy => "BUSY",
+            Self::Rejected(_) => "REJECTED",
+            Self::Other(_) => "UNKNOWN",
+        }
+    }
+}
+
+impl fmt::Display for SdkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InsufficientFee { required, available } => {
+                write!(f, "Insufficient fee: {required} microcredits are required but only {available} are available")
+            }
+            Self::InvalidInput { index, expected } => write!(f, "Invalid input {index}: expected a {expected}"),
+            Self::ProgramNotFound(program_id) => write!(f, "Program {program_id} was not found"),
+            Self::ImportMissing(program_id) => write!(f, "Import {program_id} was not provided"),
+            Self::NetworkMismatch(error) => write!(f, "{error}"),
+            Self::Aborted => write!(f, "The operation was aborted"),
+            Self::Busy => write!(f, "The program manager is busy building a transaction, await the build first"),
+            Self::InvalidArgument(message)
+            | Self::ProgramMismatch(message)
+            | Self::KeyMismatch(message)
+            | Self::QueryFailed(message)
+            | Self::ProofFailed(message)
+            | Self::Rejected(message)
+            | Self::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for SdkError {}
+
+impl From<String> for SdkError {
+    fn from(message: String) -> Self {
+        Self::Other(message)
+    }
+}
+
+impl From<&str> for SdkError {
+    fn from(message: &str) -> Self {
+        Self::Other(message.to_string())
+    }
+}
+
+impl From<NetworkMismatch> for SdkError {
+    fn from(error: NetworkMismatch) -> Self {
+        Self::NetworkMismatch(error)
+    }
+}
+
+#[cfg(not(feature = "native"))]
+impl From<SdkError> for JsValue {
+    fn from(error: SdkError) -> Self {
+        let object = js_sys::Error::new(&error.to_string());
+        object.set_name("SdkError");
+        let set = |key: &str, value: JsValue| {
+            let _ = Reflect::set(&object, &key.into(), &value);
+        };
+        set("code", error.code().into());
+        match &error {
+            SdkError::InsufficientFee { required, available } => {
+                set("required", JsValue::from(*required as f64));
+                set("available", JsValue::from(*available as f64));
+            }
+            SdkError::InvalidInput { index, expected } => {
+                set("index", JsValue::from(*index as u32));
+                set("expected", expected.into());
+            }
+            SdkError::ProgramNotFound(program_id) | SdkError::ImportMissing(program_id) => {
+                set("programId", program_id.into());
+            }
+            SdkError::NetworkMismatch(mismatch) => {
+                set("expected", mismatch.expected.to_string().into());
+                set("found", mismatch.found.to_string().into());
+            }
+            _ => {}
+        }
+        object.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Network;
+
+    #[test]
+    fn test_codes_and_messages() {
+        let error = SdkError::InsufficientFee { required: 10, available: 5 };
+        assert_eq!(error.code(), "INSUFFICIENT_FEE");
+        assert_eq!(error.to_string(), "Insufficient fee: 10 microcredits are required but only 5 are available");
+
+        let error = SdkError::InvalidInput { index: 1, expected: "u32.public".to_string() };
+        assert_eq!(error.code(), "INVALID_INPUT");
+        assert_eq!(error.to_string(), "Invalid input 1: expected a u32.public");
+
+        let error = SdkError::from(Network::MainnetV0.ensure(Network::TestnetV0).unwrap_err());
+        assert_eq!(error.code(), "NETWORK_MISMATCH");
+        assert_eq!(error.to_string(), "Network mismatch: expected a mainnet value but found a testnet value");
+
+        let error = SdkError::Rejected("Transaction at1... was rejected".to_string());
+        assert_eq!(error.code(), "REJECTED");
+
+        let error = SdkError::from("Something went wrong".to_string());
+        assert_eq!(error.code(), "UNKNOWN");
+        assert_eq!(error.to_string(), "Something went wrong");
+    }
+}
@@ -133,6 +133,14 @@
 //! -- --features "parallel, browser" -Z build-std=panic_abort,std
 //! ```
 //!
+//! ## Native Rust Library
+//!
+//! Building with the `native` feature instead of the default features leaves out the JavaScript bindings and exposes
+//! the `native` module, which provides a `ProgramManager` with typed errors for servers and command line tools.
+//! ```bash
+//! cargo build --release --no-default-features --features native
+//! ```
+//!
 //! ## Testing
 //!
 //! Run tests in NodeJS
@@ -153,10 +161,14 @@
 //!
 
 pub mod account;
+#[cfg(not(feature = "native"))]
 use std::panic;
 
 pub use account::*;
 
+pub mod error;
+pub use error::*;
+
 pub mod programs;
 pub use programs::*;
 
@@ -164,9 +176,12 @@ pub mod record;
 pub use record::*;
 
 pub mod types;
-pub use types::Field;
+pub use types::{Field, Network, NetworkMismatch};
+
+#[cfg(feature = "native")]
+pub mod native;
 
-#[cfg(not(test))]
+#[cfg(not(any(test, feature = "native")))]
 mod thread_pool;
 
 #[cfg(test)]
@@ -184,18 +199,21 @@ mod thread_pool {
     }
 }
 
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::prelude::*;
 
 use std::str::FromStr;
 
-use types::native::RecordPlaintextNative;
+use types::native::{Entry, Identifier, Literal, Network as NetworkTrait, Plaintext, Record};
 
+#[cfg(not(feature = "native"))]
 #[wasm_bindgen]
 pub fn init_panic_hook() {
     panic::set_hook(Box::new(console_error_panic_hook::hook));
 }
 
 // Facilities for cross-platform logging in both web browsers and nodeJS
+#[cfg(not(feature = "native"))]
 #[wasm_bindgen]
 extern "C" {
     // Log a &str the console in the browser or console.log in nodejs
@@ -203,6 +221,12 @@ extern "C" {
     pub fn log(s: &str);
 }
 
+/// Log a &str at debug level through the `log` crate when built as a native library
+#[cfg(feature = "native")]
+pub fn log(s: &str) {
+    ::log::debug!("{s}");
+}
+
 /// A trait providing convenient methods for accessing the amount of Aleo present in a record
 pub trait Credits {
     /// Get the amount of credits in the record if the record possesses Aleo credits
@@ -214,26 +238,23 @@ pub trait Credits {
     fn microcredits(&self) -> Result<u64, String>;
 }
 
-impl Credits for RecordPlaintextNative {
+impl<N: NetworkTrait> Credits for Record<N, Plaintext<N>> {
     fn microcredits(&self) -> Result<u64, String> {
         match self
-            .find(&[native::IdentifierNative::from_str("microcredits").map_err(|e| e.to_string())?])
+            .find(&[Identifier::<N>::from_str("microcredits").map_err(|e| e.to_string())?])
             .map_err(|e| e.to_string())?
         {
-            native::Entry::Private(native::PlaintextNative::Literal(native::LiteralNative::U64(amount), _)) => {
-                Ok(*amount)
-            }
+            Entry::Private(Plaintext::Literal(Literal::U64(amount), _)) => Ok(*amount),
             _ => Err("The record provided does not contain a microcredits field".to_string()),
         }
     }
 }
 
-#[cfg(not(test))]
+#[cfg(not(any(test, feature = "native")))]
 #[doc(hidden)]
 pub use thread_pool::run_rayon_thread;
-use types::native;
 
-#[cfg(not(test))]
+#[cfg(not(any(test, feature = "native")))]
 #[wasm_bindgen(js_name = "initThreadPool")]
 pub async fn init_thread_pool(url: web_sys::Url, num_threads: usize) -> Result<(), JsValue> {
     console_error_panic_hook::set_once();
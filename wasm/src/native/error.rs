@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::NetworkMismatch;
+
+use std::fmt;
+
+/// Errors returned by the native SDK
+#[derive(Debug)]
+pub enum Error {
+    /// An argument such as a program, function name, input or amount was invalid
+    InvalidArgument(String),
+    /// A value created for one network was used with another
+    NetworkMismatch(NetworkMismatch),
+    /// The fee record does not hold enough microcredits to pay the fee
+    InsufficientFee { required: u64, available: u64 },
+    /// Authorizing, synthesizing, proving or verifying failed in snarkVM
+    Process(anyhow::Error),
+    /// Fetching the state needed for inclusion proofs failed
+    Query(anyhow::Error),
+    /// A proving task on the blocking thread pool panicked or was cancelled
+    Task(tokio::task::JoinError),
+    /// An error reported by the shared SDK code
+    Sdk(String),
+}
+
+/// Result type of the native SDK
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidArgument(message) => write!(f, "Invalid argument: {message}"),
+            Self::NetworkMismatch(error) => write!(f, "{error}"),
+            Self::InsufficientFee { required, available } => {
+                write!(f, "Insufficient fee: {required} microcredits are required but the record holds {available}")
+            }
+            Self::Process(error) => write!(f, "Process error: {error}"),
+            Self::Query(error) => write!(f, "Query error: {error}"),
+            Self::Task(error) => write!(f, "Proving task failed: {error}"),
+            Self::Sdk(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::NetworkMismatch(error) => Some(error),
+            Self::Process(error) | Self::Query(error) => Some(error.as_ref()),
+            Self::Task(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<NetworkMismatch> for Error {
+    fn from(error: NetworkMismatch) -> Self {
+        Self::NetworkMismatch(error)
+    }
+}
+
+impl From<tokio::task::JoinError> for Error {
+    fn from(error: tokio::task::JoinError) -> Self {
+        Self::Task(error)
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Self::Sdk(message)
+    }
+}
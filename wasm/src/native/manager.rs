@@ -0,0 +1,494 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{Error, Result};
+use crate::{
+    log,
+    types::native::{
+        canary,
+        deployment_cost,
+        mainnet,
+        testnet,
+        Address,
+        Authorization,
+        Execution,
+        Fee,
+        Field,
+        Identifier,
+        Network as NetworkTrait,
+        Plaintext,
+        PrivateKey,
+        Process,
+        Program,
+        ProgramID,
+        ProgramOwner,
+        Record,
+        Transaction,
+        Value,
+    },
+    Credits,
+    DeploymentCost,
+    ExecutionCost,
+    Network,
+};
+
+use rand::{rngs::StdRng, SeedableRng};
+use snarkvm_circuit_network::Aleo;
+use snarkvm_ledger_query::QueryTrait;
+use std::str::FromStr;
+
+/// Program manager for mainnet
+pub type MainnetProgramManager = ProgramManager<mainnet::CurrentAleo>;
+/// Program manager for testnet
+pub type TestnetProgramManager = ProgramManager<testnet::CurrentAleo>;
+/// Program manager for canary
+pub type CanaryProgramManager = ProgramManager<canary::CurrentAleo>;
+
+/// How the fee of a transaction is paid
+#[derive(Clone, Debug)]
+pub struct FeeOptions<N: NetworkTrait> {
+    /// Priority fee in microcredits paid on top of the base fee
+    pub priority_fee: u64,
+    /// Record to pay the fee from privately, the fee is paid from the public balance of the
+    /// caller if it is not set
+    pub record: Option<Record<N, Plaintext<N>>>,
+}
+
+impl<N: NetworkTrait> Default for FeeOptions<N> {
+    fn default() -> Self {
+        Self { priority_fee: 0, record: None }
+    }
+}
+
+/// Native counterpart of the wasm `ProgramManager` for servers and command line tools
+///
+/// The program manager owns a process for the network of the circuit environment `A` which holds
+/// the programs and keys loaded by previous calls. Base fees are computed from the cost of the
+/// deployment or execution, and proofs are created on the blocking thread pool of the tokio
+/// runtime so they do not stall other tasks. Without the `serial` feature snarkVM parallelizes
+/// proving with rayon.
+pub struct ProgramManager<A: Aleo> {
+    process: Process<A::Network>,
+}
+
+impl<A: Aleo> ProgramManager<A> {
+    /// Create a program manager whose process contains the credits program
+    pub fn new() -> Result<Self> {
+        Ok(Self { process: Process::load_web().map_err(Error::Process)? })
+    }
+
+    /// Get the network the program manager builds transactions for
+    pub fn network(&self) -> Network {
+        Network::of::<A::Network>()
+    }
+
+    /// Get the process of the program manager
+    pub fn process(&self) -> &Process<A::Network> {
+        &self.process
+    }
+
+    /// Add a program to the process. The program manager does not fetch imports, so the imports
+    /// of the program must be added first, in dependency order.
+    pub fn add_program(&mut self, program: &Program<A::Network>) -> Result<()> {
+        self.ensure_imports(program)?;
+        match self.process.get_program(program.id()) {
+            Ok(stored_program) if stored_program == program => Ok(()),
+            Ok(_) => Err(Error::InvalidArgument(format!(
+                "A different program with the id {} was already added to the process",
+                program.id()
+            ))),
+            Err(_) => self.process.add_program(program).map_err(Error::Process),
+        }
+    }
+
+    /// Build a transaction deploying a program. The imports of the program are not fetched and
+    /// must have been added to the program manager with [`ProgramManager::add_program`].
+    pub async fn deploy<Q: QueryTrait<A::Network>>(
+        &mut self,
+        private_key: &PrivateKey<A::Network>,
+        program: &Program<A::Network>,
+        fee: FeeOptions<A::Network>,
+        query: Q,
+    ) -> Result<Transaction<A::Network>> {
+        self.ensure_imports(program)?;
+        let rng = &mut StdRng::from_entropy();
+
+        log(&format!("Synthesizing the keys of {}", program.id()));
+        let deployment = self.process.deploy::<A, _>(program, rng).map_err(Error::Process)?;
+        let (base_fee, _) = deployment_cost(&deployment).map_err(Error::Process)?;
+        let deployment_id = deployment.to_deployment_id().map_err(Error::Process)?;
+
+        let fee = self.prove_fee(private_key, fee, base_fee, deployment_id, query).await?;
+
+        log("Verifying the deployment");
+        self.process.verify_deployment::<A, _>(&deployment, rng).map_err(Error::Process)?;
+        let owner = ProgramOwner::new(private_key, deployment_id, rng).map_err(Error::Process)?;
+        Transaction::from_deployment(owner, deployment, fee).map_err(Error::Process)
+    }
+
+    /// Build a transaction executing a function. The program of the function and its imports
+    /// must have been added to the program manager.
+    pub async fn execute<I, Q>(
+        &mut self,
+        private_key: &PrivateKey<A::Network>,
+        program_id: &str,
+        function_name: &str,
+        inputs: I,
+        fee: FeeOptions<A::Network>,
+        query: Q,
+    ) -> Result<Transaction<A::Network>>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+        I::Item: TryInto<Value<A::Network>>,
+        Q: QueryTrait<A::Network> + Clone,
+    {
+        let authorization = self.authorize_execution(private_key, program_id, function_name, inputs)?;
+
+        // Check the fee record against the estimated cost so an insufficient record is reported
+        // before the execution is proven. The estimate bounds the size of the proof from above.
+        if let Some(record) = &fee.record {
+            let estimate = ExecutionCost::estimate(
+                &self.process,
+                authorization.transitions().values().cloned(),
+                fee.priority_fee,
+            )?;
+            Self::check_fee_record(record, estimate.total())?;
+        }
+
+        let locator = format!("{program_id}/{function_name}");
+        let execution = self.prove_authorization(authorization, locator, query.clone()).await?;
+        let cost = ExecutionCost::from_execution(&self.process, &execution, fee.priority_fee)?;
+        let execution_id = execution.to_execution_id().map_err(Error::Process)?;
+
+        let fee = self.prove_fee(private_key, fee, cost.base_fee(), execution_id, query).await?;
+        Transaction::from_execution(execution, Some(fee)).map_err(Error::Process)
+    }
+
+    /// Build a transaction transferring microcredits to a recipient. The amount is transferred
+    /// privately from `record` if it is set and publicly otherwise.
+    pub async fn transfer<Q: QueryTrait<A::Network> + Clone>(
+        &mut self,
+        private_key: &PrivateKey<A::Network>,
+        recipient: &Address<A::Network>,
+        amount: u64,
+        record: Option<Record<A::Network, Plaintext<A::Network>>>,
+        fee: FeeOptions<A::Network>,
+        query: Q,
+    ) -> Result<Transaction<A::Network>> {
+        let amount = format!("{amount}u64");
+        match record {
+            Some(record) => {
+                let inputs = [record.to_string(), recipient.to_string(), amount];
+                self.execute(private_key, "credits.aleo", "transfer_private", inputs.iter(), fee, query).await
+            }
+            None => {
+                let inputs = [recipient.to_string(), amount];
+                self.execute(private_key, "credits.aleo", "transfer_public", inputs.iter(), fee, query).await
+            }
+        }
+    }
+
+    /// Build a transaction splitting a record in two. Splits do not pay a fee.
+    pub async fn split<Q: QueryTrait<A::Network>>(
+        &mut self,
+        private_key: &PrivateKey<A::Network>,
+        record: &Record<A::Network, Plaintext<A::Network>>,
+        amount: u64,
+        query: Q,
+    ) -> Result<Transaction<A::Network>> {
+        let inputs = [record.to_string(), format!("{amount}u64")];
+        let execution = self.prove_execution(private_key, "credits.aleo", "split", inputs.iter(), query).await?;
+        Transaction::from_execution(execution, None).map_err(Error::Process)
+    }
+
+    /// Build a transaction joining two records into one
+    pub async fn join<Q: QueryTrait<A::Network> + Clone>(
+        &mut self,
+        private_key: &PrivateKey<A::Network>,
+        first: &Record<A::Network, Plaintext<A::Network>>,
+        second: &Record<A::Network, Plaintext<A::Network>>,
+        fee: FeeOptions<A::Network>,
+        query: Q,
+    ) -> Result<Transaction<A::Network>> {
+        let inputs = [first.to_string(), second.to_string()];
+        self.execute(private_key, "credits.aleo", "join", inputs.iter(), fee, query).await
+    }
+
+    /// Estimate the cost of deploying a program without proving the fee. The imports of the
+    /// program must have been added to the program manager.
+    pub fn estimate_deployment_fee(&self, program: &Program<A::Network>) -> Result<DeploymentCost> {
+        self.ensure_imports(program)?;
+        let deployment = self.process.deploy::<A, _>(program, &mut StdRng::from_entropy()).map_err(Error::Process)?;
+        Ok(DeploymentCost::from_deployment(&deployment)?)
+    }
+
+    /// Estimate the cost of executing a function. The execution is proven to measure its size
+    /// but the fee is not.
+    pub async fn estimate_execution_fee<I, Q>(
+        &mut self,
+        private_key: &PrivateKey<A::Network>,
+        program_id: &str,
+        function_name: &str,
+        inputs: I,
+        priority_fee: u64,
+        query: Q,
+    ) -> Result<ExecutionCost>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+        I::Item: TryInto<Value<A::Network>>,
+        Q: QueryTrait<A::Network>,
+    {
+        let execution = self.prove_execution(private_key, program_id, function_name, inputs, query).await?;
+        Ok(ExecutionCost::from_execution(&self.process, &execution, priority_fee)?)
+    }
+
+    /// Authorize, prove and verify the execution of a function
+    async fn prove_execution<I, Q>(
+        &mut self,
+        private_key: &PrivateKey<A::Network>,
+        program_id: &str,
+        function_name: &str,
+        inputs: I,
+        query: Q,
+    ) -> Result<Execution<A::Network>>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+        I::Item: TryInto<Value<A::Network>>,
+        Q: QueryTrait<A::Network>,
+    {
+        let authorization = self.authorize_execution(private_key, program_id, function_name, inputs)?;
+        self.prove_authorization(authorization, format!("{program_id}/{function_name}"), query).await
+    }
+
+    /// Authorize the execution of a function, synthesizing its keys if they are not loaded yet
+    fn authorize_execution<I>(
+        &mut self,
+        private_key: &PrivateKey<A::Network>,
+        program_id: &str,
+        function_name: &str,
+        inputs: I,
+    ) -> Result<Authorization<A::Network>>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+        I::Item: TryInto<Value<A::Network>>,
+    {
+        let rng = &mut StdRng::from_entropy();
+        let program = ProgramID::<A::Network>::from_str(program_id)
+            .map_err(|_| Error::InvalidArgument(format!("{program_id} is an invalid program name")))?;
+        let function = Identifier::<A::Network>::from_str(function_name)
+            .map_err(|_| Error::InvalidArgument(format!("{function_name} is an invalid identifier")))?;
+
+        if self.process.get_proving_key(program, function).is_err() {
+            log(&format!("Synthesizing keys for {program_id}/{function_name}"));
+            self.process.synthesize_key::<A, _>(&program, &function, rng).map_err(Error::Process)?;
+        }
+
+        log("Creating authorization");
+        self.process.authorize::<A, _>(private_key, program, function, inputs.into_iter(), rng).map_err(Error::Process)
+    }
+
+    /// Execute, prove and verify an authorized function
+    async fn prove_authorization<Q: QueryTrait<A::Network>>(
+        &mut self,
+        authorization: Authorization<A::Network>,
+        locator: String,
+        query: Q,
+    ) -> Result<Execution<A::Network>> {
+        let (_, mut trace) =
+            self.process.execute::<A, _>(authorization, &mut StdRng::from_entropy()).map_err(Error::Process)?;
+
+        log("Preparing inclusion proofs for execution");
+        trace.prepare_async(query).await.map_err(Error::Query)?;
+
+        log("Proving execution");
+        let execution =
+            tokio::task::spawn_blocking(move || trace.prove_execution::<A, _>(&locator, &mut StdRng::from_entropy()))
+                .await?
+                .map_err(Error::Process)?;
+
+        log("Verifying the execution");
+        self.process.verify_execution(&execution).map_err(Error::Process)?;
+        Ok(execution)
+    }
+
+    /// Authorize, prove and verify the fee of a deployment or execution
+    async fn prove_fee<Q: QueryTrait<A::Network>>(
+        &mut self,
+        private_key: &PrivateKey<A::Network>,
+        fee: FeeOptions<A::Network>,
+        base_fee: u64,
+        id: Field<A::Network>,
+        query: Q,
+    ) -> Result<Fee<A::Network>> {
+        let rng = &mut StdRng::from_entropy();
+
+        log("Authorizing fee");
+        let authorization = match fee.record {
+            Some(record) => {
+                Self::check_fee_record(&record, base_fee.saturating_add(fee.priority_fee))?;
+                self.process.authorize_fee_private::<A, _>(private_key, record, base_fee, fee.priority_fee, id, rng)
+            }
+            None => self.process.authorize_fee_public::<A, _>(private_key, base_fee, fee.priority_fee, id, rng),
+        }
+        .map_err(Error::Process)?;
+        let (_, mut trace) = self.process.execute::<A, _>(authorization, rng).map_err(Error::Process)?;
+
+        log("Preparing inclusion proofs for fee");
+        trace.prepare_async(query).await.map_err(Error::Query)?;
+
+        log("Proving fee");
+        let fee = tokio::task::spawn_blocking(move || trace.prove_fee::<A, _>(&mut StdRng::from_entropy()))
+            .await?
+            .map_err(Error::Process)?;
+
+        log("Verifying fee");
+        self.process.verify_fee(&fee, id).map_err(Error::Process)?;
+        Ok(fee)
+    }
+
+    /// Check that a fee record holds at least the required microcredits
+    fn check_fee_record(record: &Record<A::Network, Plaintext<A::Network>>, required: u64) -> Result<()> {
+        let available = record.microcredits()?;
+        if available < required {
+            return Err(Error::InsufficientFee { required, available });
+        }
+        Ok(())
+    }
+
+    /// Check that the imports of a program were added to the process
+    fn ensure_imports(&self, program: &Program<A::Network>) -> Result<()> {
+        let missing = program
+            .imports()
+            .keys()
+            .filter(|import| !self.process.contains_program(import))
+            .map(|import| import.to_string())
+            .collect::<Vec<_>>();
+        match missing.is_empty() {
+            true => Ok(()),
+            false => Err(Error::InvalidArgument(format!(
+                "The imports of {} must be added to the program manager first: {}",
+                program.id(),
+                missing.join(", ")
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OfflineQuery;
+
+    const HELLO_PROGRAM: &str = r#"program hello.aleo;
+
+function hello:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+"#;
+    const GREETER_PROGRAM: &str = r#"import hello.aleo;
+
+program greeter.aleo;
+
+function greet:
+    input r0 as u32.public;
+    call hello.aleo/hello r0 r0 into r1;
+    output r1 as u32.private;
+"#;
+    const PRIVATE_KEY: &str = "APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6";
+    const STATE_ROOT: &str = "sr1wjueje6hy86yw9j4lhl7jwvhjxwunw34paj4k3cn2wm5h5r2syfqd83yw4";
+
+    #[tokio::test]
+    async fn test_native_execution() {
+        let mut program_manager = TestnetProgramManager::new().unwrap();
+        assert_eq!(program_manager.network(), Network::TestnetV0);
+        let private_key = PrivateKey::from_str(PRIVATE_KEY).unwrap();
+        let program = Program::from_str(HELLO_PROGRAM).unwrap();
+        program_manager.add_program(&program).unwrap();
+        program_manager.add_program(&program).unwrap();
+
+        // Executions without record inputs only need the state root to be proven offline
+        let query = OfflineQuery::new(STATE_ROOT).unwrap();
+        let transaction = program_manager
+            .execute(&private_key, "hello.aleo", "hello", ["5u32", "5u32"], FeeOptions::default(), query)
+            .await
+            .unwrap();
+        assert!(transaction.is_execute());
+        assert!(*transaction.fee_amount().unwrap() > 0);
+
+        // The estimate matches the fee paid by the transaction
+        let query = OfflineQuery::new(STATE_ROOT).unwrap();
+        let cost = program_manager
+            .estimate_execution_fee(&private_key, "hello.aleo", "hello", ["5u32", "5u32"], 0, query)
+            .await
+            .unwrap();
+        assert_eq!(cost.total(), *transaction.fee_amount().unwrap());
+
+        // The proof stays within the bound used to estimate the cost of an unproven execution
+        let execution = transaction.execution().unwrap();
+        let unproven = Execution::from(execution.transitions().cloned(), execution.global_state_root(), None).unwrap();
+        let proof_size = execution.size_in_bytes().unwrap() - unproven.size_in_bytes().unwrap();
+        assert!(proof_size <= crate::programs::cost::EXECUTION_PROOF_SIZE_IN_BYTES);
+        let estimate = ExecutionCost::estimate(&program_manager.process, execution.transitions().cloned(), 0).unwrap();
+        assert!(estimate.storage_cost() >= cost.storage_cost());
+        assert_eq!(estimate.finalize_cost(), cost.finalize_cost());
+
+        // Invalid arguments are reported as typed errors
+        let query = OfflineQuery::new(STATE_ROOT).unwrap();
+        let error = program_manager
+            .execute(&private_key, "hello.aleo", "not a function", ["5u32", "5u32"], FeeOptions::default(), query)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidArgument(_)));
+    }
+
+    #[tokio::test]
+    async fn test_insufficient_fee_record() {
+        let mut program_manager = TestnetProgramManager::new().unwrap();
+        let private_key = PrivateKey::from_str(PRIVATE_KEY).unwrap();
+        program_manager.add_program(&Program::from_str(HELLO_PROGRAM).unwrap()).unwrap();
+
+        // The record is rejected before the execution is proven
+        let address = Address::try_from(&private_key).unwrap();
+        let record = Record::from_str(&format!(
+            "{{ owner: {address}.private, microcredits: 1u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+        let fee = FeeOptions { priority_fee: 0, record: Some(record) };
+        let query = OfflineQuery::new(STATE_ROOT).unwrap();
+        let error = program_manager
+            .execute(&private_key, "hello.aleo", "hello", ["5u32", "5u32"], fee, query)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::InsufficientFee { available: 1, .. }));
+    }
+
+    #[test]
+    fn test_missing_imports() {
+        let mut program_manager = TestnetProgramManager::new().unwrap();
+        let program = Program::from_str(GREETER_PROGRAM).unwrap();
+        let error = program_manager.add_program(&program).unwrap_err();
+        assert!(matches!(error, Error::InvalidArgument(ref message) if message.contains("hello.aleo")));
+
+        program_manager.add_program(&Program::from_str(HELLO_PROGRAM).unwrap()).unwrap();
+        program_manager.add_program(&program).unwrap();
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Native Rust interface of the SDK for servers and command line tools
+//!
+//! Enabling the `native` feature (and disabling the default `browser` and `serial` features)
+//! builds the SDK as a plain Rust library. Logging goes through the `log` crate instead of
+//! `console.log`, proofs are created with rayon on the blocking thread pool of the tokio runtime,
+//! and errors are reported with the typed [`Error`] instead of strings.
+//!
+//! ```toml
+//! aleo-wasm = { version = "0.7", default-features = false, features = ["native"] }
+//! ```
+
+pub mod error;
+pub use error::*;
+
+pub mod manager;
+pub use manager::*;
+
+pub mod query;
+pub use query::*;
+
+pub use crate::{
+    account_path,
+    Credits,
+    DeploymentCost,
+    ExecutionCost,
+    ExtendedPrivateKey,
+    KdfParams,
+    Keystore,
+    Mnemonic,
+    NetworkQuery,
+    OfflineQuery,
+    PrivateKeyCiphertext,
+    RecordCiphertext,
+    RecordPlaintext,
+    TransactionConfirmation,
+    TransactionStatus,
+    CONFIRMATION_INTERVAL,
+    CONFIRMATION_TIMEOUT,
+    DEFAULT_ENDPOINT,
+    MAX_DERIVED_ACCOUNTS,
+};
+
+// The snarkVM types taking part in the native interface, so applications do not have to pin the
+// same snarkVM revision as the SDK
+pub use crate::types::native::{
+    canary,
+    mainnet,
+    testnet,
+    Address,
+    Block,
+    Ciphertext,
+    Network as NetworkTrait,
+    Plaintext,
+    PrivateKey,
+    Program,
+    Record,
+    Transaction,
+    ViewKey,
+};
+pub use snarkvm_circuit_network::Aleo;
+
+use crate::{
+    account::{account_range, Encryptor, PrivateKey as PrivateKeyWasm},
+    types::to_network,
+    Network,
+};
+
+use std::ops::Range;
+
+/// Encrypt a private key into ciphertext using a secret, deriving the encryption key from the
+/// secret with Argon2id
+pub fn encrypt_private_key<N: NetworkTrait>(
+    private_key: &PrivateKey<N>,
+    secret: &str,
+    params: &KdfParams,
+) -> Result<PrivateKeyCiphertext> {
+    Ok(Encryptor::encrypt_private_key_with_secret(&to_network(*private_key)?, secret, params)?)
+}
+
+/// Decrypt a private key from ciphertext using a secret. Legacy ciphertexts are supported.
+pub fn decrypt_private_key<N: NetworkTrait>(ciphertext: &PrivateKeyCiphertext, secret: &str) -> Result<PrivateKey<N>> {
+    let private_key = Encryptor::decrypt_private_key_with_secret(ciphertext, secret)?;
+    Ok(to_network(private_key)?)
+}
+
+/// Encrypt a private key into a JSON keystore of the network `N` using a secret
+pub fn encrypt_keystore<N: NetworkTrait>(
+    private_key: &PrivateKey<N>,
+    secret: &str,
+    params: &KdfParams,
+) -> Result<Keystore> {
+    Ok(Keystore::encrypt(&to_network(*private_key)?, secret, Network::of::<N>(), params)?)
+}
+
+/// Decrypt the private key of a JSON keystore of the network `N` using a secret
+pub fn decrypt_keystore<N: NetworkTrait>(keystore: &Keystore, secret: &str) -> Result<PrivateKey<N>> {
+    Network::of::<N>().ensure(keystore.network())?;
+    let private_key = keystore.decrypt(secret)?;
+    Ok(to_network(private_key)?)
+}
+
+/// Derive the private key of the account backed up by a BIP-39 mnemonic and passphrase
+pub fn private_key_from_mnemonic<N: NetworkTrait>(mnemonic: &Mnemonic, passphrase: &str) -> Result<PrivateKey<N>> {
+    let private_key = PrivateKeyWasm::from_seed_bytes(&mnemonic.seed(passphrase))?;
+    Ok(to_network(private_key)?)
+}
+
+/// Derive the private key of the network `N` at a hardened derivation path of an extended key
+pub fn derive_private_key<N: NetworkTrait>(key: &ExtendedPrivateKey, path: &str) -> Result<PrivateKey<N>> {
+    let private_key = key.derive_path(path)?.private_key()?;
+    Ok(to_network(*private_key)?)
+}
+
+/// Derive the view keys of a range of at most [`MAX_DERIVED_ACCOUNTS`] accounts of a master key for
+/// watch-only scanning, the account at index `i` being derived at the path `m/44'/683'/i'/0'`
+pub fn derive_view_keys<N: NetworkTrait>(master: &ExtendedPrivateKey, accounts: Range<u32>) -> Result<Vec<ViewKey<N>>> {
+    account_range(accounts.start, accounts.len() as u32)?
+        .map(|index| {
+            let private_key = derive_private_key::<N>(master, &account_path(index))?;
+            Ok(ViewKey::try_from(&private_key).map_err(|e| e.to_string())?)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::native::testnet;
+
+    use std::str::FromStr;
+
+    #[test]
+    fn test_private_key_encryption_roundtrip() {
+        let private_key = PrivateKey::<testnet::CurrentNetwork>::from_str(
+            "APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6",
+        )
+        .unwrap();
+        let ciphertext = encrypt_private_key(&private_key, "mypassword", &KdfParams::new(64, 1, 1).unwrap()).unwrap();
+        assert_eq!(decrypt_private_key(&ciphertext, "mypassword").unwrap(), private_key);
+        assert!(decrypt_private_key(&ciphertext, "wrongpassword").is_err());
+    }
+
+    #[test]
+    fn test_keystore_roundtrip() {
+        let private_key = PrivateKey::<testnet::CurrentNetwork>::from_str(
+            "APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6",
+        )
+        .unwrap();
+        let keystore = encrypt_keystore(&private_key, "mypassword", &KdfParams::new(64, 1, 1).unwrap()).unwrap();
+        assert_eq!(keystore.network(), Network::TestnetV0);
+        let keystore = Keystore::from_str(&keystore.to_string()).unwrap();
+        assert_eq!(decrypt_keystore::<testnet::CurrentNetwork>(&keystore, "mypassword").unwrap(), private_key);
+        assert!(decrypt_keystore::<testnet::CurrentNetwork>(&keystore, "wrongpassword").is_err());
+        assert!(decrypt_keystore::<mainnet::CurrentNetwork>(&keystore, "mypassword").is_err());
+    }
+
+    #[test]
+    fn test_private_key_from_mnemonic() {
+        let mnemonic = Mnemonic::from_str("zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong").unwrap();
+        let private_key = private_key_from_mnemonic::<testnet::CurrentNetwork>(&mnemonic, "").unwrap();
+        assert_eq!(private_key.to_string(), mnemonic.to_private_key(None).unwrap().to_string());
+        assert_ne!(private_key, private_key_from_mnemonic(&mnemonic, "passphrase").unwrap());
+    }
+
+    #[test]
+    fn test_derive_view_keys() {
+        let master = ExtendedPrivateKey::from_seed(&[7u8; 32]).unwrap();
+        let view_keys = derive_view_keys::<testnet::CurrentNetwork>(&master, 3..6).unwrap();
+        assert_eq!(view_keys.len(), 3);
+        for (index, view_key) in (3..6).zip(view_keys) {
+            let private_key = derive_private_key::<testnet::CurrentNetwork>(&master, &account_path(index)).unwrap();
+            assert_eq!(view_key, ViewKey::try_from(&private_key).unwrap());
+            assert_eq!(private_key.to_string(), master.derive_account(index).unwrap().to_string());
+        }
+        assert!(derive_view_keys::<testnet::CurrentNetwork>(&master, 0..MAX_DERIVED_ACCOUNTS + 1).is_err());
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::native::{BlockMemory, Field, Network as NetworkTrait, Query},
+    NetworkQuery,
+    OfflineQuery,
+    DEFAULT_ENDPOINT,
+};
+
+use async_trait::async_trait;
+use snarkvm_console::program::StatePath;
+use snarkvm_ledger_query::QueryTrait;
+
+/// Source of the global state root and the state paths of record inputs needed to prove an
+/// execution or fee
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StateQuery {
+    /// Query the REST API of a node
+    Node(String),
+    /// Query the REST API of nodes with failover, retries and custom headers
+    Network(NetworkQuery),
+    /// Use a state root and state paths fetched ahead of time
+    Offline(OfflineQuery),
+}
+
+impl Default for StateQuery {
+    fn default() -> Self {
+        Self::Node(DEFAULT_ENDPOINT.to_string())
+    }
+}
+
+impl From<NetworkQuery> for StateQuery {
+    fn from(query: NetworkQuery) -> Self {
+        Self::Network(query)
+    }
+}
+
+impl From<OfflineQuery> for StateQuery {
+    fn from(query: OfflineQuery) -> Self {
+        Self::Offline(query)
+    }
+}
+
+impl StateQuery {
+    fn node<N: NetworkTrait>(url: &str) -> Query<N, BlockMemory<N>> {
+        Query::from(url)
+    }
+}
+
+#[async_trait(?Send)]
+impl<N: NetworkTrait> QueryTrait<N> for StateQuery {
+    fn current_state_root(&self) -> anyhow::Result<N::StateRoot> {
+        match self {
+            Self::Node(url) => Self::node::<N>(url).current_state_root(),
+            Self::Network(query) => QueryTrait::<N>::current_state_root(query),
+            Self::Offline(query) => QueryTrait::<N>::current_state_root(query),
+        }
+    }
+
+    async fn current_state_root_async(&self) -> anyhow::Result<N::StateRoot> {
+        match self {
+            Self::Node(url) => Self::node::<N>(url).current_state_root_async().await,
+            Self::Network(query) => QueryTrait::<N>::current_state_root_async(query).await,
+            Self::Offline(query) => QueryTrait::<N>::current_state_root_async(query).await,
+        }
+    }
+
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> anyhow::Result<StatePath<N>> {
+        match self {
+            Self::Node(url) => Self::node::<N>(url).get_state_path_for_commitment(commitment),
+            Self::Network(query) => query.get_state_path_for_commitment(commitment),
+            Self::Offline(query) => query.get_state_path_for_commitment(commitment),
+        }
+    }
+
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> anyhow::Result<StatePath<N>> {
+        match self {
+            Self::Node(url) => Self::node::<N>(url).get_state_path_for_commitment_async(commitment).await,
+            Self::Network(query) => query.get_state_path_for_commitment_async(commitment).await,
+            Self::Offline(query) => query.get_state_path_for_commitment_async(commitment).await,
+        }
+    }
+}
@@ -0,0 +1,293 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::{
+        native::{
+            canary,
+            mainnet,
+            testnet,
+            Authorization as AuthorizationGeneric,
+            AuthorizationNative,
+            FromBytes,
+            Network as NetworkTrait,
+            ToBytes,
+        },
+        to_network,
+    },
+    Network,
+    SdkError,
+};
+
+use std::{ops::Deref, str::FromStr};
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Webassembly Representation of an Aleo function authorization
+///
+/// An authorization contains the signed requests for a function execution (or fee payment) and
+/// the transitions they produce, but no proofs. It can be created on a device holding the private
+/// key, serialized, and handed to a separate prover which creates the final transaction without
+/// ever seeing the private key.
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Debug)]
+pub struct Authorization {
+    authorization: AuthorizationNative,
+    network: Network,
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl Authorization {
+    /// Create an authorization from a string
+    ///
+    /// @param {string} authorization String representation of an authorization
+    /// @param {Network | undefined} network (optional) The network the authorization belongs to,
+    /// defaults to mainnet
+    /// @returns {Authorization}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromString))]
+    pub fn from_string(authorization: &str, network: Option<Network>) -> Result<Authorization, String> {
+        fn parse<N: NetworkTrait>(authorization: &str) -> Result<Authorization, String> {
+            Authorization::from_native(AuthorizationGeneric::<N>::from_str(authorization).map_err(|e| e.to_string())?)
+        }
+        // Deserialize with the types of the network to validate the authorization against it
+        match network.unwrap_or_default() {
+            Network::MainnetV0 => parse::<mainnet::CurrentNetwork>(authorization),
+            Network::TestnetV0 => parse::<testnet::CurrentNetwork>(authorization),
+            Network::CanaryV0 => parse::<canary::CurrentNetwork>(authorization),
+        }
+    }
+
+    /// Get the string representation of the authorization
+    ///
+    /// @returns {string} String representation of the authorization
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toString))]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.authorization.to_string()
+    }
+
+    /// Create an authorization from a byte array
+    ///
+    /// @param {Uint8Array} bytes Byte representation of an authorization
+    /// @param {Network | undefined} network (optional) The network the authorization belongs to,
+    /// defaults to mainnet
+    /// @returns {Authorization}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromBytes))]
+    pub fn from_bytes(bytes: &[u8], network: Option<Network>) -> Result<Authorization, String> {
+        fn parse<N: NetworkTrait>(bytes: &[u8]) -> Result<Authorization, String> {
+            Authorization::from_native(AuthorizationGeneric::<N>::from_bytes_le(bytes).map_err(|e| e.to_string())?)
+        }
+        // Deserialize with the types of the network to validate the authorization against it
+        match network.unwrap_or_default() {
+            Network::MainnetV0 => parse::<mainnet::CurrentNetwork>(bytes),
+            Network::TestnetV0 => parse::<testnet::CurrentNetwork>(bytes),
+            Network::CanaryV0 => parse::<canary::CurrentNetwork>(bytes),
+        }
+    }
+
+    /// Get the byte representation of the authorization
+    ///
+    /// @returns {Uint8Array} Byte representation of the authorization
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toBytes))]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        self.authorization.to_bytes_le().map_err(|_| "Failed to serialize authorization".to_string())
+    }
+
+    /// Get the execution id of the authorization. This is the id a fee authorization must commit
+    /// to in order to pay for the execution.
+    ///
+    /// @returns {string} Execution id
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toExecutionId))]
+    pub fn to_execution_id(&self) -> Result<String, String> {
+        Ok(self.authorization.to_execution_id().map_err(|e| e.to_string())?.to_string())
+    }
+
+    /// Returns true if the authorization is for a private fee payment
+    ///
+    /// @returns {boolean}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = isFeePrivate))]
+    pub fn is_fee_private(&self) -> bool {
+        self.authorization.is_fee_private()
+    }
+
+    /// Returns true if the authorization is for a public fee payment
+    ///
+    /// @returns {boolean}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = isFeePublic))]
+    pub fn is_fee_public(&self) -> bool {
+        self.authorization.is_fee_public()
+    }
+
+    /// Get the number of transitions in the authorization
+    ///
+    /// @returns {number}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = numTransitions))]
+    pub fn num_transitions(&self) -> usize {
+        self.authorization.transitions().len()
+    }
+
+    /// Get the network the authorization belongs to
+    ///
+    /// @returns {Network}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Create a deep copy of the authorization
+    ///
+    /// @returns {Authorization} A copy of the authorization
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn copy(&self) -> Authorization {
+        self.clone()
+    }
+}
+
+// The native authorization shares its request queue between clones, so clones must be deep
+// copies to keep proving one copy from draining another.
+impl Clone for Authorization {
+    fn clone(&self) -> Self {
+        Self { authorization: self.authorization.replicate(), network: self.network }
+    }
+}
+
+impl PartialEq for Authorization {
+    fn eq(&self, other: &Self) -> bool {
+        self.authorization == other.authorization && self.network == other.network
+    }
+}
+
+impl Eq for Authorization {}
+
+impl Authorization {
+    /// Create an authorization from the native authorization of the network it was created on
+    pub(crate) fn from_native<N: NetworkTrait>(authorization: AuthorizationGeneric<N>) -> Result<Self, String> {
+        Ok(Self { authorization: to_network(authorization)?, network: Network::of::<N>() })
+    }
+
+    /// Get a deep copy of the authorization as a native authorization of the network `N`, failing
+    /// if it belongs to another network
+    pub(crate) fn to_native<N: NetworkTrait>(&self) -> Result<AuthorizationGeneric<N>, SdkError> {
+        Network::of::<N>().ensure(self.network)?;
+        Ok(to_network(self.authorization.replicate())?)
+    }
+}
+
+impl From<Authorization> for AuthorizationNative {
+    fn from(authorization: Authorization) -> Self {
+        authorization.authorization
+    }
+}
+
+/// Wrap a native authorization, whose type is the authorization of the default network
+impl From<AuthorizationNative> for Authorization {
+    fn from(authorization: AuthorizationNative) -> Self {
+        Self { authorization, network: Network::default() }
+    }
+}
+
+impl FromStr for Authorization {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Authorization::from_string(s, None)
+    }
+}
+
+impl Deref for Authorization {
+    type Target = AuthorizationNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.authorization
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::native::{testnet, CurrentAleo, CurrentNetwork, PrivateKeyNative, ProcessNative};
+
+    use rand::{rngs::StdRng, SeedableRng};
+    use wasm_bindgen_test::*;
+
+    const PRIVATE_KEY: &str = "APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6";
+    const RECORD: &str = "{  owner: aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4.private,  microcredits: 2000000u64.private,  _nonce: 4106205762862305308495708971985748592380064201230396559307556388725936304984group.public}";
+
+    fn sample_authorization() -> Authorization {
+        let process = ProcessNative::load_web().unwrap();
+        let private_key = PrivateKeyNative::from_str(PRIVATE_KEY).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &private_key,
+                "credits.aleo",
+                "split",
+                [RECORD, "1000000u64"].into_iter(),
+                &mut StdRng::from_entropy(),
+            )
+            .unwrap();
+        Authorization::from(authorization)
+    }
+
+    #[wasm_bindgen_test]
+    fn test_authorization_string_and_bytes_roundtrip() {
+        let authorization = sample_authorization();
+
+        let from_string = Authorization::from_string(&authorization.to_string(), None).unwrap();
+        assert_eq!(authorization, from_string);
+
+        let from_bytes = Authorization::from_bytes(&authorization.to_bytes().unwrap(), None).unwrap();
+        assert_eq!(authorization, from_bytes);
+
+        assert_eq!(authorization.to_execution_id().unwrap(), from_bytes.to_execution_id().unwrap());
+        assert_eq!(authorization.num_transitions(), 1);
+        assert!(!authorization.is_fee_private());
+        assert!(!authorization.is_fee_public());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_invalid_authorization_string() {
+        assert!(Authorization::from_string("garbage", None).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_authorization_network() {
+        let authorization = sample_authorization();
+        assert_eq!(authorization.network(), Network::MainnetV0);
+        assert!(authorization.to_native::<CurrentNetwork>().is_ok());
+
+        // A testnet authorization is deserialized with the testnet types
+        let process = testnet::ProcessNative::load_web().unwrap();
+        let private_key = testnet::PrivateKeyNative::from_str(PRIVATE_KEY).unwrap();
+        let native = process
+            .authorize::<testnet::CurrentAleo, _>(
+                &private_key,
+                "credits.aleo",
+                "split",
+                [RECORD, "1000000u64"].into_iter(),
+                &mut StdRng::from_entropy(),
+            )
+            .unwrap();
+        let testnet_authorization = Authorization::from_native(native).unwrap();
+        let from_string =
+            Authorization::from_string(&testnet_authorization.to_string(), Some(Network::TestnetV0)).unwrap();
+        assert_eq!(from_string.network(), Network::TestnetV0);
+        assert_eq!(testnet_authorization, from_string);
+        assert!(from_string.to_native::<testnet::CurrentNetwork>().is_ok());
+        let error = from_string.to_native::<CurrentNetwork>().unwrap_err();
+        assert_eq!(error.code(), "NETWORK_MISMATCH");
+        assert_eq!(error.to_string(), "Network mismatch: expected a mainnet value but found a testnet value");
+    }
+}
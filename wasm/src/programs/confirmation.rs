@@ -0,0 +1,456 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    programs::network_query::{sleep, timeout},
+    types::native::{
+        canary,
+        mainnet,
+        testnet,
+        ConfirmedTransaction,
+        Network as NetworkTrait,
+        Transaction as TransactionGeneric,
+    },
+    Network,
+    NetworkQuery,
+    SdkError,
+    Transaction,
+};
+
+use anyhow::{bail, ensure};
+use std::{str::FromStr, time::Duration};
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::prelude::*;
+
+/// How long `waitForConfirmation` waits for a transaction to be included in a block by default
+pub const CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// The delay between two lookups of a transaction waiting for confirmation
+pub const CONFIRMATION_INTERVAL: Duration = Duration::from_secs(3);
+
+/// The outcome of a transaction included in a block
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionStatus {
+    /// The transaction and its finalize logic were applied
+    Accepted = 0,
+    /// The deployment or execution was rejected, e.g. because its finalize logic failed, and only
+    /// its fee was paid by a fee transaction confirmed in its place
+    Rejected = 1,
+    /// The transaction was aborted without being applied or paying its fee, e.g. because the fee
+    /// could not be paid
+    Aborted = 2,
+}
+
+/// The confirmation of a transaction by the network, holding its status and the block it was
+/// included in
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionConfirmation {
+    transaction_id: String,
+    status: TransactionStatus,
+    block_height: u32,
+    block_hash: String,
+    fee_transaction_id: Option<String>,
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl TransactionConfirmation {
+    /// Get the id of the transaction that was submitted
+    ///
+    /// @returns {string}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "transactionId"))]
+    pub fn transaction_id(&self) -> String {
+        self.transaction_id.clone()
+    }
+
+    /// Get whether the transaction was accepted, rejected or aborted
+    ///
+    /// @returns {TransactionStatus}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn status(&self) -> TransactionStatus {
+        self.status
+    }
+
+    /// Get the height of the block the transaction was included in
+    ///
+    /// @returns {number}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "blockHeight"))]
+    pub fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Get the hash of the block the transaction was included in
+    ///
+    /// @returns {string}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "blockHash"))]
+    pub fn block_hash(&self) -> String {
+        self.block_hash.clone()
+    }
+
+    /// Get the id of the fee transaction confirmed in place of a rejected transaction
+    ///
+    /// @returns {string | undefined} Id of the fee transaction, undefined unless the transaction
+    /// was rejected
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "feeTransactionId"))]
+    pub fn fee_transaction_id(&self) -> Option<String> {
+        self.fee_transaction_id.clone()
+    }
+}
+
+impl TransactionConfirmation {
+    /// Get the confirmation of a transaction from the transactions confirmed in the block it was
+    /// found in. A transaction found in a block without being confirmed in it was aborted.
+    fn from_block<N: NetworkTrait>(
+        transaction_id: &N::TransactionID,
+        block_height: u32,
+        block_hash: &N::BlockHash,
+        transactions: &[ConfirmedTransaction<N>],
+    ) -> anyhow::Result<Self> {
+        let mut confirmation = Self {
+            transaction_id: transaction_id.to_string(),
+            status: TransactionStatus::Aborted,
+            block_height,
+            block_hash: block_hash.to_string(),
+            fee_transaction_id: None,
+        };
+        for transaction in transactions {
+            if transaction.to_unconfirmed_transaction_id()? != *transaction_id {
+                continue;
+            }
+            if transaction.is_accepted() {
+                confirmation.status = TransactionStatus::Accepted;
+            } else {
+                confirmation.status = TransactionStatus::Rejected;
+                confirmation.fee_transaction_id = Some(transaction.transaction().id().to_string());
+            }
+            break;
+        }
+        Ok(confirmation)
+    }
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl NetworkQuery {
+    /// Broadcast a transaction to the first endpoint of the query. The broadcast is not retried,
+    /// so if it fails look the transaction up with `getConfirmation` before broadcasting it again.
+    ///
+    /// @param {Transaction} transaction The transaction to broadcast
+    /// @returns {Promise<string>} The id of the transaction
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "submitTransaction"))]
+    pub async fn submit_transaction(&self, transaction: &Transaction) -> Result<String, SdkError> {
+        async fn submit<N: NetworkTrait>(query: &NetworkQuery, transaction: &Transaction) -> Result<String, SdkError> {
+            let transaction = transaction.to_native::<N>()?;
+            let id = query
+                .submit_native_transaction::<N>(&transaction)
+                .await
+                .map_err(|e| SdkError::QueryFailed(e.to_string()))?;
+            Ok(id.to_string())
+        }
+        match transaction.network() {
+            Network::MainnetV0 => submit::<mainnet::CurrentNetwork>(self, transaction).await,
+            Network::TestnetV0 => submit::<testnet::CurrentNetwork>(self, transaction).await,
+            Network::CanaryV0 => submit::<canary::CurrentNetwork>(self, transaction).await,
+        }
+    }
+
+    /// Look up whether a transaction was included in a block
+    ///
+    /// @param {string} transaction_id Id of the transaction
+    /// @param {Network | undefined} network (optional) The network of the transaction, defaults to
+    /// mainnet
+    /// @returns {Promise<TransactionConfirmation | undefined>} The confirmation of the transaction,
+    /// undefined while it has not been included in a block
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "getConfirmation"))]
+    pub async fn get_confirmation(
+        &self,
+        transaction_id: &str,
+        network: Option<Network>,
+    ) -> Result<Option<TransactionConfirmation>, SdkError> {
+        async fn get<N: NetworkTrait>(
+            query: &NetworkQuery,
+            transaction_id: &str,
+        ) -> Result<Option<TransactionConfirmation>, SdkError> {
+            let transaction_id = parse_transaction_id::<N>(transaction_id)?;
+            query.get_native_confirmation::<N>(&transaction_id).await.map_err(|e| SdkError::QueryFailed(e.to_string()))
+        }
+        match network.unwrap_or_default() {
+            Network::MainnetV0 => get::<mainnet::CurrentNetwork>(self, transaction_id).await,
+            Network::TestnetV0 => get::<testnet::CurrentNetwork>(self, transaction_id).await,
+            Network::CanaryV0 => get::<canary::CurrentNetwork>(self, transaction_id).await,
+        }
+    }
+
+    /// Wait for a transaction to be included in a block. The returned confirmation tells whether
+    /// the transaction was accepted, rejected with only its fee paid, or aborted.
+    ///
+    /// @param {string} transaction_id Id of the transaction
+    /// @param {number | undefined} timeout_ms (optional) How long to wait in milliseconds, defaults
+    /// to 90 seconds
+    /// @param {Network | undefined} network (optional) The network of the transaction, defaults to
+    /// mainnet
+    /// @returns {Promise<TransactionConfirmation>}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "waitForConfirmation"))]
+    pub async fn wait_for_confirmation(
+        &self,
+        transaction_id: &str,
+        timeout_ms: Option<u32>,
+        network: Option<Network>,
+    ) -> Result<TransactionConfirmation, SdkError> {
+        async fn wait<N: NetworkTrait>(
+            query: &NetworkQuery,
+            transaction_id: &str,
+            timeout: Duration,
+        ) -> Result<TransactionConfirmation, SdkError> {
+            let transaction_id = parse_transaction_id::<N>(transaction_id)?;
+            query
+                .wait_for_native_confirmation::<N>(&transaction_id, timeout, CONFIRMATION_INTERVAL)
+                .await
+                .map_err(|e| SdkError::QueryFailed(e.to_string()))
+        }
+        let timeout = timeout_ms.map_or(CONFIRMATION_TIMEOUT, |timeout_ms| Duration::from_millis(timeout_ms as u64));
+        match network.unwrap_or_default() {
+            Network::MainnetV0 => wait::<mainnet::CurrentNetwork>(self, transaction_id, timeout).await,
+            Network::TestnetV0 => wait::<testnet::CurrentNetwork>(self, transaction_id, timeout).await,
+            Network::CanaryV0 => wait::<canary::CurrentNetwork>(self, transaction_id, timeout).await,
+        }
+    }
+}
+
+impl NetworkQuery {
+    /// Broadcast a transaction to the network, returning its id
+    pub async fn submit_native_transaction<N: NetworkTrait>(
+        &self,
+        transaction: &TransactionGeneric<N>,
+    ) -> anyhow::Result<N::TransactionID> {
+        let id: N::TransactionID = self.post::<N, _>("transaction/broadcast", transaction.to_string()).await?;
+        ensure!(id == transaction.id(), "The network returned the id {id} for transaction {}", transaction.id());
+        Ok(id)
+    }
+
+    /// Get the confirmation of a transaction, `None` while it has not been included in a block
+    pub async fn get_native_confirmation<N: NetworkTrait>(
+        &self,
+        transaction_id: &N::TransactionID,
+    ) -> anyhow::Result<Option<TransactionConfirmation>> {
+        let block_hash: Option<N::BlockHash> = self.get::<N, _>(&format!("find/blockHash/{transaction_id}")).await?;
+        let Some(block_hash) = block_hash else {
+            return Ok(None);
+        };
+        let height: u32 = self.get::<N, _>(&format!("height/{block_hash}")).await?;
+        let transactions: Vec<ConfirmedTransaction<N>> =
+            self.get::<N, _>(&format!("block/{height}/transactions")).await?;
+        TransactionConfirmation::from_block(transaction_id, height, &block_hash, &transactions).map(Some)
+    }
+
+    /// Look up a transaction every `interval` until it is included in a block, failing once it
+    /// has not been confirmed by the deadline `duration` from now, however long the lookups take
+    pub async fn wait_for_native_confirmation<N: NetworkTrait>(
+        &self,
+        transaction_id: &N::TransactionID,
+        duration: Duration,
+        interval: Duration,
+    ) -> anyhow::Result<TransactionConfirmation> {
+        let poll = async {
+            loop {
+                match self.get_native_confirmation::<N>(transaction_id).await {
+                    Ok(Some(confirmation)) => return Ok(confirmation),
+                    Ok(None) => sleep(interval).await,
+                    Err(error) => return Err(error),
+                }
+            }
+        };
+        match timeout(duration, poll).await {
+            Ok(confirmation) => confirmation,
+            Err(_) => bail!("Transaction {transaction_id} was not confirmed within {} ms", duration.as_millis()),
+        }
+    }
+}
+
+/// Parse the id of a transaction of the network `N`
+fn parse_transaction_id<N: NetworkTrait>(transaction_id: &str) -> Result<N::TransactionID, SdkError> {
+    N::TransactionID::from_str(transaction_id)
+        .map_err(|_| SdkError::InvalidArgument(format!("{transaction_id} is not a valid transaction id")))
+}
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use super::*;
+    use crate::{
+        native::{FeeOptions, TestnetProgramManager},
+        types::native::{
+            testnet::{parameters::GenesisBytes, CurrentNetwork},
+            Block,
+            FromBytes,
+            PrivateKey,
+            Program,
+            Rejected,
+        },
+        OfflineQuery,
+    };
+
+    use std::{
+        collections::HashMap,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    const HELLO_PROGRAM: &str = r#"program hello.aleo;
+
+function hello:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+"#;
+    const PRIVATE_KEY: &str = "APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6";
+    const STATE_ROOT: &str = "sr1wjueje6hy86yw9j4lhl7jwvhjxwunw34paj4k3cn2wm5h5r2syfqd83yw4";
+
+    type Routes = Arc<Mutex<HashMap<String, (u16, String)>>>;
+
+    /// A node answering requests with the responses set for their method and path, recording the
+    /// requests it receives
+    struct MockNode {
+        url: String,
+        routes: Routes,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl MockNode {
+        fn start() -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let (routes, requests) = (Routes::default(), Arc::new(Mutex::new(Vec::new())));
+            let (node_routes, node_requests) = (routes.clone(), requests.clone());
+            thread::spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    let mut reader = BufReader::new(stream);
+                    let mut request = String::new();
+                    reader.read_line(&mut request).unwrap();
+                    let route = request.split_whitespace().take(2).collect::<Vec<_>>().join(" ");
+                    let mut content_length = 0;
+                    loop {
+                        let mut header = String::new();
+                        reader.read_line(&mut header).unwrap();
+                        if header.trim().is_empty() {
+                            break;
+                        }
+                        if let Some(length) = header.to_lowercase().strip_prefix("content-length:") {
+                            content_length = length.trim().parse().unwrap();
+                        }
+                        request.push_str(&header);
+                    }
+                    let mut body = vec![0; content_length];
+                    reader.read_exact(&mut body).unwrap();
+                    request.push_str(&String::from_utf8(body).unwrap());
+                    node_requests.lock().unwrap().push(request);
+
+                    let (status, body) =
+                        node_routes.lock().unwrap().get(&route).cloned().unwrap_or((404, "\"Not found\"".to_string()));
+                    let length = body.len();
+                    let response = format!(
+                        "HTTP/1.1 {status} Mock\r\nContent-Type: application/json\r\n\
+                         Content-Length: {length}\r\nConnection: close\r\n\r\n{body}"
+                    );
+                    let _ = reader.into_inner().write_all(response.as_bytes());
+                }
+            });
+            Self { url, routes, requests }
+        }
+
+        fn route(&self, route: &str, body: String) {
+            self.routes.lock().unwrap().insert(route.to_string(), (200, body));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submission_and_confirmation() {
+        let mut program_manager = TestnetProgramManager::new().unwrap();
+        let private_key = PrivateKey::from_str(PRIVATE_KEY).unwrap();
+        program_manager.add_program(&Program::from_str(HELLO_PROGRAM).unwrap()).unwrap();
+        let query = OfflineQuery::new(STATE_ROOT).unwrap();
+        let transaction = program_manager
+            .execute(&private_key, "hello.aleo", "hello", ["5u32", "5u32"], FeeOptions::default(), query)
+            .await
+            .unwrap();
+        let id = transaction.id();
+
+        // Broadcasts are sent once to the first node without failing over or retrying
+        let (unavailable, node) = (MockNode::start(), MockNode::start());
+        let mut query = NetworkQuery::from_endpoints([&unavailable.url, &node.url]).unwrap();
+        query.set_header("X-API-Key", "secret").unwrap();
+        query.set_retries(1, 0);
+        node.route("POST /testnet/transaction/broadcast", format!("\"{id}\""));
+        assert!(query.submit_native_transaction(&transaction).await.is_err());
+        assert_eq!(unavailable.requests.lock().unwrap().len(), 1);
+        assert!(node.requests.lock().unwrap().is_empty());
+
+        // Broadcasts carry the custom headers
+        let mut broadcast_query = NetworkQuery::from_endpoints([&node.url]).unwrap();
+        broadcast_query.set_header("X-API-Key", "secret").unwrap();
+        assert_eq!(broadcast_query.submit_native_transaction(&transaction).await.unwrap(), id);
+        let request = node.requests.lock().unwrap().pop().unwrap();
+        assert!(request.contains("x-api-key: secret"));
+        assert!(request.ends_with(&transaction.to_string()));
+
+        // Lookups fail over from an unavailable node, and the transaction is pending until the node
+        // finds it in a block
+        let find = format!("GET /testnet/find/blockHash/{id}");
+        node.route(&find, "null".to_string());
+        assert!(query.get_native_confirmation::<CurrentNetwork>(&id).await.unwrap().is_none());
+        assert_eq!(unavailable.requests.lock().unwrap().len(), 2);
+        let request = node.requests.lock().unwrap().pop().unwrap();
+        assert!(request.contains("x-api-key: secret"));
+        let timeout = Duration::from_millis(50);
+        let interval = Duration::from_millis(10);
+        assert!(query.wait_for_native_confirmation::<CurrentNetwork>(&id, timeout, interval).await.is_err());
+
+        let block_hash = Block::<CurrentNetwork>::from_bytes_le(&GenesisBytes::load_bytes()).unwrap().hash();
+        node.route(&find, format!("\"{block_hash}\""));
+        node.route(&format!("GET /testnet/height/{block_hash}"), "7".to_string());
+        let confirm = |transactions: Vec<ConfirmedTransaction<CurrentNetwork>>| {
+            node.route("GET /testnet/block/7/transactions", serde_json::to_string(&transactions).unwrap());
+        };
+
+        let accepted = ConfirmedTransaction::accepted_execute(0, transaction.clone(), vec![]).unwrap();
+        confirm(vec![accepted]);
+        let confirmation = query.wait_for_native_confirmation::<CurrentNetwork>(&id, timeout, interval).await.unwrap();
+        assert_eq!(confirmation.status(), TransactionStatus::Accepted);
+        assert_eq!(confirmation.transaction_id(), id.to_string());
+        assert_eq!(confirmation.block_height(), 7);
+        assert_eq!(confirmation.block_hash(), block_hash.to_string());
+        assert_eq!(confirmation.fee_transaction_id(), None);
+
+        // A rejected execution is confirmed as the fee transaction paying for it
+        let TransactionGeneric::Execute(_, execution, Some(fee)) = &transaction else {
+            panic!("Expected an execution paying a fee");
+        };
+        let fee_transaction = TransactionGeneric::from_fee(fee.clone()).unwrap();
+        let rejected = Rejected::new_execution(execution.clone());
+        confirm(vec![ConfirmedTransaction::rejected_execute(0, fee_transaction.clone(), rejected, vec![]).unwrap()]);
+        let confirmation = query.get_native_confirmation::<CurrentNetwork>(&id).await.unwrap().unwrap();
+        assert_eq!(confirmation.status(), TransactionStatus::Rejected);
+        assert_eq!(confirmation.fee_transaction_id(), Some(fee_transaction.id().to_string()));
+
+        // An aborted transaction is found in a block without being confirmed in it
+        confirm(vec![]);
+        let confirmation = query.get_native_confirmation::<CurrentNetwork>(&id).await.unwrap().unwrap();
+        assert_eq!(confirmation.status(), TransactionStatus::Aborted);
+        assert_eq!(confirmation.fee_transaction_id(), None);
+    }
+}
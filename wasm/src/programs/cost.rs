@@ -0,0 +1,270 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::native::{
+    cost_in_microcredits,
+    deployment_cost,
+    Deployment,
+    Execution,
+    Network,
+    Process,
+    Transition,
+};
+
+#[cfg(not(feature = "native"))]
+use js_sys::Array;
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+/// Upper bound of the size in bytes of the Varuna proof of an execution with a single transition.
+/// The proof (`snarkvm_algorithms::snark::varuna::Proof`) holds nine compressed G1 commitments of
+/// 48 bytes, ten scalar field evaluations and matrix sums of 32 bytes and three KZG opening proofs,
+/// which comes to a little over 1000 bytes for one circuit with one instance.
+pub(crate) const EXECUTION_PROOF_SIZE_IN_BYTES: u64 = 1_200;
+
+/// Upper bound of the size in bytes each additional transition adds to the proof of an execution,
+/// i.e. the commitments, evaluations and matrix sums of one more circuit and instance
+pub(crate) const TRANSITION_PROOF_SIZE_IN_BYTES: u64 = 400;
+
+/// Breakdown of the estimated cost of an execution in microcredits
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExecutionCost {
+    storage_cost: u64,
+    finalize_cost: u64,
+    priority_fee: u64,
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl ExecutionCost {
+    /// Get the cost of storing the execution on chain
+    ///
+    /// @returns {bigint} Storage cost in microcredits
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = storageCost))]
+    pub fn storage_cost(&self) -> u64 {
+        self.storage_cost
+    }
+
+    /// Get the cost of running the finalize scopes of the execution
+    ///
+    /// @returns {bigint} Finalize cost in microcredits
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = finalizeCost))]
+    pub fn finalize_cost(&self) -> u64 {
+        self.finalize_cost
+    }
+
+    /// Get the priority fee paid on top of the base fee
+    ///
+    /// @returns {bigint} Priority fee in microcredits
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = priorityFee))]
+    pub fn priority_fee(&self) -> u64 {
+        self.priority_fee
+    }
+
+    /// Get the minimum fee required by the network, i.e. the storage and finalize cost
+    ///
+    /// @returns {bigint} Base fee in microcredits
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = baseFee))]
+    pub fn base_fee(&self) -> u64 {
+        self.storage_cost.saturating_add(self.finalize_cost)
+    }
+
+    /// Get the total fee including the priority fee
+    ///
+    /// @returns {bigint} Total fee in microcredits
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn total(&self) -> u64 {
+        self.base_fee().saturating_add(self.priority_fee)
+    }
+}
+
+impl ExecutionCost {
+    pub(crate) fn new(storage_cost: u64, finalize_cost: u64, priority_fee: u64) -> Self {
+        Self { storage_cost, finalize_cost, priority_fee }
+    }
+
+    /// Compute the cost of an execution from its size and the finalize scopes of its transitions.
+    /// The programs of the transitions must be loaded in the process.
+    pub(crate) fn from_execution<N: Network>(
+        process: &Process<N>,
+        execution: &Execution<N>,
+        priority_fee: u64,
+    ) -> Result<Self, String> {
+        let storage_cost = execution.size_in_bytes().map_err(|e| e.to_string())?;
+
+        // Iterate over the transitions to accumulate the finalize cost.
+        let mut finalize_cost = 0u64;
+        for transition in execution.transitions() {
+            let stack = process.get_stack(transition.program_id()).map_err(|e| e.to_string())?;
+            let cost = cost_in_microcredits(stack, transition.function_name()).map_err(|e| e.to_string())?;
+            finalize_cost = finalize_cost
+                .checked_add(cost)
+                .ok_or("The finalize cost computation overflowed for an execution".to_string())?;
+        }
+
+        Ok(Self::new(storage_cost, finalize_cost, priority_fee))
+    }
+
+    /// Estimate the cost of an execution from its transitions before it is proven. The size of
+    /// the proof is bounded from above, so the estimate is never below the cost of the proven
+    /// execution.
+    pub(crate) fn estimate<N: Network>(
+        process: &Process<N>,
+        transitions: impl ExactSizeIterator<Item = Transition<N>>,
+        priority_fee: u64,
+    ) -> Result<Self, String> {
+        let num_transitions = transitions.len() as u64;
+        let execution = Execution::from(transitions, Default::default(), None).map_err(|e| e.to_string())?;
+        let cost = Self::from_execution(process, &execution, priority_fee)?;
+
+        let storage_cost = num_transitions
+            .saturating_sub(1)
+            .checked_mul(TRANSITION_PROOF_SIZE_IN_BYTES)
+            .and_then(|size| size.checked_add(EXECUTION_PROOF_SIZE_IN_BYTES))
+            .and_then(|size| size.checked_add(cost.storage_cost))
+            .ok_or("The storage cost computation overflowed for an execution".to_string())?;
+        Ok(Self::new(storage_cost, cost.finalize_cost, priority_fee))
+    }
+}
+
+/// Breakdown of the cost of a program deployment in microcredits
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentCost {
+    storage_cost: u64,
+    synthesis_cost: u64,
+    namespace_cost: u64,
+    functions: Vec<FunctionCost>,
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl DeploymentCost {
+    /// Get the cost of storing the deployment on chain
+    ///
+    /// @returns {bigint} Storage cost in microcredits
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = storageCost))]
+    pub fn storage_cost(&self) -> u64 {
+        self.storage_cost
+    }
+
+    /// Get the cost of synthesizing the circuits of the program's functions
+    ///
+    /// @returns {bigint} Synthesis cost in microcredits
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = synthesisCost))]
+    pub fn synthesis_cost(&self) -> u64 {
+        self.synthesis_cost
+    }
+
+    /// Get the cost of registering the program name
+    ///
+    /// @returns {bigint} Namespace cost in microcredits
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = namespaceCost))]
+    pub fn namespace_cost(&self) -> u64 {
+        self.namespace_cost
+    }
+
+    /// Get the total cost of the deployment
+    ///
+    /// @returns {bigint} Total cost in microcredits
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn total(&self) -> u64 {
+        self.storage_cost.saturating_add(self.synthesis_cost).saturating_add(self.namespace_cost)
+    }
+
+    /// Get the circuit sizes of the program's functions, which determine the synthesis cost
+    ///
+    /// @returns {Array<FunctionCost>} Circuit sizes of each function in the program
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen]
+    pub fn functions(&self) -> Array {
+        self.functions.iter().cloned().map(JsValue::from).collect()
+    }
+}
+
+impl DeploymentCost {
+    /// Get the circuit sizes of the program's functions
+    pub fn function_costs(&self) -> &[FunctionCost] {
+        &self.functions
+    }
+
+    /// Compute the cost of a deployment and the circuit sizes of each of its functions
+    pub(crate) fn from_deployment<N: Network>(deployment: &Deployment<N>) -> Result<Self, String> {
+        let (_, (storage_cost, synthesis_cost, namespace_cost)) =
+            deployment_cost(deployment).map_err(|err| err.to_string())?;
+        let functions = deployment
+            .verifying_keys()
+            .iter()
+            .map(|(function_name, (verifying_key, _))| FunctionCost {
+                name: function_name.to_string(),
+                num_constraints: verifying_key.circuit_info.num_constraints as u64,
+                num_variables: verifying_key.num_variables(),
+            })
+            .collect();
+        Ok(Self { storage_cost, synthesis_cost, namespace_cost, functions })
+    }
+}
+
+/// Circuit size of a single function in a program deployment
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionCost {
+    name: String,
+    num_constraints: u64,
+    num_variables: u64,
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl FunctionCost {
+    /// Get the name of the function
+    ///
+    /// @returns {string}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Get the number of constraints in the function's circuit
+    ///
+    /// @returns {bigint}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = numConstraints))]
+    pub fn num_constraints(&self) -> u64 {
+        self.num_constraints
+    }
+
+    /// Get the number of variables in the function's circuit
+    ///
+    /// @returns {bigint}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = numVariables))]
+    pub fn num_variables(&self) -> u64 {
+        self.num_variables
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_execution_cost_totals() {
+        let cost = ExecutionCost::new(3_000, 20_000, 1_000);
+        assert_eq!(cost.base_fee(), 23_000);
+        assert_eq!(cost.total(), 24_000);
+
+        let cost = ExecutionCost::new(u64::MAX, 1, 1);
+        assert_eq!(cost.total(), u64::MAX);
+    }
+}
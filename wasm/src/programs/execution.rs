@@ -15,48 +15,103 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 pub use super::*;
+#[cfg(not(feature = "native"))]
+use js_sys::{Array, Object};
 use std::{ops::Deref, str::FromStr};
-use wasm_bindgen::prelude::wasm_bindgen;
-
-use crate::types::native::{
-    CurrentNetwork,
-    ExecutionNative,
-    IdentifierNative,
-    ProcessNative,
-    ProgramID,
-    VerifyingKeyNative,
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+#[cfg(not(feature = "native"))]
+use crate::{account::ViewKey, record::OwnedRecord};
+use crate::{
+    types::{
+        native::{canary, mainnet, testnet, Execution as ExecutionGeneric, ExecutionNative, Network as NetworkTrait},
+        to_network,
+        NetworkAgnostic,
+    },
+    with_network,
+    Network,
+    NetworkProcess,
+    SdkError,
 };
 
 /// Execution of an Aleo program.
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Execution(ExecutionNative);
+pub struct Execution {
+    execution: ExecutionNative,
+    network: Network,
+}
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl Execution {
     /// Returns the string representation of the execution.
-    #[wasm_bindgen(js_name = "toString")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "toString"))]
     #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
-        self.0.to_string()
+        self.execution.to_string()
     }
 
     /// Creates an execution object from a string representation of an execution.
-    #[wasm_bindgen(js_name = "fromString")]
-    pub fn from_string(execution: &str) -> Result<Execution, String> {
-        Ok(Self(ExecutionNative::from_str(execution).map_err(|e| e.to_string())?))
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "fromString"))]
+    pub fn from_string(execution: &str, network: Option<Network>) -> Result<Execution, String> {
+        fn parse<N: NetworkTrait>(execution: &str) -> Result<Execution, String> {
+            Execution::from_native(ExecutionGeneric::<N>::from_str(execution).map_err(|e| e.to_string())?)
+        }
+        // Deserialize with the types of the network to validate the execution against it
+        match network.unwrap_or_default() {
+            Network::MainnetV0 => parse::<mainnet::CurrentNetwork>(execution),
+            Network::TestnetV0 => parse::<testnet::CurrentNetwork>(execution),
+            Network::CanaryV0 => parse::<canary::CurrentNetwork>(execution),
+        }
+    }
+
+    /// Get the network the execution belongs to
+    ///
+    /// @returns {Network}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Find and decrypt the records owned by a view key in the outputs of the execution's transitions
+    ///
+    /// @param {ViewKey} view_key View key of the account to find records for
+    /// @param {Object | undefined} programs (optional) Source code of the programs which created the
+    /// records, keyed by program id. Used to name the records of programs other than credits.aleo
+    /// @returns {Array<OwnedRecord>} Records owned by the view key
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen(js_name = ownedRecords)]
+    pub fn owned_records(&self, view_key: &ViewKey, programs: Option<Object>) -> Result<Array, SdkError> {
+        let records = OwnedRecord::find(self.execution.transitions(), view_key, programs)?;
+        Ok(records.into_iter().map(JsValue::from).collect())
+    }
+}
+
+impl Execution {
+    /// Create an execution from the native execution of the network it was created on
+    pub(crate) fn from_native<N: NetworkTrait>(execution: ExecutionGeneric<N>) -> Result<Self, String> {
+        Ok(Self { execution: to_network(execution)?, network: Network::of::<N>() })
+    }
+
+    /// Get the execution as a native execution of the network `N`, failing if it belongs to
+    /// another network
+    pub(crate) fn to_native<N: NetworkTrait>(&self) -> Result<ExecutionGeneric<N>, SdkError> {
+        Network::of::<N>().ensure(self.network)?;
+        Ok(to_network(self.execution.clone())?)
     }
 }
 
+/// Wrap a native execution, whose type is the execution of the default network
 impl From<ExecutionNative> for Execution {
-    fn from(native: ExecutionNative) -> Self {
-        Self(native)
+    fn from(execution: ExecutionNative) -> Self {
+        Self { execution, network: Network::default() }
     }
 }
 
 impl From<Execution> for ExecutionNative {
     fn from(execution: Execution) -> Self {
-        execution.0
+        execution.execution
     }
 }
 
@@ -64,7 +119,7 @@ impl Deref for Execution {
     type Target = ExecutionNative;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.execution
     }
 }
 
@@ -77,23 +132,26 @@ impl Deref for Execution {
 /// @param {Program} program The program that the function execution belongs to
 /// @param {String} function_id The name of the function that was executed
 /// @returns {boolean} True if the execution is valid, false otherwise
-#[wasm_bindgen(js_name = "verifyFunctionExecution")]
+#[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "verifyFunctionExecution"))]
 pub fn verify_function_execution(
     execution: &Execution,
     verifying_key: &VerifyingKey,
     program: &Program,
     function_id: &str,
 ) -> Result<bool, String> {
-    let function = IdentifierNative::from_str(function_id).map_err(|e| e.to_string())?;
-    let program_id = ProgramID::<CurrentNetwork>::from_str(&program.id()).unwrap();
-    let mut process = ProcessNative::load_web().map_err(|e| e.to_string())?;
-    if &program.id() != "credits.aleo" {
-        process.add_program(program).map_err(|e| e.to_string())?;
-    }
-    process
-        .insert_verifying_key(&program_id, &function, VerifyingKeyNative::from(verifying_key))
-        .map_err(|e| e.to_string())?;
-    process.verify_execution(execution).map_or(Ok(false), |_| Ok(true))
+    let mut process = NetworkProcess::load(execution.network())?;
+    with_network!(&mut process, |process| {
+        let execution: ExecutionNative = execution.to_native().map_err(|e| e.to_string())?;
+        let function = IdentifierNative::from_str(function_id).map_err(|e| e.to_string())?;
+        let program = ProgramNative::from_str(&program.to_string()).map_err(|e| e.to_string())?;
+        if &program.id().to_string() != "credits.aleo" {
+            process.add_program(&program).map_err(|e| e.to_string())?;
+        }
+        process
+            .insert_verifying_key(program.id(), &function, verifying_key.clone().into_network()?)
+            .map_err(|e| e.to_string())?;
+        process.verify_execution(&execution).map_or(Ok(false), |_| Ok(true))
+    })
 }
 
 /*#[cfg(test)]
@@ -105,7 +163,7 @@ mod tests {
 
     #[wasm_bindgen_test]
     fn test_execution_verification() {
-        let execution = Execution::from_string(EXECUTION).unwrap();
+        let execution = Execution::from_string(EXECUTION, None).unwrap();
         let verifying_key_bytes = crate::types::native::parameters::TransferPublicVerifier::load_bytes().unwrap();
         let verifying_key = VerifyingKey::from_bytes(&verifying_key_bytes).unwrap();
         assert!(
@@ -17,24 +17,25 @@
 use crate::{ProvingKey, VerifyingKey};
 
 use crate::types::native::{ProvingKeyNative, VerifyingKeyNative};
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::prelude::wasm_bindgen;
 
 /// Key pair object containing both the function proving and verifying keys
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 #[derive(Clone, Debug)]
 pub struct KeyPair {
     proving_key: Option<ProvingKey>,
     verifying_key: Option<VerifyingKey>,
 }
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl KeyPair {
     /// Create new key pair from proving and verifying keys
     ///
     /// @param {ProvingKey} proving_key Proving key corresponding to a function in an Aleo program
     /// @param {VerifyingKey} verifying_key Verifying key corresponding to a function in an Aleo program
     /// @returns {KeyPair} Key pair object containing both the function proving and verifying keys
-    #[wasm_bindgen(constructor)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(constructor))]
     pub fn new(proving_key: ProvingKey, verifying_key: VerifyingKey) -> KeyPair {
         KeyPair { proving_key: Some(proving_key), verifying_key: Some(verifying_key) }
     }
@@ -42,7 +43,7 @@ impl KeyPair {
     /// Get the proving key. This method will remove the proving key from the key pair
     ///
     /// @returns {ProvingKey}
-    #[wasm_bindgen(js_name = "provingKey")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "provingKey"))]
     pub fn proving_key(&mut self) -> Result<ProvingKey, String> {
         self.proving_key.take().ok_or("Proving key has already been removed".to_string())
     }
@@ -50,7 +51,7 @@ impl KeyPair {
     /// Get the verifying key. This method will remove the verifying key from the key pair
     ///
     /// @returns {VerifyingKey}
-    #[wasm_bindgen(js_name = "verifyingKey")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "verifyingKey"))]
     pub fn verifying_key(&mut self) -> Result<VerifyingKey, String> {
         self.verifying_key.take().ok_or("Proving key has already been removed".to_string())
     }
@@ -0,0 +1,263 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::native::{
+    Field,
+    Identifier,
+    Network as NetworkTrait,
+    Plaintext,
+    ProgramID,
+    Record,
+    ToBits,
+    Transaction,
+    Uniform,
+};
+use snarkvm_console::{
+    prelude::Zero,
+    program::{
+        BlockTree,
+        HeaderLeaf,
+        HeaderPath,
+        StatePath,
+        TransactionLeaf,
+        TransactionPath,
+        TransactionsPath,
+        TransitionLeaf,
+        TransitionPath,
+        HEADER_DEPTH,
+        TRANSACTIONS_DEPTH,
+        TRANSACTION_DEPTH,
+        TRANSITION_DEPTH,
+    },
+};
+
+use anyhow::{anyhow, ensure, Result};
+use rand::{rngs::StdRng, SeedableRng};
+use std::{collections::HashMap, str::FromStr};
+
+/// The variant of the transition leaves of output records
+const RECORD_OUTPUT_VARIANT: u8 = 3;
+
+/// The number of leaves of a block header
+const HEADER_LEAVES: usize = 1 << HEADER_DEPTH;
+
+/// The path of a record commitment to the id of the transaction creating the record
+struct TransactionLocalPath<N: NetworkTrait> {
+    transaction_path: TransactionPath<N>,
+    transaction_leaf: TransactionLeaf<N>,
+    transition_root: Field<N>,
+    tcm: Field<N>,
+    transition_path: TransitionPath<N>,
+    transition_leaf: TransitionLeaf<N>,
+}
+
+/// The parts of the state path of a record commitment which do not change as blocks are added
+struct CommitmentPath<N: NetworkTrait> {
+    height: u32,
+    transactions_path: TransactionsPath<N>,
+    transaction_id: N::TransactionID,
+    local_path: TransactionLocalPath<N>,
+}
+
+/// The hash of a block and the path of its transactions root to it
+struct BlockHeader<N: NetworkTrait> {
+    hash: N::BlockHash,
+    previous_hash: N::BlockHash,
+    header_root: Field<N>,
+    header_path: HeaderPath<N>,
+    header_leaf: HeaderLeaf<N>,
+}
+
+/// The record commitments created by a transaction with their paths to the transaction id
+type TransactionOutputs<N> = (<N as NetworkTrait>::TransactionID, Vec<(Field<N>, TransactionLocalPath<N>)>);
+
+/// Chain of blocks built from local transactions, keeping the block tree and the paths of the
+/// record commitments needed to create the state paths of inclusion proofs
+///
+/// Blocks only commit to the previous state root and to their transactions, so their hashes differ
+/// from the hashes of blocks produced by validators. The state paths of their records are valid
+/// against the state root of the chain, which is all inclusion proofs require.
+pub(crate) struct LocalChain<N: NetworkTrait> {
+    blocks: Vec<BlockHeader<N>>,
+    block_tree: BlockTree<N>,
+    commitments: HashMap<Field<N>, CommitmentPath<N>>,
+}
+
+impl<N: NetworkTrait> LocalChain<N> {
+    /// Create a chain containing an empty genesis block
+    pub(crate) fn new() -> Result<Self> {
+        let mut chain = Self { blocks: Vec::new(), block_tree: N::merkle_tree_bhp(&[])?, commitments: HashMap::new() };
+        chain.add_block(Vec::new())?;
+        Ok(chain)
+    }
+
+    /// Get the height of the last block
+    pub(crate) fn height(&self) -> u32 {
+        self.blocks.len() as u32 - 1
+    }
+
+    /// Get the global state root, the root of the tree of the block hashes
+    pub(crate) fn state_root(&self) -> N::StateRoot {
+        (*self.block_tree.root()).into()
+    }
+
+    /// Check if a record commitment was created in the chain
+    pub(crate) fn contains_commitment(&self, commitment: &Field<N>) -> bool {
+        self.commitments.contains_key(commitment)
+    }
+
+    /// Get the state path of a record commitment against the current state root
+    pub(crate) fn state_path(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        let path = self.commitments.get(commitment).ok_or_else(|| anyhow!("Commitment {commitment} not found"))?;
+        let block = &self.blocks[path.height as usize];
+        let block_path = self.block_tree.prove(path.height as usize, &(*block.hash).to_bits_le())?;
+        let local_path = &path.local_path;
+        StatePath::from(
+            self.state_root(),
+            block_path,
+            block.hash,
+            block.previous_hash,
+            block.header_root,
+            block.header_path.clone(),
+            block.header_leaf,
+            path.transactions_path.clone(),
+            path.transaction_id,
+            local_path.transaction_path.clone(),
+            local_path.transaction_leaf,
+            local_path.transition_root,
+            local_path.tcm,
+            local_path.transition_path.clone(),
+            local_path.transition_leaf,
+        )
+    }
+
+    /// Add a block containing a transaction
+    pub(crate) fn add_transaction(&mut self, transaction: &Transaction<N>) -> Result<()> {
+        let outputs = Self::transaction_outputs(transaction)?;
+        self.add_block(vec![outputs])
+    }
+
+    /// Add a block containing a transaction which creates credits records out of thin air, as the
+    /// genesis block of a network does. The records must be credits.aleo credits records.
+    pub(crate) fn mint(&mut self, records: &[Record<N, Plaintext<N>>]) -> Result<()> {
+        ensure!(!records.is_empty(), "At least one record must be minted");
+        ensure!(records.len() <= N::MAX_OUTPUTS, "At most {} records can be minted at once", N::MAX_OUTPUTS);
+
+        // Commit to the records as the outputs of a transition of credits.aleo
+        let program_id = ProgramID::<N>::from_str("credits.aleo")?;
+        let record_name = Identifier::<N>::from_str("credits")?;
+        let transition_leaves = records
+            .iter()
+            .enumerate()
+            .map(|(index, record)| {
+                let commitment = record.to_commitment(&program_id, &record_name)?;
+                Ok(TransitionLeaf::new_with_version(index as u8, RECORD_OUTPUT_VARIANT, commitment))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let transition_tree = N::merkle_tree_bhp::<TRANSITION_DEPTH>(
+            &transition_leaves.iter().map(|leaf| leaf.to_bits_le()).collect::<Vec<_>>(),
+        )?;
+        let transition_root = *transition_tree.root();
+        let tcm = Field::rand(&mut StdRng::from_entropy());
+        let transition_id = N::hash_bhp512(&(transition_root, tcm).to_bits_le())?;
+
+        // Put the transition in a transaction of its own
+        let transaction_leaf = TransactionLeaf::new_execution(0, transition_id);
+        let transaction_tree = N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&[transaction_leaf.to_bits_le()])?;
+        let transaction_path = transaction_tree.prove(0, &transaction_leaf.to_bits_le())?;
+        let transaction_id = N::TransactionID::from(*transaction_tree.root());
+
+        let commitments = transition_leaves
+            .iter()
+            .enumerate()
+            .map(|(index, transition_leaf)| {
+                let transition_path = transition_tree.prove(index, &transition_leaf.to_bits_le())?;
+                let local_path = TransactionLocalPath {
+                    transaction_path: transaction_path.clone(),
+                    transaction_leaf,
+                    transition_root,
+                    tcm,
+                    transition_path,
+                    transition_leaf: *transition_leaf,
+                };
+                Ok((transition_leaf.id(), local_path))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.add_block(vec![(transaction_id, commitments)])
+    }
+
+    /// Get the record commitments created by the transitions of a transaction
+    fn transaction_outputs(transaction: &Transaction<N>) -> Result<TransactionOutputs<N>> {
+        let mut commitments = Vec::new();
+        for transition in transaction.transitions() {
+            let transaction_leaf = transaction.to_leaf(transition.id())?;
+            let transaction_path = transaction.to_path(&transaction_leaf)?;
+            let transition_root = transition.to_root()?;
+            for commitment in transition.commitments() {
+                let transition_leaf = transition.to_leaf(commitment, false)?;
+                let local_path = TransactionLocalPath {
+                    transaction_path: transaction_path.clone(),
+                    transaction_leaf,
+                    transition_root,
+                    tcm: *transition.tcm(),
+                    transition_path: transition.to_path(&transition_leaf)?,
+                    transition_leaf,
+                };
+                commitments.push((*commitment, local_path));
+            }
+        }
+        Ok((transaction.id(), commitments))
+    }
+
+    /// Add a block containing the given transactions on top of the chain
+    fn add_block(&mut self, transactions: Vec<TransactionOutputs<N>>) -> Result<()> {
+        ensure!(
+            transactions.iter().all(|(_, outputs)| outputs.iter().all(|(c, _)| !self.contains_commitment(c))),
+            "A record commitment of the block already exists in the chain"
+        );
+
+        let transaction_ids = transactions.iter().map(|(id, _)| (**id).to_bits_le()).collect::<Vec<_>>();
+        let transactions_tree = N::merkle_tree_bhp::<TRANSACTIONS_DEPTH>(&transaction_ids)?;
+
+        // The header commits to the previous state root and to the transactions root
+        let mut header_leaves = vec![HeaderLeaf::<N>::new(0, *self.state_root())];
+        header_leaves.push(HeaderLeaf::new(1, *transactions_tree.root()));
+        header_leaves.extend((2..HEADER_LEAVES).map(|index| HeaderLeaf::new(index as u8, Field::zero())));
+        let header_tree = N::merkle_tree_bhp::<HEADER_DEPTH>(
+            &header_leaves.iter().map(|leaf| leaf.to_bits_le()).collect::<Vec<_>>(),
+        )?;
+        let header_root = *header_tree.root();
+        let header_leaf = header_leaves[1];
+        let header_path = header_tree.prove(1, &header_leaf.to_bits_le())?;
+
+        let previous_hash = self.blocks.last().map(|block| block.hash).unwrap_or_default();
+        let preimage = [(*previous_hash).to_bits_le(), header_root.to_bits_le()].concat();
+        let hash = N::BlockHash::from(N::hash_bhp1024(&preimage)?);
+        self.block_tree.append(&[(*hash).to_bits_le()])?;
+
+        let height = self.blocks.len() as u32;
+        for (index, (transaction_id, outputs)) in transactions.into_iter().enumerate() {
+            let transactions_path = transactions_tree.prove(index, &(*transaction_id).to_bits_le())?;
+            for (commitment, local_path) in outputs {
+                let path =
+                    CommitmentPath { height, transactions_path: transactions_path.clone(), transaction_id, local_path };
+                self.commitments.insert(commitment, path);
+            }
+        }
+        self.blocks.push(BlockHeader { hash, previous_hash, header_root, header_path, header_leaf });
+        Ok(())
+    }
+}
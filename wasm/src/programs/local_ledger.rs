@@ -0,0 +1,668 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(not(feature = "native"))]
+use crate::process_inputs;
+use crate::{
+    log,
+    programs::LocalChain,
+    types::{
+        native::{
+            canary,
+            mainnet,
+            testnet,
+            Address,
+            Execution,
+            Field,
+            FinalizeGlobalState,
+            FinalizeMemory,
+            FinalizeStore,
+            Identifier,
+            Network as NetworkTrait,
+            Plaintext,
+            PrivateKey,
+            Process,
+            Program,
+            ProgramID,
+            Record,
+            Scalar,
+            Transaction as TransactionGeneric,
+            TransactionNative,
+            Uniform,
+            Value,
+        },
+        to_network,
+        NetworkAgnostic,
+    },
+    Address as AddressWasm,
+    Network,
+    OfflineQuery,
+    PrivateKey as PrivateKeyWasm,
+    RecordPlaintext,
+    SdkError,
+    Transaction,
+};
+
+#[cfg(not(feature = "native"))]
+use js_sys::Array;
+use rand::{rngs::StdRng, SeedableRng};
+use snarkvm_circuit_network::Aleo;
+use snarkvm_console::program::StatePath;
+use std::{collections::HashSet, str::FromStr};
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// In-memory ledger of one network holding the deployed programs, the mapping state, the spent
+/// records and the chain of blocks, against which transactions are finalized one per block
+pub(crate) struct LedgerState<N: NetworkTrait> {
+    process: Process<N>,
+    store: FinalizeStore<N, FinalizeMemory<N>>,
+    chain: LocalChain<N>,
+    transaction_ids: HashSet<N::TransactionID>,
+    serial_numbers: HashSet<Field<N>>,
+}
+
+impl<N: NetworkTrait> LedgerState<N> {
+    /// Create a ledger containing only credits.aleo
+    pub(crate) fn new() -> Result<Self, SdkError> {
+        let process = Process::load_web().map_err(|e| e.to_string())?;
+        let store = FinalizeStore::open(None).map_err(|e| e.to_string())?;
+        let chain = LocalChain::new().map_err(|e| e.to_string())?;
+        let ledger = Self { process, store, chain, transaction_ids: HashSet::new(), serial_numbers: HashSet::new() };
+        ledger.initialize_mappings(ledger.process.get_program("credits.aleo").map_err(|e| e.to_string())?)?;
+        Ok(ledger)
+    }
+
+    /// Get the height of the last block, each applied transaction being finalized in its own block
+    pub(crate) fn height(&self) -> u32 {
+        self.chain.height()
+    }
+
+    /// Get the global state root of the chain
+    pub(crate) fn state_root(&self) -> N::StateRoot {
+        self.chain.state_root()
+    }
+
+    /// Get the state path of a record created in the ledger, valid against the current state root
+    pub(crate) fn state_path(&self, commitment: &str) -> Result<StatePath<N>, SdkError> {
+        let commitment = Field::<N>::from_str(commitment)
+            .map_err(|_| SdkError::InvalidArgument(format!("{commitment} is not a valid record commitment")))?;
+        if !self.chain.contains_commitment(&commitment) {
+            return Err(SdkError::InvalidArgument(format!("No record with the commitment {commitment} was created")));
+        }
+        Ok(self.chain.state_path(&commitment).map_err(|e| e.to_string())?)
+    }
+
+    /// Mint credits records owned by the given addresses in a new block, as the genesis block of a
+    /// network does. The records can then be spent with inclusion proofs against the state root.
+    pub(crate) fn mint(&mut self, recipients: &[(Address<N>, u64)]) -> Result<Vec<Record<N, Plaintext<N>>>, SdkError> {
+        let rng = &mut StdRng::from_entropy();
+        let records = recipients
+            .iter()
+            .map(|(address, microcredits)| {
+                let nonce = N::g_scalar_multiply(&Scalar::rand(rng));
+                Record::<N, Plaintext<N>>::from_str(&format!(
+                    "{{ owner: {address}.private, microcredits: {microcredits}u64.private, _nonce: {nonce}.public }}"
+                ))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        self.chain.mint(&records).map_err(|e| SdkError::InvalidArgument(e.to_string()))?;
+        Ok(records)
+    }
+
+    /// Add a program to the ledger without a deployment transaction. Its imports must already be
+    /// in the ledger.
+    pub(crate) fn add_program(&mut self, program: &Program<N>) -> Result<(), SdkError> {
+        if let Ok(stored_program) = self.process.get_program(program.id()) {
+            if stored_program != program {
+                return Err(SdkError::ProgramMismatch(format!(
+                    "A different program with the id {} is already in the ledger",
+                    program.id()
+                )));
+            }
+            return Ok(());
+        }
+        self.ensure_imports(program)?;
+        self.process.add_program(program).map_err(|e| SdkError::InvalidArgument(e.to_string()))?;
+        self.initialize_mappings(program)
+    }
+
+    /// Apply a deployment or execution transaction and its finalize logic in a new block. Transactions
+    /// which would be rejected on-chain, e.g. because their finalize logic aborts or they spend a
+    /// record twice, are rejected without changing the state of the ledger. Proofs are not verified.
+    pub(crate) fn apply(&mut self, transaction: &TransactionGeneric<N>) -> Result<(), SdkError> {
+        let rejected =
+            |reason: String| SdkError::Rejected(format!("Transaction {} was rejected: {reason}", transaction.id()));
+
+        if self.transaction_ids.contains(&transaction.id()) {
+            return Err(rejected("it was already applied".to_string()));
+        }
+        if let Some(serial_number) =
+            transaction.serial_numbers().find(|serial_number| self.serial_numbers.contains(*serial_number))
+        {
+            return Err(rejected(format!("the record with serial number {serial_number} was already spent")));
+        }
+
+        let state = self.next_state()?;
+        match transaction {
+            TransactionGeneric::Deploy(_, _, deployment, fee) => {
+                if self.process.contains_program(deployment.program_id()) {
+                    return Err(rejected(format!("the program {} is already deployed", deployment.program_id())));
+                }
+                self.ensure_imports(deployment.program())?;
+                let (stack, _) = self
+                    .process
+                    .finalize_deployment(state, &self.store, deployment, fee)
+                    .map_err(|e| rejected(e.to_string()))?;
+                self.process.add_stack(stack);
+            }
+            TransactionGeneric::Execute(_, execution, fee) => {
+                self.process
+                    .finalize_execution(state, &self.store, execution, fee.as_ref())
+                    .map_err(|e| rejected(e.to_string()))?;
+            }
+            TransactionGeneric::Fee(..) => {
+                return Err(rejected("fee transactions only pay for transactions rejected by the network".to_string()));
+            }
+        }
+
+        self.chain.add_transaction(transaction).map_err(|e| e.to_string())?;
+        self.transaction_ids.insert(transaction.id());
+        self.serial_numbers.extend(transaction.serial_numbers().copied());
+        Ok(())
+    }
+
+    /// Execute a function and apply the execution without proving it. The proving key of the
+    /// function is synthesized the first time the function is executed.
+    pub(crate) fn execute<A: Aleo<Network = N>>(
+        &mut self,
+        private_key: &PrivateKey<N>,
+        program_id: &str,
+        function_name: &str,
+        inputs: &[String],
+    ) -> Result<TransactionGeneric<N>, SdkError> {
+        let rng = &mut StdRng::from_entropy();
+        let program_id = ProgramID::<N>::from_str(program_id)
+            .map_err(|_| SdkError::InvalidArgument(format!("{program_id} is an invalid program id")))?;
+        let function_name = Identifier::<N>::from_str(function_name)
+            .map_err(|_| SdkError::InvalidArgument(format!("{function_name} is an invalid function name")))?;
+        let stack =
+            self.process.get_stack(program_id).map_err(|_| SdkError::ProgramNotFound(program_id.to_string()))?;
+
+        if !stack.contains_proving_key(&function_name) {
+            log(&format!("Synthesizing keys for {program_id} - {function_name}"));
+            self.process.synthesize_key::<A, _>(&program_id, &function_name, rng).map_err(|e| e.to_string())?;
+        }
+
+        let authorization = self
+            .process
+            .authorize::<A, _>(private_key, program_id, function_name, inputs.iter(), rng)
+            .map_err(|e| SdkError::InvalidArgument(e.to_string()))?;
+        let (_, trace) = self.process.execute::<A, _>(authorization, rng).map_err(|e| e.to_string())?;
+
+        // The execution is applied without a proof, so it is not valid on the network
+        let execution = Execution::from(trace.transitions().iter().cloned(), N::StateRoot::default(), None)
+            .map_err(|e| e.to_string())?;
+        let transaction = TransactionGeneric::from_execution(execution, None).map_err(|e| e.to_string())?;
+        self.apply(&transaction)?;
+        Ok(transaction)
+    }
+
+    /// Get the value stored at a key of a mapping
+    pub(crate) fn mapping_value(
+        &self,
+        program_id: &str,
+        mapping_name: &str,
+        key: &str,
+    ) -> Result<Option<Value<N>>, SdkError> {
+        let (program_id, mapping_name, key) = self.mapping_key(program_id, mapping_name, key)?;
+        Ok(self.store.get_value_confirmed(program_id, mapping_name, &key).map_err(|e| e.to_string())?)
+    }
+
+    /// Store a value at a key of a mapping, e.g. to set the public balance of an account in the
+    /// account mapping of credits.aleo
+    pub(crate) fn set_mapping_value(
+        &mut self,
+        program_id: &str,
+        mapping_name: &str,
+        key: &str,
+        value: &str,
+    ) -> Result<(), SdkError> {
+        let (program_id, mapping_name, key) = self.mapping_key(program_id, mapping_name, key)?;
+        let value = Plaintext::<N>::from_str(value)
+            .map_err(|_| SdkError::InvalidArgument(format!("{value} is not a valid plaintext value")))?;
+        self.store
+            .update_key_value(program_id, mapping_name, key, Value::Plaintext(value))
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Parse the program id, mapping name and key of a mapping entry of a program in the ledger
+    fn mapping_key(
+        &self,
+        program_id: &str,
+        mapping_name: &str,
+        key: &str,
+    ) -> Result<(ProgramID<N>, Identifier<N>, Plaintext<N>), SdkError> {
+        let program_id = ProgramID::<N>::from_str(program_id)
+            .map_err(|_| SdkError::InvalidArgument(format!("{program_id} is an invalid program id")))?;
+        let program =
+            self.process.get_program(program_id).map_err(|_| SdkError::ProgramNotFound(program_id.to_string()))?;
+        let mapping_name = Identifier::<N>::from_str(mapping_name)
+            .map_err(|_| SdkError::InvalidArgument(format!("{mapping_name} is an invalid mapping name")))?;
+        if !program.contains_mapping(&mapping_name) {
+            return Err(SdkError::InvalidArgument(format!("{program_id} has no mapping named {mapping_name}")));
+        }
+        let key = Plaintext::<N>::from_str(key)
+            .map_err(|_| SdkError::InvalidArgument(format!("{key} is not a valid mapping key")))?;
+        Ok((program_id, mapping_name, key))
+    }
+
+    /// Ensure the imports of a program are in the ledger
+    fn ensure_imports(&self, program: &Program<N>) -> Result<(), SdkError> {
+        match program.imports().keys().find(|import| !self.process.contains_program(import)) {
+            Some(import) => Err(SdkError::ImportMissing(import.to_string())),
+            None => Ok(()),
+        }
+    }
+
+    /// Initialize the mappings of a program added to the ledger
+    fn initialize_mappings(&self, program: &Program<N>) -> Result<(), SdkError> {
+        for mapping in program.mappings().values() {
+            self.store.initialize_mapping(*program.id(), *mapping.name()).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    /// Get the global state of the block finalizing the next transaction
+    fn next_state(&self) -> Result<FinalizeGlobalState, SdkError> {
+        let height = self.height() + 1;
+        Ok(FinalizeGlobalState::new::<N>(height as u64, height, 0, 0, N::BlockHash::default())
+            .map_err(|e| e.to_string())?)
+    }
+}
+
+/// The ledger state of one of the networks supported by the SDK
+enum NetworkLedger {
+    MainnetV0(LedgerState<mainnet::CurrentNetwork>),
+    TestnetV0(LedgerState<testnet::CurrentNetwork>),
+    CanaryV0(LedgerState<canary::CurrentNetwork>),
+}
+
+/// Run a block with the ledger state of a `NetworkLedger` bound to `$ledger` and the native types
+/// of its network in scope
+macro_rules! with_ledger {
+    ($network_ledger:expr, |$ledger:ident| $body:block) => {
+        match $network_ledger {
+            NetworkLedger::MainnetV0($ledger) => {
+                #[allow(unused_imports)]
+                use mainnet::*;
+                $body
+            }
+            NetworkLedger::TestnetV0($ledger) => {
+                #[allow(unused_imports)]
+                use testnet::*;
+                $body
+            }
+            NetworkLedger::CanaryV0($ledger) => {
+                #[allow(unused_imports)]
+                use canary::*;
+                $body
+            }
+        }
+    };
+}
+
+/// Local in-memory ledger for testing programs and their finalize logic without a network
+///
+/// The ledger holds the deployed programs and the state of their mappings. Deployments and
+/// executions are applied with their finalize logic, each in its own block, and transactions which
+/// would be rejected on-chain are rejected with an `SdkError` whose code is `REJECTED`, leaving
+/// the ledger unchanged. Proofs are not verified, so unproven executions from `execute` can be
+/// applied to test the flows of a program quickly.
+///
+/// The ledger also keeps a chain of its blocks. Credits records minted with `mint` and the records
+/// created by applied transactions get state paths against the state root of the chain, so
+/// `offlineQuery` provides what the program manager needs to prove executions spending them.
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+pub struct LocalLedger {
+    ledger: NetworkLedger,
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl LocalLedger {
+    /// Create a local ledger containing only credits.aleo
+    ///
+    /// @param {Network | undefined} network (optional) The network the ledger simulates, defaults
+    /// to mainnet
+    /// @returns {LocalLedger}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(constructor))]
+    pub fn new(network: Option<Network>) -> Result<LocalLedger, SdkError> {
+        let ledger = match network.unwrap_or_default() {
+            Network::MainnetV0 => NetworkLedger::MainnetV0(LedgerState::new()?),
+            Network::TestnetV0 => NetworkLedger::TestnetV0(LedgerState::new()?),
+            Network::CanaryV0 => NetworkLedger::CanaryV0(LedgerState::new()?),
+        };
+        Ok(Self { ledger })
+    }
+
+    /// Get the network the ledger simulates
+    ///
+    /// @returns {Network}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn network(&self) -> Network {
+        match self.ledger {
+            NetworkLedger::MainnetV0(_) => Network::MainnetV0,
+            NetworkLedger::TestnetV0(_) => Network::TestnetV0,
+            NetworkLedger::CanaryV0(_) => Network::CanaryV0,
+        }
+    }
+
+    /// Get the height of the last block. Each applied transaction is finalized in its own block.
+    ///
+    /// @returns {number} Block height
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn height(&self) -> u32 {
+        with_ledger!(&self.ledger, |ledger| { ledger.height() })
+    }
+
+    /// Add a program to the ledger without deploying it. Its imports must already be in the ledger.
+    ///
+    /// @param {string} program Source code of the program
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = addProgram))]
+    pub fn add_program(&mut self, program: &str) -> Result<(), SdkError> {
+        with_ledger!(&mut self.ledger, |ledger| {
+            let program = ProgramNative::from_str(program).map_err(|e| SdkError::InvalidArgument(e.to_string()))?;
+            ledger.add_program(&program)
+        })
+    }
+
+    /// Apply a deployment or execution transaction and run its finalize logic
+    ///
+    /// @param {Transaction} transaction Transaction to apply
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = applyTransaction))]
+    pub fn apply_transaction(&mut self, transaction: &Transaction) -> Result<(), SdkError> {
+        self.network().ensure(transaction.network())?;
+        let transaction = TransactionNative::from(transaction.clone());
+        with_ledger!(&mut self.ledger, |ledger| {
+            let transaction: TransactionNative = to_network(transaction)?;
+            ledger.apply(&transaction)
+        })
+    }
+
+    /// Execute a function of a program in the ledger and apply the execution with its finalize
+    /// logic. The execution is not proven, so the returned transaction is only valid locally.
+    ///
+    /// @param {PrivateKey} private_key Private key of the caller
+    /// @param {string} program_id Id of the program
+    /// @param {string} function_name Name of the function to execute
+    /// @param {Array<string>} inputs Inputs of the function
+    /// @returns {Transaction} Unproven execution transaction
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen]
+    pub fn execute(
+        &mut self,
+        private_key: &PrivateKeyWasm,
+        program_id: &str,
+        function_name: &str,
+        inputs: Array,
+    ) -> Result<Transaction, SdkError> {
+        let inputs = process_inputs!(inputs);
+        self.execute_with_inputs(private_key, program_id, function_name, &inputs)
+    }
+
+    /// Mint a credits record owned by an address in a new block, as the genesis block of a network
+    /// does
+    ///
+    /// @param {Address} address Owner of the record
+    /// @param {number} microcredits Amount of microcredits of the record
+    /// @returns {RecordPlaintext} Minted record
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn mint(&mut self, address: &AddressWasm, microcredits: u64) -> Result<RecordPlaintext, SdkError> {
+        with_ledger!(&mut self.ledger, |ledger| {
+            let address: AddressNative = to_network(**address)?;
+            let record = ledger.mint(&[(address, microcredits)])?.remove(0);
+            Ok(RecordPlaintext::from(to_network::<_, mainnet::RecordPlaintextNative>(record)?))
+        })
+    }
+
+    /// Get the global state root of the chain of the ledger
+    ///
+    /// @returns {string} State root
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = stateRoot))]
+    pub fn state_root(&self) -> String {
+        with_ledger!(&self.ledger, |ledger| { ledger.state_root().to_string() })
+    }
+
+    /// Get the state path of a record created in the ledger against the current state root
+    ///
+    /// @param {string} commitment Commitment of the record
+    /// @returns {string} State path
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = statePath))]
+    pub fn state_path(&self, commitment: &str) -> Result<String, SdkError> {
+        with_ledger!(&self.ledger, |ledger| { Ok(ledger.state_path(commitment)?.to_string()) })
+    }
+
+    /// Create an offline query with the current state root and the state paths of records created
+    /// in the ledger, to prove executions spending the records without a network
+    ///
+    /// @param {Array<string>} commitments Commitments of the records to spend
+    /// @returns {OfflineQuery}
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen(js_name = offlineQuery)]
+    pub fn offline_query(&self, commitments: Array) -> Result<OfflineQuery, SdkError> {
+        let commitments = commitments
+            .iter()
+            .enumerate()
+            .map(|(index, commitment)| {
+                commitment
+                    .as_string()
+                    .ok_or(SdkError::InvalidInput { index, expected: "record commitment string".to_string() })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.offline_query_for_commitments(&commitments)
+    }
+
+    /// Get the value stored at a key of a mapping
+    ///
+    /// @param {string} program_id Id of the program
+    /// @param {string} mapping_name Name of the mapping
+    /// @param {string} key Key of the value
+    /// @returns {string | undefined} Value stored at the key, undefined if there is none
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = getMappingValue))]
+    pub fn get_mapping_value(
+        &self,
+        program_id: &str,
+        mapping_name: &str,
+        key: &str,
+    ) -> Result<Option<String>, SdkError> {
+        with_ledger!(&self.ledger, |ledger| {
+            Ok(ledger.mapping_value(program_id, mapping_name, key)?.map(|value| value.to_string()))
+        })
+    }
+
+    /// Store a value at a key of a mapping, e.g. to fund the public balance of an account with
+    /// `setMappingValue("credits.aleo", "account", address, "1000000u64")`
+    ///
+    /// @param {string} program_id Id of the program
+    /// @param {string} mapping_name Name of the mapping
+    /// @param {string} key Key of the value
+    /// @param {string} value Value to store
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = setMappingValue))]
+    pub fn set_mapping_value(
+        &mut self,
+        program_id: &str,
+        mapping_name: &str,
+        key: &str,
+        value: &str,
+    ) -> Result<(), SdkError> {
+        with_ledger!(&mut self.ledger, |ledger| { ledger.set_mapping_value(program_id, mapping_name, key, value) })
+    }
+}
+
+impl LocalLedger {
+    /// Execute a function of a program in the ledger with inputs given as strings, see `execute`
+    pub fn execute_with_inputs(
+        &mut self,
+        private_key: &PrivateKeyWasm,
+        program_id: &str,
+        function_name: &str,
+        inputs: &[String],
+    ) -> Result<Transaction, SdkError> {
+        with_ledger!(&mut self.ledger, |ledger| {
+            let private_key: PrivateKeyNative = private_key.into_network()?;
+            let transaction = ledger.execute::<CurrentAleo>(&private_key, program_id, function_name, inputs)?;
+            Ok(Transaction::from_native(transaction)?)
+        })
+    }
+
+    /// Create an offline query with the current state root and the state paths of the records with
+    /// the given commitments, see `offlineQuery`
+    pub fn offline_query_for_commitments(&self, commitments: &[String]) -> Result<OfflineQuery, SdkError> {
+        let mut offline_query = OfflineQuery::new(&self.state_root())?;
+        for commitment in commitments {
+            offline_query.add_state_path(commitment, &self.state_path(commitment)?)?;
+        }
+        Ok(offline_query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::native::testnet::{
+        AddressNative,
+        CurrentAleo,
+        CurrentNetwork,
+        IdentifierNative,
+        PrivateKeyNative,
+        ProgramIDNative,
+        ProgramNative,
+    };
+    use snarkvm_ledger_query::QueryTrait;
+
+    use wasm_bindgen_test::*;
+
+    const PRIVATE_KEY: &str = "APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6";
+
+    const COUNTER_PROGRAM: &str = r"program counter_test.aleo;
+
+mapping counter:
+    key as u8.public;
+    value as u64.public;
+
+function increment:
+    input r0 as u64.public;
+    async increment r0 into r1;
+    output r1 as counter_test.aleo/increment.future;
+
+finalize increment:
+    input r0 as u64.public;
+    get.or_use counter[0u8] 0u64 into r1;
+    add r1 r0 into r2;
+    lte r2 10u64 into r3;
+    assert.eq r3 true;
+    set r2 into counter[0u8];
+";
+
+    #[wasm_bindgen_test]
+    fn test_finalize_updates_and_rejections() {
+        let mut ledger = LedgerState::<CurrentNetwork>::new().unwrap();
+        let private_key = PrivateKeyNative::from_str(PRIVATE_KEY).unwrap();
+        let program = ProgramNative::from_str(COUNTER_PROGRAM).unwrap();
+        let increment = |ledger: &mut LedgerState<CurrentNetwork>, amount: &str| {
+            ledger.execute::<CurrentAleo>(&private_key, "counter_test.aleo", "increment", &[amount.to_string()])
+        };
+
+        // Ensure functions can only be executed once the program is in the ledger
+        assert!(matches!(increment(&mut ledger, "4u64"), Err(SdkError::ProgramNotFound(_))));
+        ledger.add_program(&program).unwrap();
+        assert_eq!(ledger.mapping_value("counter_test.aleo", "counter", "0u8").unwrap(), None);
+
+        // Ensure the finalize logic of executions updates the mapping
+        let transaction = increment(&mut ledger, "4u64").unwrap();
+        increment(&mut ledger, "5u64").unwrap();
+        assert_eq!(ledger.mapping_value("counter_test.aleo", "counter", "0u8").unwrap().unwrap().to_string(), "9u64");
+        assert_eq!(ledger.height(), 2);
+
+        // Ensure executions aborting in finalize and replayed transactions are rejected
+        assert!(matches!(increment(&mut ledger, "3u64"), Err(SdkError::Rejected(_))));
+        assert!(matches!(ledger.apply(&transaction), Err(SdkError::Rejected(_))));
+        assert_eq!(ledger.mapping_value("counter_test.aleo", "counter", "0u8").unwrap().unwrap().to_string(), "9u64");
+        assert_eq!(ledger.height(), 2);
+
+        // Ensure mapping values can be set directly
+        ledger.set_mapping_value("counter_test.aleo", "counter", "0u8", "0u64").unwrap();
+        increment(&mut ledger, "3u64").unwrap();
+        assert_eq!(ledger.mapping_value("counter_test.aleo", "counter", "0u8").unwrap().unwrap().to_string(), "3u64");
+        assert!(ledger.mapping_value("counter_test.aleo", "missing", "0u8").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_minted_records_state_paths() {
+        let mut ledger = LedgerState::<CurrentNetwork>::new().unwrap();
+        let private_key = PrivateKeyNative::from_str(PRIVATE_KEY).unwrap();
+        let address = AddressNative::try_from(&private_key).unwrap();
+        let credits = ProgramIDNative::from_str("credits.aleo").unwrap();
+        let commitment = |record: &Record<CurrentNetwork, Plaintext<CurrentNetwork>>| {
+            record.to_commitment(&credits, &IdentifierNative::from_str("credits").unwrap()).unwrap()
+        };
+
+        // Ensure minted records get state paths against the state root of the chain
+        let records = ledger.mint(&[(address, 1_000_000), (address, 2_000_000)]).unwrap();
+        assert_eq!(ledger.height(), 1);
+        for record in &records {
+            let state_path = ledger.state_path(&commitment(record).to_string()).unwrap();
+            assert_eq!(state_path.global_state_root(), ledger.state_root());
+        }
+        assert!(ledger.state_path(&Field::<CurrentNetwork>::from_u8(1).to_string()).is_err());
+
+        // Ensure an offline query built from the chain prepares an execution spending a minted record
+        let mut offline_query = OfflineQuery::new(&ledger.state_root().to_string()).unwrap();
+        let state_path = ledger.state_path(&commitment(&records[0]).to_string()).unwrap();
+        offline_query.add_state_path(&commitment(&records[0]).to_string(), &state_path.to_string()).unwrap();
+        assert_eq!(
+            QueryTrait::<CurrentNetwork>::get_state_path_for_commitment(&offline_query, &commitment(&records[0]))
+                .unwrap(),
+            state_path
+        );
+
+        let rng = &mut StdRng::from_entropy();
+        let transfer_private = IdentifierNative::from_str("transfer_private").unwrap();
+        let inputs = [records[0].to_string(), address.to_string(), "100u64".to_string()];
+        ledger.process.synthesize_key::<CurrentAleo, _>(&credits, &transfer_private, rng).unwrap();
+        let authorization = ledger
+            .process
+            .authorize::<CurrentAleo, _>(&private_key, credits, transfer_private, inputs.iter(), rng)
+            .unwrap();
+        let (_, mut trace) = ledger.process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        trace.prepare_async(offline_query).await.unwrap();
+
+        // Ensure the records created by applied transactions get state paths in a new block
+        let transaction =
+            ledger.execute::<CurrentAleo>(&private_key, "credits.aleo", "transfer_private", &inputs).unwrap();
+        assert_eq!(ledger.height(), 2);
+        for commitment in transaction.commitments().chain(Some(&commitment(&records[1]))) {
+            let state_path = ledger.state_path(&commitment.to_string()).unwrap();
+            assert_eq!(state_path.global_state_root(), ledger.state_root());
+        }
+
+        // Ensure spent records cannot be spent again
+        let result = ledger.execute::<CurrentAleo>(&private_key, "credits.aleo", "transfer_private", &inputs);
+        assert!(matches!(result, Err(SdkError::Rejected(_))));
+    }
+}
@@ -19,43 +19,88 @@ macro_rules! process_inputs {
     ($inputs:expr) => {{
         let mut inputs_native = Vec::<String>::new();
         log("parsing inputs");
-        for input in $inputs.to_vec().iter() {
+        for (index, input) in $inputs.to_vec().iter().enumerate() {
             if let Some(input) = input.as_string() {
                 inputs_native.push(input);
             } else {
-                return Err("Invalid input - all inputs must be a string specifying the type".to_string());
+                return Err($crate::SdkError::InvalidInput {
+                    index,
+                    expected: "string specifying the type".to_string(),
+                });
             }
         }
         inputs_native
     }};
 }
 
+/// Run a block with the process of a `NetworkProcess` bound to `$process`. Inside the block the
+/// native types of the process's network (`CurrentAleo`, `ProcessNative`, `ProgramNative`, ...)
+/// shadow the types of the default network, so the same code runs on every network.
+#[macro_export]
+macro_rules! with_network {
+    ($network_process:expr, |$process:ident| $body:block) => {
+        match $network_process {
+            $crate::programs::NetworkProcess::MainnetV0($process) => {
+                #[allow(unused_imports)]
+                use $crate::types::native::mainnet::*;
+                $body
+            }
+            $crate::programs::NetworkProcess::TestnetV0($process) => {
+                #[allow(unused_imports)]
+                use $crate::types::native::testnet::*;
+                $body
+            }
+            $crate::programs::NetworkProcess::CanaryV0($process) => {
+                #[allow(unused_imports)]
+                use $crate::types::native::canary::*;
+                $body
+            }
+        }
+    };
+}
+
+/// Select the metadata of a credits.aleo key from the parameters of the given network
+#[macro_export]
+macro_rules! parameters_metadata {
+    ($network:expr, $key:ident) => {
+        match $network {
+            $crate::Network::MainnetV0 => $crate::types::native::mainnet::parameters::$key::METADATA,
+            $crate::Network::TestnetV0 => $crate::types::native::testnet::parameters::$key::METADATA,
+            $crate::Network::CanaryV0 => $crate::types::native::canary::parameters::$key::METADATA,
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! execute_program {
-    ($process:expr, $inputs:expr, $program_string:expr, $function_id_string:expr, $private_key:expr, $proving_key:expr, $verifying_key:expr, $rng:expr) => {{
+    ($process:expr, $inputs:expr, $program_string:expr, $function_id_string:expr, $private_key:expr, $proving_key:expr, $verifying_key:expr, $rng:expr, $progress:expr) => {{
         if (($proving_key.is_some() && $verifying_key.is_none())
             || ($proving_key.is_none() && $verifying_key.is_some()))
         {
-            return Err(
+            return Err($crate::SdkError::KeyMismatch(
                 "If specifying a key for a program execution, both the proving and verifying key must be specified"
                     .to_string(),
-            );
+            ));
         }
 
         log("Loading program");
-        let program =
-            ProgramNative::from_str($program_string).map_err(|_| "The program ID provided was invalid".to_string())?;
+        let program = ProgramNative::from_str($program_string)
+            .map_err(|_| $crate::SdkError::InvalidArgument("The program ID provided was invalid".to_string()))?;
         log("Loading function");
         let function_name = IdentifierNative::from_str($function_id_string)
-            .map_err(|_| "The function name provided was invalid".to_string())?;
+            .map_err(|_| $crate::SdkError::InvalidArgument("The function name provided was invalid".to_string()))?;
 
         let program_id = program.id().to_string();
 
+        // Check the inputs before synthesizing keys, which takes far longer than a failed check
+        let function_inputs: Vec<String> = $inputs;
+        Self::check_inputs(&program, &function_name, &function_inputs)?;
+
         if program_id != "credits.aleo" {
             log("Adding program to the process");
             if let Ok(stored_program) = $process.get_program(program.id()) {
                 if stored_program != &program {
-                    return Err("The program provided does not match the program stored in the cache, please clear the cache before proceeding".to_string());
+                    return Err($crate::SdkError::ProgramMismatch("The program provided does not match the program stored in the cache, please clear the cache before proceeding".to_string()));
                 }
             } else {
                 $process.add_program(&program).map_err(|e| e.to_string())?;
@@ -68,21 +113,30 @@ macro_rules! execute_program {
             } else {
                 log(&format!("Inserting externally provided proving and verifying keys for {program_id} - {function_name:?}"));
                 $process
-                    .insert_proving_key(program.id(), &function_name, ProvingKeyNative::from(proving_key))
-                    .map_err(|e| e.to_string())?;
+                    .insert_proving_key(program.id(), &function_name, $crate::types::NetworkAgnostic::into_network(proving_key)?)
+                    .map_err(|e| $crate::SdkError::KeyMismatch(e.to_string()))?;
                 if let Some(verifying_key) = $verifying_key {
-                    $process.insert_verifying_key(program.id(), &function_name, VerifyingKeyNative::from(verifying_key)).map_err(|e| e.to_string())?;
+                    $process.insert_verifying_key(program.id(), &function_name, $crate::types::NetworkAgnostic::into_network(verifying_key)?).map_err(|e| $crate::SdkError::KeyMismatch(e.to_string()))?;
                 }
             }
         };
 
+        if !Self::contains_key($process, program.id(), &function_name) {
+            $progress.stage($crate::ProgressStage::Synthesize).await?;
+            log(&format!("Synthesizing keys for {program_id} - {function_name:?}"));
+            $process
+                .synthesize_key::<CurrentAleo, _>(program.id(), &function_name, $rng)
+                .map_err(|e| e.to_string())?;
+        }
+
+        $progress.stage($crate::ProgressStage::Authorize).await?;
         log("Creating authorization");
         let authorization = $process
             .authorize::<CurrentAleo, _>(
                 $private_key,
                 program.id(),
                 function_name,
-                $inputs.iter(),
+                function_inputs.iter(),
                 $rng,
             )
             .map_err(|err| err.to_string())?;
@@ -98,16 +152,17 @@ macro_rules! execute_program {
 
 #[macro_export]
 macro_rules! execute_fee {
-    ($process:expr, $private_key:expr, $fee_record:expr, $fee_microcredits:expr, $submission_url:expr, $fee_proving_key:expr, $fee_verifying_key:expr, $execution_id:expr, $rng:expr, $offline_query:expr) => {{
+    ($process:expr, $private_key:expr, $fee_record:expr, $fee_microcredits:expr, $priority_fee_microcredits:expr, $query:expr, $fee_proving_key:expr, $fee_verifying_key:expr, $execution_id:expr, $rng:expr, $offline_query:expr, $progress:expr) => {{
         if (($fee_proving_key.is_some() && $fee_verifying_key.is_none())
             || ($fee_proving_key.is_none() && $fee_verifying_key.is_some()))
         {
-            return Err(
+            return Err($crate::SdkError::KeyMismatch(
                  "Missing key - both the proving and verifying key must be specified for a program execution"
                     .to_string(),
-            );
+            ));
         }
 
+        $progress.stage($crate::ProgressStage::ProveFee).await?;
         if let Some(fee_proving_key) = $fee_proving_key {
             let credits = ProgramIDNative::from_str("credits.aleo").unwrap();
             let fee = if $fee_record.is_some() {
@@ -120,11 +175,11 @@ macro_rules! execute_fee {
             } else {
                 log("Inserting externally provided fee proving and verifying keys");
                 $process
-                    .insert_proving_key(&credits, &fee, ProvingKeyNative::from(fee_proving_key)).map_err(|e| e.to_string())?;
+                    .insert_proving_key(&credits, &fee, $crate::types::NetworkAgnostic::into_network(fee_proving_key)?).map_err(|e| $crate::SdkError::KeyMismatch(e.to_string()))?;
                 if let Some(fee_verifying_key) = $fee_verifying_key {
                     $process
-                        .insert_verifying_key(&credits, &fee, VerifyingKeyNative::from(fee_verifying_key))
-                        .map_err(|e| e.to_string())?;
+                        .insert_verifying_key(&credits, &fee, $crate::types::NetworkAgnostic::into_network(fee_verifying_key)?)
+                        .map_err(|e| $crate::SdkError::KeyMismatch(e.to_string()))?;
                 }
             }
         };
@@ -137,13 +192,13 @@ macro_rules! execute_fee {
                     $private_key,
                     fee_record_native,
                     $fee_microcredits,
-                    0u64,
+                    $priority_fee_microcredits,
                     $execution_id,
                     $rng,
                 ).map_err(|e| e.to_string())?
             }
             None => {
-                $process.authorize_fee_public::<CurrentAleo, _>($private_key, $fee_microcredits, 0u64, $execution_id, $rng).map_err(|e| e.to_string())?
+                $process.authorize_fee_public::<CurrentAleo, _>($private_key, $fee_microcredits, $priority_fee_microcredits, $execution_id, $rng).map_err(|e| e.to_string())?
             }
         };
 
@@ -153,29 +208,16 @@ macro_rules! execute_fee {
             .map_err(|err| err.to_string())?;
 
         if let Some(offline_query) = $offline_query {
-            trace.prepare_async(offline_query).await.map_err(|err| err.to_string())?;
+            trace.prepare_async(offline_query).await.map_err(|err| $crate::SdkError::QueryFailed(err.to_string()))?;
         } else {
-            let query = QueryNative::from($submission_url);
-            trace.prepare_async(query).await.map_err(|err| err.to_string())?;
+            trace.prepare_async($query.clone()).await.map_err(|err| $crate::SdkError::QueryFailed(err.to_string()))?;
         };
-        let fee = trace.prove_fee::<CurrentAleo, _>(&mut StdRng::from_entropy()).map_err(|e|e.to_string())?;
+        $progress.ensure_not_aborted()?;
+        let fee = trace.prove_fee::<CurrentAleo, _>(&mut StdRng::from_entropy()).map_err(|e| $crate::SdkError::ProofFailed(e.to_string()))?;
 
         log("Verifying fee execution");
-        $process.verify_fee(&fee, $execution_id).map_err(|e| e.to_string())?;
+        $process.verify_fee(&fee, $execution_id).map_err(|e| $crate::SdkError::ProofFailed(e.to_string()))?;
 
         fee
     }};
 }
-
-#[macro_export]
-macro_rules! get_process {
-    ($self:expr, $cache:expr, $new_process:expr) => {
-        if $cache {
-            &mut $self.process
-        } else {
-            let new_process = ProcessNative::load_web().map_err(|err| err.to_string())?;
-            $new_process = Some(new_process);
-            $new_process.as_mut().unwrap()
-        }
-    };
-}
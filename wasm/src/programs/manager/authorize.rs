@@ -0,0 +1,330 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+    log,
+    process_inputs,
+    types::NetworkAgnostic,
+    with_network,
+    Authorization,
+    OfflineQuery,
+    PrivateKey,
+    Progress,
+    ProgressStage,
+    RecordPlaintext,
+    Transaction,
+};
+
+use js_sys::{Array, Function, Object};
+use rand::{rngs::StdRng, SeedableRng};
+use std::str::FromStr;
+use web_sys::AbortSignal;
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Authorize a function execution without proving it. The resulting authorization contains
+    /// the signed requests for the execution and can be serialized and handed to a separate prover
+    /// via `proveAuthorization`.
+    ///
+    /// @param private_key The private key of the sender
+    /// @param program The source code of the program being executed
+    /// @param function The name of the function to execute
+    /// @param inputs A javascript array of inputs to the function
+    /// @param imports (optional) Provide a list of imports to use for the function execution in the
+    /// form of a javascript object where the keys are a string of the program name and the values
+    /// are a string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
+    /// @returns {Authorization}
+    #[wasm_bindgen(js_name = authorize)]
+    pub fn authorize(
+        &self,
+        private_key: &PrivateKey,
+        program: &str,
+        function: &str,
+        inputs: Array,
+        imports: Option<Object>,
+    ) -> Result<Authorization, SdkError> {
+        log(&format!("Authorizing function: {function}"));
+
+        with_network!(&mut *self.try_lock_process()?, |process| {
+            let private_key: PrivateKeyNative = private_key.into_network()?;
+
+            log("Check program imports are valid and add them to the process");
+            let program = ProgramNative::from_str(program).map_err(|e| SdkError::InvalidArgument(e.to_string()))?;
+            ProgramManager::resolve_imports(process, &program, imports)?;
+            if program.id().to_string() != "credits.aleo" {
+                Self::add_program_to_process(process, &program)?;
+            }
+
+            let function_name = IdentifierNative::from_str(function)
+                .map_err(|_| SdkError::InvalidArgument("The function name provided was invalid".to_string()))?;
+
+            let inputs = process_inputs!(inputs);
+            Self::check_inputs(&program, &function_name, &inputs)?;
+
+            log("Creating authorization");
+            let authorization = process
+                .authorize::<CurrentAleo, _>(
+                    &private_key,
+                    program.id(),
+                    function_name,
+                    inputs.iter(),
+                    &mut StdRng::from_entropy(),
+                )
+                .map_err(|err| err.to_string())?;
+
+            Ok(Authorization::from_native(authorization)?)
+        })
+    }
+
+    /// Authorize a fee payment for an execution without proving it. If a fee record is provided
+    /// the fee is paid privately, otherwise it is paid from the public balance of the sender.
+    ///
+    /// @param private_key The private key of the sender
+    /// @param fee_credits The amount of credits to pay as a fee
+    /// @param execution_id The execution id of the authorization the fee pays for
+    /// @param fee_record (optional) The record to spend the fee from
+    /// @param priority_fee_credits (optional) The amount of credits to pay as a priority fee
+    /// @returns {Authorization}
+    #[wasm_bindgen(js_name = authorizeFee)]
+    pub fn authorize_fee(
+        &self,
+        private_key: &PrivateKey,
+        fee_credits: f64,
+        execution_id: &str,
+        fee_record: Option<RecordPlaintext>,
+        priority_fee_credits: Option<f64>,
+    ) -> Result<Authorization, SdkError> {
+        log("Authorizing fee");
+        let (fee_microcredits, priority_fee_microcredits) =
+            Self::validate_fee(fee_credits, priority_fee_credits, fee_record.as_ref())?;
+        let rng = &mut StdRng::from_entropy();
+
+        with_network!(&*self.try_lock_process()?, |process| {
+            let private_key: PrivateKeyNative = private_key.into_network()?;
+            let execution_id = FieldNative::from_str(execution_id).map_err(|e| e.to_string())?;
+            let fee_authorization = match fee_record {
+                Some(fee_record) => {
+                    let fee_record_native = RecordPlaintextNative::from_str(&fee_record.to_string()).unwrap();
+                    process
+                        .authorize_fee_private::<CurrentAleo, _>(
+                            &private_key,
+                            fee_record_native,
+                            fee_microcredits,
+                            priority_fee_microcredits,
+                            execution_id,
+                            rng,
+                        )
+                        .map_err(|e| e.to_string())?
+                }
+                None => process
+                    .authorize_fee_public::<CurrentAleo, _>(
+                        &private_key,
+                        fee_microcredits,
+                        priority_fee_microcredits,
+                        execution_id,
+                        rng,
+                    )
+                    .map_err(|e| e.to_string())?,
+            };
+
+            Ok(Authorization::from_native(fee_authorization)?)
+        })
+    }
+
+    /// Prove an authorization created by `authorize` (and optionally a fee authorization created
+    /// by `authorizeFee`) and create an execution transaction. This does not require the private
+    /// key of the account that signed the authorizations.
+    ///
+    /// @param authorization The authorization of the function execution
+    /// @param program The source code of the program being executed
+    /// @param fee_authorization (optional) The authorization of the fee paying for the execution
+    /// @param url The url of the Aleo network node to query, or a `NetworkQuery`
+    /// @param imports (optional) Provide a list of imports to use for the function execution in the
+    /// form of a javascript object where the keys are a string of the program name and the values
+    /// are a string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
+    /// @param proving_key (optional) Provide a proving key to use for the function execution
+    /// @param verifying_key (optional) Provide a verifying key to use for the function execution
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @param on_progress (optional) A function called with the `ProgressStage` that is starting each
+    /// time the transaction build moves to a new stage
+    /// @param signal (optional) An `AbortSignal` which stops the transaction build before its next
+    /// stage once it has been aborted
+    /// @returns {Transaction}
+    #[wasm_bindgen(js_name = proveAuthorization)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn prove_authorization(
+        &self,
+        authorization: &Authorization,
+        program: &str,
+        fee_authorization: Option<Authorization>,
+        url: Option<NetworkQueryLike>,
+        imports: Option<Object>,
+        proving_key: Option<ProvingKey>,
+        verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+        offline_query: Option<OfflineQuery>,
+        on_progress: Option<Function>,
+        signal: Option<AbortSignal>,
+    ) -> Result<Transaction, SdkError> {
+        let progress = Progress::new(on_progress, signal);
+        if proving_key.is_some() != verifying_key.is_some() || fee_proving_key.is_some() != fee_verifying_key.is_some()
+        {
+            return Err(SdkError::KeyMismatch(
+                "If specifying a key for a program execution, both the proving and verifying key must be specified"
+                    .to_string(),
+            ));
+        }
+
+        let query = NetworkQuery::from_js(url)?;
+        let rng = &mut StdRng::from_entropy();
+
+        with_network!(&mut *self.lock_process().await, |process| {
+            progress.stage(ProgressStage::LoadProgram).await?;
+            let program = ProgramNative::from_str(program).map_err(|e| SdkError::InvalidArgument(e.to_string()))?;
+
+            progress.stage(ProgressStage::ResolveImports).await?;
+            log("Check program imports are valid and add them to the process");
+            ProgramManager::resolve_imports(process, &program, imports)?;
+            if program.id().to_string() != "credits.aleo" {
+                Self::add_program_to_process(process, &program)?;
+            }
+
+            // Work on a copy so the caller's authorization can be proven again if this attempt fails.
+            let authorization: AuthorizationNative = authorization.to_native()?;
+            let fee_authorization: Option<AuthorizationNative> =
+                fee_authorization.map(|fee_authorization| fee_authorization.to_native()).transpose()?;
+            let request = authorization.peek_next().map_err(|e| e.to_string())?;
+            if request.program_id() != program.id() {
+                return Err(SdkError::InvalidArgument(format!(
+                    "The authorization is for {} but the program provided is {}",
+                    request.program_id(),
+                    program.id()
+                )));
+            }
+            let locator = format!("{}/{}", request.program_id(), request.function_name());
+
+            if let (Some(proving_key), Some(verifying_key)) = (proving_key, verifying_key) {
+                if !Self::contains_key(process, program.id(), request.function_name()) {
+                    log(&format!("Inserting externally provided proving and verifying keys for {locator}"));
+                    process
+                        .insert_proving_key(program.id(), request.function_name(), proving_key.into_network()?)
+                        .map_err(|e| SdkError::KeyMismatch(e.to_string()))?;
+                    process
+                        .insert_verifying_key(program.id(), request.function_name(), verifying_key.into_network()?)
+                        .map_err(|e| SdkError::KeyMismatch(e.to_string()))?;
+                }
+            }
+
+            if !Self::contains_key(process, program.id(), request.function_name()) {
+                progress.stage(ProgressStage::Synthesize).await?;
+                log(&format!("Synthesizing keys for {locator}"));
+                process
+                    .synthesize_key::<CurrentAleo, _>(program.id(), request.function_name(), rng)
+                    .map_err(|e| e.to_string())?;
+            }
+
+            log("Executing authorization");
+            let execution_id = authorization.to_execution_id().map_err(|e| e.to_string())?;
+            let (_, mut trace) =
+                process.execute::<CurrentAleo, _>(authorization, rng).map_err(|err| err.to_string())?;
+
+            progress.stage(ProgressStage::PrepareInclusion).await?;
+            log("Preparing inclusion proofs for execution");
+            if let Some(offline_query) = offline_query.as_ref() {
+                trace.prepare_async(offline_query.clone()).await.map_err(|err| err.to_string())?;
+            } else {
+                trace.prepare_async(query.clone()).await.map_err(|err| SdkError::QueryFailed(err.to_string()))?;
+            }
+
+            progress.stage(ProgressStage::Prove).await?;
+            log("Proving execution");
+            let execution = trace
+                .prove_execution::<CurrentAleo, _>(&locator, rng)
+                .map_err(|e| SdkError::ProofFailed(e.to_string()))?;
+
+            progress.stage(ProgressStage::Verify).await?;
+            log("Verifying execution");
+            process.verify_execution(&execution).map_err(|err| err.to_string())?;
+
+            let fee = match fee_authorization {
+                Some(fee_authorization) => {
+                    if !fee_authorization.is_fee_private() && !fee_authorization.is_fee_public() {
+                        return Err(SdkError::InvalidArgument(
+                            "The fee authorization provided is not a fee authorization".to_string(),
+                        ));
+                    }
+
+                    progress.stage(ProgressStage::ProveFee).await?;
+
+                    if let (Some(fee_proving_key), Some(fee_verifying_key)) = (fee_proving_key, fee_verifying_key) {
+                        let credits = ProgramIDNative::from_str("credits.aleo").unwrap();
+                        let fee_identifier = if fee_authorization.is_fee_private() {
+                            IdentifierNative::from_str("fee_private").unwrap()
+                        } else {
+                            IdentifierNative::from_str("fee_public").unwrap()
+                        };
+                        if !Self::contains_key(process, &credits, &fee_identifier) {
+                            log("Inserting externally provided fee proving and verifying keys");
+                            process
+                                .insert_proving_key(&credits, &fee_identifier, fee_proving_key.into_network()?)
+                                .map_err(|e| SdkError::KeyMismatch(e.to_string()))?;
+                            process
+                                .insert_verifying_key(&credits, &fee_identifier, fee_verifying_key.into_network()?)
+                                .map_err(|e| SdkError::KeyMismatch(e.to_string()))?;
+                        }
+                    }
+
+                    log("Executing fee authorization");
+                    let (_, mut fee_trace) =
+                        process.execute::<CurrentAleo, _>(fee_authorization, rng).map_err(|err| err.to_string())?;
+                    if let Some(offline_query) = offline_query {
+                        fee_trace
+                            .prepare_async(offline_query)
+                            .await
+                            .map_err(|err| SdkError::QueryFailed(err.to_string()))?;
+                    } else {
+                        fee_trace
+                            .prepare_async(query.clone())
+                            .await
+                            .map_err(|err| SdkError::QueryFailed(err.to_string()))?;
+                    }
+
+                    progress.ensure_not_aborted()?;
+                    log("Proving fee");
+                    let fee =
+                        fee_trace.prove_fee::<CurrentAleo, _>(rng).map_err(|e| SdkError::ProofFailed(e.to_string()))?;
+
+                    log("Verifying fee execution");
+                    process.verify_fee(&fee, execution_id).map_err(|_| {
+                        SdkError::ProofFailed(
+                            "The fee authorization does not pay for the execution provided".to_string(),
+                        )
+                    })?;
+                    Some(fee)
+                }
+                None => None,
+            };
+
+            log("Creating execution transaction");
+            let transaction = TransactionNative::from_execution(execution, fee).map_err(|err| err.to_string())?;
+            Ok(Transaction::from_native(transaction)?)
+        })
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+    log,
+    types::{native::ToBytes, NetworkAgnostic},
+    with_network,
+};
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Add a program and its imports to the program manager's cache. Subsequent executions of the
+    /// program's functions will reuse the cached program and any keys synthesized for it.
+    ///
+    /// @param program The source code of the program to add
+    /// @param imports (optional) Provide a list of imports for the program in the form of a
+    /// javascript object where the keys are a string of the program name and the values are a
+    /// string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
+    #[wasm_bindgen(js_name = addProgram)]
+    pub fn add_program(&self, program: &str, imports: Option<Object>) -> Result<(), SdkError> {
+        with_network!(&mut *self.try_lock_process()?, |process| {
+            let program = ProgramNative::from_str(program).map_err(|err| SdkError::InvalidArgument(err.to_string()))?;
+            Self::resolve_imports(process, &program, imports)?;
+            Self::add_program_to_process(process, &program)
+        })
+    }
+
+    /// Check if a program is stored in the program manager's cache
+    ///
+    /// @param program_id The id of the program (e.g. "hello.aleo")
+    /// @returns {boolean}
+    #[wasm_bindgen(js_name = hasProgram)]
+    pub fn has_program(&self, program_id: &str) -> Result<bool, SdkError> {
+        with_network!(&*self.try_lock_process()?, |process| {
+            let program_id = ProgramIDNative::from_str(program_id).map_err(|err| err.to_string())?;
+            Ok(process.contains_program(&program_id))
+        })
+    }
+
+    /// Remove all programs and keys from the program manager's cache and free the memory they use
+    #[wasm_bindgen(js_name = clearCache)]
+    pub fn clear_cache(&self) -> Result<(), SdkError> {
+        log("Clearing the program manager cache");
+        *self.try_lock_process()? = NetworkProcess::load(self.network)?;
+        Ok(())
+    }
+
+    /// Store a proving and verifying key for a function in the program manager's cache. If the
+    /// program is not yet cached it is added. Keys that are already cached cannot be overwritten.
+    ///
+    /// @param program The source code of the program the function belongs to
+    /// @param function The name of the function the keys are for
+    /// @param proving_key The proving key for the function
+    /// @param verifying_key The verifying key for the function
+    #[wasm_bindgen(js_name = cacheKeypairInWasmMemory)]
+    pub fn cache_keypair_in_wasm_memory(
+        &self,
+        program: &str,
+        function: &str,
+        proving_key: ProvingKey,
+        verifying_key: VerifyingKey,
+    ) -> Result<(), SdkError> {
+        with_network!(&mut *self.try_lock_process()?, |process| {
+            let program = ProgramNative::from_str(program).map_err(|err| SdkError::InvalidArgument(err.to_string()))?;
+            let function_id =
+                IdentifierNative::from_str(function).map_err(|err| SdkError::InvalidArgument(err.to_string()))?;
+            if !program.contains_function(&function_id) {
+                return Err(SdkError::InvalidArgument(format!(
+                    "Function {function} does not exist in {}",
+                    program.id()
+                )));
+            }
+            Self::add_program_to_process(process, &program)?;
+
+            if Self::contains_key(process, program.id(), &function_id) {
+                return Err(SdkError::InvalidArgument(format!(
+                    "Keys for {}/{function} are already cached",
+                    program.id()
+                )));
+            }
+            process
+                .insert_proving_key(program.id(), &function_id, proving_key.into_network()?)
+                .map_err(|err| SdkError::KeyMismatch(err.to_string()))?;
+            process
+                .insert_verifying_key(program.id(), &function_id, verifying_key.into_network()?)
+                .map_err(|err| SdkError::KeyMismatch(err.to_string()))
+        })
+    }
+
+    /// Get a copy of the proving and verifying key cached for a function
+    ///
+    /// @param program_id The id of the program (e.g. "hello.aleo")
+    /// @param function The name of the function
+    /// @returns {KeyPair}
+    #[wasm_bindgen(js_name = getCachedKeypair)]
+    pub fn get_cached_keypair(&self, program_id: &str, function: &str) -> Result<KeyPair, SdkError> {
+        with_network!(&*self.try_lock_process()?, |process| {
+            let program_id =
+                ProgramIDNative::from_str(program_id).map_err(|err| SdkError::InvalidArgument(err.to_string()))?;
+            let function_id =
+                IdentifierNative::from_str(function).map_err(|err| SdkError::InvalidArgument(err.to_string()))?;
+            if !process.contains_program(&program_id) {
+                return Err(SdkError::ProgramNotFound(program_id.to_string()));
+            }
+            let proving_key = process.get_proving_key(program_id, function_id).map_err(|err| err.to_string())?;
+            let verifying_key = process.get_verifying_key(program_id, function_id).map_err(|err| err.to_string())?;
+            Ok(KeyPair::new(ProvingKey::from_network(proving_key)?, VerifyingKey::from_network(verifying_key)?))
+        })
+    }
+
+    /// Check if a proving and verifying key are cached for a function
+    ///
+    /// @param program_id The id of the program (e.g. "hello.aleo")
+    /// @param function The name of the function
+    /// @returns {boolean}
+    #[wasm_bindgen(js_name = keyExists)]
+    pub fn key_exists(&self, program_id: &str, function: &str) -> Result<bool, SdkError> {
+        with_network!(&*self.try_lock_process()?, |process| {
+            let program_id = ProgramIDNative::from_str(program_id).map_err(|err| err.to_string())?;
+            let function_id = IdentifierNative::from_str(function).map_err(|err| err.to_string())?;
+            Ok(Self::contains_key(process, &program_id, &function_id))
+        })
+    }
+
+    /// Remove all cached proving and verifying keys while keeping the cached programs
+    #[wasm_bindgen(js_name = clearKeyCache)]
+    pub fn clear_key_cache(&self) -> Result<(), SdkError> {
+        log("Clearing cached proving and verifying keys");
+        with_network!(&mut *self.try_lock_process()?, |process| {
+            for program_id in process.program_ids() {
+                if let Ok(stack) = process.get_stack(program_id) {
+                    for function_id in stack.program().functions().keys() {
+                        stack.remove_proving_key(function_id);
+                        stack.remove_verifying_key(function_id);
+                    }
+                }
+            }
+        });
+        Ok(())
+    }
+
+    /// Get the approximate number of bytes used by the programs and keys in the program manager's
+    /// cache. The size is computed by serializing every cached key, so it should not be called
+    /// in performance sensitive code.
+    ///
+    /// @returns {number} The size of the cache in bytes
+    #[wasm_bindgen(js_name = memoryUsage)]
+    pub fn memory_usage(&self) -> Result<u64, SdkError> {
+        let mut size = 0usize;
+        with_network!(&*self.try_lock_process()?, |process| {
+            for program_id in process.program_ids() {
+                let stack = process.get_stack(program_id).map_err(|err| err.to_string())?;
+                size = size.saturating_add(stack.program().to_bytes_le().map_err(|err| err.to_string())?.len());
+                for function_id in stack.program().functions().keys() {
+                    if let Ok(proving_key) = stack.get_proving_key(function_id) {
+                        size = size.saturating_add(proving_key.to_bytes_le().map_err(|err| err.to_string())?.len());
+                    }
+                    if let Ok(verifying_key) = stack.get_verifying_key(function_id) {
+                        size = size.saturating_add(verifying_key.to_bytes_le().map_err(|err| err.to_string())?.len());
+                    }
+                }
+            }
+        });
+        Ok(size as u64)
+    }
+}
+
+impl ProgramManager {
+    /// Add a program to a process, failing if a different program with the same id is cached
+    pub(crate) fn add_program_to_process<N: NetworkTrait>(
+        process: &mut Process<N>,
+        program: &Program<N>,
+    ) -> Result<(), SdkError> {
+        match process.get_program(program.id()) {
+            Ok(stored_program) if stored_program != program => Err(SdkError::ProgramMismatch(format!(
+                "A different version of {} is already cached, please clear the cache before proceeding",
+                program.id()
+            ))),
+            Ok(_) => Ok(()),
+            Err(_) => process.add_program(program).map_err(|err| SdkError::Other(err.to_string())),
+        }
+    }
+}
@@ -16,20 +16,23 @@
 
 use super::*;
 
-use crate::{execute_fee, log, OfflineQuery, PrivateKey, RecordPlaintext, Transaction};
-use crate::types::native::{
-    CurrentAleo,
-    CurrentNetwork,
-    ProcessNative,
-    ProgramIDNative,
-    ProgramNative,
-    ProgramOwnerNative,
-    RecordPlaintextNative,
-    TransactionNative,
+use crate::{
+    execute_fee,
+    log,
+    types::NetworkAgnostic,
+    with_network,
+    DeploymentCost,
+    OfflineQuery,
+    PrivateKey,
+    Progress,
+    ProgressStage,
+    RecordPlaintext,
+    Transaction,
 };
-use js_sys::Object;
+use js_sys::{Function, Object};
 use rand::{rngs::StdRng, SeedableRng};
 use std::str::FromStr;
+use web_sys::AbortSignal;
 
 #[wasm_bindgen]
 impl ProgramManager {
@@ -43,88 +46,111 @@ impl ProgramManager {
     /// for the deployment to succeed
     /// @param fee_credits The amount of credits to pay as a fee
     /// @param fee_record The record to spend the fee from
-    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param url The url of the Aleo network node to query, or a `NetworkQuery`
     /// @param imports (optional) Provide a list of imports to use for the program deployment in the
     /// form of a javascript object where the keys are a string of the program name and the values
     /// are a string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
     /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
     /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @param priority_fee_credits (optional) The amount of credits to pay as a priority fee
+    /// @param on_progress (optional) A function called with the `ProgressStage` that is starting each
+    /// time the transaction build moves to a new stage
+    /// @param signal (optional) An `AbortSignal` which stops the transaction build before its next
+    /// stage once it has been aborted
     /// @returns {Transaction}
     #[wasm_bindgen(js_name = buildDeploymentTransaction)]
     #[allow(clippy::too_many_arguments)]
     pub async fn deploy(
+        &self,
         private_key: &PrivateKey,
         program: &str,
         fee_credits: f64,
         fee_record: Option<RecordPlaintext>,
-        url: Option<String>,
+        url: Option<NetworkQueryLike>,
         imports: Option<Object>,
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
         offline_query: Option<OfflineQuery>,
-    ) -> Result<Transaction, String> {
+        priority_fee_credits: Option<f64>,
+        on_progress: Option<Function>,
+        signal: Option<AbortSignal>,
+    ) -> Result<Transaction, SdkError> {
         log("Creating deployment transaction");
+        let progress = Progress::new(on_progress, signal);
         // Convert fee to microcredits and check that the fee record has enough credits to pay it
-        let fee_microcredits = match &fee_record {
-            Some(fee_record) => Self::validate_amount(fee_credits, fee_record, true)?,
-            None => (fee_credits * 1_000_000.0) as u64,
-        };
-
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
-        let process = &mut process_native;
-
-        log("Checking program has a valid name");
-        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
-
-        log("Checking program imports are valid and add them to the process");
-        ProgramManager::resolve_imports(process, &program, imports)?;
-        let rng = &mut StdRng::from_entropy();
-
-        log("Creating deployment");
-        let node_url = url.as_deref().unwrap_or(DEFAULT_URL);
-        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).map_err(|err| err.to_string())?;
-        if deployment.program().functions().is_empty() {
-            return Err("Attempted to create an empty transaction deployment".to_string());
-        }
-
-        log("Ensuring the fee is sufficient to pay for the deployment");
-        let (minimum_deployment_cost, (_, _, _)) =
-            deployment_cost::<CurrentNetwork>(&deployment).map_err(|err| err.to_string())?;
-        if fee_microcredits < minimum_deployment_cost {
-            return Err(format!(
-                "Fee is too low to pay for the deployment. The minimum fee is {} credits",
-                minimum_deployment_cost as f64 / 1_000_000.0
-            ));
-        }
-
-        let deployment_id = deployment.to_deployment_id().map_err(|e| e.to_string())?;
-
-        let fee = execute_fee!(
-            process,
-            private_key,
-            fee_record,
-            fee_microcredits,
-            node_url,
-            fee_proving_key,
-            fee_verifying_key,
-            deployment_id,
-            rng,
-            offline_query
-        );
-
-        // Create the program owner
-        let owner = ProgramOwnerNative::new(private_key, deployment_id, &mut StdRng::from_entropy())
-            .map_err(|err| err.to_string())?;
-
-        log("Verifying the deployment and fees");
-        process
-            .verify_deployment::<CurrentAleo, _>(&deployment, &mut StdRng::from_entropy())
-            .map_err(|err| err.to_string())?;
-
-        log("Creating deployment transaction");
-        Ok(Transaction::from(
-            TransactionNative::from_deployment(owner, deployment, fee).map_err(|err| err.to_string())?,
-        ))
+        let (fee_microcredits, priority_fee_microcredits) =
+            Self::validate_fee(fee_credits, priority_fee_credits, fee_record.as_ref())?;
+
+        with_network!(&mut *self.lock_process().await, |process| {
+            let private_key: PrivateKeyNative = private_key.into_network()?;
+
+            progress.stage(ProgressStage::LoadProgram).await?;
+            log("Checking program has a valid name");
+            let program = ProgramNative::from_str(program).map_err(|err| SdkError::InvalidArgument(err.to_string()))?;
+
+            progress.stage(ProgressStage::ResolveImports).await?;
+            log("Checking program imports are valid and add them to the process");
+            ProgramManager::resolve_imports(process, &program, imports)?;
+            let rng = &mut StdRng::from_entropy();
+
+            progress.stage(ProgressStage::Synthesize).await?;
+            log("Creating deployment");
+            let query = NetworkQuery::from_js(url)?;
+            let deployment = process.deploy::<CurrentAleo, _>(&program, rng).map_err(|err| err.to_string())?;
+            if deployment.program().functions().is_empty() {
+                return Err(SdkError::InvalidArgument(
+                    "Attempted to create an empty transaction deployment".to_string(),
+                ));
+            }
+
+            log("Ensuring the fee is sufficient to pay for the deployment");
+            let deployment_cost = DeploymentCost::from_deployment(&deployment)?;
+            if fee_microcredits < deployment_cost.total() {
+                log(&format!(
+                    "Fee is too low to pay for the deployment. The minimum fee is {} credits (storage: {}, synthesis: {}, namespace: {} credits)",
+                    deployment_cost.total() as f64 / 1_000_000.0,
+                    deployment_cost.storage_cost() as f64 / 1_000_000.0,
+                    deployment_cost.synthesis_cost() as f64 / 1_000_000.0,
+                    deployment_cost.namespace_cost() as f64 / 1_000_000.0,
+                ));
+                return Err(SdkError::InsufficientFee {
+                    required: deployment_cost.total(),
+                    available: fee_microcredits,
+                });
+            }
+
+            let deployment_id = deployment.to_deployment_id().map_err(|e| e.to_string())?;
+
+            let fee = execute_fee!(
+                process,
+                &private_key,
+                fee_record,
+                fee_microcredits,
+                priority_fee_microcredits,
+                &query,
+                fee_proving_key,
+                fee_verifying_key,
+                deployment_id,
+                rng,
+                offline_query,
+                progress
+            );
+
+            // Create the program owner
+            let owner = ProgramOwnerNative::new(&private_key, deployment_id, &mut StdRng::from_entropy())
+                .map_err(|err| err.to_string())?;
+
+            progress.stage(ProgressStage::Verify).await?;
+            log("Verifying the deployment and fees");
+            process
+                .verify_deployment::<CurrentAleo, _>(&deployment, &mut StdRng::from_entropy())
+                .map_err(|err| SdkError::ProofFailed(err.to_string()))?;
+
+            log("Creating deployment transaction");
+            let transaction =
+                TransactionNative::from_deployment(owner, deployment, fee).map_err(|err| err.to_string())?;
+            Ok(Transaction::from_native(transaction)?)
+        })
     }
 
     /// Estimate the fee for a program deployment
@@ -135,33 +161,36 @@ impl ProgramManager {
     /// @param imports (optional) Provide a list of imports to use for the deployment fee estimation
     /// in the form of a javascript object where the keys are a string of the program name and the values
     /// are a string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
-    /// @returns {u64}
+    /// @returns {DeploymentCost} Breakdown of the deployment cost in microcredits
     #[wasm_bindgen(js_name = estimateDeploymentFee)]
-    pub async fn estimate_deployment_fee(program: &str, imports: Option<Object>) -> Result<u64, String> {
+    pub async fn estimate_deployment_fee(
+        &self,
+        program: &str,
+        imports: Option<Object>,
+    ) -> Result<DeploymentCost, SdkError> {
         log(
             "Disclaimer: Fee estimation is experimental and may not represent a correct estimate on any current or future network",
         );
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
-        let process = &mut process_native;
-
-        log("Check program has a valid name");
-        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
-
-        log("Check program imports are valid and add them to the process");
-        ProgramManager::resolve_imports(process, &program, imports)?;
-
-        log("Create sample deployment");
-        let deployment =
-            process.deploy::<CurrentAleo, _>(&program, &mut StdRng::from_entropy()).map_err(|err| err.to_string())?;
-        if deployment.program().functions().is_empty() {
-            return Err("Attempted to create an empty transaction deployment".to_string());
-        }
-
-        log("Estimate the deployment fee");
-        let (minimum_deployment_cost, (_, _, _)) =
-            deployment_cost::<CurrentNetwork>(&deployment).map_err(|err| err.to_string())?;
-
-        Ok(minimum_deployment_cost)
+        with_network!(&mut *self.lock_process().await, |process| {
+            log("Check program has a valid name");
+            let program = ProgramNative::from_str(program).map_err(|err| SdkError::InvalidArgument(err.to_string()))?;
+
+            log("Check program imports are valid and add them to the process");
+            ProgramManager::resolve_imports(process, &program, imports)?;
+
+            log("Create sample deployment");
+            let deployment = process
+                .deploy::<CurrentAleo, _>(&program, &mut StdRng::from_entropy())
+                .map_err(|err| err.to_string())?;
+            if deployment.program().functions().is_empty() {
+                return Err(SdkError::InvalidArgument(
+                    "Attempted to create an empty transaction deployment".to_string(),
+                ));
+            }
+
+            log("Estimate the deployment fee");
+            Ok(DeploymentCost::from_deployment(&deployment)?)
+        })
     }
 
     /// Estimate the component of the deployment cost which comes from the fee for the program name.
@@ -173,7 +202,7 @@ impl ProgramManager {
     /// @param name The name of the program to be deployed
     /// @returns {u64}
     #[wasm_bindgen(js_name = estimateProgramNameCost)]
-    pub fn program_name_cost(name: &str) -> Result<u64, String> {
+    pub fn program_name_cost(name: &str) -> Result<u64, SdkError> {
         log(
             "Disclaimer: Fee estimation is experimental and may not represent a correct estimate on any current or future network",
         );
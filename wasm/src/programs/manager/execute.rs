@@ -22,20 +22,22 @@ use crate::{
     execute_program,
     log,
     process_inputs,
-    ExecutionResponse, OfflineQuery, PrivateKey, RecordPlaintext, Transaction,
+    types::NetworkAgnostic,
+    with_network,
+    ExecutionCost,
+    ExecutionResponse,
+    OfflineQuery,
+    PrivateKey,
+    Progress,
+    ProgressStage,
+    RecordPlaintext,
+    Transaction,
 };
 
-use crate::types::native::{
-    CurrentAleo,
-    IdentifierNative,
-    ProcessNative,
-    ProgramNative,
-    RecordPlaintextNative,
-    TransactionNative,
-};
-use js_sys::{Array, Object};
+use js_sys::{Array, Function, Object};
 use rand::{rngs::StdRng, SeedableRng};
 use std::str::FromStr;
+use web_sys::AbortSignal;
 
 #[wasm_bindgen]
 impl ProgramManager {
@@ -57,9 +59,14 @@ impl ProgramManager {
     /// are a string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
     /// @param {ProvingKey | undefined} proving_key (optional) Provide a verifying key to use for the function execution
     /// @param {VerifyingKey | undefined} verifying_key (optional) Provide a verifying key to use for the function execution
+    /// @param {Function | undefined} on_progress (optional) A function called with the `ProgressStage`
+    /// that is starting each time the execution moves to a new stage
+    /// @param {AbortSignal | undefined} signal (optional) A signal which stops the execution before its
+    /// next stage once it has been aborted
     #[wasm_bindgen(js_name = executeFunctionOffline)]
     #[allow(clippy::too_many_arguments)]
     pub async fn execute_function_offline(
+        &self,
         private_key: &PrivateKey,
         program: &str,
         function: &str,
@@ -69,54 +76,66 @@ impl ProgramManager {
         imports: Option<Object>,
         proving_key: Option<ProvingKey>,
         verifying_key: Option<VerifyingKey>,
-        url: Option<String>,
+        url: Option<NetworkQueryLike>,
         offline_query: Option<OfflineQuery>,
-    ) -> Result<ExecutionResponse, String> {
+        on_progress: Option<Function>,
+        signal: Option<AbortSignal>,
+    ) -> Result<ExecutionResponse, SdkError> {
         log(&format!("Executing local function: {function}"));
-        let node_url = url.as_deref().unwrap_or(DEFAULT_URL);
+        let progress = Progress::new(on_progress, signal);
+        let query = NetworkQuery::from_js(url)?;
         let inputs = inputs.to_vec();
         let rng = &mut StdRng::from_entropy();
 
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
-        let process = &mut process_native;
-
-        log("Check program imports are valid and add them to the process");
-        let program_native = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
-        ProgramManager::resolve_imports(process, &program_native, imports)?;
-
-        let (response, mut trace) = execute_program!(
-            process,
-            process_inputs!(inputs),
-            program,
-            function,
-            private_key,
-            proving_key,
-            verifying_key,
-            rng
-        );
-
-        let mut execution_response = if prove_execution {
-            log("Preparing inclusion proofs for execution");
-            if let Some(offline_query) = offline_query {
-                trace.prepare_async(offline_query).await.map_err(|err| err.to_string())?;
+        with_network!(&mut *self.lock_process().await, |process| {
+            let private_key: PrivateKeyNative = private_key.into_network()?;
+
+            progress.stage(ProgressStage::LoadProgram).await?;
+            let program_native =
+                ProgramNative::from_str(program).map_err(|e| SdkError::InvalidArgument(e.to_string()))?;
+
+            progress.stage(ProgressStage::ResolveImports).await?;
+            log("Check program imports are valid and add them to the process");
+            ProgramManager::resolve_imports(process, &program_native, imports)?;
+
+            let (response, mut trace) = execute_program!(
+                process,
+                process_inputs!(inputs),
+                program,
+                function,
+                &private_key,
+                proving_key,
+                verifying_key,
+                rng,
+                progress
+            );
+
+            let mut execution_response = if prove_execution {
+                progress.stage(ProgressStage::PrepareInclusion).await?;
+                log("Preparing inclusion proofs for execution");
+                if let Some(offline_query) = offline_query {
+                    trace.prepare_async(offline_query).await.map_err(|err| SdkError::QueryFailed(err.to_string()))?;
+                } else {
+                    trace.prepare_async(query.clone()).await.map_err(|err| SdkError::QueryFailed(err.to_string()))?;
+                }
+
+                progress.stage(ProgressStage::Prove).await?;
+                log("Proving execution");
+                let locator = program_native.id().to_string().add("/").add(function);
+                let execution = trace
+                    .prove_execution::<CurrentAleo, _>(&locator, rng)
+                    .map_err(|e| SdkError::ProofFailed(e.to_string()))?;
+                ExecutionResponse::new(Some(execution), function, response, process, program)?
             } else {
-                let query = QueryNative::from(node_url);
-                trace.prepare_async(query).await.map_err(|err| err.to_string())?;
-            }
-
-            log("Proving execution");
-            let locator = program_native.id().to_string().add("/").add(function);
-            let execution = trace.prove_execution::<CurrentAleo, _>(&locator, rng).map_err(|e| e.to_string())?;
-            ExecutionResponse::new(Some(execution), function, response, process, program)?
-        } else {
-            ExecutionResponse::new(None, function, response, process, program)?
-        };
+                ExecutionResponse::new(None, function, response, process, program)?
+            };
 
-        if cache {
-            execution_response.add_proving_key(process, function, program_native.id())?;
-        }
+            if cache {
+                execution_response.add_proving_key(process, function, program_native.id())?;
+            }
 
-        Ok(execution_response)
+            Ok(execution_response)
+        })
     }
 
     /// Execute Aleo function and create an Aleo execution transaction
@@ -127,7 +146,7 @@ impl ProgramManager {
     /// @param inputs A javascript array of inputs to the function
     /// @param fee_credits The amount of credits to pay as a fee
     /// @param fee_record The record to spend the fee from
-    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param url The url of the Aleo network node to query, or a `NetworkQuery`
     /// If this is set to 'true' the keys synthesized (or passed in as optional parameters via the
     /// `proving_key` and `verifying_key` arguments) will be stored in the ProgramManager's memory
     /// and used for subsequent transactions. If this is set to 'false' the proving and verifying
@@ -139,91 +158,115 @@ impl ProgramManager {
     /// @param verifying_key (optional) Provide a verifying key to use for the function execution
     /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
     /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @param priority_fee_credits (optional) The amount of credits to pay as a priority fee
+    /// @param on_progress (optional) A function called with the `ProgressStage` that is starting each
+    /// time the transaction build moves to a new stage
+    /// @param signal (optional) An `AbortSignal` which stops the transaction build before its next
+    /// stage once it has been aborted
     /// @returns {Transaction}
     #[wasm_bindgen(js_name = buildExecutionTransaction)]
     #[allow(clippy::too_many_arguments)]
     pub async fn execute(
+        &self,
         private_key: &PrivateKey,
         program: &str,
         function: &str,
         inputs: Array,
         fee_credits: f64,
         fee_record: Option<RecordPlaintext>,
-        url: Option<String>,
+        url: Option<NetworkQueryLike>,
         imports: Option<Object>,
         proving_key: Option<ProvingKey>,
         verifying_key: Option<VerifyingKey>,
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
         offline_query: Option<OfflineQuery>,
-    ) -> Result<Transaction, String> {
+        priority_fee_credits: Option<f64>,
+        on_progress: Option<Function>,
+        signal: Option<AbortSignal>,
+    ) -> Result<Transaction, SdkError> {
         log(&format!("Executing function: {function} on-chain"));
-        let fee_microcredits = match &fee_record {
-            Some(fee_record) => Self::validate_amount(fee_credits, fee_record, true)?,
-            None => (fee_credits * 1_000_000.0) as u64,
-        };
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
-        let process = &mut process_native;
-        let node_url = url.as_deref().unwrap_or(DEFAULT_URL);
-
-        log("Check program imports are valid and add them to the process");
-        let program_native = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
-        ProgramManager::resolve_imports(process, &program_native, imports)?;
+        let progress = Progress::new(on_progress, signal);
+        let (fee_microcredits, priority_fee_microcredits) =
+            Self::validate_fee(fee_credits, priority_fee_credits, fee_record.as_ref())?;
+        let query = NetworkQuery::from_js(url)?;
         let rng = &mut StdRng::from_entropy();
 
-        log("Executing program");
-        let (_, mut trace) = execute_program!(
-            process,
-            process_inputs!(inputs),
-            program,
-            function,
-            private_key,
-            proving_key,
-            verifying_key,
-            rng
-        );
-
-        log("Preparing inclusion proofs for execution");
-        if let Some(offline_query) = offline_query.as_ref() {
-            trace.prepare_async(offline_query.clone()).await.map_err(|err| err.to_string())?;
-        } else {
-            let query = QueryNative::from(node_url);
-            trace.prepare_async(query).await.map_err(|err| err.to_string())?;
-        }
-
-        log("Proving execution");
-        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
-        let locator = program.id().to_string().add("/").add(function);
-        let execution = trace
-            .prove_execution::<CurrentAleo, _>(&locator, &mut StdRng::from_entropy())
-            .map_err(|e| e.to_string())?;
-        let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
-
-        log("Executing fee");
-        let fee = execute_fee!(
-            process,
-            private_key,
-            fee_record,
-            fee_microcredits,
-            node_url,
-            fee_proving_key,
-            fee_verifying_key,
-            execution_id,
-            rng,
-            offline_query
-        );
-
-        // Verify the execution
-        process.verify_execution(&execution).map_err(|err| err.to_string())?;
+        with_network!(&mut *self.lock_process().await, |process| {
+            let private_key: PrivateKeyNative = private_key.into_network()?;
+
+            progress.stage(ProgressStage::LoadProgram).await?;
+            let program_native =
+                ProgramNative::from_str(program).map_err(|e| SdkError::InvalidArgument(e.to_string()))?;
+
+            progress.stage(ProgressStage::ResolveImports).await?;
+            log("Check program imports are valid and add them to the process");
+            ProgramManager::resolve_imports(process, &program_native, imports)?;
+
+            log("Executing program");
+            let (_, mut trace) = execute_program!(
+                process,
+                process_inputs!(inputs),
+                program,
+                function,
+                &private_key,
+                proving_key,
+                verifying_key,
+                rng,
+                progress
+            );
+
+            progress.stage(ProgressStage::PrepareInclusion).await?;
+            log("Preparing inclusion proofs for execution");
+            if let Some(offline_query) = offline_query.as_ref() {
+                trace
+                    .prepare_async(offline_query.clone())
+                    .await
+                    .map_err(|err| SdkError::QueryFailed(err.to_string()))?;
+            } else {
+                trace.prepare_async(query.clone()).await.map_err(|err| SdkError::QueryFailed(err.to_string()))?;
+            }
 
-        log("Creating execution transaction");
-        let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
-        Ok(Transaction::from(transaction))
+            progress.stage(ProgressStage::Prove).await?;
+            log("Proving execution");
+            let locator = program_native.id().to_string().add("/").add(function);
+            let execution = trace
+                .prove_execution::<CurrentAleo, _>(&locator, &mut StdRng::from_entropy())
+                .map_err(|e| SdkError::ProofFailed(e.to_string()))?;
+            let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
+
+            log("Executing fee");
+            let fee = execute_fee!(
+                process,
+                &private_key,
+                fee_record,
+                fee_microcredits,
+                priority_fee_microcredits,
+                &query,
+                fee_proving_key,
+                fee_verifying_key,
+                execution_id,
+                rng,
+                offline_query,
+                progress
+            );
+
+            progress.stage(ProgressStage::Verify).await?;
+            log("Verifying the execution");
+            process.verify_execution(&execution).map_err(|err| SdkError::ProofFailed(err.to_string()))?;
+
+            log("Creating execution transaction");
+            let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
+            Ok(Transaction::from_native(transaction)?)
+        })
     }
 
-    /// Estimate Fee for Aleo function execution. Note if "cache" is set to true, the proving and
-    /// verifying keys will be stored in the ProgramManager's memory and used for subsequent
-    /// program executions.
+    /// Estimate the fee for an Aleo function execution without proving it. The function is only
+    /// authorized, which evaluates it and produces its transitions, so the estimate is available
+    /// in milliseconds rather than the time it takes to synthesize keys and create a proof. The
+    /// storage cost is computed from the size of the transitions plus an upper bound of the proof
+    /// size, so the estimate may exceed the cost of the proven execution by a few hundred
+    /// microcredits but never falls short of it.
     ///
     /// Disclaimer: Fee estimation is experimental and may not represent a correct estimate on any current or future network
     ///
@@ -231,85 +274,55 @@ impl ProgramManager {
     /// @param program The source code of the program to estimate the execution fee for
     /// @param function The name of the function to execute
     /// @param inputs A javascript array of inputs to the function
-    /// @param url The url of the Aleo network node to send the transaction to
     /// @param imports (optional) Provide a list of imports to use for the fee estimation in the
     /// form of a javascript object where the keys are a string of the program name and the values
     /// are a string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
-    /// @param proving_key (optional) Provide a verifying key to use for the fee estimation
-    /// @param verifying_key (optional) Provide a verifying key to use for the fee estimation
-    /// @returns {u64} Fee in microcredits
+    /// @param priority_fee_credits (optional) The amount of credits to pay as a priority fee
+    /// @returns {ExecutionCost} Breakdown of the fee in microcredits
     #[wasm_bindgen(js_name = estimateExecutionFee)]
-    #[allow(clippy::too_many_arguments)]
-    pub async fn estimate_execution_fee(
+    pub fn estimate_execution_fee(
+        &self,
         private_key: &PrivateKey,
         program: &str,
         function: &str,
         inputs: Array,
-        url: Option<String>,
         imports: Option<Object>,
-        proving_key: Option<ProvingKey>,
-        verifying_key: Option<VerifyingKey>,
-        offline_query: Option<OfflineQuery>,
-    ) -> Result<u64, String> {
+        priority_fee_credits: Option<f64>,
+    ) -> Result<ExecutionCost, SdkError> {
         log(
             "Disclaimer: Fee estimation is experimental and may not represent a correct estimate on any current or future network",
         );
-        log(&format!("Executing local function: {function}"));
+        let priority_fee = Self::validate_priority_fee(priority_fee_credits)?;
 
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
-        let process = &mut process_native;
+        with_network!(&mut *self.try_lock_process()?, |process| {
+            let private_key: PrivateKeyNative = private_key.into_network()?;
 
-        log("Check program imports are valid and add them to the process");
-        let program_native = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
-        ProgramManager::resolve_imports(process, &program_native, imports)?;
-        let rng = &mut StdRng::from_entropy();
-
-        log("Generating execution trace");
-        let (_, mut trace) = execute_program!(
-            process,
-            process_inputs!(inputs),
-            program,
-            function,
-            private_key,
-            proving_key,
-            verifying_key,
-            rng
-        );
-
-        // Execute the program
-        let node_url = url.as_deref().unwrap_or(DEFAULT_URL);
-        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
-        let locator = program.id().to_string().add("/").add(function);
-        if let Some(offline_query) = offline_query {
-            trace.prepare_async(offline_query).await.map_err(|err| err.to_string())?;
-        } else {
-            let query = QueryNative::from(node_url);
-            trace.prepare_async(query).await.map_err(|err| err.to_string())?;
-        }
-        let execution = trace.prove_execution::<CurrentAleo, _>(&locator, rng).map_err(|e| e.to_string())?;
-
-        // Get the storage cost in bytes for the program execution
-        log("Estimating cost");
-        let storage_cost = execution.size_in_bytes().map_err(|e| e.to_string())?;
-
-        // Compute the finalize cost in microcredits.
-        let mut finalize_cost = 0u64;
-        // Iterate over the transitions to accumulate the finalize cost.
-        for transition in execution.transitions() {
-            // Retrieve the function name, program id, and program.
-            let function_name = transition.function_name();
-            let program_id = transition.program_id();
-            let stack = process.get_stack(program_id).map_err(|e| e.to_string())?;
-
-            // Calculate the finalize cost for the function identified in the transition
-            let cost = cost_in_microcredits(stack, function_name).map_err(|e| e.to_string())?;
-
-            // Accumulate the finalize cost.
-            finalize_cost = finalize_cost
-                .checked_add(cost)
-                .ok_or("The finalize cost computation overflowed for an execution".to_string())?;
-        }
-        Ok(storage_cost + finalize_cost)
+            log("Check program imports are valid and add them to the process");
+            let program = ProgramNative::from_str(program).map_err(|e| SdkError::InvalidArgument(e.to_string()))?;
+            ProgramManager::resolve_imports(process, &program, imports)?;
+            if program.id().to_string() != "credits.aleo" {
+                Self::add_program_to_process(process, &program)?;
+            }
+            let function_name = IdentifierNative::from_str(function)
+                .map_err(|_| SdkError::InvalidArgument("The function name provided was invalid".to_string()))?;
+
+            let inputs = process_inputs!(inputs);
+            Self::check_inputs(&program, &function_name, &inputs)?;
+
+            log("Authorizing function to compute its transitions");
+            let authorization = process
+                .authorize::<CurrentAleo, _>(
+                    &private_key,
+                    program.id(),
+                    function_name,
+                    inputs.iter(),
+                    &mut StdRng::from_entropy(),
+                )
+                .map_err(|err| err.to_string())?;
+
+            log("Estimating cost");
+            Ok(ExecutionCost::estimate(process, authorization.transitions().values().cloned(), priority_fee)?)
+        })
     }
 
     /// Estimate the finalize fee component for executing a function. This fee is additional to the
@@ -322,19 +335,23 @@ impl ProgramManager {
     /// @param function The function to estimate the finalize fee for
     /// @returns {u64} Fee in microcredits
     #[wasm_bindgen(js_name = estimateFinalizeFee)]
-    pub fn estimate_finalize_fee(program: &str, function: &str) -> Result<u64, String> {
+    pub fn estimate_finalize_fee(&self, program: &str, function: &str) -> Result<u64, SdkError> {
         log(
             "Disclaimer: Fee estimation is experimental and may not represent a correct estimate on any current or future network",
         );
 
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
-        let process = &mut process_native;
-
-        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
-        let function_id = IdentifierNative::from_str(function).map_err(|err| err.to_string())?;
+        with_network!(&mut *self.try_lock_process()?, |process| {
+            let program = ProgramNative::from_str(program).map_err(|err| SdkError::InvalidArgument(err.to_string()))?;
+            let function_id =
+                IdentifierNative::from_str(function).map_err(|err| SdkError::InvalidArgument(err.to_string()))?;
+            if program.id().to_string() != "credits.aleo" {
+                Self::add_program_to_process(process, &program)?;
+            }
 
-        let stack = process.get_stack(program.id()).map_err(|e| e.to_string())?;
+            let stack =
+                process.get_stack(program.id()).map_err(|_| SdkError::ProgramNotFound(program.id().to_string()))?;
 
-        cost_in_microcredits(stack, &function_id).map_err(|e| e.to_string())
+            Ok(cost_in_microcredits(stack, &function_id).map_err(|e| e.to_string())?)
+        })
     }
 }
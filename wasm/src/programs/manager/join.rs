@@ -17,21 +17,24 @@
 use super::*;
 
 use crate::{
-    execute_fee, execute_program, log, process_inputs,
-    OfflineQuery, PrivateKey, RecordPlaintext, Transaction,
+    execute_fee,
+    execute_program,
+    log,
+    process_inputs,
+    types::NetworkAgnostic,
+    with_network,
+    OfflineQuery,
+    PrivateKey,
+    Progress,
+    ProgressStage,
+    RecordPlaintext,
+    Transaction,
 };
 
-use crate::types::native::{
-    CurrentAleo,
-    IdentifierNative,
-    ProcessNative,
-    ProgramNative,
-    RecordPlaintextNative,
-    TransactionNative,
-};
-use js_sys::Array;
+use js_sys::{Array, Function};
 use rand::{rngs::StdRng, SeedableRng};
 use std::str::FromStr;
+use web_sys::AbortSignal;
 
 #[wasm_bindgen]
 impl ProgramManager {
@@ -43,104 +46,122 @@ impl ProgramManager {
     /// @param record_2 The second record to combine
     /// @param fee_credits The amount of credits to pay as a fee
     /// @param fee_record The record to spend the fee from
-    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param url The url of the Aleo network node to query, or a `NetworkQuery`
     /// @param join_proving_key (optional) Provide a proving key to use for the join function
     /// @param join_verifying_key (optional) Provide a verifying key to use for the join function
     /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
     /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @param priority_fee_credits (optional) The amount of credits to pay as a priority fee
+    /// @param on_progress (optional) A function called with the `ProgressStage` that is starting each
+    /// time the transaction build moves to a new stage
+    /// @param signal (optional) An `AbortSignal` which stops the transaction build before its next
+    /// stage once it has been aborted
     /// @returns {Transaction} Transaction object
     #[wasm_bindgen(js_name = buildJoinTransaction)]
     #[allow(clippy::too_many_arguments)]
     pub async fn join(
+        &self,
         private_key: &PrivateKey,
         record_1: RecordPlaintext,
         record_2: RecordPlaintext,
         fee_credits: f64,
         fee_record: Option<RecordPlaintext>,
-        url: Option<String>,
+        url: Option<NetworkQueryLike>,
         join_proving_key: Option<ProvingKey>,
         join_verifying_key: Option<VerifyingKey>,
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
         offline_query: Option<OfflineQuery>,
-    ) -> Result<Transaction, String> {
+        priority_fee_credits: Option<f64>,
+        on_progress: Option<Function>,
+        signal: Option<AbortSignal>,
+    ) -> Result<Transaction, SdkError> {
         log("Executing join program");
-        let fee_microcredits = match &fee_record {
-            Some(fee_record) => Self::validate_amount(fee_credits, fee_record, true)?,
-            None => (fee_credits * 1_000_000.0) as u64,
-        };
+        let progress = Progress::new(on_progress, signal);
+        let (fee_microcredits, priority_fee_microcredits) =
+            Self::validate_fee(fee_credits, priority_fee_credits, fee_record.as_ref())?;
         let rng = &mut StdRng::from_entropy();
 
         log("Setup program and inputs");
-        let node_url = url.as_deref().unwrap_or(DEFAULT_URL);
-        let program = ProgramNative::credits().unwrap().to_string();
+        let query = NetworkQuery::from_js(url)?;
         let inputs = Array::new_with_length(2);
         inputs.set(0u32, wasm_bindgen::JsValue::from_str(&record_1.to_string()));
         inputs.set(1u32, wasm_bindgen::JsValue::from_str(&record_2.to_string()));
 
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
-        let process = &mut process_native;
-
-        let stack = process.get_stack("credits.aleo").map_err(|e| e.to_string())?;
-        let fee_identifier = if fee_record.is_some() {
-            IdentifierNative::from_str("fee_private").map_err(|e| e.to_string())?
-        } else {
-            IdentifierNative::from_str("fee_public").map_err(|e| e.to_string())?
-        };
-        if !stack.contains_proving_key(&fee_identifier) && fee_proving_key.is_some() && fee_verifying_key.is_some() {
-            let fee_proving_key = fee_proving_key.clone().unwrap();
-            let fee_verifying_key = fee_verifying_key.clone().unwrap();
-            stack
-                .insert_proving_key(&fee_identifier, ProvingKeyNative::from(fee_proving_key))
-                .map_err(|e| e.to_string())?;
-            stack
-                .insert_verifying_key(&fee_identifier, VerifyingKeyNative::from(fee_verifying_key))
-                .map_err(|e| e.to_string())?;
-        }
-
-        log("Executing the join function");
-        let (_, mut trace) = execute_program!(
-            process,
-            process_inputs!(inputs),
-            &program,
-            "join",
-            private_key,
-            join_proving_key,
-            join_verifying_key,
-            rng
-        );
-
-        log("Preparing inclusion proof for the join execution");
-        if let Some(offline_query) = offline_query.as_ref() {
-            trace.prepare_async(offline_query.clone()).await.map_err(|err| err.to_string())?;
-        } else {
-            let query = QueryNative::from(node_url);
-            trace.prepare_async(query).await.map_err(|err| err.to_string())?;
-        }
-
-        log("Proving the join execution");
-        let execution = trace.prove_execution::<CurrentAleo, _>("credits.aleo/join", rng).map_err(|e| e.to_string())?;
-        let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
-
-        log("Verifying the join execution");
-        process.verify_execution(&execution).map_err(|err| err.to_string())?;
-
-        log("Executing the fee");
-        let fee = execute_fee!(
-            process,
-            private_key,
-            fee_record,
-            fee_microcredits,
-            node_url,
-            fee_proving_key,
-            fee_verifying_key,
-            execution_id,
-            rng,
-            offline_query
-        );
-
-        log("Creating execution transaction for join");
-        let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
-        Ok(Transaction::from(transaction))
+        with_network!(&mut *self.lock_process().await, |process| {
+            let private_key: PrivateKeyNative = private_key.into_network()?;
+            progress.stage(ProgressStage::LoadProgram).await?;
+            let program = ProgramNative::credits().map_err(|e| e.to_string())?.to_string();
+
+            let stack = process.get_stack("credits.aleo").map_err(|e| e.to_string())?;
+            let fee_identifier = if fee_record.is_some() {
+                IdentifierNative::from_str("fee_private").map_err(|e| e.to_string())?
+            } else {
+                IdentifierNative::from_str("fee_public").map_err(|e| e.to_string())?
+            };
+            if !stack.contains_proving_key(&fee_identifier) && fee_proving_key.is_some() && fee_verifying_key.is_some()
+            {
+                let fee_proving_key = fee_proving_key.clone().unwrap();
+                let fee_verifying_key = fee_verifying_key.clone().unwrap();
+                stack
+                    .insert_proving_key(&fee_identifier, fee_proving_key.into_network()?)
+                    .map_err(|e| SdkError::KeyMismatch(e.to_string()))?;
+                stack
+                    .insert_verifying_key(&fee_identifier, fee_verifying_key.into_network()?)
+                    .map_err(|e| SdkError::KeyMismatch(e.to_string()))?;
+            }
+
+            log("Executing the join function");
+            let (_, mut trace) = execute_program!(
+                process,
+                process_inputs!(inputs),
+                &program,
+                "join",
+                &private_key,
+                join_proving_key,
+                join_verifying_key,
+                rng,
+                progress
+            );
+
+            progress.stage(ProgressStage::PrepareInclusion).await?;
+            log("Preparing inclusion proof for the join execution");
+            if let Some(offline_query) = offline_query.as_ref() {
+                trace.prepare_async(offline_query.clone()).await.map_err(|err| err.to_string())?;
+            } else {
+                trace.prepare_async(query.clone()).await.map_err(|err| SdkError::QueryFailed(err.to_string()))?;
+            }
+
+            progress.stage(ProgressStage::Prove).await?;
+            log("Proving the join execution");
+            let execution = trace
+                .prove_execution::<CurrentAleo, _>("credits.aleo/join", rng)
+                .map_err(|e| SdkError::ProofFailed(e.to_string()))?;
+            let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
+
+            progress.stage(ProgressStage::Verify).await?;
+            log("Verifying the join execution");
+            process.verify_execution(&execution).map_err(|err| err.to_string())?;
+
+            log("Executing the fee");
+            let fee = execute_fee!(
+                process,
+                &private_key,
+                fee_record,
+                fee_microcredits,
+                priority_fee_microcredits,
+                &query,
+                fee_proving_key,
+                fee_verifying_key,
+                execution_id,
+                rng,
+                offline_query,
+                progress
+            );
+
+            log("Creating execution transaction for join");
+            let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
+            Ok(Transaction::from_native(transaction)?)
+        })
     }
 }
@@ -14,76 +14,183 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod authorize;
+pub mod cache;
 pub mod deploy;
 pub mod execute;
 pub mod join;
 pub mod split;
+pub mod staking;
 pub mod transfer;
 
 use crate::{
-    // types::{
-    //     cost_in_microcredits, deployment_cost, CurrentAleo, IdentifierNative, ProcessNative, ProgramIDNative,
-    //     ProgramNative, ProvingKeyNative, QueryNative, VerifyingKeyNative,
-    // },
-    KeyPair, PrivateKey, ProvingKey, RecordPlaintext, VerifyingKey,
+    KeyPair,
+    Network,
+    NetworkProcess,
+    NetworkQuery,
+    NetworkQueryLike,
+    PrivateKey,
+    ProvingKey,
+    RecordPlaintext,
+    SdkError,
+    VerifyingKey,
 };
 
-const DEFAULT_URL: &str = "https://api.explorer.provable.org/v1";
-
-// use crate::{KeyPair, PrivateKey, ProvingKey, RecordPlaintext, VerifyingKey};
-
 use crate::types::native::{
     cost_in_microcredits,
-    deployment_cost,
-    IdentifierNative,
-    ProcessNative,
-    ProgramIDNative,
-    ProgramNative,
-    ProvingKeyNative,
-    QueryNative,
-    VerifyingKeyNative,
+    Identifier,
+    Network as NetworkTrait,
+    Plaintext,
+    PlaintextType,
+    Process,
+    Program,
+    ProgramID,
+    Value,
+    ValueType,
 };
+use futures::lock::{Mutex, MutexGuard};
 use js_sys::{Object, Reflect};
 use std::str::FromStr;
 use wasm_bindgen::prelude::wasm_bindgen;
-
+use web_sys::AbortSignal;
+
+/// The program manager owns a process which holds the programs, proving keys and verifying keys
+/// loaded during previous calls. Reusing the same program manager across calls avoids loading
+/// imports and synthesizing keys again for functions that have already been executed.
+///
+/// Each program manager is bound to the network it was created for. Transactions, executions and
+/// authorizations it creates are tagged with that network and values from another network are
+/// rejected.
+///
+/// Transactions can be built concurrently with the same program manager. Builds wait for the
+/// builds started before them to finish, as they share the process. Synchronous methods such as
+/// `addProgram` fail with a `BUSY` error while a build is running.
 #[wasm_bindgen]
-#[derive(Clone)]
-pub struct ProgramManager;
+pub struct ProgramManager {
+    network: Network,
+    process: Mutex<NetworkProcess>,
+}
 
 #[wasm_bindgen]
 impl ProgramManager {
+    /// Create a new program manager with an empty cache
+    ///
+    /// @param {Network | undefined} network (optional) The network to build transactions for,
+    /// defaults to mainnet
+    /// @returns {ProgramManager}
+    #[wasm_bindgen(constructor)]
+    pub fn new(network: Option<Network>) -> Result<ProgramManager, SdkError> {
+        let network = network.unwrap_or_default();
+        Ok(Self { network, process: Mutex::new(NetworkProcess::load(network)?) })
+    }
+
+    /// Get the network the program manager builds transactions for
+    ///
+    /// @returns {Network}
+    #[wasm_bindgen]
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Lock the process for a transaction build, waiting for the builds started before it
+    pub(crate) async fn lock_process(&self) -> MutexGuard<'_, NetworkProcess> {
+        self.process.lock().await
+    }
+
+    /// Lock the process for a synchronous call, failing while a transaction build holds it
+    pub(crate) fn try_lock_process(&self) -> Result<MutexGuard<'_, NetworkProcess>, SdkError> {
+        self.process.try_lock().ok_or(SdkError::Busy)
+    }
+
+    /// Convert an amount of credits to microcredits, rejecting amounts which are not finite, are
+    /// negative or do not fit in a u64
+    pub(crate) fn credits_to_microcredits(credits: f64, name: &str) -> Result<u64, SdkError> {
+        if !credits.is_finite() || credits < 0.0 {
+            return Err(SdkError::InvalidArgument(format!("{name} must be a non-negative number of credits")));
+        }
+        let microcredits = (credits * 1_000_000.0).round();
+        // `u64::MAX as f64` rounds up to 2^64, which is already out of range
+        if microcredits >= u64::MAX as f64 {
+            return Err(SdkError::InvalidArgument(format!("{name} of {credits} credits is too large")));
+        }
+        Ok(microcredits as u64)
+    }
+
     /// Validate that an amount being paid from a record is greater than zero and that the record
     /// has enough credits to pay the amount
-    pub(crate) fn validate_amount(credits: f64, amount: &RecordPlaintext, fee: bool) -> Result<u64, String> {
+    pub(crate) fn validate_amount(credits: f64, amount: &RecordPlaintext, fee: bool) -> Result<u64, SdkError> {
         let name = if fee { "Fee" } else { "Amount" };
 
-        if credits <= 0.0 {
-            return Err(format!("{name} must be greater than zero to deploy or execute a program"));
+        let microcredits = Self::credits_to_microcredits(credits, name)?;
+        if microcredits == 0 {
+            return Err(SdkError::InvalidArgument(format!(
+                "{name} must be greater than zero to deploy or execute a program"
+            )));
         }
-        let microcredits = (credits * 1_000_000.0f64) as u64;
         if amount.microcredits() < microcredits {
-            return Err(format!("{name} record does not have enough credits to pay the specified fee"));
+            return Err(SdkError::InsufficientFee { required: microcredits, available: amount.microcredits() });
         }
 
         Ok(microcredits)
     }
 
+    /// Validate the base fee and optional priority fee for a transaction and, if the fee is paid
+    /// from a record, that the record has enough credits to pay both. Returns the base and
+    /// priority fee in microcredits
+    pub(crate) fn validate_fee(
+        fee_credits: f64,
+        priority_fee_credits: Option<f64>,
+        fee_record: Option<&RecordPlaintext>,
+    ) -> Result<(u64, u64), SdkError> {
+        let fee_microcredits = match fee_record {
+            Some(fee_record) => Self::validate_amount(fee_credits, fee_record, true)?,
+            None => Self::credits_to_microcredits(fee_credits, "Fee")?,
+        };
+        let priority_fee_microcredits = Self::validate_priority_fee(priority_fee_credits)?;
+
+        if let Some(fee_record) = fee_record {
+            let total_fee = fee_microcredits
+                .checked_add(priority_fee_microcredits)
+                .ok_or(SdkError::InvalidArgument("The total fee computation overflowed".to_string()))?;
+            if fee_record.microcredits() < total_fee {
+                return Err(SdkError::InsufficientFee { required: total_fee, available: fee_record.microcredits() });
+            }
+        }
+
+        Ok((fee_microcredits, priority_fee_microcredits))
+    }
+
+    /// Validate that an optional priority fee is a non-negative number and convert it to microcredits
+    pub(crate) fn validate_priority_fee(priority_fee_credits: Option<f64>) -> Result<u64, SdkError> {
+        match priority_fee_credits {
+            Some(priority_fee_credits) => Self::credits_to_microcredits(priority_fee_credits, "Priority fee"),
+            None => Ok(0u64),
+        }
+    }
+
     /// Synthesize proving and verifying keys for a program
     ///
     /// @param program {string} The program source code of the program to synthesize keys for
     /// @param function_id {string} The function to synthesize keys for
     /// @param inputs {Array} The inputs to the function
     /// @param imports {Object | undefined} The imports for the program
+    /// @param on_progress {Function | undefined} (optional) A function called with the `ProgressStage`
+    /// that is starting each time the key synthesis moves to a new stage
+    /// @param signal {AbortSignal | undefined} (optional) A signal which stops the key synthesis
+    /// before its next stage once it has been aborted
     #[wasm_bindgen(js_name = "synthesizeKeyPair")]
+    #[allow(clippy::too_many_arguments)]
     pub async fn synthesize_keypair(
+        &self,
         private_key: &PrivateKey,
         program: &str,
         function_id: &str,
         inputs: js_sys::Array,
         imports: Option<Object>,
-    ) -> Result<KeyPair, String> {
-        ProgramManager::execute_function_offline(
+        on_progress: Option<js_sys::Function>,
+        signal: Option<AbortSignal>,
+    ) -> Result<KeyPair, SdkError> {
+        self.execute_function_offline(
             private_key,
             program,
             function_id,
@@ -95,16 +202,19 @@ impl ProgramManager {
             None,
             None,
             None,
+            on_progress,
+            signal,
         )
         .await?
         .get_keys()
+        .map_err(SdkError::from)
     }
 
     /// Check if a process contains a keypair for a specific function
-    pub(crate) fn contains_key(
-        process: &ProcessNative,
-        program_id: &ProgramIDNative,
-        function_id: &IdentifierNative,
+    pub(crate) fn contains_key<N: NetworkTrait>(
+        process: &Process<N>,
+        program_id: &ProgramID<N>,
+        function_id: &Identifier<N>,
     ) -> bool {
         process.get_stack(program_id).map_or_else(
             |_| false,
@@ -112,42 +222,90 @@ impl ProgramManager {
         )
     }
 
-    /// Resolve imports for a program in depth first search order
-    pub(crate) fn resolve_imports(
-        process: &mut ProcessNative,
-        program: &ProgramNative,
+    /// Check that the number of inputs matches the function and that every input parses as the
+    /// type the function declares for it, so mistakes are reported with the index of the input
+    pub(crate) fn check_inputs<N: NetworkTrait>(
+        program: &Program<N>,
+        function_name: &Identifier<N>,
+        inputs: &[String],
+    ) -> Result<(), SdkError> {
+        let function = program.get_function(function_name).map_err(|_| {
+            SdkError::InvalidArgument(format!("Function {function_name} does not exist in {}", program.id()))
+        })?;
+        let input_types = function.input_types();
+        if input_types.len() != inputs.len() {
+            return Err(SdkError::InvalidArgument(format!(
+                "{}/{function_name} expects {} inputs but {} were provided",
+                program.id(),
+                input_types.len(),
+                inputs.len()
+            )));
+        }
+
+        for (index, (input, input_type)) in inputs.iter().zip(input_types.iter()).enumerate() {
+            let plaintext_type = match input_type {
+                ValueType::Constant(plaintext_type)
+                | ValueType::Public(plaintext_type)
+                | ValueType::Private(plaintext_type) => Some(plaintext_type),
+                _ => None,
+            };
+            let matches = match (Value::<N>::from_str(input), plaintext_type) {
+                (Ok(Value::Plaintext(Plaintext::Literal(literal, _))), Some(PlaintextType::Literal(literal_type))) => {
+                    literal.to_type() == *literal_type
+                }
+                (Ok(Value::Plaintext(Plaintext::Literal(..))), Some(_)) => false,
+                (Ok(Value::Plaintext(_)), Some(PlaintextType::Literal(_))) => false,
+                (Ok(Value::Plaintext(_)), Some(_)) => true,
+                (Ok(Value::Record(_)), None) => {
+                    matches!(input_type, ValueType::Record(_) | ValueType::ExternalRecord(_))
+                }
+                _ => false,
+            };
+            if !matches {
+                return Err(SdkError::InvalidInput { index, expected: input_type.to_string() });
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolve imports for a program in depth first search order. Imports which are not provided
+    /// must already be loaded in the process.
+    pub(crate) fn resolve_imports<N: NetworkTrait>(
+        process: &mut Process<N>,
+        program: &Program<N>,
         imports: Option<Object>,
-    ) -> Result<(), String> {
-        if let Some(imports) = imports {
-            program.imports().keys().try_for_each(|program_id| {
-                // Get the program string
-                let program_id = program_id.to_string();
-                if let Some(import_string) = Reflect::get(&imports, &program_id.as_str().into())
-                    .map_err(|_| "Program import not found in imports provided".to_string())?
-                    .as_string()
-                {
-                    if &program_id != "credits.aleo" {
-                        crate::log(&format!("Importing program: {}", program_id));
-                        let import = ProgramNative::from_str(&import_string).map_err(|err| err.to_string())?;
-                        // If the program has imports, add them
-                        Self::resolve_imports(process, &import, Some(imports.clone()))?;
-                        // If the process does not already contain the program, add it
-                        if !process.contains_program(import.id()) {
-                            process.add_program(&import).map_err(|err| err.to_string())?;
-                        }
+    ) -> Result<(), SdkError> {
+        for program_id in program.imports().keys() {
+            // Get the program string
+            let import_string = imports
+                .as_ref()
+                .and_then(|imports| Reflect::get(imports, &program_id.to_string().into()).ok())
+                .and_then(|import| import.as_string());
+            match import_string {
+                Some(import_string) if program_id.to_string() != "credits.aleo" => {
+                    crate::log(&format!("Importing program: {}", program_id));
+                    let import = Program::<N>::from_str(&import_string).map_err(|err| {
+                        SdkError::InvalidArgument(format!("The import {program_id} is not a valid program: {err}"))
+                    })?;
+                    // If the program has imports, add them
+                    Self::resolve_imports(process, &import, imports.clone())?;
+                    // If the process does not already contain the program, add it
+                    if !process.contains_program(import.id()) {
+                        process.add_program(&import).map_err(|err| err.to_string())?;
                     }
                 }
-                Ok::<(), String>(())
-            })
-        } else {
-            Ok(())
+                _ if process.contains_program(program_id) => {}
+                _ => return Err(SdkError::ImportMissing(program_id.to_string())),
+            }
         }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::native::{ProcessNative, ProgramNative};
 
     use js_sys::{Object, Reflect};
     use wasm_bindgen::JsValue;
@@ -225,4 +383,66 @@ function add_and_double:
         assert_eq!(double_import, &double_program);
         assert!(main_program.is_err());
     }
+
+    #[wasm_bindgen_test]
+    fn test_missing_import() {
+        let imports = Object::new();
+        Reflect::set(&imports, &JsValue::from_str("addition_test.aleo"), &JsValue::from_str(ADDITION_PROGRAM)).unwrap();
+        Reflect::set(&imports, &JsValue::from_str("double_test.aleo"), &JsValue::from_str(MULTIPLY_IMPORT_PROGRAM))
+            .unwrap();
+
+        let mut process = ProcessNative::load_web().unwrap();
+        let program = ProgramNative::from_str(NESTED_IMPORT_PROGRAM).unwrap();
+        let error = ProgramManager::resolve_imports(&mut process, &program, Some(imports)).unwrap_err();
+        assert_eq!(error, SdkError::ImportMissing("multiply_test.aleo".to_string()));
+
+        let error = ProgramManager::resolve_imports(&mut process, &program, None).unwrap_err();
+        assert_eq!(error.code(), "IMPORT_MISSING");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_credits_to_microcredits() {
+        assert_eq!(ProgramManager::credits_to_microcredits(1.5, "Amount").unwrap(), 1_500_000);
+        assert_eq!(ProgramManager::credits_to_microcredits(0.0, "Amount").unwrap(), 0);
+
+        // Amounts which cannot be represented in microcredits are rejected instead of saturating
+        for credits in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -1.0, u64::MAX as f64 / 1_000_000.0, 1e20] {
+            let error = ProgramManager::credits_to_microcredits(credits, "Amount").unwrap_err();
+            assert_eq!(error.code(), "INVALID_ARGUMENT");
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_bond_amount_validation() {
+        assert_eq!(ProgramManager::validate_bond_amount(10.0).unwrap(), 10_000_000);
+        for credits in [0.5, f64::NAN, f64::INFINITY, -10.0, 1e20] {
+            assert_eq!(ProgramManager::validate_bond_amount(credits).unwrap_err().code(), "INVALID_ARGUMENT");
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_fee_validation() {
+        assert_eq!(ProgramManager::validate_fee(0.5, Some(0.25), None).unwrap(), (500_000, 250_000));
+        assert_eq!(ProgramManager::validate_fee(0.0, None, None).unwrap(), (0, 0));
+
+        // Negative and non-finite public fees are rejected instead of being paid as zero
+        for fee in [-1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(ProgramManager::validate_fee(fee, None, None).unwrap_err().code(), "INVALID_ARGUMENT");
+            assert_eq!(ProgramManager::validate_fee(0.5, Some(fee), None).unwrap_err().code(), "INVALID_ARGUMENT");
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_builds_share_the_process() {
+        let program_manager = ProgramManager::new(None).unwrap();
+
+        // Synchronous calls fail while a build holds the process instead of panicking
+        let process = program_manager.lock_process().await;
+        assert_eq!(program_manager.add_program(MULTIPLY_PROGRAM, None).unwrap_err(), SdkError::Busy);
+        assert_eq!(program_manager.has_program("multiply_test.aleo").unwrap_err().code(), "BUSY");
+        drop(process);
+
+        program_manager.add_program(MULTIPLY_PROGRAM, None).unwrap();
+        assert!(program_manager.has_program("multiply_test.aleo").unwrap());
+    }
 }
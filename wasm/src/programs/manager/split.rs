@@ -20,13 +20,20 @@ use crate::{
     execute_program,
     log,
     process_inputs,
-    OfflineQuery, PrivateKey, RecordPlaintext, Transaction
+    types::NetworkAgnostic,
+    with_network,
+    OfflineQuery,
+    PrivateKey,
+    Progress,
+    ProgressStage,
+    RecordPlaintext,
+    Transaction,
 };
 
-use crate::types::native::{CurrentAleo, IdentifierNative, ProcessNative, ProgramNative, TransactionNative};
-use js_sys::Array;
+use js_sys::{Array, Function};
 use rand::{rngs::StdRng, SeedableRng};
 use std::{ops::Add, str::FromStr};
+use web_sys::AbortSignal;
 
 #[wasm_bindgen]
 impl ProgramManager {
@@ -36,64 +43,78 @@ impl ProgramManager {
     /// @param split_amount The amount of the credit split. This amount will be subtracted from the
     /// value of the record and two new records will be created with the split amount and the remainder
     /// @param amount_record The record to split
-    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param url The url of the Aleo network node to query, or a `NetworkQuery`
     /// @param split_proving_key (optional) Provide a proving key to use for the split function
     /// @param split_verifying_key (optional) Provide a verifying key to use for the split function
+    /// @param on_progress (optional) A function called with the `ProgressStage` that is starting each
+    /// time the transaction build moves to a new stage
+    /// @param signal (optional) An `AbortSignal` which stops the transaction build before its next
+    /// stage once it has been aborted
     /// @returns {Transaction} Transaction object
     #[wasm_bindgen(js_name = buildSplitTransaction)]
     #[allow(clippy::too_many_arguments)]
     pub async fn split(
+        &self,
         private_key: &PrivateKey,
         split_amount: f64,
         amount_record: RecordPlaintext,
-        url: Option<String>,
+        url: Option<NetworkQueryLike>,
         split_proving_key: Option<ProvingKey>,
         split_verifying_key: Option<VerifyingKey>,
         offline_query: Option<OfflineQuery>,
-    ) -> Result<Transaction, String> {
+        on_progress: Option<Function>,
+        signal: Option<AbortSignal>,
+    ) -> Result<Transaction, SdkError> {
         log("Executing split program");
+        let progress = Progress::new(on_progress, signal);
         let amount_microcredits = Self::validate_amount(split_amount, &amount_record, false)?;
 
         log("Setup the program and inputs");
-        let node_url = url.as_deref().unwrap_or(DEFAULT_URL);
-        let program = ProgramNative::credits().unwrap().to_string();
+        let query = NetworkQuery::from_js(url)?;
         let inputs = Array::new_with_length(2u32);
         inputs.set(0u32, wasm_bindgen::JsValue::from_str(&amount_record.to_string()));
         inputs.set(1u32, wasm_bindgen::JsValue::from_str(&amount_microcredits.to_string().add("u64")));
 
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
-        let process = &mut process_native;
-        let rng = &mut StdRng::from_entropy();
+        with_network!(&mut *self.lock_process().await, |process| {
+            let private_key: PrivateKeyNative = private_key.into_network()?;
+            progress.stage(ProgressStage::LoadProgram).await?;
+            let program = ProgramNative::credits().map_err(|e| e.to_string())?.to_string();
+            let rng = &mut StdRng::from_entropy();
 
-        log("Executing the split function");
-        let (_, mut trace) = execute_program!(
-            process,
-            process_inputs!(inputs),
-            &program,
-            "split",
-            private_key,
-            split_proving_key,
-            split_verifying_key,
-            rng
-        );
+            log("Executing the split function");
+            let (_, mut trace) = execute_program!(
+                process,
+                process_inputs!(inputs),
+                &program,
+                "split",
+                &private_key,
+                split_proving_key,
+                split_verifying_key,
+                rng,
+                progress
+            );
 
-        log("Preparing the inclusion proof for the split execution");
-        if let Some(offline_query) = offline_query.as_ref() {
-            trace.prepare_async(offline_query.clone()).await.map_err(|err| err.to_string())?;
-        } else {
-            let query = QueryNative::from(node_url);
-            trace.prepare_async(query).await.map_err(|err| err.to_string())?;
-        }
+            progress.stage(ProgressStage::PrepareInclusion).await?;
+            log("Preparing the inclusion proof for the split execution");
+            if let Some(offline_query) = offline_query.as_ref() {
+                trace.prepare_async(offline_query.clone()).await.map_err(|err| err.to_string())?;
+            } else {
+                trace.prepare_async(query.clone()).await.map_err(|err| SdkError::QueryFailed(err.to_string()))?;
+            }
 
-        log("Proving the split execution");
-        let execution =
-            trace.prove_execution::<CurrentAleo, _>("credits.aleo/split", rng).map_err(|e| e.to_string())?;
+            progress.stage(ProgressStage::Prove).await?;
+            log("Proving the split execution");
+            let execution = trace
+                .prove_execution::<CurrentAleo, _>("credits.aleo/split", rng)
+                .map_err(|e| SdkError::ProofFailed(e.to_string()))?;
 
-        log("Verifying the split execution");
-        process.verify_execution(&execution).map_err(|err| err.to_string())?;
+            progress.stage(ProgressStage::Verify).await?;
+            log("Verifying the split execution");
+            process.verify_execution(&execution).map_err(|err| err.to_string())?;
 
-        log("Creating execution transaction for split");
-        let transaction = TransactionNative::from_execution(execution, None).map_err(|err| err.to_string())?;
-        Ok(Transaction::from(transaction))
+            log("Creating execution transaction for split");
+            let transaction = TransactionNative::from_execution(execution, None).map_err(|err| err.to_string())?;
+            Ok(Transaction::from_native(transaction)?)
+        })
     }
 }
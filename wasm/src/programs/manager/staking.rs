@@ -0,0 +1,516 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+    execute_fee,
+    execute_program,
+    log,
+    process_inputs,
+    types::NetworkAgnostic,
+    with_network,
+    Address,
+    OfflineQuery,
+    PrivateKey,
+    Progress,
+    ProgressStage,
+    RecordPlaintext,
+    Transaction,
+};
+
+use js_sys::{Array, Function};
+use rand::{rngs::StdRng, SeedableRng};
+use std::{ops::Add, str::FromStr};
+use wasm_bindgen::JsValue;
+use web_sys::AbortSignal;
+
+/// The minimum amount of microcredits `credits.aleo` accepts in a single bond or unbond call
+const MINIMUM_BOND_MICROCREDITS: u64 = 1_000_000;
+
+/// The maximum commission percentage a validator can charge its delegators
+const MAXIMUM_COMMISSION: u8 = 100;
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Bond credits to a validator from a public balance
+    ///
+    /// Note that the network additionally requires the total amount bonded by a delegator to be at
+    /// least 10,000 credits. This check happens in the finalize scope and cannot be performed here.
+    ///
+    /// @param private_key The private key of the staker
+    /// @param validator The address of the validator to bond to
+    /// @param withdrawal_address The address the bonded credits are returned to when unbonded
+    /// @param amount_credits The amount of credits to bond
+    /// @param fee_credits The amount of credits to pay as a fee
+    /// @param fee_record The record to spend the fee from
+    /// @param url The url of the Aleo network node to query, or a `NetworkQuery`
+    /// @param bond_proving_key (optional) Provide a proving key to use for the bond_public function
+    /// @param bond_verifying_key (optional) Provide a verifying key to use for the bond_public function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @param priority_fee_credits (optional) The amount of credits to pay as a priority fee
+    /// @param on_progress (optional) A function called with the `ProgressStage` that is starting each
+    /// time the transaction build moves to a new stage
+    /// @param signal (optional) An `AbortSignal` which stops the transaction build before its next
+    /// stage once it has been aborted
+    /// @returns {Transaction}
+    #[wasm_bindgen(js_name = buildBondPublicTransaction)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn bond_public(
+        &self,
+        private_key: &PrivateKey,
+        validator: &str,
+        withdrawal_address: &str,
+        amount_credits: f64,
+        fee_credits: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: Option<NetworkQueryLike>,
+        bond_proving_key: Option<ProvingKey>,
+        bond_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+        offline_query: Option<OfflineQuery>,
+        priority_fee_credits: Option<f64>,
+        on_progress: Option<Function>,
+        signal: Option<AbortSignal>,
+    ) -> Result<Transaction, SdkError> {
+        log("Executing bond_public program");
+        let validator = Self::validate_address(validator, "Validator")?;
+        let withdrawal_address = Self::validate_address(withdrawal_address, "Withdrawal")?;
+        let amount_microcredits = Self::validate_bond_amount(amount_credits)?;
+
+        let inputs = [
+            JsValue::from_str(&validator.to_string()),
+            JsValue::from_str(&withdrawal_address.to_string()),
+            JsValue::from_str(&amount_microcredits.to_string().add("u64")),
+        ]
+        .into_iter()
+        .collect::<Array>();
+
+        self.execute_staking_function(
+            private_key,
+            "bond_public",
+            inputs,
+            fee_credits,
+            fee_record,
+            url,
+            bond_proving_key,
+            bond_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+            offline_query,
+            priority_fee_credits,
+            on_progress,
+            signal,
+        )
+        .await
+    }
+
+    /// Bond credits to the caller's own address as a validator
+    ///
+    /// Note that the network additionally requires a validator to hold at least 10,000,000 credits
+    /// in total stake. This check happens in the finalize scope and cannot be performed here.
+    ///
+    /// @param private_key The private key of the validator
+    /// @param withdrawal_address The address the bonded credits are returned to when unbonded. This
+    /// must be different from the validator address
+    /// @param amount_credits The amount of credits to bond
+    /// @param commission The commission percentage (0 - 100) the validator charges its delegators
+    /// @param fee_credits The amount of credits to pay as a fee
+    /// @param fee_record The record to spend the fee from
+    /// @param url The url of the Aleo network node to query, or a `NetworkQuery`
+    /// @param bond_proving_key (optional) Provide a proving key to use for the bond_validator function
+    /// @param bond_verifying_key (optional) Provide a verifying key to use for the bond_validator function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @param priority_fee_credits (optional) The amount of credits to pay as a priority fee
+    /// @param on_progress (optional) A function called with the `ProgressStage` that is starting each
+    /// time the transaction build moves to a new stage
+    /// @param signal (optional) An `AbortSignal` which stops the transaction build before its next
+    /// stage once it has been aborted
+    /// @returns {Transaction}
+    #[wasm_bindgen(js_name = buildBondValidatorTransaction)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn bond_validator(
+        &self,
+        private_key: &PrivateKey,
+        withdrawal_address: &str,
+        amount_credits: f64,
+        commission: u8,
+        fee_credits: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: Option<NetworkQueryLike>,
+        bond_proving_key: Option<ProvingKey>,
+        bond_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+        offline_query: Option<OfflineQuery>,
+        priority_fee_credits: Option<f64>,
+        on_progress: Option<Function>,
+        signal: Option<AbortSignal>,
+    ) -> Result<Transaction, SdkError> {
+        log("Executing bond_validator program");
+        let withdrawal_address = Self::validate_address(withdrawal_address, "Withdrawal")?;
+        if withdrawal_address == private_key.to_address() {
+            return Err(SdkError::InvalidArgument(
+                "The withdrawal address must be different from the validator address".to_string(),
+            ));
+        }
+        let amount_microcredits = Self::validate_bond_amount(amount_credits)?;
+        if commission > MAXIMUM_COMMISSION {
+            return Err(SdkError::InvalidArgument(format!(
+                "Commission must be between 0 and {MAXIMUM_COMMISSION} percent"
+            )));
+        }
+
+        let inputs = [
+            JsValue::from_str(&withdrawal_address.to_string()),
+            JsValue::from_str(&amount_microcredits.to_string().add("u64")),
+            JsValue::from_str(&commission.to_string().add("u8")),
+        ]
+        .into_iter()
+        .collect::<Array>();
+
+        self.execute_staking_function(
+            private_key,
+            "bond_validator",
+            inputs,
+            fee_credits,
+            fee_record,
+            url,
+            bond_proving_key,
+            bond_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+            offline_query,
+            priority_fee_credits,
+            on_progress,
+            signal,
+        )
+        .await
+    }
+
+    /// Unbond credits from a validator. The unbonded credits can be claimed with a
+    /// `claim_unbond_public` transaction once the unbonding period has passed
+    ///
+    /// @param private_key The private key of the staker or of the staker's withdrawal address
+    /// @param staker_address The address of the staker whose credits are being unbonded
+    /// @param amount_credits The amount of credits to unbond
+    /// @param fee_credits The amount of credits to pay as a fee
+    /// @param fee_record The record to spend the fee from
+    /// @param url The url of the Aleo network node to query, or a `NetworkQuery`
+    /// @param unbond_proving_key (optional) Provide a proving key to use for the unbond_public function
+    /// @param unbond_verifying_key (optional) Provide a verifying key to use for the unbond_public function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @param priority_fee_credits (optional) The amount of credits to pay as a priority fee
+    /// @param on_progress (optional) A function called with the `ProgressStage` that is starting each
+    /// time the transaction build moves to a new stage
+    /// @param signal (optional) An `AbortSignal` which stops the transaction build before its next
+    /// stage once it has been aborted
+    /// @returns {Transaction}
+    #[wasm_bindgen(js_name = buildUnbondPublicTransaction)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn unbond_public(
+        &self,
+        private_key: &PrivateKey,
+        staker_address: &str,
+        amount_credits: f64,
+        fee_credits: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: Option<NetworkQueryLike>,
+        unbond_proving_key: Option<ProvingKey>,
+        unbond_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+        offline_query: Option<OfflineQuery>,
+        priority_fee_credits: Option<f64>,
+        on_progress: Option<Function>,
+        signal: Option<AbortSignal>,
+    ) -> Result<Transaction, SdkError> {
+        log("Executing unbond_public program");
+        let staker_address = Self::validate_address(staker_address, "Staker")?;
+        let amount_microcredits = Self::credits_to_microcredits(amount_credits, "Unbond amount")?;
+        if amount_microcredits == 0 {
+            return Err(SdkError::InvalidArgument("Amount must be greater than zero to unbond credits".to_string()));
+        }
+
+        let inputs = [
+            JsValue::from_str(&staker_address.to_string()),
+            JsValue::from_str(&amount_microcredits.to_string().add("u64")),
+        ]
+        .into_iter()
+        .collect::<Array>();
+
+        self.execute_staking_function(
+            private_key,
+            "unbond_public",
+            inputs,
+            fee_credits,
+            fee_record,
+            url,
+            unbond_proving_key,
+            unbond_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+            offline_query,
+            priority_fee_credits,
+            on_progress,
+            signal,
+        )
+        .await
+    }
+
+    /// Claim credits that have finished unbonding and return them to the staker's withdrawal
+    /// address
+    ///
+    /// @param private_key The private key of the account paying for the transaction
+    /// @param staker_address The address of the staker whose unbonded credits are being claimed
+    /// @param fee_credits The amount of credits to pay as a fee
+    /// @param fee_record The record to spend the fee from
+    /// @param url The url of the Aleo network node to query, or a `NetworkQuery`
+    /// @param claim_proving_key (optional) Provide a proving key to use for the claim_unbond_public function
+    /// @param claim_verifying_key (optional) Provide a verifying key to use for the claim_unbond_public function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @param priority_fee_credits (optional) The amount of credits to pay as a priority fee
+    /// @param on_progress (optional) A function called with the `ProgressStage` that is starting each
+    /// time the transaction build moves to a new stage
+    /// @param signal (optional) An `AbortSignal` which stops the transaction build before its next
+    /// stage once it has been aborted
+    /// @returns {Transaction}
+    #[wasm_bindgen(js_name = buildClaimUnbondPublicTransaction)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn claim_unbond_public(
+        &self,
+        private_key: &PrivateKey,
+        staker_address: &str,
+        fee_credits: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: Option<NetworkQueryLike>,
+        claim_proving_key: Option<ProvingKey>,
+        claim_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+        offline_query: Option<OfflineQuery>,
+        priority_fee_credits: Option<f64>,
+        on_progress: Option<Function>,
+        signal: Option<AbortSignal>,
+    ) -> Result<Transaction, SdkError> {
+        log("Executing claim_unbond_public program");
+        let staker_address = Self::validate_address(staker_address, "Staker")?;
+
+        let inputs = [JsValue::from_str(&staker_address.to_string())].into_iter().collect::<Array>();
+
+        self.execute_staking_function(
+            private_key,
+            "claim_unbond_public",
+            inputs,
+            fee_credits,
+            fee_record,
+            url,
+            claim_proving_key,
+            claim_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+            offline_query,
+            priority_fee_credits,
+            on_progress,
+            signal,
+        )
+        .await
+    }
+
+    /// Open or close a validator to new delegators. This must be called by the validator itself
+    ///
+    /// @param private_key The private key of the validator
+    /// @param is_open Whether the validator accepts new delegators
+    /// @param fee_credits The amount of credits to pay as a fee
+    /// @param fee_record The record to spend the fee from
+    /// @param url The url of the Aleo network node to query, or a `NetworkQuery`
+    /// @param set_state_proving_key (optional) Provide a proving key to use for the set_validator_state function
+    /// @param set_state_verifying_key (optional) Provide a verifying key to use for the set_validator_state function
+    /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
+    /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @param priority_fee_credits (optional) The amount of credits to pay as a priority fee
+    /// @param on_progress (optional) A function called with the `ProgressStage` that is starting each
+    /// time the transaction build moves to a new stage
+    /// @param signal (optional) An `AbortSignal` which stops the transaction build before its next
+    /// stage once it has been aborted
+    /// @returns {Transaction}
+    #[wasm_bindgen(js_name = buildSetValidatorStateTransaction)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn set_validator_state(
+        &self,
+        private_key: &PrivateKey,
+        is_open: bool,
+        fee_credits: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: Option<NetworkQueryLike>,
+        set_state_proving_key: Option<ProvingKey>,
+        set_state_verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+        offline_query: Option<OfflineQuery>,
+        priority_fee_credits: Option<f64>,
+        on_progress: Option<Function>,
+        signal: Option<AbortSignal>,
+    ) -> Result<Transaction, SdkError> {
+        log("Executing set_validator_state program");
+        let inputs = [JsValue::from_str(&is_open.to_string())].into_iter().collect::<Array>();
+
+        self.execute_staking_function(
+            private_key,
+            "set_validator_state",
+            inputs,
+            fee_credits,
+            fee_record,
+            url,
+            set_state_proving_key,
+            set_state_verifying_key,
+            fee_proving_key,
+            fee_verifying_key,
+            offline_query,
+            priority_fee_credits,
+            on_progress,
+            signal,
+        )
+        .await
+    }
+
+    /// Validate that a string is a valid Aleo address
+    pub(crate) fn validate_address(address: &str, name: &str) -> Result<Address, SdkError> {
+        Address::from_str(address)
+            .map_err(|_| SdkError::InvalidArgument(format!("{name} address {address} is not a valid Aleo address")))
+    }
+
+    /// Validate that a bond amount meets the minimum accepted by `credits.aleo`
+    pub(crate) fn validate_bond_amount(amount_credits: f64) -> Result<u64, SdkError> {
+        let amount_microcredits = Self::credits_to_microcredits(amount_credits, "Bond amount")?;
+        if amount_microcredits < MINIMUM_BOND_MICROCREDITS {
+            return Err(SdkError::InvalidArgument(format!(
+                "Bond amount must be at least {} credits",
+                MINIMUM_BOND_MICROCREDITS as f64 / 1_000_000.0
+            )));
+        }
+        Ok(amount_microcredits)
+    }
+
+    /// Execute a public staking function in `credits.aleo` and create the resulting transaction
+    #[allow(clippy::too_many_arguments)]
+    async fn execute_staking_function(
+        &self,
+        private_key: &PrivateKey,
+        function: &str,
+        inputs: Array,
+        fee_credits: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: Option<NetworkQueryLike>,
+        proving_key: Option<ProvingKey>,
+        verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+        offline_query: Option<OfflineQuery>,
+        priority_fee_credits: Option<f64>,
+        on_progress: Option<Function>,
+        signal: Option<AbortSignal>,
+    ) -> Result<Transaction, SdkError> {
+        let progress = Progress::new(on_progress, signal);
+        let (fee_microcredits, priority_fee_microcredits) =
+            Self::validate_fee(fee_credits, priority_fee_credits, fee_record.as_ref())?;
+
+        log("Setup the program and inputs");
+        let query = NetworkQuery::from_js(url)?;
+        let rng = &mut StdRng::from_entropy();
+
+        with_network!(&mut *self.lock_process().await, |process| {
+            let private_key: PrivateKeyNative = private_key.into_network()?;
+            progress.stage(ProgressStage::LoadProgram).await?;
+            let program = ProgramNative::credits().map_err(|e| e.to_string())?.to_string();
+            let fee_identifier = if fee_record.is_some() {
+                IdentifierNative::from_str("fee_private").map_err(|e| e.to_string())?
+            } else {
+                IdentifierNative::from_str("fee_public").map_err(|e| e.to_string())?
+            };
+            let stack = process.get_stack("credits.aleo").map_err(|e| e.to_string())?;
+            if !stack.contains_proving_key(&fee_identifier) && fee_proving_key.is_some() && fee_verifying_key.is_some()
+            {
+                let fee_proving_key = fee_proving_key.clone().unwrap();
+                let fee_verifying_key = fee_verifying_key.clone().unwrap();
+                stack
+                    .insert_proving_key(&fee_identifier, fee_proving_key.into_network()?)
+                    .map_err(|e| SdkError::KeyMismatch(e.to_string()))?;
+                stack
+                    .insert_verifying_key(&fee_identifier, fee_verifying_key.into_network()?)
+                    .map_err(|e| SdkError::KeyMismatch(e.to_string()))?;
+            }
+
+            log(&format!("Executing the {function} function"));
+            let (_, mut trace) = execute_program!(
+                process,
+                process_inputs!(inputs),
+                &program,
+                function,
+                &private_key,
+                proving_key,
+                verifying_key,
+                rng,
+                progress
+            );
+
+            progress.stage(ProgressStage::PrepareInclusion).await?;
+            log(&format!("Preparing the inclusion proof for the {function} execution"));
+            if let Some(offline_query) = offline_query.as_ref() {
+                trace.prepare_async(offline_query.clone()).await.map_err(|err| err.to_string())?;
+            } else {
+                trace.prepare_async(query.clone()).await.map_err(|err| SdkError::QueryFailed(err.to_string()))?;
+            }
+
+            progress.stage(ProgressStage::Prove).await?;
+            log(&format!("Proving the {function} execution"));
+            let locator = format!("credits.aleo/{function}");
+            let execution = trace
+                .prove_execution::<CurrentAleo, _>(&locator, rng)
+                .map_err(|e| SdkError::ProofFailed(e.to_string()))?;
+            let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
+
+            progress.stage(ProgressStage::Verify).await?;
+            log(&format!("Verifying the {function} execution"));
+            process.verify_execution(&execution).map_err(|err| err.to_string())?;
+
+            log("Executing the fee");
+            let fee = execute_fee!(
+                process,
+                &private_key,
+                fee_record,
+                fee_microcredits,
+                priority_fee_microcredits,
+                &query,
+                fee_proving_key,
+                fee_verifying_key,
+                execution_id,
+                rng,
+                offline_query,
+                progress
+            );
+
+            log(&format!("Creating execution transaction for {function}"));
+            let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
+            Ok(Transaction::from_native(transaction)?)
+        })
+    }
+}
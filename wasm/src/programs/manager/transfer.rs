@@ -21,20 +21,21 @@ use crate::{
     execute_program,
     log,
     process_inputs,
-    OfflineQuery, PrivateKey, RecordPlaintext, Transaction,
+    types::NetworkAgnostic,
+    with_network,
+    OfflineQuery,
+    PrivateKey,
+    Progress,
+    ProgressStage,
+    RecordPlaintext,
+    Transaction,
 };
 
-use crate::types::native::{
-    CurrentAleo,
-    IdentifierNative,
-    ProcessNative,
-    ProgramNative,
-    RecordPlaintextNative,
-    TransactionNative,
-};
+use js_sys::Function;
 use rand::{rngs::StdRng, SeedableRng};
 use std::{ops::Add, str::FromStr};
 use wasm_bindgen::JsValue;
+use web_sys::AbortSignal;
 
 #[wasm_bindgen]
 impl ProgramManager {
@@ -47,15 +48,21 @@ impl ProgramManager {
     /// @param amount_record The record to fund the amount from
     /// @param fee_credits The amount of credits to pay as a fee
     /// @param fee_record The record to spend the fee from
-    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param url The url of the Aleo network node to query, or a `NetworkQuery`
     /// @param transfer_verifying_key (optional) Provide a verifying key to use for the transfer
     /// function
     /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
     /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
+    /// @param priority_fee_credits (optional) The amount of credits to pay as a priority fee
+    /// @param on_progress (optional) A function called with the `ProgressStage` that is starting each
+    /// time the transaction build moves to a new stage
+    /// @param signal (optional) An `AbortSignal` which stops the transaction build before its next
+    /// stage once it has been aborted
     /// @returns {Transaction}
     #[wasm_bindgen(js_name = buildTransferTransaction)]
     #[allow(clippy::too_many_arguments)]
     pub async fn transfer(
+        &self,
         private_key: &PrivateKey,
         amount_credits: f64,
         recipient: &str,
@@ -63,26 +70,27 @@ impl ProgramManager {
         amount_record: Option<RecordPlaintext>,
         fee_credits: f64,
         fee_record: Option<RecordPlaintext>,
-        url: Option<String>,
+        url: Option<NetworkQueryLike>,
         transfer_proving_key: Option<ProvingKey>,
         transfer_verifying_key: Option<VerifyingKey>,
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
         offline_query: Option<OfflineQuery>,
-    ) -> Result<Transaction, String> {
+        priority_fee_credits: Option<f64>,
+        on_progress: Option<Function>,
+        signal: Option<AbortSignal>,
+    ) -> Result<Transaction, SdkError> {
         log("Executing transfer program");
-        let fee_microcredits = match &fee_record {
-            Some(fee_record) => Self::validate_amount(fee_credits, fee_record, true)?,
-            None => (fee_credits * 1_000_000.0) as u64,
-        };
+        let progress = Progress::new(on_progress, signal);
+        let (fee_microcredits, priority_fee_microcredits) =
+            Self::validate_fee(fee_credits, priority_fee_credits, fee_record.as_ref())?;
         let amount_microcredits = match &amount_record {
             Some(amount_record) => Self::validate_amount(amount_credits, amount_record, true)?,
             None => (amount_credits * 1_000_000.0) as u64,
         };
 
         log("Setup the program and inputs");
-        let node_url = url.as_deref().unwrap_or(DEFAULT_URL);
-        let program = ProgramNative::credits().unwrap().to_string();
+        let query = NetworkQuery::from_js(url)?;
         let rng = &mut StdRng::from_entropy();
 
         log("Transfer Type is:");
@@ -91,7 +99,9 @@ impl ProgramManager {
         let (transfer_type, inputs) = match transfer_type {
             "private" | "transfer_private" | "transferPrivate" => {
                 if amount_record.is_none() {
-                    return Err("Amount record must be provided for private transfers".to_string());
+                    return Err(SdkError::InvalidArgument(
+                        "Amount record must be provided for private transfers".to_string(),
+                    ));
                 }
                 let inputs = [
                     JsValue::from_str(&amount_record.unwrap().to_string()),
@@ -104,7 +114,9 @@ impl ProgramManager {
             }
             "private_to_public" | "privateToPublic" | "transfer_private_to_public" | "transferPrivateToPublic" => {
                 if amount_record.is_none() {
-                    return Err("Amount record must be provided for private transfers".to_string());
+                    return Err(SdkError::InvalidArgument(
+                        "Amount record must be provided for private transfers".to_string(),
+                    ));
                 }
                 let inputs = [
                     JsValue::from_str(&amount_record.unwrap().to_string()),
@@ -133,72 +145,82 @@ impl ProgramManager {
                     .collect::<js_sys::Array>();
                 ("transfer_public_to_private", inputs)
             }
-            _ => return Err("Invalid transfer type".to_string()),
+            _ => return Err(SdkError::InvalidArgument("Invalid transfer type".to_string())),
         };
 
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
-        let process = &mut process_native;
-        let fee_identifier = if fee_record.is_some() {
-            IdentifierNative::from_str("fee_private").map_err(|e| e.to_string())?
-        } else {
-            IdentifierNative::from_str("fee_public").map_err(|e| e.to_string())?
-        };
-        let stack = process.get_stack("credits.aleo").map_err(|e| e.to_string())?;
-        if !stack.contains_proving_key(&fee_identifier) && fee_proving_key.is_some() && fee_verifying_key.is_some() {
-            let fee_proving_key = fee_proving_key.clone().unwrap();
-            let fee_verifying_key = fee_verifying_key.clone().unwrap();
-            stack
-                .insert_proving_key(&fee_identifier, ProvingKeyNative::from(fee_proving_key))
-                .map_err(|e| e.to_string())?;
-            stack
-                .insert_verifying_key(&fee_identifier, VerifyingKeyNative::from(fee_verifying_key))
-                .map_err(|e| e.to_string())?;
-        }
-
-        log("Executing transfer function");
-        let (_, mut trace) = execute_program!(
-            process,
-            process_inputs!(inputs),
-            &program,
-            transfer_type,
-            private_key,
-            transfer_proving_key,
-            transfer_verifying_key,
-            rng
-        );
-
-        log("Preparing the inclusion proof for the transfer execution");
-        if let Some(offline_query) = offline_query.as_ref() {
-            trace.prepare_async(offline_query.clone()).await.map_err(|err| err.to_string())?;
-        } else {
-            let query = QueryNative::from(node_url);
-            trace.prepare_async(query).await.map_err(|err| err.to_string())?;
-        }
-
-        log("Proving the transfer execution");
-        let execution =
-            trace.prove_execution::<CurrentAleo, _>("credits.aleo/transfer", rng).map_err(|e| e.to_string())?;
-        let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
-
-        log("Verifying the transfer execution");
-        process.verify_execution(&execution).map_err(|err| err.to_string())?;
-
-        log("Executing the fee");
-        let fee = execute_fee!(
-            process,
-            private_key,
-            fee_record,
-            fee_microcredits,
-            node_url,
-            fee_proving_key,
-            fee_verifying_key,
-            execution_id,
-            rng,
-            offline_query
-        );
-
-        log("Creating execution transaction for transfer");
-        let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
-        Ok(Transaction::from(transaction))
+        with_network!(&mut *self.lock_process().await, |process| {
+            let private_key: PrivateKeyNative = private_key.into_network()?;
+            progress.stage(ProgressStage::LoadProgram).await?;
+            let program = ProgramNative::credits().map_err(|e| e.to_string())?.to_string();
+            let fee_identifier = if fee_record.is_some() {
+                IdentifierNative::from_str("fee_private").map_err(|e| e.to_string())?
+            } else {
+                IdentifierNative::from_str("fee_public").map_err(|e| e.to_string())?
+            };
+            let stack = process.get_stack("credits.aleo").map_err(|e| e.to_string())?;
+            if !stack.contains_proving_key(&fee_identifier) && fee_proving_key.is_some() && fee_verifying_key.is_some()
+            {
+                let fee_proving_key = fee_proving_key.clone().unwrap();
+                let fee_verifying_key = fee_verifying_key.clone().unwrap();
+                stack
+                    .insert_proving_key(&fee_identifier, fee_proving_key.into_network()?)
+                    .map_err(|e| SdkError::KeyMismatch(e.to_string()))?;
+                stack
+                    .insert_verifying_key(&fee_identifier, fee_verifying_key.into_network()?)
+                    .map_err(|e| SdkError::KeyMismatch(e.to_string()))?;
+            }
+
+            log("Executing transfer function");
+            let (_, mut trace) = execute_program!(
+                process,
+                process_inputs!(inputs),
+                &program,
+                transfer_type,
+                &private_key,
+                transfer_proving_key,
+                transfer_verifying_key,
+                rng,
+                progress
+            );
+
+            progress.stage(ProgressStage::PrepareInclusion).await?;
+            log("Preparing the inclusion proof for the transfer execution");
+            if let Some(offline_query) = offline_query.as_ref() {
+                trace.prepare_async(offline_query.clone()).await.map_err(|err| err.to_string())?;
+            } else {
+                trace.prepare_async(query.clone()).await.map_err(|err| SdkError::QueryFailed(err.to_string()))?;
+            }
+
+            progress.stage(ProgressStage::Prove).await?;
+            log("Proving the transfer execution");
+            let execution = trace
+                .prove_execution::<CurrentAleo, _>("credits.aleo/transfer", rng)
+                .map_err(|e| SdkError::ProofFailed(e.to_string()))?;
+            let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
+
+            progress.stage(ProgressStage::Verify).await?;
+            log("Verifying the transfer execution");
+            process.verify_execution(&execution).map_err(|err| err.to_string())?;
+
+            log("Executing the fee");
+            let fee = execute_fee!(
+                process,
+                &private_key,
+                fee_record,
+                fee_microcredits,
+                priority_fee_microcredits,
+                &query,
+                fee_proving_key,
+                fee_verifying_key,
+                execution_id,
+                rng,
+                offline_query,
+                progress
+            );
+
+            log("Creating execution transaction for transfer");
+            let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
+            Ok(Transaction::from_native(transaction)?)
+        })
     }
 }
@@ -16,23 +16,49 @@
 
 mod macros;
 
+pub mod authorization;
+pub use authorization::*;
+
+pub mod confirmation;
+pub use confirmation::*;
+
+pub mod cost;
+pub use cost::*;
+
 pub mod execution;
 pub use execution::*;
 
 pub mod keypair;
 pub use keypair::*;
 
-#[cfg(feature = "browser")]
+#[cfg(all(feature = "browser", not(feature = "native")))]
 pub mod manager;
-#[cfg(feature = "browser")]
+#[cfg(all(feature = "browser", not(feature = "native")))]
 pub use manager::*;
 
+pub(crate) mod local_chain;
+pub(crate) use local_chain::*;
+
+pub mod local_ledger;
+pub use local_ledger::*;
+
+pub mod network_query;
+pub use network_query::*;
+
 pub mod offline_query;
 pub use offline_query::*;
 
+pub(crate) mod process;
+pub(crate) use process::*;
+
 pub mod program;
 pub use program::*;
 
+#[cfg(not(feature = "native"))]
+pub mod progress;
+#[cfg(not(feature = "native"))]
+pub use progress::*;
+
 pub mod proving_key;
 pub use proving_key::*;
 
@@ -42,5 +68,8 @@ pub use response::*;
 pub mod transaction;
 pub use transaction::*;
 
+pub mod transition;
+pub use transition::*;
+
 pub mod verifying_key;
 pub use verifying_key::*;
@@ -0,0 +1,386 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::native::{Field, Network as NetworkTrait},
+    Network,
+    SdkError,
+};
+use snarkvm_console::program::StatePath;
+use snarkvm_ledger_query::QueryTrait;
+
+use anyhow::{anyhow, bail, ensure};
+use async_trait::async_trait;
+use futures::future::{select, Either};
+use indexmap::IndexMap;
+#[cfg(not(feature = "native"))]
+use js_sys::Array;
+use reqwest::header::{HeaderName, HeaderValue};
+use serde::de::DeserializeOwned;
+use std::{
+    collections::BTreeMap,
+    future::Future,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::prelude::*;
+
+/// Endpoint queried for the state root and state paths when none is configured
+pub const DEFAULT_ENDPOINT: &str = "https://api.explorer.provable.org/v1";
+
+/// The number of block heights whose state roots are cached
+const CACHED_STATE_ROOTS: usize = 16;
+
+/// The longest delay between two attempts to query the endpoints
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+#[cfg(not(feature = "native"))]
+#[wasm_bindgen]
+extern "C" {
+    /// The url of a node or a `NetworkQuery`, accepted by the program manager wherever it queries
+    /// the network
+    #[wasm_bindgen(typescript_type = "string | NetworkQuery")]
+    pub type NetworkQueryLike;
+}
+
+/// A client of the REST API of Aleo nodes used to fetch the global state root and the state paths
+/// needed to create inclusion proofs, and to broadcast transactions and track their confirmation.
+///
+/// Requests go to the endpoints in order, failing over to the next endpoint when one does not
+/// respond with a success within the timeout. Once every endpoint failed, the requests are retried
+/// with an exponential backoff. Transactions are broadcast once to the first endpoint only, as a
+/// broadcast that timed out may still have reached the node. Custom headers, e.g. API keys, are sent with every request. The
+/// state root is cached per block height, and clones of a query share the cache.
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone, Debug)]
+pub struct NetworkQuery {
+    endpoints: Vec<String>,
+    headers: IndexMap<String, String>,
+    timeout: Duration,
+    retries: u32,
+    backoff: Duration,
+    state_roots: Arc<Mutex<BTreeMap<u32, String>>>,
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl NetworkQuery {
+    /// Create a query of the given endpoints, which are tried in order
+    ///
+    /// @param {Array<string> | undefined} endpoints (optional) Urls of the REST API of nodes,
+    /// defaults to the Provable explorer API
+    /// @returns {NetworkQuery}
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen(constructor)]
+    pub fn new(endpoints: Option<Array>) -> Result<NetworkQuery, String> {
+        let endpoints = match endpoints {
+            Some(endpoints) => endpoints
+                .iter()
+                .enumerate()
+                .map(|(index, endpoint)| endpoint.as_string().ok_or(format!("Endpoint {index} is not a string")))
+                .collect::<Result<Vec<_>, _>>()?,
+            None => vec![DEFAULT_ENDPOINT.to_string()],
+        };
+        Self::from_endpoints(endpoints)
+    }
+
+    /// Get the endpoints of the query
+    ///
+    /// @returns {Array<string>}
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen]
+    pub fn endpoints(&self) -> Array {
+        self.endpoints.iter().map(|endpoint| JsValue::from_str(endpoint)).collect()
+    }
+
+    /// Send a header with every request, e.g. an API key
+    ///
+    /// @param {string} name Name of the header
+    /// @param {string} value Value of the header
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "setHeader"))]
+    pub fn set_header(&mut self, name: &str, value: &str) -> Result<(), String> {
+        HeaderName::from_str(name).map_err(|_| format!("{name} is not a valid header name"))?;
+        HeaderValue::from_str(value).map_err(|_| format!("The value of the header {name} is invalid"))?;
+        self.headers.insert(name.to_string(), value.to_string());
+        Ok(())
+    }
+
+    /// Set how long to wait for an endpoint to respond before failing over to the next one
+    ///
+    /// @param {number} timeout_ms Timeout in milliseconds, defaults to 30 seconds
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "setTimeout"))]
+    pub fn set_timeout(&mut self, timeout_ms: u32) {
+        self.timeout = Duration::from_millis(timeout_ms as u64);
+    }
+
+    /// Set how many times the endpoints are tried again once all of them failed and the delay
+    /// before the first retry, which doubles with each retry
+    ///
+    /// @param {number} retries Number of retries, defaults to 3
+    /// @param {number} backoff_ms Delay before the first retry in milliseconds, defaults to 1 second
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "setRetries"))]
+    pub fn set_retries(&mut self, retries: u32, backoff_ms: u32) {
+        self.retries = retries;
+        self.backoff = Duration::from_millis(backoff_ms as u64);
+    }
+
+    /// Create a copy of the query sharing its cache
+    ///
+    /// @returns {NetworkQuery}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "clone"))]
+    pub fn clone_query(&self) -> NetworkQuery {
+        self.clone()
+    }
+}
+
+impl NetworkQuery {
+    /// Create a query of the given endpoints, which are tried in order
+    pub fn from_endpoints<S: Into<String>>(endpoints: impl IntoIterator<Item = S>) -> Result<Self, String> {
+        let endpoints =
+            endpoints.into_iter().map(|endpoint| endpoint.into().trim_end_matches('/').to_string()).collect::<Vec<_>>();
+        if endpoints.is_empty() {
+            return Err("At least one endpoint is required".to_string());
+        }
+        if let Some(endpoint) =
+            endpoints.iter().find(|endpoint| !endpoint.starts_with("http://") && !endpoint.starts_with("https://"))
+        {
+            return Err(format!("Endpoint {endpoint} must be an http or https url"));
+        }
+        Ok(Self {
+            endpoints,
+            headers: IndexMap::new(),
+            timeout: Duration::from_secs(30),
+            retries: 3,
+            backoff: Duration::from_secs(1),
+            state_roots: Arc::new(Mutex::new(BTreeMap::new())),
+        })
+    }
+
+    /// Get the query passed to the program manager in place of a url, the default query if none
+    /// was passed
+    #[cfg(not(feature = "native"))]
+    pub(crate) fn from_js(query: Option<NetworkQueryLike>) -> Result<Self, SdkError> {
+        let Some(query) = query.map(JsValue::from).filter(|query| !query.is_undefined() && !query.is_null()) else {
+            return Ok(Self::default());
+        };
+        if let Some(url) = query.as_string() {
+            return Self::from_endpoints([url]).map_err(SdkError::InvalidArgument);
+        }
+        // Converting a JS object into a query moves the query out of it, so convert a copy made
+        // by its `clone` method to leave the query of the caller usable
+        let invalid = || SdkError::InvalidArgument("Expected a url or a NetworkQuery".to_string());
+        let copy = js_sys::Reflect::get(&query, &"clone".into())
+            .ok()
+            .and_then(|clone| clone.dyn_into::<js_sys::Function>().ok())
+            .and_then(|clone| clone.call0(&query).ok())
+            .ok_or_else(invalid)?;
+        <Self as wasm_bindgen::convert::TryFromJsValue>::try_from_js_value(copy).map_err(|_| invalid())
+    }
+
+    /// Get the delay before a retry, doubling with each retry
+    fn backoff_delay(&self, retry: u32) -> Duration {
+        self.backoff.saturating_mul(2u32.saturating_pow(retry.saturating_sub(1))).min(MAX_BACKOFF)
+    }
+
+    /// Get a resource of the network from the first endpoint responding, retrying with an
+    /// exponential backoff when all endpoints fail
+    pub(crate) async fn get<N: NetworkTrait, T: DeserializeOwned>(&self, path: &str) -> anyhow::Result<T> {
+        self.request::<N, T>(path).await
+    }
+
+    /// Post a json body to a resource of the network. The body is sent once to the first endpoint
+    /// without failing over or retrying, since a request that failed may still have been applied.
+    pub(crate) async fn post<N: NetworkTrait, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: String,
+    ) -> anyhow::Result<T> {
+        let network = Network::of::<N>();
+        self.fetch(&format!("{}/{network}/{path}", self.endpoints[0]), Some(body))
+            .await
+            .map_err(|e| anyhow!("Failed to post {path} to the network: {e}"))
+    }
+
+    /// Get a resource from the endpoints in order until one of them responds with a success
+    async fn request<N: NetworkTrait, T: DeserializeOwned>(&self, path: &str) -> anyhow::Result<T> {
+        let network = Network::of::<N>();
+        let mut errors = Vec::new();
+        for retry in 0..=self.retries {
+            if retry > 0 {
+                sleep(self.backoff_delay(retry)).await;
+            }
+            for endpoint in &self.endpoints {
+                match self.fetch(&format!("{endpoint}/{network}/{path}"), None).await {
+                    Ok(value) => return Ok(value),
+                    Err(error) => errors.push(error.to_string()),
+                }
+            }
+        }
+        bail!("Failed to get {path} from the network: {}", errors.join(", "))
+    }
+
+    /// Get a json resource from a url, posting the json body if there is one
+    async fn fetch<T: DeserializeOwned>(&self, url: &str, body: Option<String>) -> anyhow::Result<T> {
+        let client = reqwest::Client::new();
+        let mut request = match body {
+            Some(body) => client.post(url).header("Content-Type", "application/json").body(body),
+            None => client.get(url),
+        };
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+        let response = timeout(self.timeout, request.send()).await??;
+        let status = response.status();
+        let body = timeout(self.timeout, response.text()).await??;
+        ensure!(status.is_success(), "{url} responded with {status}: {body}");
+        serde_json::from_str(&body).map_err(|e| anyhow!("{url} responded with an invalid body: {e}"))
+    }
+}
+
+impl Default for NetworkQuery {
+    fn default() -> Self {
+        Self::from_endpoints([DEFAULT_ENDPOINT]).unwrap()
+    }
+}
+
+impl PartialEq for NetworkQuery {
+    fn eq(&self, other: &Self) -> bool {
+        self.endpoints == other.endpoints
+            && self.headers == other.headers
+            && self.timeout == other.timeout
+            && self.retries == other.retries
+            && self.backoff == other.backoff
+    }
+}
+
+impl Eq for NetworkQuery {}
+
+#[async_trait(?Send)]
+impl<N: NetworkTrait> QueryTrait<N> for NetworkQuery {
+    fn current_state_root(&self) -> anyhow::Result<N::StateRoot> {
+        bail!("A network query can only be used asynchronously")
+    }
+
+    async fn current_state_root_async(&self) -> anyhow::Result<N::StateRoot> {
+        let height: u32 = self.get::<N, _>("latest/height").await?;
+        let cached = self.state_roots.lock().unwrap().get(&height).cloned();
+        if let Some(state_root) = cached {
+            return N::StateRoot::from_str(&state_root).map_err(|e| anyhow!("{e}"));
+        }
+
+        let state_root: N::StateRoot = self.get::<N, _>(&format!("stateRoot/{height}")).await?;
+        let mut state_roots = self.state_roots.lock().unwrap();
+        state_roots.insert(height, state_root.to_string());
+        while state_roots.len() > CACHED_STATE_ROOTS {
+            state_roots.pop_first();
+        }
+        Ok(state_root)
+    }
+
+    fn get_state_path_for_commitment(&self, _commitment: &Field<N>) -> anyhow::Result<StatePath<N>> {
+        bail!("A network query can only be used asynchronously")
+    }
+
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> anyhow::Result<StatePath<N>> {
+        self.get::<N, _>(&format!("statePath/{commitment}")).await
+    }
+}
+
+/// Wait for a future to complete, failing once the timeout elapses
+pub(crate) async fn timeout<F: Future>(duration: Duration, future: F) -> anyhow::Result<F::Output> {
+    let delay = sleep(duration);
+    futures::pin_mut!(future, delay);
+    match select(future, delay).await {
+        Either::Left((output, _)) => Ok(output),
+        Either::Right(_) => bail!("No response within {} ms", duration.as_millis()),
+    }
+}
+
+/// Wait for a duration on the tokio runtime
+#[cfg(feature = "native")]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await
+}
+
+/// Wait for a duration with the `setTimeout` of the javascript environment
+#[cfg(not(feature = "native"))]
+pub(crate) async fn sleep(duration: Duration) {
+    let promise =
+        js_sys::Promise::new(&mut |resolve, _| match js_sys::Reflect::get(&js_sys::global(), &"setTimeout".into()) {
+            Ok(set_timeout) if set_timeout.is_function() => {
+                let set_timeout = js_sys::Function::from(set_timeout);
+                let _ = set_timeout.call2(&JsValue::NULL, &resolve, &JsValue::from(duration.as_millis() as f64));
+            }
+            _ => {
+                let _ = resolve.call0(&JsValue::NULL);
+            }
+        });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_endpoints_and_headers() {
+        let query = NetworkQuery::from_endpoints(["https://node-a.example/v1/", "http://node-b.example"]).unwrap();
+        assert_eq!(query.endpoints, ["https://node-a.example/v1", "http://node-b.example"]);
+        assert_eq!(NetworkQuery::default().endpoints, [DEFAULT_ENDPOINT]);
+        assert!(NetworkQuery::from_endpoints(Vec::<String>::new()).is_err());
+        assert!(NetworkQuery::from_endpoints(["node.example"]).is_err());
+
+        let mut query = NetworkQuery::default();
+        query.set_header("X-API-Key", "secret").unwrap();
+        assert_eq!(query.headers.get("X-API-Key").unwrap(), "secret");
+        assert!(query.set_header("Invalid Name", "secret").is_err());
+        assert!(query.set_header("X-API-Key", "line\nbreak").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_backoff_and_shared_cache() {
+        let mut query = NetworkQuery::default();
+        query.set_retries(5, 500);
+        let delays = (1..=5).map(|retry| query.backoff_delay(retry).as_millis()).collect::<Vec<_>>();
+        assert_eq!(delays, [500, 1000, 2000, 4000, 8000]);
+        assert_eq!(query.backoff_delay(20), MAX_BACKOFF);
+
+        let clone = query.clone_query();
+        query.state_roots.lock().unwrap().insert(1, "sr1".to_string());
+        assert_eq!(clone.state_roots.lock().unwrap().get(&1).unwrap(), "sr1");
+        assert_eq!(clone, query);
+    }
+
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen_test]
+    fn test_from_js_leaves_the_query_usable() {
+        let mut query = NetworkQuery::from_endpoints(["https://node.example"]).unwrap();
+        query.set_header("X-API-Key", "secret").unwrap();
+        let value = JsValue::from(query.clone());
+
+        // Ensure the query of the caller can be passed again after it was converted
+        for _ in 0..2 {
+            assert_eq!(NetworkQuery::from_js(Some(value.clone().unchecked_into())).unwrap(), query);
+        }
+        let url = JsValue::from_str("https://node.example/");
+        assert_eq!(NetworkQuery::from_js(Some(url.unchecked_into())).unwrap().endpoints, query.endpoints);
+        assert_eq!(NetworkQuery::from_js(None).unwrap(), NetworkQuery::default());
+        assert!(NetworkQuery::from_js(Some(JsValue::from(5).unchecked_into())).is_err());
+    }
+}
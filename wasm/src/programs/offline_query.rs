@@ -14,33 +14,47 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::native::{CurrentNetwork, Field, Network};
-use snarkvm_console::program::StatePath;
+use crate::types::{
+    native::{Block, CurrentNetwork, Field, Network as NetworkTrait, ToBits},
+    to_network,
+};
+#[cfg(not(feature = "native"))]
+use crate::{
+    types::native::{canary, mainnet, testnet},
+    Network,
+};
+use snarkvm_console::program::{BlockTree, HeaderLeaf, StatePath};
 use snarkvm_ledger_query::QueryTrait;
 
-use anyhow::anyhow;
+use anyhow::{anyhow, ensure};
 use async_trait::async_trait;
 use indexmap::IndexMap;
+#[cfg(not(feature = "native"))]
+use js_sys::Array;
 use serde::{Deserialize, Serialize};
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::prelude::wasm_bindgen;
 
+#[cfg(not(feature = "native"))]
+use std::fmt::Display;
 use std::str::FromStr;
 
 /// An offline query object used to insert the global state root and state paths needed to create
 /// a valid inclusion proof offline.
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct OfflineQuery {
     state_paths: IndexMap<Field<CurrentNetwork>, StatePath<CurrentNetwork>>,
-    state_root: <CurrentNetwork as Network>::StateRoot,
+    state_root: <CurrentNetwork as NetworkTrait>::StateRoot,
 }
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl OfflineQuery {
     /// Creates a new offline query object. The state root is required to be passed in as a string
-    #[wasm_bindgen(constructor)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(constructor))]
     pub fn new(state_root: &str) -> Result<OfflineQuery, String> {
-        let state_root = <CurrentNetwork as Network>::StateRoot::from_str(state_root).map_err(|e| e.to_string())?;
+        let state_root =
+            <CurrentNetwork as NetworkTrait>::StateRoot::from_str(state_root).map_err(|e| e.to_string())?;
         Ok(Self { state_paths: IndexMap::new(), state_root })
     }
 
@@ -48,7 +62,7 @@ impl OfflineQuery {
     ///
     /// @param {string} commitment: The commitment corresponding to a record inpout
     /// @param {string} state_path: The state path corresponding to the commitment
-    #[wasm_bindgen(js_name = "addStatePath")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "addStatePath"))]
     pub fn add_state_path(&mut self, commitment: &str, state_path: &str) -> Result<(), String> {
         let commitment = Field::from_str(commitment).map_err(|e| e.to_string())?;
         let state_path = StatePath::from_str(state_path).map_err(|e| e.to_string())?;
@@ -56,55 +70,241 @@ impl OfflineQuery {
         Ok(())
     }
 
+    /// Create an offline query from the blocks of a chain, computing the global state root and the
+    /// state paths of the given record commitments. The blocks must start with the genesis block and
+    /// end with the latest block, so they provide every block hash of the block tree.
+    ///
+    /// @param {Array<string>} blocks JSON strings of the blocks, in order of their height
+    /// @param {Array<string>} commitments Commitments of the records created in the blocks
+    /// @param {Network | undefined} network (optional) The network of the blocks, defaults to mainnet
+    /// @returns {OfflineQuery}
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen(js_name = "fromBlocks")]
+    pub fn from_blocks(blocks: Array, commitments: Array, network: Option<Network>) -> Result<OfflineQuery, String> {
+        fn from_blocks<N: NetworkTrait>(blocks: &Array, commitments: &Array) -> Result<OfflineQuery, String> {
+            let blocks = parse_array::<Block<N>>(blocks, "block")?;
+            let commitments = parse_array::<Field<N>>(commitments, "record commitment")?;
+            OfflineQuery::from_native_blocks(&blocks, &commitments).map_err(|e| e.to_string())
+        }
+        match network.unwrap_or_default() {
+            Network::MainnetV0 => from_blocks::<mainnet::CurrentNetwork>(&blocks, &commitments),
+            Network::TestnetV0 => from_blocks::<testnet::CurrentNetwork>(&blocks, &commitments),
+            Network::CanaryV0 => from_blocks::<canary::CurrentNetwork>(&blocks, &commitments),
+        }
+    }
+
+    /// Create an offline query from a block and the hashes of the blocks of the chain, computing the
+    /// global state root and the state paths of the given record commitments
+    ///
+    /// @param {string} block JSON string of the block creating the records
+    /// @param {Array<string>} block_hashes Hashes of the blocks from the genesis block to the latest
+    /// block, in order of their height
+    /// @param {Array<string>} commitments Commitments of the records created in the block
+    /// @param {Network | undefined} network (optional) The network of the block, defaults to mainnet
+    /// @returns {OfflineQuery}
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen(js_name = "fromBlock")]
+    pub fn from_block(
+        block: &str,
+        block_hashes: Array,
+        commitments: Array,
+        network: Option<Network>,
+    ) -> Result<OfflineQuery, String> {
+        fn from_block<N: NetworkTrait>(
+            block: &str,
+            block_hashes: &Array,
+            commitments: &Array,
+        ) -> Result<OfflineQuery, String> {
+            let block = Block::<N>::from_str(block).map_err(|e| format!("Invalid block: {e}"))?;
+            let block_hashes = parse_array::<N::BlockHash>(block_hashes, "block hash")?;
+            let commitments = parse_array::<Field<N>>(commitments, "record commitment")?;
+            OfflineQuery::from_native_block(&block, &block_hashes, &commitments).map_err(|e| e.to_string())
+        }
+        match network.unwrap_or_default() {
+            Network::MainnetV0 => from_block::<mainnet::CurrentNetwork>(block, &block_hashes, &commitments),
+            Network::TestnetV0 => from_block::<testnet::CurrentNetwork>(block, &block_hashes, &commitments),
+            Network::CanaryV0 => from_block::<canary::CurrentNetwork>(block, &block_hashes, &commitments),
+        }
+    }
+
     /// Get a json string representation of the offline query object
-    #[wasm_bindgen(js_name = "toString")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "toString"))]
     #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
         serde_json::to_string(&self).unwrap()
     }
 
     /// Create an offline query object from a json string representation
-    #[wasm_bindgen(js_name = "fromString")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "fromString"))]
     pub fn from_string(s: &str) -> Result<OfflineQuery, String> {
         serde_json::from_str(s).map_err(|e| e.to_string())
     }
 }
 
+impl OfflineQuery {
+    /// Create an offline query from the blocks of a chain, from the genesis block to the latest
+    /// block, with the state paths of the given record commitments
+    pub fn from_native_blocks<N: NetworkTrait>(blocks: &[Block<N>], commitments: &[Field<N>]) -> anyhow::Result<Self> {
+        let mut block_tree: BlockTree<N> = N::merkle_tree_bhp(&[])?;
+        for (height, block) in blocks.iter().enumerate() {
+            ensure!(
+                block.height() as usize == height,
+                "Expected the block at height {height}, found {}",
+                block.height()
+            );
+            if let Some(previous_block) = height.checked_sub(1).map(|height| &blocks[height]) {
+                ensure!(
+                    block.previous_hash() == previous_block.hash()
+                        && *block.header().previous_state_root() == *block_tree.root(),
+                    "Block {} does not follow block {}",
+                    block.hash(),
+                    previous_block.hash()
+                );
+            }
+            block_tree.append(&[(*block.hash()).to_bits_le()])?;
+        }
+        Self::from_block_tree(&block_tree, blocks, commitments)
+    }
+
+    /// Create an offline query from a block and the hashes of the blocks from the genesis block to
+    /// the latest block, with the state paths of the given record commitments
+    pub fn from_native_block<N: NetworkTrait>(
+        block: &Block<N>,
+        block_hashes: &[N::BlockHash],
+        commitments: &[Field<N>],
+    ) -> anyhow::Result<Self> {
+        ensure!(
+            block_hashes.get(block.height() as usize) == Some(&block.hash()),
+            "The block hashes do not contain block {} at height {}",
+            block.hash(),
+            block.height()
+        );
+        let leaves = block_hashes.iter().map(|hash| (**hash).to_bits_le()).collect::<Vec<_>>();
+        let block_tree: BlockTree<N> = N::merkle_tree_bhp(&leaves)?;
+        Self::from_block_tree(&block_tree, std::slice::from_ref(block), commitments)
+    }
+
+    /// Create an offline query with the state root of a block tree and the state paths of record
+    /// commitments created in the given blocks of the tree
+    fn from_block_tree<N: NetworkTrait>(
+        block_tree: &BlockTree<N>,
+        blocks: &[Block<N>],
+        commitments: &[Field<N>],
+    ) -> anyhow::Result<Self> {
+        let state_root = N::StateRoot::from(*block_tree.root());
+        let mut offline_query =
+            Self { state_paths: IndexMap::new(), state_root: to_network(state_root).map_err(|e| anyhow!(e))? };
+        for commitment in commitments {
+            let state_path = blocks
+                .iter()
+                .find_map(|block| block_state_path(block_tree, block, commitment).transpose())
+                .ok_or_else(|| anyhow!("The record commitment {commitment} was not found in the blocks"))??;
+            offline_query.state_paths.insert(
+                to_network(*commitment).map_err(|e| anyhow!(e))?,
+                to_network(state_path).map_err(|e| anyhow!(e))?,
+            );
+        }
+        Ok(offline_query)
+    }
+}
+
+/// Compute the state path of a record commitment if it was created in the given block, as a node
+/// computes it from its block store
+fn block_state_path<N: NetworkTrait>(
+    block_tree: &BlockTree<N>,
+    block: &Block<N>,
+    commitment: &Field<N>,
+) -> anyhow::Result<Option<StatePath<N>>> {
+    for confirmed_transaction in block.transactions().iter() {
+        let transaction = confirmed_transaction.transaction();
+        let Some(transition) =
+            transaction.transitions().find(|transition| transition.commitments().any(|c| c == commitment))
+        else {
+            continue;
+        };
+        let transition_leaf = transition.to_leaf(commitment, false)?;
+        let transition_path = transition.to_path(&transition_leaf)?;
+        let transaction_leaf = transaction.to_leaf(transition.id())?;
+        let transaction_path = transaction.to_path(&transaction_leaf)?;
+        let transactions_path = block.transactions().to_path(transaction.id())?;
+        let header_leaf = HeaderLeaf::<N>::new(1, block.header().transactions_root());
+        let header_path = block.header().to_path(&header_leaf)?;
+        let block_path = block_tree.prove(block.height() as usize, &(*block.hash()).to_bits_le())?;
+        let state_path = StatePath::from(
+            N::StateRoot::from(*block_tree.root()),
+            block_path,
+            block.hash(),
+            block.previous_hash(),
+            block.header().to_root()?,
+            header_path,
+            header_leaf,
+            transactions_path,
+            transaction.id(),
+            transaction_path,
+            transaction_leaf,
+            transition.to_root()?,
+            *transition.tcm(),
+            transition_path,
+            transition_leaf,
+        )?;
+        return Ok(Some(state_path));
+    }
+    Ok(None)
+}
+
+/// Parse the strings of a javascript array
+#[cfg(not(feature = "native"))]
+fn parse_array<T: FromStr>(values: &Array, name: &str) -> Result<Vec<T>, String>
+where
+    T::Err: Display,
+{
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let value = value.as_string().ok_or_else(|| format!("Element {index} is not a {name} string"))?;
+            T::from_str(&value).map_err(|e| format!("Invalid {name} at index {index}: {e}"))
+        })
+        .collect()
+}
+
+// The state paths are stored with the types of the default network and converted to the types of
+// the network of the process proving the execution when they are queried.
 #[async_trait(?Send)]
-impl QueryTrait<CurrentNetwork> for OfflineQuery {
-    fn current_state_root(&self) -> anyhow::Result<<CurrentNetwork as Network>::StateRoot> {
-        Ok(self.state_root)
+impl<N: NetworkTrait> QueryTrait<N> for OfflineQuery {
+    fn current_state_root(&self) -> anyhow::Result<N::StateRoot> {
+        to_network(self.state_root).map_err(|e| anyhow!(e))
     }
 
-    async fn current_state_root_async(&self) -> anyhow::Result<<CurrentNetwork as Network>::StateRoot> {
-        Ok(self.state_root)
+    async fn current_state_root_async(&self) -> anyhow::Result<N::StateRoot> {
+        self.current_state_root()
     }
 
-    fn get_state_path_for_commitment(
-        &self,
-        commitment: &Field<CurrentNetwork>,
-    ) -> anyhow::Result<StatePath<CurrentNetwork>> {
-        self.state_paths.get(commitment).cloned().ok_or(anyhow!("State path not found for commitment"))
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> anyhow::Result<StatePath<N>> {
+        let commitment: Field<CurrentNetwork> = to_network(*commitment).map_err(|e| anyhow!(e))?;
+        let state_path =
+            self.state_paths.get(&commitment).cloned().ok_or(anyhow!("State path not found for commitment"))?;
+        to_network(state_path).map_err(|e| anyhow!(e))
     }
 
-    async fn get_state_path_for_commitment_async(
-        &self,
-        commitment: &Field<CurrentNetwork>,
-    ) -> anyhow::Result<StatePath<CurrentNetwork>> {
-        self.state_paths.get(commitment).cloned().ok_or(anyhow!("State path not found for commitment"))
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> anyhow::Result<StatePath<N>> {
+        self.get_state_path_for_commitment(commitment)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        programs::local_ledger::LedgerState,
+        types::native::{mainnet, Address, FromBytes},
+        RecordPlaintext,
+    };
 
     use wasm_bindgen_test::*;
 
     const OFFLINE_QUERY: &str =
         r#"{"state_paths":{},"state_root":"sr1wjueje6hy86yw9j4lhl7jwvhjxwunw34paj4k3cn2wm5h5r2syfqd83yw4"}"#;
-    //const RECORD: &str = "{  owner: aleo1rlwt9w0fl242h40w454m68vttd6vm4lmetu5r57unm5g354y9yzsyexf0y.private,  microcredits: 1000000u64.private,  _nonce: 2899260364216345893364017846447050369739821279831870104066405119445828210771group.public}";
-    //const RECORD_STATE_PATH: &str = "path1q96tnxt82uslg3ck2h7ll6fej7gemjd6x58k2k68zdfmwj7sd2q39u24qgqqqqqqqz8vhmupdu4wg3cv08ul4sjlz6je764cznh6ye9qkuc57272er7qzzy3nhhnyfxkvfs2m8zplzsxq2ctf2u5edwp0yavvyxsz54c99qrfs9aay3vhyecmc8f560glgmqv9c0awkg3upuj9rtm5u8t36dyyz7jsksttvfdkd75znvh6h83lqpq6q0eclym87t8ra2days24ew5racm54fffl3z4u2c29tzwykys7plxmct7khyuddgh6268ywgzyfzxe4uqm5svma27ptqccznezwmkj0vcpma3e9vu5lun96knvf5qus44sz5093p5wcdxwkjjr356knt65wjpnzpek2ad789req5e67rqqwrln54hlvhefl2xg36g6n2dn06k6l5jwn3y8xtlfg60wcr5huzvxluvc62x7sx74rpvjldq67v7fmtj0n3mvmczqg5dunz8aa7dumzpkehlddjk7gpjcn0fselmwx08ggf0vtfr4lvr6mpjycgtvfres5qwsgsu25xd27p23f4czqalhf3fhyvg4evwa2u4y27f2q59khvhjsfkqrr67gkw23s47vrmql5q0uk8cp63dpr4ttdehtq8rls0zmj2qvtns3uqeg2fann8e777nhmsddggxn3x67203309kngauujtuw0g8436902ggxze9cfprv8nh8n265phfls95ud9lfzwnvj80let33cpt2x5c5avy0czx3m5vv3ra8r0cw2f2e22dz72lzwkl3c5z8qfuupgs7xhpg42areg227kkflyhpn0cj260yhpeg567fkskljmv7zckqwz6rnk6l2yg7xpeyc3dy907wefjn5w35prnacapd4acn20qeldgwwmuev0d8t6tz02x2kv8qg9sxhakx6fmw5rd35fda735pchuct5gcg9v7559gaxswfjytkkhslq44kvj8ld2pj29xvvqdlsn6zez45pyyzk022z5wng8yez9mttc0s26mxfrlk4qe9znxxqxlcfapv326qjzpt849p28f5rjv3za44u8c9ddny3lm2svj3fnrqr0uy7skg4dqfpq4n6js4r56pexg3w667ruzkkejgla4gxfg5e3sph7z0gty2ksyss2eafg236dqunyghdd0p7pttvey0765ry52vccqmlp859j9tgzgg9v7559gaxswfjytkkhslq44kvj8ld2pj29xvvqdlsn6zez45pyyzk022z5wng8yez9mttc0s26mxfrlk4qe9znxxqxlcfapv326qjzpt849p28f5rjv3za44u8c9ddny3lm2svj3fnrqr0uy7skg4dqfpq4n6js4r56pexg3w667ruzkkejgla4gxfg5e3sph7z0gty2ksyss2eafg236dqunyghdd0p7pttvey0765ry52vccqmlp859j9tgzgg9v7559gaxswfjytkkhslq44kvj8ld2pj29xvvqdlsn6zez45pyyzk022z5wng8yez9mttc0s26mxfrlk4qe9znxxqxlcfapv326qjzpt849p28f5rjv3za44u8c9ddny3lm2svj3fnrqr0uy7skg4dqfpq4n6js4r56pexg3w667ruzkkejgla4gxfg5e3sph7z0gty2ksyxqgy0ryzze4sllaq3hvhsk90h2qhx308xmfg63n740w6wjrk3qgl99r8gr9efj5yeddqdetk6n8cww9sawuhes4l5tan0zctne6n6px58ez93eujhk4qrreq9m0a9m54s93f4zvfa25k0a4w79gfl9grp5qsqqqqqqqqqqzr7mae3y6kkgqc3q3u2cte5vu328u7slgw9dnw9zu4x6uyvt5apykyzgq7g2pjq384shpxqvenrf2xzqpe6era69vcprljemjfu7rq98km83fkceft37tef3l5yd0u3d0vklnrre0xxus0x4cuy48dafs9q8a9z296cvk40q48sva0ndq7uhz4fk87xxrkku9x487afcaus0fpqqsqqqqqqqqqqzzpt849p28f5rjv3za44u8c9ddny3lm2svj3fnrqr0uy7skg4dqgcnhf9dy704t3qcqwfz2zn23tyaax8uyfw9rflmz807edsq542qdss2eafg236dqunyghdd0p7pttvey0765ry52vccqmlp859j9tgzgg9v7559gaxswfjytkkhslq44kvj8ld2pj29xvvqdlsn6zez45pyyzk022z5wng8yez9mttc0s26mxfrlk4qe9znxxqxlcfapv326qjzpt849p28f5rjv3za44u8c9ddny3lm2svj3fnrqr0uy7skg4dqfpq4n6js4r56pexg3w667ruzkkejgla4gxfg5e3sph7z0gty2ksyss2eafg236dqunyghdd0p7pttvey0765ry52vccqmlp859j9tgzgg9v7559gaxswfjytkkhslq44kvj8ld2pj29xvvqdlsn6zez45pyyzk022z5wng8yez9mttc0s26mxfrlk4qe9znxxqxlcfapv326qjzpt849p28f5rjv3za44u8c9ddny3lm2svj3fnrqr0uy7skg4dqfpq4n6js4r56pexg3w667ruzkkejgla4gxfg5e3sph7z0gty2ksyss2eafg236dqunyghdd0p7pttvey0765ry52vccqmlp859j9tgzgg9v7559gaxswfjytkkhslq44kvj8ld2pj29xvvqdlsn6zez45pyyzk022z5wng8yez9mttc0s26mxfrlk4qe9znxxqxlcfapv326qjzpt849p28f5rjv3za44u8c9ddny3lm2svj3fnrqr0uy7skg4dqfvfkqsgfjgwe874pq983afd72ptcv6hx664appmslfk2jptvjecdqqqqqqqqqqqqppq4n6js4r56pexg3w667ruzkkejgla4gxfg5e3sph7z0gty2ksyss2eafg236dqunyghdd0p7pttvey0765ry52vccqmlp859j9tgzgg9v7559gaxswfjytkkhslq44kvj8ld2pj29xvvqdlsn6zez45pyyzk022z5wng8yez9mttc0s26mxfrlk4qe9znxxqxlcfapv326qjzpt849p28f5rjv3za44u8c9ddny3lm2svj3fnrqr0uy7skg4dqgqgqqzk3ftj0dmy8gphrl85l8cfgrf8vpzdflzw7zu4ppfnxsn3vv2wqvatgjpp5234t647yyh37w3jmz37yyur5c5cg4rxgpwjecnpm2xsrceze3ptsf2gw50t0zznhzx0an9ezz8zfa37kxkw8ucw7f3gwlvqsyqqqqqqqqqqq0ylf8je3k7c464x6fpcur8s7ju93yum7kyq0p6hjkqdg24smwsz9vllufwczy2t0v3elfsv5ymkh8rp34acu9cc0rhjut7d9x684cqvyzk022z5wng8yez9mttc0s26mxfrlk4qe9znxxqxlcfapv326qjzpt849p28f5rjv3za44u8c9ddny3lm2svj3fnrqr0uy7skg4dqfpq4n6js4r56pexg3w667ruzkkejgla4gxfg5e3sph7z0gty2ksyqypq8m5dp6ejcmzg46vm4ng7xvxrya7dd4z5qam2afc8gclnkz50cgsgkwy6dt";
     const STATE_ROOT: &str = "sr1wjueje6hy86yw9j4lhl7jwvhjxwunw34paj4k3cn2wm5h5r2syfqd83yw4";
 
     #[wasm_bindgen_test]
@@ -116,39 +316,64 @@ mod tests {
         assert_eq!(offline_query_from_str, offline_query);
     }
 
-    /*#[wasm_bindgen_test]
+    #[wasm_bindgen_test]
     async fn test_state_path_construction() {
-        // Create an offline query
-        let mut offline_query = OfflineQuery::new(STATE_ROOT).unwrap();
+        // Mint a record in a local chain to get a valid state path and state root
+        let mut ledger = LedgerState::<CurrentNetwork>::new().unwrap();
+        let address = Address::from_str("aleo1rlwt9w0fl242h40w454m68vttd6vm4lmetu5r57unm5g354y9yzsyexf0y").unwrap();
+        let record = ledger.mint(&[(address, 1_000_000)]).unwrap().remove(0);
+        let record_plaintext = RecordPlaintext::from(record);
+        let record_commitment = record_plaintext.commitment("credits.aleo", "credits").unwrap().to_string();
+        let expected_state_path = ledger.state_path(&record_commitment).unwrap();
+        let expected_state_root = ledger.state_root();
 
-        // Compute the record commitment for the input record
-        let record_plaintext = RecordPlaintext::from_string(RECORD).unwrap();
-        let record_commitment = record_plaintext.commitment("credits.aleo", "credits").unwrap();
-
-        // Add a valid state path
-        offline_query.add_state_path(&record_commitment.to_string(), RECORD_STATE_PATH).unwrap();
-
-        // Construct the expected state path and expected state root
-        let expected_state_path = StatePath::<CurrentNetwork>::from_str(RECORD_STATE_PATH).unwrap();
-        println!("expected_state_path: {:?}", expected_state_path.global_state_root().to_string());
-        let expected_state_root = <CurrentNetwork as Network>::StateRoot::from_str(STATE_ROOT).unwrap();
+        // Create an offline query and add the state path of the record
+        let mut offline_query = OfflineQuery::new(&expected_state_root.to_string()).unwrap();
+        offline_query.add_state_path(&record_commitment, &expected_state_path.to_string()).unwrap();
 
         // Check that the state path can be retrieved from the query trait
+        let record_commitment = Field::<CurrentNetwork>::from_str(&record_commitment).unwrap();
+        assert_eq!(offline_query.get_state_path_for_commitment(&record_commitment).unwrap(), expected_state_path);
         assert_eq!(
-            offline_query.get_state_path_for_commitment(&record_commitment.clone().into()).unwrap(),
-            expected_state_path
+            offline_query.get_state_path_for_commitment_async(&record_commitment).await.unwrap().global_state_root(),
+            expected_state_root
         );
+
+        // Check that the state root can be retrieved from the query trait
+        assert_eq!(QueryTrait::<CurrentNetwork>::current_state_root(&offline_query).unwrap(), expected_state_root);
         assert_eq!(
-            offline_query
-                .get_state_path_for_commitment_async(&record_commitment.into())
-                .await
-                .unwrap()
-                .global_state_root(),
+            QueryTrait::<CurrentNetwork>::current_state_root_async(&offline_query).await.unwrap(),
             expected_state_root
         );
+    }
 
-        // Check that the state root can be retrieved from the query trait
-        assert_eq!(offline_query.current_state_root().unwrap(), expected_state_root);
-        assert_eq!(offline_query.current_state_root_async().await.unwrap(), expected_state_root);
-    }*/
+    #[wasm_bindgen_test]
+    fn test_from_blocks() {
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(&mainnet::parameters::GenesisBytes::load_bytes()).unwrap();
+        let commitments = genesis
+            .transactions()
+            .iter()
+            .flat_map(|transaction| transaction.transaction().commitments().copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        // Ensure the state root is the root of the block tree and the state paths are valid against it
+        let offline_query = OfflineQuery::from_native_blocks(&[genesis.clone()], &commitments).unwrap();
+        let block_tree: BlockTree<CurrentNetwork> =
+            CurrentNetwork::merkle_tree_bhp(&[(*genesis.hash()).to_bits_le()]).unwrap();
+        assert_eq!(*offline_query.state_root, *block_tree.root());
+        for commitment in &commitments {
+            let state_path = offline_query.get_state_path_for_commitment(commitment).unwrap();
+            assert_eq!(state_path.global_state_root(), offline_query.state_root);
+            assert_eq!(state_path.block_hash(), genesis.hash());
+        }
+
+        // Ensure the state paths computed from a block and the block hashes are the same
+        let from_block = OfflineQuery::from_native_block(&genesis, &[genesis.hash()], &commitments).unwrap();
+        assert_eq!(from_block, offline_query);
+
+        // Ensure blocks out of order, missing block hashes and unknown commitments are rejected
+        assert!(OfflineQuery::from_native_blocks(&[genesis.clone(), genesis.clone()], &[]).is_err());
+        assert!(OfflineQuery::from_native_block(&genesis, &[], &[]).is_err());
+        assert!(OfflineQuery::from_native_blocks(&[genesis], &[Field::from_u8(1)]).is_err());
+    }
 }
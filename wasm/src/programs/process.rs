@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::native::{canary, mainnet, testnet},
+    Network,
+};
+
+/// The process of one of the networks supported by the SDK. Code that needs the native types of
+/// the network a process belongs to runs inside the `with_network!` macro, which matches on the
+/// process and brings the native types of its network into scope.
+pub(crate) enum NetworkProcess {
+    MainnetV0(mainnet::ProcessNative),
+    TestnetV0(testnet::ProcessNative),
+    CanaryV0(canary::ProcessNative),
+}
+
+impl NetworkProcess {
+    /// Load a process for the given network containing the credits program
+    pub(crate) fn load(network: Network) -> Result<Self, String> {
+        Ok(match network {
+            Network::MainnetV0 => Self::MainnetV0(mainnet::ProcessNative::load_web().map_err(|e| e.to_string())?),
+            Network::TestnetV0 => Self::TestnetV0(testnet::ProcessNative::load_web().map_err(|e| e.to_string())?),
+            Network::CanaryV0 => Self::CanaryV0(canary::ProcessNative::load_web().map_err(|e| e.to_string())?),
+        })
+    }
+}
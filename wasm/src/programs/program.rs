@@ -14,27 +14,31 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(not(feature = "native"))]
+use crate::types::native::{CurrentNetwork, EntryType, PlaintextType, ValueType};
 use crate::{
     account::Address,
-    types::native::{CurrentNetwork, EntryType, IdentifierNative, PlaintextType, ProgramNative, ValueType},
+    types::native::{IdentifierNative, ProgramNative},
 };
 
+#[cfg(not(feature = "native"))]
 use js_sys::{Array, Object, Reflect};
 use std::{ops::Deref, str::FromStr};
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
 /// Webassembly Representation of an Aleo program
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Program(ProgramNative);
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl Program {
     /// Create a program from a program string
     ///
     /// @param {string} program Aleo program source code
     /// @returns {Program} Program object
-    #[wasm_bindgen(js_name = "fromString")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "fromString"))]
     pub fn from_string(program: &str) -> Result<Program, String> {
         Ok(Self(ProgramNative::from_str(program).map_err(|err| err.to_string())?))
     }
@@ -42,7 +46,7 @@ impl Program {
     /// Get a string representation of the program
     ///
     /// @returns {string} String containing the program source code
-    #[wasm_bindgen(js_name = "toString")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "toString"))]
     #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
         self.0.to_string()
@@ -52,7 +56,7 @@ impl Program {
     ///
     /// @param {string} functionName Name of the function to check for
     /// @returns {boolean} True if the program is valid, false otherwise
-    #[wasm_bindgen(js_name = "hasFunction")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "hasFunction"))]
     pub fn has_function(&self, function_name: &str) -> bool {
         IdentifierNative::from_str(function_name).map_or(false, |identifier| self.0.contains_function(&identifier))
     }
@@ -76,6 +80,7 @@ impl Program {
     /// const credits_program = aleo_wasm.Program.getCreditsProgram();
     /// const credits_functions = credits_program.getFunctions();
     /// console.log(credits_functions === expected_functions); // Output should be "true"
+    #[cfg(not(feature = "native"))]
     #[wasm_bindgen(js_name = "getFunctions")]
     pub fn get_functions(&self) -> Array {
         let array = Array::new_with_length(self.0.functions().len() as u32);
@@ -123,6 +128,7 @@ impl Program {
     /// const credits_program = aleo_wasm.Program.getCreditsProgram();
     /// const transfer_function_inputs = credits_program.getFunctionInputs("transfer_private");
     /// console.log(transfer_function_inputs === expected_inputs); // Output should be "true"
+    #[cfg(not(feature = "native"))]
     #[wasm_bindgen(js_name = "getFunctionInputs")]
     pub fn get_function_inputs(&self, function_name: String) -> Result<Array, String> {
         let function_id = IdentifierNative::from_str(&function_name).map_err(|e| e.to_string())?;
@@ -203,6 +209,7 @@ impl Program {
     /// const credits_program = aleo_wasm.Program.getCreditsProgram();
     /// const credits_mappings = credits_program.getMappings();
     /// console.log(credits_mappings === expected_mappings); // Output should be "true"
+    #[cfg(not(feature = "native"))]
     #[wasm_bindgen(js_name = "getMappings")]
     pub fn get_mappings(&self) -> Result<Array, String> {
         let mappings = Array::new();
@@ -224,6 +231,7 @@ impl Program {
 
     // Get the value of a plaintext input as a javascript object (this function is not part of the
     // public API)
+    #[cfg(not(feature = "native"))]
     fn get_plaintext_input(
         &self,
         plaintext: &PlaintextType<CurrentNetwork>,
@@ -297,6 +305,7 @@ impl Program {
     /// const credits_program = aleo_wasm.Program.getCreditsProgram();
     /// const credits_record = credits_program.getRecordMembers("Credits");
     /// console.log(credits_record === expected_record); // Output should be "true"
+    #[cfg(not(feature = "native"))]
     #[wasm_bindgen(js_name = "getRecordMembers")]
     pub fn get_record_members(&self, record_name: String) -> Result<Object, String> {
         let record_id = IdentifierNative::from_str(&record_name).map_err(|e| e.to_string())?;
@@ -387,6 +396,7 @@ impl Program {
     /// const program = aleo_wasm.Program.fromString(STRUCT_PROGRAM);
     /// const struct_members = program.getStructMembers("token");
     /// console.log(struct_members === expected_struct_members); // Output should be "true"
+    #[cfg(not(feature = "native"))]
     #[wasm_bindgen(js_name = "getStructMembers")]
     pub fn get_struct_members(&self, struct_name: String) -> Result<Array, String> {
         let struct_id = IdentifierNative::from_str(&struct_name).map_err(|e| e.to_string())?;
@@ -408,7 +418,7 @@ impl Program {
     /// Get the credits.aleo program
     ///
     /// @returns {Program} The credits.aleo program
-    #[wasm_bindgen(js_name = "getCreditsProgram")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "getCreditsProgram"))]
     pub fn get_credits_program() -> Program {
         Program::from(ProgramNative::credits().unwrap())
     }
@@ -416,7 +426,7 @@ impl Program {
     /// Get the id of the program
     ///
     /// @returns {string} The id of the program
-    #[wasm_bindgen]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
     pub fn id(&self) -> String {
         self.0.id().to_string()
     }
@@ -424,7 +434,7 @@ impl Program {
     /// Get a unique address of the program
     ///
     /// @returns {Address} The address of the program
-    #[wasm_bindgen]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
     pub fn address(&self) -> Result<Address, String> {
         Ok(Address::from(self.0.id().to_address().map_err(|e| e.to_string())?))
     }
@@ -433,7 +443,7 @@ impl Program {
     ///
     /// @param {Program} other The other program to compare
     /// @returns {boolean} True if the programs are equal, false otherwise
-    #[wasm_bindgen(js_name = "isEqual")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isEqual"))]
     pub fn is_equal(&self, other: &Program) -> bool {
         self == other
     }
@@ -460,6 +470,7 @@ impl Program {
     /// const program = aleo_wasm.Program.fromString(DOUBLE_TEST_PROGRAM);
     /// const imports = program.getImports();
     /// console.log(imports === expected_imports); // Output should be "true"
+    #[cfg(not(feature = "native"))]
     #[wasm_bindgen(js_name = "getImports")]
     pub fn get_imports(&self) -> Array {
         let imports = Array::new_with_length(self.0.imports().len() as u32);
@@ -498,7 +509,7 @@ impl FromStr for Program {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "native")))]
 mod tests {
     use super::*;
 
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{log, programs::network_query::sleep, SdkError};
+
+use js_sys::Function;
+use std::time::Duration;
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+use web_sys::AbortSignal;
+
+/// The stages a program manager reports to a progress callback while it builds a transaction
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProgressStage {
+    LoadProgram = 0,
+    ResolveImports = 1,
+    Synthesize = 2,
+    Authorize = 3,
+    PrepareInclusion = 4,
+    Prove = 5,
+    ProveFee = 6,
+    Verify = 7,
+}
+
+/// Reports the stages of a long running operation to an optional javascript callback and stops
+/// the operation between stages once an optional abort signal has been triggered
+#[derive(Default)]
+pub(crate) struct Progress {
+    callback: Option<Function>,
+    signal: Option<AbortSignal>,
+}
+
+impl Progress {
+    pub(crate) fn new(callback: Option<Function>, signal: Option<AbortSignal>) -> Self {
+        Self { callback, signal }
+    }
+
+    /// Return an error if the abort signal has been triggered, otherwise report the start of a
+    /// stage to the callback. Errors thrown by the callback are logged and do not stop the operation.
+    ///
+    /// The stages are followed by blocking work such as synthesizing keys or proving, so the event
+    /// loop is given a turn whenever a callback or signal is set. This lets the page render the
+    /// reported stage and run the abort handlers before the next stage starts.
+    pub(crate) async fn stage(&self, stage: ProgressStage) -> Result<(), SdkError> {
+        self.ensure_not_aborted()?;
+        if let Some(callback) = &self.callback {
+            if callback.call1(&JsValue::NULL, &JsValue::from(stage)).is_err() {
+                log(&format!("The progress callback threw an error at stage {stage:?}"));
+            }
+        }
+        if self.callback.is_some() || self.signal.is_some() {
+            sleep(Duration::ZERO).await;
+        }
+        self.ensure_not_aborted()
+    }
+
+    /// Return an error if the abort signal has been triggered
+    pub(crate) fn ensure_not_aborted(&self) -> Result<(), SdkError> {
+        match &self.signal {
+            Some(signal) if signal.aborted() => Err(SdkError::Aborted),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use js_sys::{Array, Reflect};
+    use std::{cell::Cell, rc::Rc};
+    use wasm_bindgen::{closure::Closure, JsCast};
+    use wasm_bindgen_test::*;
+    use web_sys::AbortController;
+
+    #[wasm_bindgen_test]
+    async fn test_progress_stages() {
+        let stages = Array::new();
+        let recorded = stages.clone();
+        let callback = Closure::<dyn Fn(JsValue)>::new(move |stage: JsValue| {
+            recorded.push(&stage);
+        });
+        let progress = Progress::new(Some(callback.as_ref().unchecked_ref::<Function>().clone()), None);
+
+        progress.stage(ProgressStage::Authorize).await.unwrap();
+        progress.stage(ProgressStage::Prove).await.unwrap();
+
+        assert_eq!(stages.length(), 2);
+        assert_eq!(stages.get(0).as_f64(), Some(ProgressStage::Authorize as u32 as f64));
+        assert_eq!(stages.get(1).as_f64(), Some(ProgressStage::Prove as u32 as f64));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_progress_abort() {
+        let controller = AbortController::new().unwrap();
+        let progress = Progress::new(None, Some(controller.signal()));
+        assert!(progress.stage(ProgressStage::LoadProgram).await.is_ok());
+
+        controller.abort();
+        assert_eq!(progress.stage(ProgressStage::Prove).await.unwrap_err(), SdkError::Aborted);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_progress_abort_without_callback() {
+        // An abort scheduled on the event loop is seen by the stage when only a signal is set
+        let controller = AbortController::new().unwrap();
+        let progress = Progress::new(None, Some(controller.signal()));
+        let abort = Closure::<dyn Fn()>::new(move || controller.abort());
+        let set_timeout = Function::from(Reflect::get(&js_sys::global(), &"setTimeout".into()).unwrap());
+        set_timeout.call2(&JsValue::NULL, abort.as_ref(), &JsValue::from(0)).unwrap();
+
+        assert_eq!(progress.stage(ProgressStage::Prove).await.unwrap_err().code(), "ABORTED");
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_progress_yields_to_event_loop() {
+        // A timer scheduled by the callback fires before the stage returns
+        let fired = Rc::new(Cell::new(false));
+        let timer_fired = fired.clone();
+        let timer = Closure::<dyn Fn()>::new(move || timer_fired.set(true));
+        let set_timeout = Function::from(Reflect::get(&js_sys::global(), &"setTimeout".into()).unwrap());
+        let callback = Closure::<dyn Fn(JsValue)>::new(move |_: JsValue| {
+            set_timeout.call2(&JsValue::NULL, timer.as_ref(), &JsValue::from(0)).unwrap();
+        });
+        let progress = Progress::new(Some(callback.as_ref().unchecked_ref::<Function>().clone()), None);
+
+        progress.stage(ProgressStage::Prove).await.unwrap();
+        assert!(fired.get());
+    }
+}
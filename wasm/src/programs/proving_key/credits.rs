@@ -16,7 +16,7 @@
 
 use super::*;
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl ProvingKey {
     fn prover_checksum(function_metadata: &'static str) -> String {
         let metadata: serde_json::Value =
@@ -30,10 +30,12 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("bond_public_proving_key.bin");
     /// provingKey.isBondPublicProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the bond_public function, false if otherwise
-    #[wasm_bindgen(js_name = "isBondPublicProver")]
-    pub fn is_bond_public_prover(&self) -> bool {
-        self.checksum() == ProvingKey::prover_checksum(crate::types::native::parameters::BondPublicProver::METADATA)
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isBondPublicProver"))]
+    pub fn is_bond_public_prover(&self, network: Option<Network>) -> bool {
+        self.checksum()
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(network.unwrap_or_default(), BondPublicProver))
     }
 
     /// Verify if the proving key is for the bond_validator function
@@ -42,10 +44,15 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("bond_validator_proving_key.bin");
     /// provingKey.isBondPublicProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the bond_validator function, false if otherwise
-    #[wasm_bindgen(js_name = "isBondValidatorProver")]
-    pub fn is_bond_validator_prover(&self) -> bool {
-        self.checksum() == ProvingKey::prover_checksum(crate::types::native::parameters::BondValidatorProver::METADATA)
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isBondValidatorProver"))]
+    pub fn is_bond_validator_prover(&self, network: Option<Network>) -> bool {
+        self.checksum()
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(
+                network.unwrap_or_default(),
+                BondValidatorProver
+            ))
     }
 
     /// Verify if the proving key is for the claim_unbond function
@@ -54,11 +61,15 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("claim_unbond_proving_key.bin");
     /// provingKey.isClaimUnbondProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the claim_unbond function, false if otherwise
-    #[wasm_bindgen(js_name = "isClaimUnbondPublicProver")]
-    pub fn is_claim_unbond_public_prover(&self) -> bool {
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isClaimUnbondPublicProver"))]
+    pub fn is_claim_unbond_public_prover(&self, network: Option<Network>) -> bool {
         self.checksum()
-            == ProvingKey::prover_checksum(crate::types::native::parameters::ClaimUnbondPublicProver::METADATA)
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(
+                network.unwrap_or_default(),
+                ClaimUnbondPublicProver
+            ))
     }
 
     /// Verify if the proving key is for the fee_private function
@@ -67,10 +78,12 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("fee_private_proving_key.bin");
     /// provingKey.isFeePrivateProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the fee_private function, false if otherwise
-    #[wasm_bindgen(js_name = "isFeePrivateProver")]
-    pub fn is_fee_private_prover(&self) -> bool {
-        self.checksum() == ProvingKey::prover_checksum(crate::types::native::parameters::FeePrivateProver::METADATA)
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isFeePrivateProver"))]
+    pub fn is_fee_private_prover(&self, network: Option<Network>) -> bool {
+        self.checksum()
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(network.unwrap_or_default(), FeePrivateProver))
     }
 
     /// Verify if the proving key is for the fee_public function
@@ -79,10 +92,12 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("fee_public_proving_key.bin");
     /// provingKey.isFeePublicProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the fee_public function, false if otherwise
-    #[wasm_bindgen(js_name = "isFeePublicProver")]
-    pub fn is_fee_public_prover(&self) -> bool {
-        self.checksum() == ProvingKey::prover_checksum(crate::types::native::parameters::FeePublicProver::METADATA)
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isFeePublicProver"))]
+    pub fn is_fee_public_prover(&self, network: Option<Network>) -> bool {
+        self.checksum()
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(network.unwrap_or_default(), FeePublicProver))
     }
 
     /// Verify if the proving key is for the inclusion function
@@ -91,10 +106,12 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("inclusion_proving_key.bin");
     /// provingKey.isInclusionProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the inclusion function, false if otherwise
-    #[wasm_bindgen(js_name = "isInclusionProver")]
-    pub fn is_inclusion_prover(&self) -> bool {
-        self.checksum() == ProvingKey::prover_checksum(crate::types::native::parameters::InclusionProver::METADATA)
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isInclusionProver"))]
+    pub fn is_inclusion_prover(&self, network: Option<Network>) -> bool {
+        self.checksum()
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(network.unwrap_or_default(), InclusionProver))
     }
 
     /// Verify if the proving key is for the join function
@@ -103,10 +120,12 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("join_proving_key.bin");
     /// provingKey.isJoinProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the join function, false if otherwise
-    #[wasm_bindgen(js_name = "isJoinProver")]
-    pub fn is_join_prover(&self) -> bool {
-        self.checksum() == ProvingKey::prover_checksum(crate::types::native::parameters::JoinProver::METADATA)
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isJoinProver"))]
+    pub fn is_join_prover(&self, network: Option<Network>) -> bool {
+        self.checksum()
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(network.unwrap_or_default(), JoinProver))
     }
 
     /// Verify if the proving key is for the set_validator_state function
@@ -115,11 +134,15 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("set_validator_set_proving_key.bin");
     /// provingKey.isSetValidatorStateProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the set_validator_state function, false if otherwise
-    #[wasm_bindgen(js_name = "isSetValidatorStateProver")]
-    pub fn is_set_validator_state_prover(&self) -> bool {
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isSetValidatorStateProver"))]
+    pub fn is_set_validator_state_prover(&self, network: Option<Network>) -> bool {
         self.checksum()
-            == ProvingKey::prover_checksum(crate::types::native::parameters::SetValidatorStateProver::METADATA)
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(
+                network.unwrap_or_default(),
+                SetValidatorStateProver
+            ))
     }
 
     /// Verify if the proving key is for the split function
@@ -128,10 +151,12 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("split_proving_key.bin");
     /// provingKey.isSplitProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the split function, false if otherwise
-    #[wasm_bindgen(js_name = "isSplitProver")]
-    pub fn is_split_prover(&self) -> bool {
-        self.checksum() == ProvingKey::prover_checksum(crate::types::native::parameters::SplitProver::METADATA)
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isSplitProver"))]
+    pub fn is_split_prover(&self, network: Option<Network>) -> bool {
+        self.checksum()
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(network.unwrap_or_default(), SplitProver))
     }
 
     /// Verify if the proving key is for the transfer_private function
@@ -140,11 +165,15 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("transfer_private_proving_key.bin");
     /// provingKey.isTransferPrivateProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the transfer_private function, false if otherwise
-    #[wasm_bindgen(js_name = "isTransferPrivateProver")]
-    pub fn is_transfer_private_prover(&self) -> bool {
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isTransferPrivateProver"))]
+    pub fn is_transfer_private_prover(&self, network: Option<Network>) -> bool {
         self.checksum()
-            == ProvingKey::prover_checksum(crate::types::native::parameters::TransferPrivateProver::METADATA)
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(
+                network.unwrap_or_default(),
+                TransferPrivateProver
+            ))
     }
 
     /// Verify if the proving key is for the transfer_private_to_public function
@@ -153,11 +182,15 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("transfer_private_to_public_proving_key.bin");
     /// provingKey.isTransferPrivateToPublicProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the transfer_private_to_public function, false if otherwise
-    #[wasm_bindgen(js_name = "isTransferPrivateToPublicProver")]
-    pub fn is_transfer_private_to_public_prover(&self) -> bool {
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isTransferPrivateToPublicProver"))]
+    pub fn is_transfer_private_to_public_prover(&self, network: Option<Network>) -> bool {
         self.checksum()
-            == ProvingKey::prover_checksum(crate::types::native::parameters::TransferPrivateToPublicProver::METADATA)
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(
+                network.unwrap_or_default(),
+                TransferPrivateToPublicProver
+            ))
     }
 
     /// Verify if the proving key is for the transfer_public function
@@ -166,10 +199,15 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("transfer_public_proving_key.bin");
     /// provingKey.isTransferPublicProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the transfer_public function, false if otherwise
-    #[wasm_bindgen(js_name = "isTransferPublicProver")]
-    pub fn is_transfer_public_prover(&self) -> bool {
-        self.checksum() == ProvingKey::prover_checksum(crate::types::native::parameters::TransferPublicProver::METADATA)
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isTransferPublicProver"))]
+    pub fn is_transfer_public_prover(&self, network: Option<Network>) -> bool {
+        self.checksum()
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(
+                network.unwrap_or_default(),
+                TransferPublicProver
+            ))
     }
 
     /// Verify if the proving key is for the transfer_public_as_signer function
@@ -178,11 +216,15 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("transfer_public_as_signer_proving_key.bin");
     /// provingKey.isTransferPublicAsSignerProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the transfer_public function, false if otherwise
-    #[wasm_bindgen(js_name = "isTransferPublicAsSignerProver")]
-    pub fn is_transfer_public_as_signer_prover(&self) -> bool {
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isTransferPublicAsSignerProver"))]
+    pub fn is_transfer_public_as_signer_prover(&self, network: Option<Network>) -> bool {
         self.checksum()
-            == ProvingKey::prover_checksum(crate::types::native::parameters::TransferPublicAsSignerProver::METADATA)
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(
+                network.unwrap_or_default(),
+                TransferPublicAsSignerProver
+            ))
     }
 
     /// Verify if the proving key is for the transfer_public_to_private function
@@ -191,11 +233,15 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("transfer_public_to_private_proving_key.bin");
     /// provingKey.isTransferPublicToPrivateProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the transfer_public_to_private function, false if otherwise
-    #[wasm_bindgen(js_name = "isTransferPublicToPrivateProver")]
-    pub fn is_transfer_public_to_private_prover(&self) -> bool {
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isTransferPublicToPrivateProver"))]
+    pub fn is_transfer_public_to_private_prover(&self, network: Option<Network>) -> bool {
         self.checksum()
-            == ProvingKey::prover_checksum(crate::types::native::parameters::TransferPublicToPrivateProver::METADATA)
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(
+                network.unwrap_or_default(),
+                TransferPublicToPrivateProver
+            ))
     }
 
     /// Verify if the proving key is for the unbond_public function
@@ -204,14 +250,16 @@ impl ProvingKey {
     /// const provingKey = ProvingKey.fromBytes("unbond_public.bin");
     /// provingKey.isUnbondPublicProver() ? console.log("Key verified") : throw new Error("Invalid key");
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {boolean} returns true if the proving key is for the unbond_public_prover function, false if otherwise
-    #[wasm_bindgen(js_name = "isUnbondPublicProver")]
-    pub fn is_unbond_public_prover(&self) -> bool {
-        self.checksum() == ProvingKey::prover_checksum(crate::types::native::parameters::UnbondPublicProver::METADATA)
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isUnbondPublicProver"))]
+    pub fn is_unbond_public_prover(&self, network: Option<Network>) -> bool {
+        self.checksum()
+            == ProvingKey::prover_checksum(crate::parameters_metadata!(network.unwrap_or_default(), UnbondPublicProver))
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "native")))]
 mod tests {
     use super::*;
     use crate::Metadata;
@@ -226,94 +274,94 @@ mod tests {
     async fn test_proving_key_checksum() {
         const DELAY: u32 = 100;
 
-        let prover_uri = Metadata::bond_public().prover;
+        let prover_uri = Metadata::bond_public(None).prover;
         let proving_key_bytes = reqwest::get(prover_uri).await.unwrap().bytes().await.unwrap().to_vec();
         let prover = ProvingKey::from_bytes(&proving_key_bytes).unwrap();
-        assert!(prover.is_bond_public_prover());
-        assert!(!prover.is_claim_unbond_public_prover());
+        assert!(prover.is_bond_public_prover(None));
+        assert!(!prover.is_claim_unbond_public_prover(None));
 
         sleep(DELAY).await;
 
-        let prover_uri = Metadata::claim_unbond_public().prover;
+        let prover_uri = Metadata::claim_unbond_public(None).prover;
         let proving_key_bytes = reqwest::get(prover_uri).await.unwrap().bytes().await.unwrap().to_vec();
         let prover = ProvingKey::from_bytes(&proving_key_bytes).unwrap();
-        assert!(prover.is_claim_unbond_public_prover());
-        assert!(!prover.is_fee_private_prover());
+        assert!(prover.is_claim_unbond_public_prover(None));
+        assert!(!prover.is_fee_private_prover(None));
 
         sleep(DELAY).await;
 
-        let prover_uri = Metadata::fee_private().prover;
+        let prover_uri = Metadata::fee_private(None).prover;
         let proving_key_bytes = reqwest::get(prover_uri).await.unwrap().bytes().await.unwrap().to_vec();
         let prover = ProvingKey::from_bytes(&proving_key_bytes).unwrap();
-        assert!(prover.is_fee_private_prover());
-        assert!(!prover.is_fee_public_prover());
+        assert!(prover.is_fee_private_prover(None));
+        assert!(!prover.is_fee_public_prover(None));
 
         sleep(DELAY).await;
 
-        let prover_uri = Metadata::fee_public().prover;
+        let prover_uri = Metadata::fee_public(None).prover;
         let proving_key_bytes = reqwest::get(prover_uri).await.unwrap().bytes().await.unwrap().to_vec();
         let prover = ProvingKey::from_bytes(&proving_key_bytes).unwrap();
-        assert!(prover.is_fee_public_prover());
-        assert!(!prover.is_join_prover());
+        assert!(prover.is_fee_public_prover(None));
+        assert!(!prover.is_join_prover(None));
 
         sleep(DELAY).await;
 
-        let prover_uri = Metadata::join().prover;
+        let prover_uri = Metadata::join(None).prover;
         let proving_key_bytes = reqwest::get(prover_uri).await.unwrap().bytes().await.unwrap().to_vec();
         let prover = ProvingKey::from_bytes(&proving_key_bytes).unwrap();
-        assert!(prover.is_join_prover());
-        assert!(!prover.is_set_validator_state_prover());
+        assert!(prover.is_join_prover(None));
+        assert!(!prover.is_set_validator_state_prover(None));
 
         sleep(DELAY).await;
 
-        let prover_uri = Metadata::set_validator_state().prover;
+        let prover_uri = Metadata::set_validator_state(None).prover;
         let proving_key_bytes = reqwest::get(prover_uri).await.unwrap().bytes().await.unwrap().to_vec();
         let prover = ProvingKey::from_bytes(&proving_key_bytes).unwrap();
-        assert!(prover.is_set_validator_state_prover());
-        assert!(!prover.is_split_prover());
+        assert!(prover.is_set_validator_state_prover(None));
+        assert!(!prover.is_split_prover(None));
         sleep(DELAY).await;
 
-        let prover_uri = Metadata::split().prover;
+        let prover_uri = Metadata::split(None).prover;
         let proving_key_bytes = reqwest::get(prover_uri).await.unwrap().bytes().await.unwrap().to_vec();
         let prover = ProvingKey::from_bytes(&proving_key_bytes).unwrap();
-        assert!(prover.is_split_prover());
-        assert!(!prover.is_transfer_private_prover());
+        assert!(prover.is_split_prover(None));
+        assert!(!prover.is_transfer_private_prover(None));
         sleep(DELAY).await;
 
-        let prover_uri = Metadata::transfer_private().prover;
+        let prover_uri = Metadata::transfer_private(None).prover;
         let proving_key_bytes = reqwest::get(prover_uri).await.unwrap().bytes().await.unwrap().to_vec();
         let prover = ProvingKey::from_bytes(&proving_key_bytes).unwrap();
-        assert!(prover.is_transfer_private_prover());
-        assert!(!prover.is_transfer_private_to_public_prover());
+        assert!(prover.is_transfer_private_prover(None));
+        assert!(!prover.is_transfer_private_to_public_prover(None));
 
         sleep(DELAY).await;
 
-        let prover_uri = Metadata::transfer_private_to_public().prover;
+        let prover_uri = Metadata::transfer_private_to_public(None).prover;
         let proving_key_bytes = reqwest::get(prover_uri).await.unwrap().bytes().await.unwrap().to_vec();
         let prover = ProvingKey::from_bytes(&proving_key_bytes).unwrap();
-        assert!(prover.is_transfer_private_to_public_prover());
-        assert!(!prover.is_transfer_public_prover());
+        assert!(prover.is_transfer_private_to_public_prover(None));
+        assert!(!prover.is_transfer_public_prover(None));
 
         sleep(DELAY).await;
 
-        let prover_uri = Metadata::transfer_public().prover;
+        let prover_uri = Metadata::transfer_public(None).prover;
         let proving_key_bytes = reqwest::get(prover_uri).await.unwrap().bytes().await.unwrap().to_vec();
         let prover = ProvingKey::from_bytes(&proving_key_bytes).unwrap();
-        assert!(prover.is_transfer_public_prover());
-        assert!(!prover.is_transfer_public_to_private_prover());
+        assert!(prover.is_transfer_public_prover(None));
+        assert!(!prover.is_transfer_public_to_private_prover(None));
 
         sleep(DELAY).await;
 
-        let prover_uri = Metadata::transfer_public_to_private().prover;
+        let prover_uri = Metadata::transfer_public_to_private(None).prover;
         let proving_key_bytes = reqwest::get(prover_uri).await.unwrap().bytes().await.unwrap().to_vec();
         let prover = ProvingKey::from_bytes(&proving_key_bytes).unwrap();
-        assert!(prover.is_transfer_public_to_private_prover());
+        assert!(prover.is_transfer_public_to_private_prover(None));
         sleep(DELAY).await;
 
-        let prover_uri = Metadata::unbond_public().prover;
+        let prover_uri = Metadata::unbond_public(None).prover;
         let proving_key_bytes = reqwest::get(prover_uri).await.unwrap().bytes().await.unwrap().to_vec();
         let prover = ProvingKey::from_bytes(&proving_key_bytes).unwrap();
-        assert!(prover.is_unbond_public_prover());
-        assert!(!prover.is_bond_public_prover());
+        assert!(prover.is_unbond_public_prover(None));
+        assert!(!prover.is_bond_public_prover(None));
     }
 }
@@ -16,19 +16,23 @@
 
 mod credits;
 
-use crate::types::native::{FromBytes, ProvingKeyNative, ToBytes};
+use crate::{
+    types::native::{FromBytes, ProvingKeyNative, ToBytes},
+    Network,
+};
 
 use sha2::Digest;
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::prelude::wasm_bindgen;
 
 use std::{ops::Deref, str::FromStr};
 
 /// Proving key for a function within an Aleo program
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 #[derive(Clone, Debug)]
 pub struct ProvingKey(ProvingKeyNative);
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl ProvingKey {
     /// Return the checksum of the proving key
     ///
@@ -40,7 +44,7 @@ impl ProvingKey {
     /// Create a copy of the proving key
     ///
     /// @returns {ProvingKey} A copy of the proving key
-    #[wasm_bindgen]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
     pub fn copy(&self) -> ProvingKey {
         self.0.clone().into()
     }
@@ -49,7 +53,7 @@ impl ProvingKey {
     ///
     /// @param {Uint8Array} bytes Byte array representation of a proving key
     /// @returns {ProvingKey}
-    #[wasm_bindgen(js_name = "fromBytes")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "fromBytes"))]
     pub fn from_bytes(bytes: &[u8]) -> Result<ProvingKey, String> {
         Ok(Self(ProvingKeyNative::from_bytes_le(bytes).map_err(|e| e.to_string())?))
     }
@@ -57,7 +61,7 @@ impl ProvingKey {
     /// Create a proving key from string
     ///
     /// @param {string} String representation of the proving key
-    #[wasm_bindgen(js_name = "fromString")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "fromString"))]
     pub fn from_string(string: &str) -> Result<ProvingKey, String> {
         Ok(Self(ProvingKeyNative::from_str(string).map_err(|e| e.to_string())?))
     }
@@ -65,7 +69,7 @@ impl ProvingKey {
     /// Return the byte representation of a proving key
     ///
     /// @returns {Uint8Array} Byte array representation of a proving key
-    #[wasm_bindgen(js_name = "toBytes")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "toBytes"))]
     pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
         self.0.to_bytes_le().map_err(|_| "Failed to serialize proving key".to_string())
     }
@@ -73,7 +77,7 @@ impl ProvingKey {
     /// Get a string representation of the proving key
     ///
     /// @returns {string} String representation of the proving key
-    #[wasm_bindgen(js_name = "toString")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "toString"))]
     #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
         format!("{:?}", self.0)
@@ -106,7 +110,7 @@ impl PartialEq for ProvingKey {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "native")))]
 mod tests {
     use super::*;
     use crate::Metadata;
@@ -114,7 +118,7 @@ mod tests {
 
     #[wasm_bindgen_test]
     async fn test_proving_key() {
-        let transer_public_prover = Metadata::transfer_public().prover;
+        let transer_public_prover = Metadata::transfer_public(None).prover;
 
         let bytes = reqwest::get(transer_public_prover).await.unwrap().bytes().await.unwrap().to_vec();
         let key = ProvingKey::from_bytes(&bytes).unwrap();
@@ -127,10 +131,6 @@ mod tests {
             ProvingKey::from_string(&transfer_public_proving_key_string).unwrap();
         assert_eq!(key, transfer_public_proving_key_from_string);
 
-        #[cfg(feature = "testnet")]
-        let checksum = "846f86cabf9fa50e5abe347e559a8e9f3018459b5af9fdf52f1c44892b05f7e5";
-
-        #[cfg(feature = "mainnet")]
         let checksum = "f8e5f6437b945174b62313ece8a1c9dcbeac5dfff5b0fef2e968c9b92f86da06";
 
         let transfer_public_proving_key_checksum = key.checksum();
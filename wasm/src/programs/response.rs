@@ -14,79 +14,87 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::native::{
-    ExecutionNative,
-    IdentifierNative,
-    ProcessNative,
-    ProgramIDNative,
-    ProgramNative,
-    ProvingKeyNative,
-    ResponseNative,
-    VerifyingKeyNative,
+use crate::types::{
+    native::{
+        Execution as ExecutionGeneric,
+        IdentifierNative,
+        Network as NetworkTrait,
+        Process,
+        ProgramID,
+        ProgramNative,
+        ProvingKeyNative,
+        Response,
+        ValueNative,
+        VerifyingKeyNative,
+    },
+    to_network,
 };
 
 use crate::{Execution, KeyPair, Program, ProvingKey, VerifyingKey};
-use std::{ops::Deref, str::FromStr};
+use std::str::FromStr;
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
 /// Webassembly Representation of an Aleo function execution response
 ///
 /// This object is returned by the execution of an Aleo function off-chain. It provides methods for
 /// retrieving the outputs of the function execution.
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 pub struct ExecutionResponse {
-    execution: Option<ExecutionNative>,
+    execution: Option<Execution>,
     function_id: IdentifierNative,
-    response: ResponseNative,
+    outputs: Vec<ValueNative>,
     program: ProgramNative,
     proving_key: Option<ProvingKeyNative>,
     verifying_key: VerifyingKeyNative,
 }
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl ExecutionResponse {
-    pub(crate) fn new(
-        execution: Option<ExecutionNative>,
+    pub(crate) fn new<N: NetworkTrait>(
+        execution: Option<ExecutionGeneric<N>>,
         function_id: &str,
-        response: ResponseNative,
-        process: &ProcessNative,
+        response: Response<N>,
+        process: &Process<N>,
         program: &str,
     ) -> Result<Self, String> {
         let program = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
         let verifying_key = process
-            .get_verifying_key(program.id(), function_id)
+            .get_verifying_key(program.id().to_string().as_str(), function_id)
             .map_err(|_| format!("Could not find verifying key for {:?}/{:?}", program.id(), function_id))?;
+        let outputs: Vec<ValueNative> = response.outputs().iter().cloned().map(to_network).collect::<Result<_, _>>()?;
 
         Ok(Self {
-            execution,
-            response,
+            execution: execution.map(Execution::from_native).transpose()?,
+            outputs,
             function_id: IdentifierNative::from_str(function_id).map_err(|e| e.to_string())?,
             program,
             proving_key: None,
-            verifying_key,
+            verifying_key: to_network(verifying_key)?,
         })
     }
 
-    pub(crate) fn add_proving_key(
+    pub(crate) fn add_proving_key<N: NetworkTrait>(
         &mut self,
-        process: &ProcessNative,
+        process: &Process<N>,
         function_id: &str,
-        program_id: &ProgramIDNative,
+        program_id: &ProgramID<N>,
     ) -> Result<(), String> {
         let proving_key = process
             .get_proving_key(program_id, function_id)
             .map_err(|_| format!("Could not find proving key for {:?}/{:?}", program_id, function_id))?;
-        self.proving_key = Some(proving_key);
+        self.proving_key = Some(to_network(proving_key)?);
         Ok(())
     }
 
     /// Get the outputs of the executed function
     ///
     /// @returns {Array} Array of strings representing the outputs of the function
+    #[cfg(not(feature = "native"))]
     #[wasm_bindgen(js_name = "getOutputs")]
     pub fn get_outputs(&self) -> js_sys::Array {
         let array = js_sys::Array::new_with_length(0u32);
-        self.response.outputs().iter().enumerate().for_each(|(i, output)| {
+        self.outputs.iter().enumerate().for_each(|(i, output)| {
             array.set(i as u32, JsValue::from_str(&output.to_string()));
         });
         array
@@ -95,13 +103,13 @@ impl ExecutionResponse {
     /// Returns the execution object if present, null if otherwise.
     ///
     /// @returns {Execution | undefined} The execution object if present, null if otherwise
-    #[wasm_bindgen(js_name = "getExecution")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "getExecution"))]
     pub fn get_execution(&self) -> Option<Execution> {
-        self.execution.clone().map(Execution::from)
+        self.execution.clone()
     }
 
     /// Returns the program keys if present
-    #[wasm_bindgen(js_name = "getKeys")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "getKeys"))]
     pub fn get_keys(&mut self) -> Result<KeyPair, String> {
         if let Some(proving_key) = self.proving_key.take() {
             Ok(KeyPair::new(ProvingKey::from(proving_key), VerifyingKey::from(self.verifying_key.clone())))
@@ -115,7 +123,7 @@ impl ExecutionResponse {
     /// function. Subsequent calls will return null.
     ///
     /// @returns {ProvingKey | undefined} The proving key
-    #[wasm_bindgen(js_name = "getProvingKey")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "getProvingKey"))]
     pub fn get_proving_key(&mut self) -> Option<ProvingKey> {
         self.proving_key.take().map(ProvingKey::from)
     }
@@ -123,28 +131,27 @@ impl ExecutionResponse {
     /// Returns the verifying_key associated with the program
     ///
     /// @returns {VerifyingKey} The verifying key
-    #[wasm_bindgen(js_name = "getVerifyingKey")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "getVerifyingKey"))]
     pub fn get_verifying_key(&self) -> VerifyingKey {
         VerifyingKey::from(self.verifying_key.clone())
     }
 
     /// Returns the function identifier
-    #[wasm_bindgen(js_name = "getFunctionId")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "getFunctionId"))]
     pub fn get_function_id(&self) -> String {
         format!("{:?}", self.function_id)
     }
 
     /// Returns the program
-    #[wasm_bindgen(js_name = "getProgram")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "getProgram"))]
     pub fn get_program(&self) -> Program {
         Program::from(self.program.clone())
     }
 }
 
-impl Deref for ExecutionResponse {
-    type Target = ResponseNative;
-
-    fn deref(&self) -> &Self::Target {
-        &self.response
+impl ExecutionResponse {
+    /// Get the outputs of the executed function as strings
+    pub fn outputs(&self) -> Vec<String> {
+        self.outputs.iter().map(ToString::to_string).collect()
     }
 }
@@ -14,38 +14,79 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::native::TransactionNative;
+#[cfg(not(feature = "native"))]
+use crate::{account::ViewKey, record::OwnedRecord};
+use crate::{
+    types::{
+        native::{
+            canary,
+            mainnet,
+            testnet,
+            Network as NetworkTrait,
+            Transaction as TransactionGeneric,
+            TransactionNative,
+        },
+        to_network,
+    },
+    Network,
+    SdkError,
+    Transition,
+};
 
+#[cfg(not(feature = "native"))]
+use js_sys::{Array, Object};
 use std::str::FromStr;
-use wasm_bindgen::prelude::wasm_bindgen;
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
 /// Webassembly Representation of an Aleo transaction
 ///
 /// This object is created when generating an on-chain function deployment or execution and is the
 /// object that should be submitted to the Aleo Network in order to deploy or execute a function.
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Transaction(TransactionNative);
+pub struct Transaction {
+    transaction: TransactionNative,
+    network: Network,
+}
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl Transaction {
     /// Create a transaction from a string
     ///
     /// @param {string} transaction String representation of a transaction
+    /// @param {Network | undefined} network (optional) The network the transaction belongs to,
+    /// defaults to mainnet
     /// @returns {Transaction}
-    #[wasm_bindgen(js_name = fromString)]
-    pub fn from_string(transaction: &str) -> Result<Transaction, String> {
-        Transaction::from_str(transaction)
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromString))]
+    pub fn from_string(transaction: &str, network: Option<Network>) -> Result<Transaction, String> {
+        fn parse<N: NetworkTrait>(transaction: &str) -> Result<Transaction, String> {
+            Transaction::from_native(TransactionGeneric::<N>::from_str(transaction).map_err(|e| e.to_string())?)
+        }
+        // Deserialize with the types of the network to validate the transaction against it
+        match network.unwrap_or_default() {
+            Network::MainnetV0 => parse::<mainnet::CurrentNetwork>(transaction),
+            Network::TestnetV0 => parse::<testnet::CurrentNetwork>(transaction),
+            Network::CanaryV0 => parse::<canary::CurrentNetwork>(transaction),
+        }
+    }
+
+    /// Get the network the transaction belongs to
+    ///
+    /// @returns {Network}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn network(&self) -> Network {
+        self.network
     }
 
     /// Get the transaction as a string. If you want to submit this transaction to the Aleo Network
     /// this function will create the string that should be submitted in the `POST` data.
     ///
     /// @returns {string} String representation of the transaction
-    #[wasm_bindgen(js_name = toString)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toString))]
     #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
-        self.0.to_string()
+        self.transaction.to_string()
     }
 
     /// Get the id of the transaction. This is the merkle root of the transaction's inclusion proof.
@@ -55,33 +96,135 @@ impl Transaction {
     /// value can be used to lookup the transaction data on-chain.
     ///
     /// @returns {string} Transaction id
-    #[wasm_bindgen(js_name = transactionId)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = transactionId))]
     pub fn transaction_id(&self) -> String {
-        self.0.id().to_string()
+        self.transaction.id().to_string()
     }
 
     /// Get the type of the transaction (will return "deploy" or "execute")
     ///
     /// @returns {string} Transaction type
-    #[wasm_bindgen(js_name = transactionType)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = transactionType))]
     pub fn transaction_type(&self) -> String {
-        match &self.0 {
+        match &self.transaction {
             TransactionNative::Deploy(..) => "deploy".to_string(),
             TransactionNative::Execute(..) => "execute".to_string(),
             TransactionNative::Fee(..) => "fee".to_string(),
         }
     }
+
+    /// Get the total fee paid by the transaction in microcredits. This is the sum of the base fee
+    /// and the priority fee.
+    ///
+    /// @returns {bigint} Total fee in microcredits
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = feeAmount))]
+    pub fn fee_amount(&self) -> Result<u64, String> {
+        Ok(*self.transaction.fee_amount().map_err(|e| e.to_string())?)
+    }
+
+    /// Get the base fee paid by the transaction in microcredits
+    ///
+    /// @returns {bigint} Base fee in microcredits
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = baseFeeAmount))]
+    pub fn base_fee_amount(&self) -> Result<u64, String> {
+        Ok(*self.transaction.base_fee_amount().map_err(|e| e.to_string())?)
+    }
+
+    /// Get the priority fee paid by the transaction in microcredits
+    ///
+    /// @returns {bigint} Priority fee in microcredits
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = priorityFeeAmount))]
+    pub fn priority_fee_amount(&self) -> Result<u64, String> {
+        Ok(*self.transaction.priority_fee_amount().map_err(|e| e.to_string())?)
+    }
+
+    /// Get the transitions of the transaction, including the fee transition
+    ///
+    /// @returns {Array<Transition>} Transitions of the transaction
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen]
+    pub fn transitions(&self) -> Array {
+        self.transaction
+            .transitions()
+            .map(|transition| JsValue::from(Transition::new(transition.clone(), self.network)))
+            .collect()
+    }
+
+    /// Get the transition which pays the fee of the transaction. Its amounts are available from
+    /// `baseFeeAmount` and `priorityFeeAmount`.
+    ///
+    /// @returns {Transition | undefined} Fee transition, undefined if the transaction pays no fee
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = feeTransition))]
+    pub fn fee_transition(&self) -> Option<Transition> {
+        let fee = match &self.transaction {
+            TransactionNative::Deploy(.., fee) | TransactionNative::Fee(_, fee) => Some(fee),
+            TransactionNative::Execute(_, _, fee) => fee.as_ref(),
+        };
+        fee.map(|fee| Transition::new(fee.transition().clone(), self.network))
+    }
+
+    /// Get the global state root the transaction was proven against
+    ///
+    /// @returns {string} Global state root
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = globalStateRoot))]
+    pub fn global_state_root(&self) -> String {
+        match &self.transaction {
+            TransactionNative::Execute(_, execution, _) => execution.global_state_root().to_string(),
+            TransactionNative::Deploy(.., fee) | TransactionNative::Fee(_, fee) => fee.global_state_root().to_string(),
+        }
+    }
+
+    /// Get a transition of the transaction by its id
+    ///
+    /// @param {string} transition_id Id of the transition
+    /// @returns {Transition}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn transition(&self, transition_id: &str) -> Result<Transition, String> {
+        self.transaction
+            .transitions()
+            .find(|transition| transition.id().to_string() == transition_id)
+            .map(|transition| Transition::new(transition.clone(), self.network))
+            .ok_or_else(|| format!("Transition {transition_id} was not found in the transaction"))
+    }
+
+    /// Find and decrypt the records owned by a view key in the outputs of the transaction's execution and fee transitions
+    ///
+    /// @param {ViewKey} view_key View key of the account to find records for
+    /// @param {Object | undefined} programs (optional) Source code of the programs which created the
+    /// records, keyed by program id. Used to name the records of programs other than credits.aleo
+    /// @returns {Array<OwnedRecord>} Records owned by the view key
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen(js_name = ownedRecords)]
+    pub fn owned_records(&self, view_key: &ViewKey, programs: Option<Object>) -> Result<Array, SdkError> {
+        let records = OwnedRecord::find(self.transaction.transitions(), view_key, programs)?;
+        Ok(records.into_iter().map(JsValue::from).collect())
+    }
+}
+
+impl Transaction {
+    /// Create a transaction from the native transaction of the network it was created on
+    pub(crate) fn from_native<N: NetworkTrait>(transaction: TransactionGeneric<N>) -> Result<Self, String> {
+        Ok(Self { transaction: to_network(transaction)?, network: Network::of::<N>() })
+    }
+
+    /// Get a copy of the transaction as a native transaction of the network `N`, failing if it
+    /// belongs to another network
+    pub(crate) fn to_native<N: NetworkTrait>(&self) -> Result<TransactionGeneric<N>, SdkError> {
+        Network::of::<N>().ensure(self.network)?;
+        Ok(to_network(self.transaction.clone())?)
+    }
 }
 
 impl From<Transaction> for TransactionNative {
     fn from(transaction: Transaction) -> Self {
-        transaction.0
+        transaction.transaction
     }
 }
 
+/// Wrap a native transaction, whose type is the transaction of the default network
 impl From<TransactionNative> for Transaction {
     fn from(transaction: TransactionNative) -> Self {
-        Self(transaction)
+        Self { transaction, network: Network::default() }
     }
 }
 
@@ -89,21 +232,28 @@ impl FromStr for Transaction {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(TransactionNative::from_str(s).map_err(|e| e.to_string())?))
+        Transaction::from_string(s, None)
     }
 }
 
-/*#[cfg(test)]
+#[cfg(test)]
 mod tests {
     use super::*;
+
+    #[cfg(not(feature = "native"))]
+    use wasm_bindgen::convert::TryFromJsValue;
     use wasm_bindgen_test::*;
+
     const TRANSACTION_STRING: &str = "{\"type\":\"execute\",\"id\":\"at1rh04nydu2m07n9wm3pugmlaqh7775lfuawa86ed4eymv2q9wkc9qahtx66\",\"execution\":{\"transitions\":[{\"id\":\"au1xe07pjnw6970k9lh0rfvpdnudcz0gcyy5qmv2efp3qrdxkkaj5rseklkfk\",\"program\":\"credits.aleo\",\"function\":\"transfer_public\",\"inputs\":[{\"type\":\"public\",\"id\":\"6830040130268084683056203786650856838291629627526850542328121029117462649106field\",\"value\":\"aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8\"},{\"type\":\"public\",\"id\":\"3522622156280992546879723962866054193411134839313162974822034464277507937156field\",\"value\":\"1u64\"}],\"outputs\":[{\"type\":\"future\",\"id\":\"6287946476679554718269040652777030908815963134664267470652690289159774741065field\",\"value\":\"{\\n  program_id: credits.aleo,\\n  function_name: transfer_public,\\n  arguments: [\\n    aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8,\\n    aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8,\\n    1u64\\n  ]\\n}\"}],\"tpk\":\"1124897318163588088766079717854473596955076479615330099205126740598806373414group\",\"tcm\":\"5379517959399780344431681060960827894902462418861353186658003065156167347920field\"}],\"global_state_root\":\"sr1tml46c266j4gzv9qpk0adkt6tkl4mjq7s7supuy8dmv9lq09j5zsd5eqsz\",\"proof\":\"proof1qyqsqqqqqqqqqqqpqqqqqqqqqqq9eqfncmzufz24n5xvfk2yy2lm7k0jh2y23yj5ssekln7h2nmlc62mnjwe794rn5dxwwf7unaamfyqqxzhaqnm3xws740w8gwt3dt22r5l43xa9rhn6yc0vpuu46mal3a86n3qmc8yegeh8afyetmz7rs8mq8766v6rryrnhnhl8xudl3tr7rk50f0lrz36cjwp0vpg46fzq4wv9n3eglkn9ztx4kzhh9d0wmqgcqvv2e6lrnaqp9cafaxjh88pzfjn26vyq3y50hazf9c9ysc84x33mn4wculvu67z2utduq5qyy933qqtn7u5rtsztmtakuu2japhf7qcvrc663vkuk9s0twufhh42d2kk3ukf00290jxqe9qnfwr3txz05spv7tp88e7dduldq5wwulae6wm3nztzmzdjrypfz08awuvkzuale9h96hy8nyjt2znntu20c4xemlsyqpfn6ce0sv5nn5shxx2up8kw9xtyle3pcyaum9hsw29ctqcjqmn53j7dxy6ep37cfvnflxqctpuqqgtgss9tp5rzg4vp46fw8nsjztdum9xm2xp0d8hglr2v8fuyh38afsw0kymhn2lznaag7cwud0guqtpdn2l2zgn4sjg7n0gdd0ueg5ujeydmqkxx8dp9a4g456q4jvukjt2cycuvef5slqt3hwnuh6ez5qys785f6xw8xsc5ns6ee3la7rf3p24mkpeakd5ay73q30m3qezux2xzqv35zy8jclv7lxpvcnej8tkstf00fzh9l44q382hpt8eejyp3vs3pq2n3n2e0eq30zatqyrvqsqs4cllynrcytc6v9seuqgtdnzy5rr3vcwwhlrxzm2h66e9q4z94r7zpnkm6xt4yermvys6twaw6sncwt5x64qjdnatddjpeh97uszkvamu6kmltu2unnq2mq4kverfsg8ncpvkhvre77yjhgmw5nevw2az0s2dwr6navrchn7pdwkmmjcu9dedn40jacflfeld7agznzjw3cpwewyhhufu49l5ttjpqrcpl3yzn2m3h3mgq5ea4xedf8370lmmr4ansr60x5d0qwrx08n6r8qe6vq2jlk5t8fey0mcgteef0hxe84vm5khehwjr9vu7p839jpysctaz3z88zcum9nw6z04gqvj3dqvldndldatwknwu4plnlpsqxhg5x8qc0qvqqqqqqqqqqqf57wtv2gucqnx8n0ejkhtywfmxrvewes27sr0ng67f7900w4kga6ztwnzhvtpd4rv0qqhjfmkwssqqvzr0sf8p6hsda4wgak42gtdcxfkf8xfywmdpgecqhxcptdrtvluv9adsn8vc5vwds4kd54thnaggqqxz3799lcez0f8v2xencv2z76fwvgp52wrnh5qyjtteckn2nhlhq8e60eq358pw2lezf74flec2wp4e8gvk5xtrwwy36j36axmy9pmh9kwa9cnsykxzwx38kdtqhnqytsyqqefuv9d\"},\"fee\":{\"transition\":{\"id\":\"au1etgu5md0jd6r3ddyyyg2sr63r5t07tpyaz452redx4rkzjq0dsqqhpp390\",\"program\":\"credits.aleo\",\"function\":\"fee_public\",\"inputs\":[{\"type\":\"public\",\"id\":\"6202819827443625105167501394613513736747919402680330317066933896152810974533field\",\"value\":\"263388u64\"},{\"type\":\"public\",\"id\":\"562300717734796433896686862094601391435687829906952149841892194225660591975field\",\"value\":\"10000u64\"},{\"type\":\"public\",\"id\":\"7883237601094350949043895657366086123587113448531301549046231111645620087265field\",\"value\":\"2003683512649368822747780913503982093177226997476011687299266369052228066725field\"}],\"outputs\":[{\"type\":\"future\",\"id\":\"4003143084174123056047141027259441734516045162674697571360224656380933228034field\",\"value\":\"{\\n  program_id: credits.aleo,\\n  function_name: fee_public,\\n  arguments: [\\n    aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8,\\n    273388u64\\n  ]\\n}\"}],\"tpk\":\"6391092584190750271169179247921812490330238842823180033518604373506188720330group\",\"tcm\":\"1287501176934805652296722632500653287206667978232913581134582827412687172453field\"},\"global_state_root\":\"sr1tml46c266j4gzv9qpk0adkt6tkl4mjq7s7supuy8dmv9lq09j5zsd5eqsz\",\"proof\":\"proof1qyqsqqqqqqqqqqqpqqqqqqqqqqq0vc7dct0xgm89qpdtq9cljzp27k4u0fa0nz0hd8nqlsgjkcj08evn2840dj4k4ygh8tlce383kmqqq9suy0upadgravumuq32nne0zd6673xwj74vunuugsyv40q5ypfjkmdt7t2dsl60ahqpquh5scecyqq6xcz9v72n84l2xl62c79vdc6h99vm5z4ag2mzt5j8lng4dfscxlf9ka067nyu05yh0xq46s7fhzqtf3eadr4kd4fpqqgmkzaj348juxuxqjexv5xtlyl38p6cu5lnn4wylg0w3a02wj4atm5k8rumsaup6dsk6lg9l2r7y3x0cjgw3fc9c9pqnkm86p8udhsjrxxmtp2gaevawyrezqk58x2hwfrgs83f2zhqqhrlxrq6642xjvj02mcj9llucyu234zd04m3hpl05hzu4fx2cuu8wxh74uw0h4dsq8l7g9qm3cn7s87d6drs29j4rexrfwjlnn4hsuvt09elcjtju9ll44vkv004hnmc3rx2gsjwxqgrhxc0w0prntkvzqpmtk33a6fq0v0adeagnc30c76va997pfe37d48z2yvjg9npnvu7xq56tzu32gsnvq8mxnu86zvj5qt2w268h4k9f20smjy2256n6tl7v3m0zxvaqjg8qgwvucwr5r0eufxn7h48eqx6zxawhr5l03jtkgqccqkn5qc67ds9hwn8xrh8hwahu8xf8tykuaq0m7kqm4ww3uzncqtnjxnxgswkj8zecqjvj0p5cs9muejvzgfr6dfr6ekmxm62quecp5q40j9n5vaflwjmwmme5n29ejnf4gfq6yfjt0gdf95mecszfprqjuyr7hvn93qk5qlhcdve9e9sh5q42wj5y74v50jycsp5zuy76es7669stqf3wl0su6zkd44yuwu8tglqdk62mmmjx9590qxcftuqzsthvky3pp0pzpmfelawh0snx439xq5m47zt4u4tf3qga0l5zasvs95n5yulrumfj5uyxs3ssnf47vnh7v2xxmetc46gml2hxwtzsf5uykwdg36l5xxfe9zyn3fzv58vk4hddudkm93plkkqszl956aat24q55tfd5cvmxyy4vu4pg7lvrztf6zm8mpa74ruy8f7vzescnct06qwtf06tygpqya32ehfh23crr9s0s6g8dvzwaq9k26jq0qqk287ps8qvqqqqqqqqqqqn3e0c3qnwqq9pcuczyc53yy7qrya5gapevrkpxf8tm7mmeg8ujh88ru7lj24j0d9almx5uherfysqqwdy8z5kr7ky8qhz4z4ze8qx8mlcd5ksxs7vdpktn2nz3437yejegtu0k6u68up4spsfhgmt9z03vqq9fujl8u73uquc8xjpqqpvwr8dsf4zy3s5p2n43w7gh6kte0luxs76pmkhns42fkktusftt4ymyw083c580awfx97zeq76wyp3x2fn3lmeepwpkva2ms9u757yp2mj62qqqqsuydxg\"}}";
 
     const TRANSACTION_ID: &str = "at1rh04nydu2m07n9wm3pugmlaqh7775lfuawa86ed4eymv2q9wkc9qahtx66";
+    const TRANSITION_ID: &str = "au1xe07pjnw6970k9lh0rfvpdnudcz0gcyy5qmv2efp3qrdxkkaj5rseklkfk";
+    const FEE_TRANSITION_ID: &str = "au1etgu5md0jd6r3ddyyyg2sr63r5t07tpyaz452redx4rkzjq0dsqqhpp390";
+    const GLOBAL_STATE_ROOT: &str = "sr1tml46c266j4gzv9qpk0adkt6tkl4mjq7s7supuy8dmv9lq09j5zsd5eqsz";
 
     #[wasm_bindgen_test]
     fn test_transaction_string_constructor_and_accessor_methods() {
-        let transaction = Transaction::from_string(TRANSACTION_STRING).unwrap();
+        let transaction = Transaction::from_string(TRANSACTION_STRING, None).unwrap();
         let transaction_id = transaction.transaction_id();
         let transaction_type = transaction.transaction_type();
         let recovered_string = transaction.to_string();
@@ -118,4 +268,40 @@ mod tests {
         let transaction_from_native = Transaction::from(transaction_native);
         assert_eq!(transaction, transaction_from_native);
     }
-}*/
+
+    #[wasm_bindgen_test]
+    fn test_transaction_transitions() {
+        let transaction = Transaction::from_string(TRANSACTION_STRING, None).unwrap();
+        assert_eq!(transaction.global_state_root(), GLOBAL_STATE_ROOT);
+
+        // The transitions include the fee transition after the execution's transitions
+        #[cfg(not(feature = "native"))]
+        {
+            let transitions = transaction
+                .transitions()
+                .iter()
+                .map(|transition| Transition::try_from_js_value(transition).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(transitions.len(), 2);
+            assert_eq!(transitions[0].id(), TRANSITION_ID);
+            assert_eq!(transitions[0].function_name(), "transfer_public");
+            assert!(!transitions[0].is_fee());
+            assert_eq!(transitions[1].id(), FEE_TRANSITION_ID);
+            assert!(transitions[1].is_fee());
+            assert!(transitions.iter().all(|transition| transition.network() == Network::MainnetV0));
+        }
+
+        let fee_transition = transaction.fee_transition().unwrap();
+        assert_eq!(fee_transition.id(), FEE_TRANSITION_ID);
+        assert_eq!(fee_transition.program_id(), "credits.aleo");
+        assert_eq!(fee_transition.function_name(), "fee_public");
+        assert_eq!(transaction.base_fee_amount().unwrap(), 263388);
+        assert_eq!(transaction.priority_fee_amount().unwrap(), 10000);
+        assert_eq!(transaction.fee_amount().unwrap(), 273388);
+
+        // Transitions are looked up by their id
+        assert_eq!(transaction.transition(TRANSITION_ID).unwrap().function_name(), "transfer_public");
+        assert_eq!(transaction.transition(FEE_TRANSITION_ID).unwrap(), fee_transition);
+        assert!(transaction.transition(TRANSACTION_ID).is_err());
+    }
+}
@@ -0,0 +1,538 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(not(feature = "native"))]
+use crate::{
+    account::ViewKey,
+    types::native::{Argument, Value},
+};
+use crate::{
+    types::{
+        native::{
+            canary,
+            compute_function_id,
+            mainnet,
+            testnet,
+            CurrentNetwork,
+            Field,
+            Group,
+            Identifier,
+            Input,
+            Network as NetworkTrait,
+            Output,
+            ProgramID,
+            Transition as TransitionGeneric,
+            TransitionNative,
+            ViewKey as ViewKeyGeneric,
+            U16,
+        },
+        to_network,
+    },
+    Network,
+};
+
+#[cfg(not(feature = "native"))]
+use js_sys::Array;
+use std::str::FromStr;
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+/// Webassembly Representation of an Aleo transition
+///
+/// A transition is the record of a single function call within an execution or fee. Its private
+/// inputs and outputs are encrypted and can only be read with the view key of the account which
+/// made the call.
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transition {
+    transition: TransitionNative,
+    network: Network,
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl Transition {
+    /// Create a transition from a string
+    ///
+    /// @param {string} transition String representation of a transition
+    /// @param {Network | undefined} network (optional) The network the transition belongs to,
+    /// defaults to mainnet
+    /// @returns {Transition}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromString))]
+    pub fn from_string(transition: &str, network: Option<Network>) -> Result<Transition, String> {
+        fn parse<N: NetworkTrait>(transition: &str) -> Result<Transition, String> {
+            Transition::from_native(TransitionGeneric::<N>::from_str(transition).map_err(|e| e.to_string())?)
+        }
+        // Deserialize with the types of the network to validate the transition against it
+        match network.unwrap_or_default() {
+            Network::MainnetV0 => parse::<mainnet::CurrentNetwork>(transition),
+            Network::TestnetV0 => parse::<testnet::CurrentNetwork>(transition),
+            Network::CanaryV0 => parse::<canary::CurrentNetwork>(transition),
+        }
+    }
+
+    /// Get the transition as a string
+    ///
+    /// @returns {string} String representation of the transition
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toString))]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.transition.to_string()
+    }
+
+    /// Get the network the transition belongs to
+    ///
+    /// @returns {Network}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Get the id of the transition
+    ///
+    /// @returns {string} Transition id
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn id(&self) -> String {
+        self.transition.id().to_string()
+    }
+
+    /// Get the id of the program the transition belongs to
+    ///
+    /// @returns {string} Program id
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = programId))]
+    pub fn program_id(&self) -> String {
+        self.transition.program_id().to_string()
+    }
+
+    /// Get the name of the function the transition executed
+    ///
+    /// @returns {string} Function name
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = functionName))]
+    pub fn function_name(&self) -> String {
+        self.transition.function_name().to_string()
+    }
+
+    /// Get the transition public key
+    ///
+    /// @returns {string} Transition public key
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn tpk(&self) -> String {
+        self.transition.tpk().to_string()
+    }
+
+    /// Get the transition commitment
+    ///
+    /// @returns {string} Transition commitment
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn tcm(&self) -> String {
+        self.transition.tcm().to_string()
+    }
+
+    /// Returns true if the transition pays the fee of a transaction
+    ///
+    /// @returns {boolean}
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = isFee))]
+    pub fn is_fee(&self) -> bool {
+        self.transition.is_fee_private() || self.transition.is_fee_public()
+    }
+
+    /// Get the inputs of the transition
+    ///
+    /// @returns {Array<TransitionEntry>} Inputs of the transition
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen]
+    pub fn inputs(&self) -> Array {
+        self.transition.inputs().iter().map(TransitionEntry::from).map(JsValue::from).collect()
+    }
+
+    /// Get the outputs of the transition
+    ///
+    /// @returns {Array<TransitionEntry>} Outputs of the transition
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen]
+    pub fn outputs(&self) -> Array {
+        self.transition.outputs().iter().map(TransitionEntry::from).map(JsValue::from).collect()
+    }
+
+    /// Get the arguments the transition passes to the finalize scope of its function. Arguments
+    /// which are futures of called programs are returned as their string representation.
+    ///
+    /// @returns {Array<string>} Finalize arguments, empty if the function has no finalize scope
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen(js_name = finalizeArguments)]
+    pub fn finalize_arguments(&self) -> Array {
+        self.transition
+            .outputs()
+            .iter()
+            .filter_map(|output| match output {
+                Output::Future(_, Some(future)) => Some(future),
+                _ => None,
+            })
+            .flat_map(|future| future.arguments())
+            .map(|argument| match argument {
+                Argument::Plaintext(plaintext) => JsValue::from_str(&plaintext.to_string()),
+                Argument::Future(future) => JsValue::from_str(&future.to_string()),
+            })
+            .collect()
+    }
+
+    /// Decrypt the inputs of the transition with the view key of the account which made the call
+    ///
+    /// The returned array has one entry per input. Constant, public and private inputs are
+    /// returned as plaintext values, record inputs are undefined as only their serial number is
+    /// part of the transition.
+    ///
+    /// @param {ViewKey} view_key View key of the account which created the transition
+    /// @returns {Array<string | undefined>} Plaintext values of the inputs
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen(js_name = decryptInputs)]
+    pub fn decrypt_inputs(&self, view_key: &ViewKey) -> Result<Array, String> {
+        self.decrypt(view_key, false)
+    }
+
+    /// Decrypt the outputs of the transition with the view key of the account which made the call
+    ///
+    /// The returned array has one entry per output. Constant, public and private outputs are
+    /// returned as plaintext values, futures as their finalize arguments and records as plaintext
+    /// records if they are owned by the view key. Other outputs are undefined.
+    ///
+    /// @param {ViewKey} view_key View key of the account which created the transition
+    /// @returns {Array<string | undefined>} Plaintext values of the outputs
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen(js_name = decryptOutputs)]
+    pub fn decrypt_outputs(&self, view_key: &ViewKey) -> Result<Array, String> {
+        self.decrypt(view_key, true)
+    }
+}
+
+impl Transition {
+    /// Create a transition from a transition of a transaction or execution of the given network
+    pub(crate) fn new(transition: TransitionNative, network: Network) -> Self {
+        Self { transition, network }
+    }
+
+    /// Create a transition from the native transition of the network it was created on
+    pub(crate) fn from_native<N: NetworkTrait>(transition: TransitionGeneric<N>) -> Result<Self, String> {
+        Ok(Self::new(to_network(transition)?, Network::of::<N>()))
+    }
+
+    /// Decrypt the inputs or outputs of the transition with the types of its network
+    #[cfg(not(feature = "native"))]
+    fn decrypt(&self, view_key: &ViewKey, outputs: bool) -> Result<Array, String> {
+        let values = match self.network {
+            Network::MainnetV0 => self.decrypt_native::<mainnet::CurrentNetwork>(view_key, outputs)?,
+            Network::TestnetV0 => self.decrypt_native::<testnet::CurrentNetwork>(view_key, outputs)?,
+            Network::CanaryV0 => self.decrypt_native::<canary::CurrentNetwork>(view_key, outputs)?,
+        };
+        Ok(values
+            .into_iter()
+            .map(|value| value.map_or(JsValue::UNDEFINED, |value| JsValue::from_str(&value)))
+            .collect())
+    }
+
+    #[cfg(not(feature = "native"))]
+    fn decrypt_native<N: NetworkTrait>(
+        &self,
+        view_key: &ViewKey,
+        outputs: bool,
+    ) -> Result<Vec<Option<String>>, String> {
+        let transition: TransitionGeneric<N> = to_network(self.transition.clone())?;
+        let view_key: ViewKeyGeneric<N> = to_network(**view_key)?;
+
+        // Only the account which made the call can derive the transition view key
+        let tvk = transition_view_key(&view_key, transition.tpk());
+        if N::hash_psd2(&[tvk]).map_err(|e| e.to_string())? != *transition.tcm() {
+            return Err("The view key provided did not create the transition".to_string());
+        }
+        let entry_key = |index: usize| {
+            let index = u16::try_from(index).map_err(|_| "The transition has too many entries".to_string())?;
+            entry_view_key::<N>(tvk, transition.program_id(), transition.function_name(), index)
+        };
+
+        if !outputs {
+            return transition
+                .inputs()
+                .iter()
+                .enumerate()
+                .map(|(index, input)| match input {
+                    Input::Constant(_, Some(plaintext)) | Input::Public(_, Some(plaintext)) => {
+                        Ok(Some(plaintext.to_string()))
+                    }
+                    Input::Private(_, Some(ciphertext)) => {
+                        let plaintext = ciphertext.decrypt_symmetric(entry_key(index)?).map_err(|e| e.to_string())?;
+                        Ok(Some(plaintext.to_string()))
+                    }
+                    _ => Ok(None),
+                })
+                .collect();
+        }
+
+        let num_inputs = transition.inputs().len();
+        transition
+            .outputs()
+            .iter()
+            .enumerate()
+            .map(|(index, output)| match output {
+                Output::Constant(_, Some(plaintext)) | Output::Public(_, Some(plaintext)) => {
+                    Ok(Some(plaintext.to_string()))
+                }
+                Output::Private(_, Some(ciphertext)) => {
+                    let plaintext =
+                        ciphertext.decrypt_symmetric(entry_key(num_inputs + index)?).map_err(|e| e.to_string())?;
+                    Ok(Some(plaintext.to_string()))
+                }
+                Output::Record(_, _, Some(record)) if record.is_owner(&view_key) => {
+                    let record = record.decrypt(&view_key).map_err(|e| e.to_string())?;
+                    Ok(Some(Value::Record(record).to_string()))
+                }
+                Output::Future(_, Some(future)) => Ok(Some(future.to_string())),
+                _ => Ok(None),
+            })
+            .collect()
+    }
+}
+
+/// An input or output of a transition
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitionEntry {
+    visibility: &'static str,
+    id: String,
+    value: Option<String>,
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl TransitionEntry {
+    /// Get the visibility of the entry, one of "constant", "public", "private", "record",
+    /// "external_record" or "future"
+    ///
+    /// @returns {string} Visibility of the entry
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn visibility(&self) -> String {
+        self.visibility.to_string()
+    }
+
+    /// Get the id of the entry. This is the serial number of record inputs and the commitment of
+    /// record outputs.
+    ///
+    /// @returns {string} Id of the entry
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    /// Get the value of the entry. Constant and public entries are plaintext, private entries are
+    /// ciphertext, record outputs are record ciphertext and future outputs are the future. Record
+    /// inputs and external records have no value.
+    ///
+    /// @returns {string | undefined} Value of the entry
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn value(&self) -> Option<String> {
+        self.value.clone()
+    }
+}
+
+impl From<&Input<CurrentNetwork>> for TransitionEntry {
+    fn from(input: &Input<CurrentNetwork>) -> Self {
+        let (visibility, value) = match input {
+            Input::Constant(_, value) => ("constant", value.as_ref().map(ToString::to_string)),
+            Input::Public(_, value) => ("public", value.as_ref().map(ToString::to_string)),
+            Input::Private(_, value) => ("private", value.as_ref().map(ToString::to_string)),
+            Input::Record(..) => ("record", None),
+            Input::ExternalRecord(_) => ("external_record", None),
+        };
+        Self { visibility, id: input.id().to_string(), value }
+    }
+}
+
+impl From<&Output<CurrentNetwork>> for TransitionEntry {
+    fn from(output: &Output<CurrentNetwork>) -> Self {
+        let (visibility, value) = match output {
+            Output::Constant(_, value) => ("constant", value.as_ref().map(ToString::to_string)),
+            Output::Public(_, value) => ("public", value.as_ref().map(ToString::to_string)),
+            Output::Private(_, value) => ("private", value.as_ref().map(ToString::to_string)),
+            Output::Record(_, _, value) => ("record", value.as_ref().map(ToString::to_string)),
+            Output::ExternalRecord(_) => ("external_record", None),
+            Output::Future(_, value) => ("future", value.as_ref().map(ToString::to_string)),
+        };
+        Self { visibility, id: output.id().to_string(), value }
+    }
+}
+
+/// Derive the transition view key from the transition public key and the view key of the caller
+pub(crate) fn transition_view_key<N: NetworkTrait>(view_key: &ViewKeyGeneric<N>, tpk: &Group<N>) -> Field<N> {
+    (*tpk * **view_key).to_x_coordinate()
+}
+
+/// Derive the symmetric key which encrypts the private input or output at `index` of a transition,
+/// where outputs are indexed after the inputs
+pub(crate) fn entry_view_key<N: NetworkTrait>(
+    tvk: Field<N>,
+    program_id: &ProgramID<N>,
+    function_name: &Identifier<N>,
+    index: u16,
+) -> Result<Field<N>, String> {
+    let function_id = compute_function_id(&U16::new(N::ID), program_id, function_name).map_err(|e| e.to_string())?;
+    N::hash_psd4(&[function_id, tvk, Field::from_u16(index)]).map_err(|e| e.to_string())
+}
+
+impl From<Transition> for TransitionNative {
+    fn from(transition: Transition) -> Self {
+        transition.transition
+    }
+}
+
+/// Wrap a native transition, whose type is the transition of the default network
+impl From<TransitionNative> for Transition {
+    fn from(transition: TransitionNative) -> Self {
+        Self { transition, network: Network::default() }
+    }
+}
+
+impl FromStr for Transition {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Transition::from_string(s, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(feature = "native"))]
+    use crate::account::PrivateKey;
+    use crate::types::native::{CurrentAleo, PrivateKeyNative, ProcessNative};
+
+    use rand::{rngs::StdRng, SeedableRng};
+    use wasm_bindgen_test::*;
+
+    const PRIVATE_KEY: &str = "APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6";
+    const RECORD: &str = "{  owner: aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4.private,  microcredits: 2000000u64.private,  _nonce: 4106205762862305308495708971985748592380064201230396559307556388725936304984group.public}";
+
+    fn sample_transition() -> Transition {
+        let process = ProcessNative::load_web().unwrap();
+        let private_key = PrivateKeyNative::from_str(PRIVATE_KEY).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &private_key,
+                "credits.aleo",
+                "split",
+                [RECORD, "1500000u64"].into_iter(),
+                &mut StdRng::from_entropy(),
+            )
+            .unwrap();
+        let transition = authorization.transitions().values().next().unwrap().clone();
+        Transition::from(transition)
+    }
+
+    #[wasm_bindgen_test]
+    fn test_transition_string_roundtrip() {
+        let transition = sample_transition();
+        let from_string = Transition::from_string(&transition.to_string(), None).unwrap();
+        assert_eq!(transition, from_string);
+        assert_eq!(transition.program_id(), "credits.aleo");
+        assert_eq!(transition.function_name(), "split");
+        assert!(Transition::from_string("garbage", None).is_err());
+    }
+
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen_test]
+    fn test_decrypt_transition_entries() {
+        let transition = sample_transition();
+        let view_key = ViewKey::from_private_key(&PrivateKey::from_string(PRIVATE_KEY).unwrap());
+
+        // The record input is only a serial number, the amount is a private input
+        let inputs = transition.decrypt_inputs(&view_key).unwrap();
+        assert_eq!(inputs.length(), 2);
+        assert!(inputs.get(0).is_undefined());
+        assert_eq!(inputs.get(1).as_string().unwrap(), "1500000u64");
+
+        // Both records created by the split are owned by the caller
+        let outputs = transition.decrypt_outputs(&view_key).unwrap();
+        assert_eq!(outputs.length(), 2);
+        assert!(outputs.get(0).as_string().unwrap().contains("1500000u64.private"));
+        assert!(outputs.get(1).as_string().unwrap().contains("500000u64.private"));
+
+        // A single ciphertext can be decrypted from the transition public key
+        let native = TransitionNative::from(transition.clone());
+        let ciphertext = match &native.inputs()[1] {
+            Input::Private(_, Some(ciphertext)) => ciphertext.to_string(),
+            _ => panic!("Expected a private input"),
+        };
+        let plaintext = view_key
+            .decrypt_ciphertext(&ciphertext, &native.tpk().to_string(), "credits.aleo", "split", 1, None)
+            .unwrap();
+        assert_eq!(plaintext, "1500000u64");
+
+        // Another account cannot decrypt the transition
+        let other_view_key = ViewKey::from_private_key(&PrivateKey::new());
+        assert!(transition.decrypt_inputs(&other_view_key).is_err());
+        assert!(transition.decrypt_outputs(&other_view_key).is_err());
+    }
+
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen_test]
+    fn test_transition_entries() {
+        let transition = sample_transition();
+        assert!(!transition.is_fee());
+        assert!(transition.tpk().ends_with("group"));
+        assert!(transition.tcm().ends_with("field"));
+        assert_eq!(transition.inputs().length(), 2);
+        assert_eq!(transition.outputs().length(), 2);
+        assert_eq!(transition.finalize_arguments().length(), 0);
+
+        // Record inputs only reveal their serial number, record outputs their ciphertext
+        let native = TransitionNative::from(transition);
+        let inputs = native.inputs().iter().map(TransitionEntry::from).collect::<Vec<_>>();
+        assert_eq!(inputs[0].visibility(), "record");
+        assert_eq!(inputs[0].id(), native.inputs()[0].id().to_string());
+        assert!(inputs[0].value().is_none());
+        assert_eq!(inputs[1].visibility(), "private");
+        assert!(inputs[1].value().unwrap().starts_with("ciphertext1"));
+        let outputs = native.outputs().iter().map(TransitionEntry::from).collect::<Vec<_>>();
+        assert!(outputs.iter().all(|output| output.visibility() == "record"));
+        assert!(outputs.iter().all(|output| output.value().unwrap().starts_with("record1")));
+    }
+
+    #[cfg(not(feature = "native"))]
+    #[wasm_bindgen_test]
+    fn test_transition_finalize_arguments() {
+        let process = ProcessNative::load_web().unwrap();
+        let private_key = PrivateKeyNative::from_str(PRIVATE_KEY).unwrap();
+        let recipient = "aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4";
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &private_key,
+                "credits.aleo",
+                "transfer_public",
+                [recipient, "1u64"].into_iter(),
+                &mut StdRng::from_entropy(),
+            )
+            .unwrap();
+        let transition = Transition::from(authorization.transitions().values().next().unwrap().clone());
+
+        // The public inputs are passed on to the finalize scope together with the caller
+        let native = TransitionNative::from(transition.clone());
+        assert!(native.inputs().iter().map(TransitionEntry::from).all(|input| input.visibility() == "public"));
+        let output = TransitionEntry::from(&native.outputs()[0]);
+        assert_eq!(output.visibility(), "future");
+        assert!(output.value().unwrap().contains("transfer_public"));
+        let arguments = transition.finalize_arguments();
+        assert_eq!(arguments.length(), 3);
+        assert_eq!(arguments.get(1).as_string().unwrap(), recipient);
+        assert_eq!(arguments.get(2).as_string().unwrap(), "1u64");
+    }
+}
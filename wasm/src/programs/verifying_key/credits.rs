@@ -16,254 +16,295 @@
 
 use super::*;
 
-#[wasm_bindgen]
 impl VerifyingKey {
-    fn get_credits_verifying_key(name: &str) -> VerifyingKey {
-        let vk = CurrentNetwork::get_credits_verifying_key(name.to_string()).unwrap().clone();
+    fn get_credits_verifying_key(name: &str, network: Option<Network>) -> VerifyingKey {
+        match network.unwrap_or_default() {
+            Network::MainnetV0 => Self::credits_verifying_key::<mainnet::CurrentNetwork>(name),
+            Network::TestnetV0 => Self::credits_verifying_key::<testnet::CurrentNetwork>(name),
+            Network::CanaryV0 => Self::credits_verifying_key::<canary::CurrentNetwork>(name),
+        }
+    }
+
+    fn credits_verifying_key<N: NetworkTrait>(name: &str) -> VerifyingKey {
+        let vk = match name {
+            "inclusion" => N::inclusion_verifying_key().clone(),
+            name => N::get_credits_verifying_key(name.to_string()).unwrap().clone(),
+        };
         let num_variables = vk.circuit_info.num_public_and_private_variables as u64;
-        VerifyingKey::from(VerifyingKeyNative::new(vk, num_variables))
+        VerifyingKey::from_network(crate::types::native::VerifyingKey::<N>::new(vk, num_variables)).unwrap()
     }
+}
 
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl VerifyingKey {
     /// Returns the verifying key for the bond_public function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the bond_public function
-    #[wasm_bindgen(js_name = "bondPublicVerifier")]
-    pub fn bond_public_verifier() -> VerifyingKey {
-        VerifyingKey::get_credits_verifying_key("bond_public")
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "bondPublicVerifier"))]
+    pub fn bond_public_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("bond_public", network)
     }
 
     /// Returns the verifying key for the bond_validator function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the bond_validator function
-    #[wasm_bindgen(js_name = "bondValidatorVerifier")]
-    pub fn bond_validator_verifier() -> VerifyingKey {
-        VerifyingKey::get_credits_verifying_key("bond_validator")
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "bondValidatorVerifier"))]
+    pub fn bond_validator_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("bond_validator", network)
     }
 
     /// Returns the verifying key for the claim_delegator function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the claim_unbond_public function
-    #[wasm_bindgen(js_name = "claimUnbondPublicVerifier")]
-    pub fn claim_unbond_public_verifier() -> VerifyingKey {
-        VerifyingKey::get_credits_verifying_key("claim_unbond_public")
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "claimUnbondPublicVerifier"))]
+    pub fn claim_unbond_public_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("claim_unbond_public", network)
     }
 
     /// Returns the verifying key for the fee_private function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the fee_private function
-    #[wasm_bindgen(js_name = "feePrivateVerifier")]
-    pub fn fee_private_verifier() -> VerifyingKey {
-        VerifyingKey::get_credits_verifying_key("fee_private")
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "feePrivateVerifier"))]
+    pub fn fee_private_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("fee_private", network)
     }
 
     /// Returns the verifying key for the fee_public function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the fee_public function
-    #[wasm_bindgen(js_name = "feePublicVerifier")]
-    pub fn fee_public_verifier() -> VerifyingKey {
-        VerifyingKey::get_credits_verifying_key("fee_public")
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "feePublicVerifier"))]
+    pub fn fee_public_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("fee_public", network)
     }
 
     /// Returns the verifying key for the inclusion function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the inclusion function
-    #[wasm_bindgen(js_name = "inclusionVerifier")]
-    pub fn inclusion_verifier() -> VerifyingKey {
-        let vk = CurrentNetwork::inclusion_verifying_key().clone();
-        let num_variables = vk.circuit_info.num_public_and_private_variables as u64;
-        VerifyingKey::from(VerifyingKeyNative::new(vk, num_variables))
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "inclusionVerifier"))]
+    pub fn inclusion_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("inclusion", network)
     }
 
     /// Returns the verifying key for the join function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the join function
-    #[wasm_bindgen(js_name = "joinVerifier")]
-    pub fn join_verifier() -> VerifyingKey {
-        VerifyingKey::get_credits_verifying_key("join")
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "joinVerifier"))]
+    pub fn join_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("join", network)
     }
 
     /// Returns the verifying key for the set_validator_state function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the set_validator_state function
-    #[wasm_bindgen(js_name = "setValidatorStateVerifier")]
-    pub fn set_validator_state_verifier() -> VerifyingKey {
-        VerifyingKey::get_credits_verifying_key("set_validator_state")
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "setValidatorStateVerifier"))]
+    pub fn set_validator_state_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("set_validator_state", network)
     }
 
     /// Returns the verifying key for the split function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the split function
-    #[wasm_bindgen(js_name = "splitVerifier")]
-    pub fn split_verifier() -> VerifyingKey {
-        VerifyingKey::get_credits_verifying_key("split")
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "splitVerifier"))]
+    pub fn split_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("split", network)
     }
 
     /// Returns the verifying key for the transfer_private function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the transfer_private function
-    #[wasm_bindgen(js_name = "transferPrivateVerifier")]
-    pub fn transfer_private_verifier() -> VerifyingKey {
-        VerifyingKey::get_credits_verifying_key("transfer_private")
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "transferPrivateVerifier"))]
+    pub fn transfer_private_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("transfer_private", network)
     }
 
     /// Returns the verifying key for the transfer_private_to_public function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the transfer_private_to_public function
-    #[wasm_bindgen(js_name = "transferPrivateToPublicVerifier")]
-    pub fn transfer_private_to_public_verifier() -> VerifyingKey {
-        VerifyingKey::get_credits_verifying_key("transfer_private_to_public")
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "transferPrivateToPublicVerifier"))]
+    pub fn transfer_private_to_public_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("transfer_private_to_public", network)
     }
 
     /// Returns the verifying key for the transfer_public function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the transfer_public function
-    #[wasm_bindgen(js_name = "transferPublicVerifier")]
-    pub fn transfer_public_verifier() -> VerifyingKey {
-        VerifyingKey::get_credits_verifying_key("transfer_public")
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "transferPublicVerifier"))]
+    pub fn transfer_public_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("transfer_public", network)
     }
 
     /// Returns the verifying key for the transfer_public_as_signer function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the transfer_public_as_signer function
-    #[wasm_bindgen(js_name = "transferPublicAsSignerVerifier")]
-    pub fn transfer_public_as_signer_verifier() -> VerifyingKey {
-        VerifyingKey::get_credits_verifying_key("transfer_public_as_signer")
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "transferPublicAsSignerVerifier"))]
+    pub fn transfer_public_as_signer_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("transfer_public_as_signer", network)
     }
 
     /// Returns the verifying key for the transfer_public_to_private function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the transfer_public_to_private function
-    #[wasm_bindgen(js_name = "transferPublicToPrivateVerifier")]
-    pub fn transfer_public_to_private_verifier() -> VerifyingKey {
-        VerifyingKey::get_credits_verifying_key("transfer_public_to_private")
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "transferPublicToPrivateVerifier"))]
+    pub fn transfer_public_to_private_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("transfer_public_to_private", network)
     }
 
     /// Returns the verifying key for the unbond_public function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the unbond_public function
-    #[wasm_bindgen(js_name = "unbondPublicVerifier")]
-    pub fn unbond_public_verifier() -> VerifyingKey {
-        VerifyingKey::get_credits_verifying_key("unbond_public")
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "unbondPublicVerifier"))]
+    pub fn unbond_public_verifier(network: Option<Network>) -> VerifyingKey {
+        VerifyingKey::get_credits_verifying_key("unbond_public", network)
     }
 
     /// Returns the verifying key for the bond_public function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the bond_public function
-    #[wasm_bindgen(js_name = "isBondPublicVerifier")]
-    pub fn is_bond_public_verifier(&self) -> bool {
-        self == &Self::bond_public_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isBondPublicVerifier"))]
+    pub fn is_bond_public_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::bond_public_verifier(network)
     }
 
     /// Returns the verifying key for the bond_validator function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {VerifyingKey} Verifying key for the bond_validator function
-    #[wasm_bindgen(js_name = "isBondValidatorVerifier")]
-    pub fn is_bond_validator_verifier(&self) -> bool {
-        self == &Self::bond_validator_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isBondValidatorVerifier"))]
+    pub fn is_bond_validator_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::bond_validator_verifier(network)
     }
 
     /// Verifies the verifying key is for the claim_delegator function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {bool}
-    #[wasm_bindgen(js_name = "isClaimUnbondPublicVerifier")]
-    pub fn is_claim_unbond_public_verifier(&self) -> bool {
-        self == &Self::claim_unbond_public_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isClaimUnbondPublicVerifier"))]
+    pub fn is_claim_unbond_public_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::claim_unbond_public_verifier(network)
     }
 
     /// Verifies the verifying key is for the fee_private function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {bool}
-    #[wasm_bindgen(js_name = "isFeePrivateVerifier")]
-    pub fn is_fee_private_verifier(&self) -> bool {
-        self == &Self::fee_private_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isFeePrivateVerifier"))]
+    pub fn is_fee_private_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::fee_private_verifier(network)
     }
 
     /// Verifies the verifying key is for the fee_public function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {bool}
-    #[wasm_bindgen(js_name = "isFeePublicVerifier")]
-    pub fn is_fee_public_verifier(&self) -> bool {
-        self == &Self::fee_public_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isFeePublicVerifier"))]
+    pub fn is_fee_public_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::fee_public_verifier(network)
     }
 
     /// Verifies the verifying key is for the inclusion function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {bool}
-    #[wasm_bindgen(js_name = "isInclusionVerifier")]
-    pub fn is_inclusion_verifier(&self) -> bool {
-        self == &Self::inclusion_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isInclusionVerifier"))]
+    pub fn is_inclusion_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::inclusion_verifier(network)
     }
 
     /// Verifies the verifying key is for the join function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {bool}
-    #[wasm_bindgen(js_name = "isJoinVerifier")]
-    pub fn is_join_verifier(&self) -> bool {
-        self == &Self::join_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isJoinVerifier"))]
+    pub fn is_join_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::join_verifier(network)
     }
 
     /// Verifies the verifying key is for the set_validator_state function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {bool}
-    #[wasm_bindgen(js_name = "isSetValidatorStateVerifier")]
-    pub fn is_set_validator_state_verifier(&self) -> bool {
-        self == &Self::set_validator_state_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isSetValidatorStateVerifier"))]
+    pub fn is_set_validator_state_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::set_validator_state_verifier(network)
     }
 
     /// Verifies the verifying key is for the split function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {bool}
-    #[wasm_bindgen(js_name = "isSplitVerifier")]
-    pub fn is_split_verifier(&self) -> bool {
-        self == &Self::split_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isSplitVerifier"))]
+    pub fn is_split_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::split_verifier(network)
     }
 
     /// Verifies the verifying key is for the transfer_private function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {bool}
-    #[wasm_bindgen(js_name = "isTransferPrivateVerifier")]
-    pub fn is_transfer_private_verifier(&self) -> bool {
-        self == &Self::transfer_private_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isTransferPrivateVerifier"))]
+    pub fn is_transfer_private_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::transfer_private_verifier(network)
     }
 
     /// Verifies the verifying key is for the transfer_private_to_public function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {bool}
-    #[wasm_bindgen(js_name = "isTransferPrivateToPublicVerifier")]
-    pub fn is_transfer_private_to_public_verifier(&self) -> bool {
-        self == &Self::transfer_private_to_public_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isTransferPrivateToPublicVerifier"))]
+    pub fn is_transfer_private_to_public_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::transfer_private_to_public_verifier(network)
     }
 
     /// Verifies the verifying key is for the transfer_public function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {bool}
-    #[wasm_bindgen(js_name = "isTransferPublicVerifier")]
-    pub fn is_transfer_public_verifier(&self) -> bool {
-        self == &Self::transfer_public_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isTransferPublicVerifier"))]
+    pub fn is_transfer_public_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::transfer_public_verifier(network)
     }
 
     /// Verifies the verifying key is for the transfer_public_as_signer function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {bool}
-    #[wasm_bindgen(js_name = "isTransferPublicAsSignerVerifier")]
-    pub fn is_transfer_public_as_signer_verifier(&self) -> bool {
-        self == &Self::transfer_public_as_signer_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isTransferPublicAsSignerVerifier"))]
+    pub fn is_transfer_public_as_signer_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::transfer_public_as_signer_verifier(network)
     }
 
     /// Verifies the verifying key is for the transfer_public_to_private function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {bool}
-    #[wasm_bindgen(js_name = "isTransferPublicToPrivateVerifier")]
-    pub fn is_transfer_public_to_private_verifier(&self) -> bool {
-        self == &Self::transfer_public_to_private_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isTransferPublicToPrivateVerifier"))]
+    pub fn is_transfer_public_to_private_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::transfer_public_to_private_verifier(network)
     }
 
     /// Verifies the verifying key is for the unbond_public function
     ///
+    /// @param {Network | undefined} network (optional) The network of the key, defaults to mainnet
     /// @returns {bool}
-    #[wasm_bindgen(js_name = "isUnbondPublicVerifier")]
-    pub fn is_unbond_public_verifier(&self) -> bool {
-        self == &Self::unbond_public_verifier()
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "isUnbondPublicVerifier"))]
+    pub fn is_unbond_public_verifier(&self, network: Option<Network>) -> bool {
+        self == &Self::unbond_public_verifier(network)
     }
 }
 
@@ -274,35 +315,35 @@ mod tests {
 
     #[wasm_bindgen_test]
     fn test_key_loading() {
-        let bond_public = VerifyingKey::bond_public_verifier();
-        assert!(bond_public.is_bond_public_verifier());
-        let bond_validator = VerifyingKey::bond_validator_verifier();
-        assert!(bond_validator.is_bond_validator_verifier());
-        let claim_unbond_public = VerifyingKey::claim_unbond_public_verifier();
-        assert!(claim_unbond_public.is_claim_unbond_public_verifier());
-        let fee_private = VerifyingKey::fee_private_verifier();
-        assert!(fee_private.is_fee_private_verifier());
-        let fee_public = VerifyingKey::fee_public_verifier();
-        assert!(fee_public.is_fee_public_verifier());
-        let inclusion = VerifyingKey::inclusion_verifier();
-        assert!(inclusion.is_inclusion_verifier());
-        let join = VerifyingKey::join_verifier();
-        assert!(join.is_join_verifier());
-        let set_validator_state = VerifyingKey::set_validator_state_verifier();
-        assert!(set_validator_state.is_set_validator_state_verifier());
-        let split = VerifyingKey::split_verifier();
-        assert!(split.is_split_verifier());
-        let transfer_private = VerifyingKey::transfer_private_verifier();
-        assert!(transfer_private.is_transfer_private_verifier());
-        let transfer_private_to_public = VerifyingKey::transfer_private_to_public_verifier();
-        assert!(transfer_private_to_public.is_transfer_private_to_public_verifier());
-        let transfer_public = VerifyingKey::transfer_public_verifier();
-        assert!(transfer_public.is_transfer_public_verifier());
-        let transfer_public_as_signer = VerifyingKey::transfer_public_as_signer_verifier();
-        assert!(transfer_public_as_signer.is_transfer_public_as_signer_verifier());
-        let transfer_public_to_private = VerifyingKey::transfer_public_to_private_verifier();
-        assert!(transfer_public_to_private.is_transfer_public_to_private_verifier());
-        let unbond_public = VerifyingKey::unbond_public_verifier();
-        assert!(unbond_public.is_unbond_public_verifier());
+        let bond_public = VerifyingKey::bond_public_verifier(None);
+        assert!(bond_public.is_bond_public_verifier(None));
+        let bond_validator = VerifyingKey::bond_validator_verifier(None);
+        assert!(bond_validator.is_bond_validator_verifier(None));
+        let claim_unbond_public = VerifyingKey::claim_unbond_public_verifier(None);
+        assert!(claim_unbond_public.is_claim_unbond_public_verifier(None));
+        let fee_private = VerifyingKey::fee_private_verifier(None);
+        assert!(fee_private.is_fee_private_verifier(None));
+        let fee_public = VerifyingKey::fee_public_verifier(None);
+        assert!(fee_public.is_fee_public_verifier(None));
+        let inclusion = VerifyingKey::inclusion_verifier(None);
+        assert!(inclusion.is_inclusion_verifier(None));
+        let join = VerifyingKey::join_verifier(None);
+        assert!(join.is_join_verifier(None));
+        let set_validator_state = VerifyingKey::set_validator_state_verifier(None);
+        assert!(set_validator_state.is_set_validator_state_verifier(None));
+        let split = VerifyingKey::split_verifier(None);
+        assert!(split.is_split_verifier(None));
+        let transfer_private = VerifyingKey::transfer_private_verifier(None);
+        assert!(transfer_private.is_transfer_private_verifier(None));
+        let transfer_private_to_public = VerifyingKey::transfer_private_to_public_verifier(None);
+        assert!(transfer_private_to_public.is_transfer_private_to_public_verifier(None));
+        let transfer_public = VerifyingKey::transfer_public_verifier(None);
+        assert!(transfer_public.is_transfer_public_verifier(None));
+        let transfer_public_as_signer = VerifyingKey::transfer_public_as_signer_verifier(None);
+        assert!(transfer_public_as_signer.is_transfer_public_as_signer_verifier(None));
+        let transfer_public_to_private = VerifyingKey::transfer_public_to_private_verifier(None);
+        assert!(transfer_public_to_private.is_transfer_public_to_private_verifier(None));
+        let unbond_public = VerifyingKey::unbond_public_verifier(None);
+        assert!(unbond_public.is_unbond_public_verifier(None));
     }
 }
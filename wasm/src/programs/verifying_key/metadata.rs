@@ -36,9 +36,14 @@ pub struct Metadata {
 }
 
 impl Metadata {
-    const BASE_URL: &'static str = crate::types::native::BASE_URL;
-
-    fn new(name: &str, verifying_key: &str, locator: &str, prover: &'static str, verifier: &'static str) -> Self {
+    fn new(
+        network: Network,
+        name: &str,
+        verifying_key: &str,
+        locator: &str,
+        prover: &'static str,
+        verifier: &'static str,
+    ) -> Self {
         fn url(function_name: &str, kind: &str, proving_key_metadata: &'static str) -> String {
             let metadata: serde_json::Value =
                 serde_json::from_str(proving_key_metadata).expect("Metadata was not well-formatted");
@@ -49,7 +54,7 @@ impl Metadata {
         Self {
             name: name.to_string(),
             locator: locator.to_string(),
-            prover: format!("{}{}", Self::BASE_URL, url(name, "prover", prover)),
+            prover: format!("{}{}", network.parameters_url(), url(name, "prover", prover)),
             verifier: url(name, "verifier", verifier),
             verifying_key: verifying_key.to_string(),
         }
@@ -58,173 +63,207 @@ impl Metadata {
 
 #[wasm_bindgen]
 impl Metadata {
+    /// Get the url the credits.aleo proving keys of a network are hosted at
+    ///
+    /// @param {Network | undefined} network (optional) The network of the keys, defaults to mainnet
+    /// @returns {string}
     #[wasm_bindgen(js_name = baseUrl)]
-    pub fn base_url() -> String {
-        Self::BASE_URL.to_string()
+    pub fn base_url(network: Option<Network>) -> String {
+        network.unwrap_or_default().parameters_url().to_string()
     }
 
     #[wasm_bindgen]
-    pub fn bond_public() -> Metadata {
+    pub fn bond_public(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "bond_public",
             "bondPublicVerifier",
             "credits.aleo/bond_public",
-            crate::types::native::parameters::BondPublicProver::METADATA,
-            crate::types::native::parameters::BondPublicVerifier::METADATA,
+            crate::parameters_metadata!(network, BondPublicProver),
+            crate::parameters_metadata!(network, BondPublicVerifier),
         )
     }
 
     #[wasm_bindgen]
-    pub fn bond_validator() -> Metadata {
+    pub fn bond_validator(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "bond_validator",
             "bondValidatorVerifier",
             "credits.aleo/bond_validator",
-            crate::types::native::parameters::BondValidatorProver::METADATA,
-            crate::types::native::parameters::BondValidatorVerifier::METADATA,
+            crate::parameters_metadata!(network, BondValidatorProver),
+            crate::parameters_metadata!(network, BondValidatorVerifier),
         )
     }
 
     #[wasm_bindgen]
-    pub fn claim_unbond_public() -> Metadata {
+    pub fn claim_unbond_public(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "claim_unbond_public",
             "claimUnbondPublicVerifier",
             "credits.aleo/claim_unbond_public",
-            crate::types::native::parameters::ClaimUnbondPublicProver::METADATA,
-            crate::types::native::parameters::ClaimUnbondPublicVerifier::METADATA,
+            crate::parameters_metadata!(network, ClaimUnbondPublicProver),
+            crate::parameters_metadata!(network, ClaimUnbondPublicVerifier),
         )
     }
 
     #[wasm_bindgen]
-    pub fn fee_private() -> Metadata {
+    pub fn fee_private(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "fee_private",
             "feePrivateVerifier",
             "credits.aleo/fee_private",
-            crate::types::native::parameters::FeePrivateProver::METADATA,
-            crate::types::native::parameters::FeePrivateVerifier::METADATA,
+            crate::parameters_metadata!(network, FeePrivateProver),
+            crate::parameters_metadata!(network, FeePrivateVerifier),
         )
     }
 
     #[wasm_bindgen]
-    pub fn fee_public() -> Metadata {
+    pub fn fee_public(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "fee_public",
             "feePublicVerifier",
             "credits.aleo/fee_public",
-            crate::types::native::parameters::FeePublicProver::METADATA,
-            crate::types::native::parameters::FeePublicVerifier::METADATA,
+            crate::parameters_metadata!(network, FeePublicProver),
+            crate::parameters_metadata!(network, FeePublicVerifier),
         )
     }
 
     #[wasm_bindgen]
-    pub fn inclusion() -> Metadata {
+    pub fn inclusion(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "inclusion",
             "inclusionVerifier",
             "inclusion",
-            crate::types::native::parameters::InclusionProver::METADATA,
-            crate::types::native::parameters::InclusionVerifier::METADATA,
+            crate::parameters_metadata!(network, InclusionProver),
+            crate::parameters_metadata!(network, InclusionVerifier),
         )
     }
 
     #[wasm_bindgen]
-    pub fn join() -> Metadata {
+    pub fn join(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "join",
             "joinVerifier",
             "credits.aleo/join",
-            crate::types::native::parameters::JoinProver::METADATA,
-            crate::types::native::parameters::JoinVerifier::METADATA,
+            crate::parameters_metadata!(network, JoinProver),
+            crate::parameters_metadata!(network, JoinVerifier),
         )
     }
 
     #[wasm_bindgen]
-    pub fn set_validator_state() -> Metadata {
+    pub fn set_validator_state(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "set_validator_state",
             "setValidatorStateVerifier",
             "credits.aleo/set_validator_state",
-            crate::types::native::parameters::SetValidatorStateProver::METADATA,
-            crate::types::native::parameters::SetValidatorStateVerifier::METADATA,
+            crate::parameters_metadata!(network, SetValidatorStateProver),
+            crate::parameters_metadata!(network, SetValidatorStateVerifier),
         )
     }
 
     #[wasm_bindgen]
-    pub fn split() -> Metadata {
+    pub fn split(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "split",
             "splitVerifier",
             "credits.aleo/split",
-            crate::types::native::parameters::SplitProver::METADATA,
-            crate::types::native::parameters::SplitVerifier::METADATA,
+            crate::parameters_metadata!(network, SplitProver),
+            crate::parameters_metadata!(network, SplitVerifier),
         )
     }
 
     #[wasm_bindgen]
-    pub fn transfer_private() -> Metadata {
+    pub fn transfer_private(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "transfer_private",
             "transferPrivateVerifier",
             "credits.aleo/transfer_private",
-            crate::types::native::parameters::TransferPrivateProver::METADATA,
-            crate::types::native::parameters::TransferPrivateVerifier::METADATA,
+            crate::parameters_metadata!(network, TransferPrivateProver),
+            crate::parameters_metadata!(network, TransferPrivateVerifier),
         )
     }
 
     #[wasm_bindgen]
-    pub fn transfer_private_to_public() -> Metadata {
+    pub fn transfer_private_to_public(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "transfer_private_to_public",
             "transferPrivateToPublicVerifier",
             "credits.aleo/transfer_private_to_public",
-            crate::types::native::parameters::TransferPrivateToPublicProver::METADATA,
-            crate::types::native::parameters::TransferPrivateToPublicVerifier::METADATA,
+            crate::parameters_metadata!(network, TransferPrivateToPublicProver),
+            crate::parameters_metadata!(network, TransferPrivateToPublicVerifier),
         )
     }
 
     #[wasm_bindgen]
-    pub fn transfer_public() -> Metadata {
+    pub fn transfer_public(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "transfer_public",
             "transferPublicVerifier",
             "credits.aleo/transfer_public",
-            crate::types::native::parameters::TransferPublicProver::METADATA,
-            crate::types::native::parameters::TransferPublicVerifier::METADATA,
+            crate::parameters_metadata!(network, TransferPublicProver),
+            crate::parameters_metadata!(network, TransferPublicVerifier),
         )
     }
 
     #[wasm_bindgen]
-    pub fn transfer_public_as_signer() -> Metadata {
+    pub fn transfer_public_as_signer(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "transfer_public_as_signer",
             "transferPublicAsSignerVerifier",
             "credits.aleo/transfer_public_as_signer",
-            crate::types::native::parameters::TransferPublicAsSignerProver::METADATA,
-            crate::types::native::parameters::TransferPublicAsSignerVerifier::METADATA,
+            crate::parameters_metadata!(network, TransferPublicAsSignerProver),
+            crate::parameters_metadata!(network, TransferPublicAsSignerVerifier),
         )
     }
 
     #[wasm_bindgen]
-    pub fn transfer_public_to_private() -> Metadata {
+    pub fn transfer_public_to_private(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "transfer_public_to_private",
             "transferPublicToPrivateVerifier",
             "credits.aleo/transfer_public_to_private",
-            crate::types::native::parameters::TransferPublicToPrivateProver::METADATA,
-            crate::types::native::parameters::TransferPublicToPrivateVerifier::METADATA,
+            crate::parameters_metadata!(network, TransferPublicToPrivateProver),
+            crate::parameters_metadata!(network, TransferPublicToPrivateVerifier),
         )
     }
 
     #[wasm_bindgen]
-    pub fn unbond_public() -> Metadata {
+    pub fn unbond_public(network: Option<Network>) -> Metadata {
+        let network = network.unwrap_or_default();
         Metadata::new(
+            network,
             "unbond_public",
             "unbondPublicVerifier",
             "credits.aleo/unbond_public",
-            crate::types::native::parameters::UnbondPublicProver::METADATA,
-            crate::types::native::parameters::UnbondPublicVerifier::METADATA,
+            crate::parameters_metadata!(network, UnbondPublicProver),
+            crate::parameters_metadata!(network, UnbondPublicVerifier),
         )
     }
 }
@@ -15,23 +15,32 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 mod credits;
+#[cfg(not(feature = "native"))]
 mod metadata;
 
-use crate::types::native::{CurrentNetwork, FromBytes, Network, ToBytes, VerifyingKeyNative};
+use crate::{
+    types::{
+        native::{canary, mainnet, testnet, FromBytes, Network as NetworkTrait, ToBytes, VerifyingKeyNative},
+        NetworkAgnostic,
+    },
+    Network,
+};
 
 use sha2::Digest;
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::prelude::wasm_bindgen;
 
 use std::{ops::Deref, str::FromStr};
 
+#[cfg(not(feature = "native"))]
 pub use metadata::Metadata;
 
 /// Verifying key for a function within an Aleo program
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 #[derive(Clone, Debug)]
 pub struct VerifyingKey(VerifyingKeyNative);
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl VerifyingKey {
     /// Get the checksum of the verifying key
     ///
@@ -43,7 +52,7 @@ impl VerifyingKey {
     /// Create a copy of the verifying key
     ///
     /// @returns {VerifyingKey} A copy of the verifying key
-    #[wasm_bindgen]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
     pub fn copy(&self) -> VerifyingKey {
         self.0.clone().into()
     }
@@ -52,7 +61,7 @@ impl VerifyingKey {
     ///
     /// @param {Uint8Array} bytes Byte representation of a verifying key
     /// @returns {VerifyingKey}
-    #[wasm_bindgen(js_name = "fromBytes")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "fromBytes"))]
     pub fn from_bytes(bytes: &[u8]) -> Result<VerifyingKey, String> {
         Ok(Self(VerifyingKeyNative::from_bytes_le(bytes).map_err(|e| e.to_string())?))
     }
@@ -61,7 +70,7 @@ impl VerifyingKey {
     ///
     /// @param {String} string String representation of a verifying key
     /// @returns {VerifyingKey}
-    #[wasm_bindgen(js_name = "fromString")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "fromString"))]
     pub fn from_string(string: &str) -> Result<VerifyingKey, String> {
         Ok(Self(VerifyingKeyNative::from_str(string).map_err(|e| e.to_string())?))
     }
@@ -69,7 +78,7 @@ impl VerifyingKey {
     /// Create a byte array from a verifying key
     ///
     /// @returns {Uint8Array} Byte representation of a verifying key
-    #[wasm_bindgen(js_name = "toBytes")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "toBytes"))]
     pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
         self.0.to_bytes_le().map_err(|_| "Failed to serialize verifying key".to_string())
     }
@@ -77,7 +86,7 @@ impl VerifyingKey {
     /// Get a string representation of the verifying key
     ///
     /// @returns {String} String representation of the verifying key
-    #[wasm_bindgen(js_name = "toString")]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = "toString"))]
     #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
         self.0.to_string()
@@ -121,10 +130,6 @@ mod tests {
     use super::*;
     use wasm_bindgen_test::*;
 
-    #[cfg(feature = "testnet")]
-    const TRANSFER_PUBLIC_VERIFYING_KEY: &str = "verifier1qygqqqqqqqqqqqp3xqqqqqqqqqqzvvqqqqqqqqqq23hqqqqqqqqqqau5qqqqqqqqqq5yzqqqqqqqqqqvqqqqqqqqqqqz2rh4q6m4u0ycv2z5qx95echpdcsktezkr2j9cvff0dngp45jfqggm8q3578nkhjudslm2rdpsgcpks4ulquyqrtd978zvj65pxmhkudyjtj005h66jcnwg3f6mdqqaqjed3avcz599kth8a3nak0tftsrk4hczcdvlmrdnzsa6rfppy72flsrhdhn6npxfxt2rrudk8jrk5fefkawhhrf0psccp6l9akckaps898v5mk7vprkx90gg798d6j5tvvtma0r9phq3jndan5rkwv8wmkngeha3pzrjzslkt8ct9umm4wfq8dhnpdv8m4plq70c3d6wxs4l3cv4gyqhgjtwfuydc5fflulwgjvdtaxxlmpf0l5n800jn2lwnt5kqqtdsx2lvzl3kw3ns5hnsu4jzg9ejptg7n7r7d9dvqsrvldw9paeq86hjxeaac6typemynwzt8w3vq3e27l4swd9aqxaas7zsy5mw7ftnjct9jq6t9rdq0kx8vjag77nt0z894xqkrfp27hehk83nfcg7cpjcc7f8mznga33xp36seafxpn26rq4w5l9uawtx02mzpq9kuyyx7nvhtad0mxk5su659xx5wv3yhqqy3577kwc40spvp9dst59ap2ll5uuq7qaauy9vy5yvrjd77c443evxrwfsee8jg5hvt6f9xaupjzq2cmuuwdvjs0qtxmnwjmdepu5979qy4nhqs63zljatt7kkggddxctt74crayt4djvnh3u6tjzm8wyqpc7nf0r0k9mp34x9musgdqegkmscey50ckyehm67c5tcdlawkh6my2v3xzwaug7c4y9xyw0dfkqup24jdckk5wxkyfd8rsp6h220dj786t54fyj59ehtq0gyut6lcattumgdss4kkchrdp7f5sjgcu7ycpzq0d47m6xe3yh2q5q76fa20lm46pe8fcd9yqcrxduhkdqfe4c9pw3pqqqqqqqqqq0qvtsl";
-
-    #[cfg(feature = "mainnet")]
     const TRANSFER_PUBLIC_VERIFYING_KEY: &str = "verifier1qygqqqqqqqqqqqp3xqqqqqqqqqqzvvqqqqqqqqqq23hqqqqqqqqqqau5qqqqqqqqqq5yzqqqqqqqqqqvqqqqqqqqqqqz2rh4q6m4u0ycv2z5qx95echpdcsktezkr2j9cvff0dngp45jfqggm8q3578nkhjudslm2rdpsgcpks4ulquyqrtd978zvj65pxmhkudyjtj005h66jcnwg3f6mdqqaqjed3avcz599kth8a3nak0tftsrk4hczcdvlmrdnzsa6rfppy72flsrhdhn6npxfxt2rrudk8jrk5fefkawhhrf0psccp6l9akckaps898v5mk7vprkx90gg798d6j5tvvtma0r9phq3jndan5rkwv8wmkngeha3pzrjzslkt8ct9umm4wfq8dhnpdv8m4plq70c3d6wxs4l3cv4gyqhgjtwfuydc5fflulwgjvdtaxxlmpf0l5n800jn2lwnt5kqqtdsx2lvzl3kw3ns5hnsu4jzg9ejptg7n7r7d9dvqsrvldw9paeq86hjxeaac6typemynwzt8w3vq3e27l4swd9aqxaas7zsy5mw7ftnjct9jq6t9rdq0kx8vjag77nt0z894xqkrfp27hehk83nfcg7cpjcc7f8mznga33xp36seafxpn26rq4w5l9uawtx02mzpq9kuyyx7nvhtad0mxk5su659xx5wv3yhqqy3577kwc40spvp9dst59ap2ll5uuq7qaauy9vy5yvrjd77c443evxrwfsee8jg5hvt6f9xaupjzqdd2jexnaselrezp7eptfjtpgs6ga5p3nkycwf26k6nkrf8j302e3a62rq7x5xpz9r8q9ttf36y3vqq6kvvmnuuzv6zkv0p8ds0g6z7stfhtxvzl4vjgj9vf35tnkxxvhmk4xgqcycz6g5aaejlmskvr8uq24jdckk5wxkyfd8rsp6h220dj786t54fyj59ehtq0gyut6lcattumgdss4kkchrdp7f5sjgcu7ycppt59jx985rpqa04krg2sh5je7dt6rntnvf2xd76vrgh3ldnp33lw3pqqqqqqqqqqjgex6x";
 
     #[allow(dead_code)]
@@ -223,10 +228,6 @@ mod tests {
         let transfer_public_verifier = VerifyingKey::from_bytes(&transfer_public_verifier_bytes).unwrap();
         let transfer_public_verifying_key_checksum = transfer_public_verifier.checksum();
 
-        #[cfg(feature = "testnet")]
-        let checksum = "1b5109468e7992c39bbbc299dd1f94f18e49928e253e60ed68c7d9f02e73fe8d";
-
-        #[cfg(feature = "mainnet")]
         let checksum = "ea77f42a35b3f891e7753c7333df365f356883550c4602df11f270237bef340d";
 
         assert_eq!(transfer_public_verifying_key_checksum, checksum);
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod owned_record;
+pub use owned_record::*;
+
 pub mod record_ciphertext;
 pub use record_ciphertext::*;
 
@@ -0,0 +1,265 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::RecordPlaintext;
+#[cfg(not(feature = "native"))]
+use crate::{
+    account::ViewKey,
+    types::native::{ProgramIDNative, ProgramNative, TransitionNative, TransitionOutputNative, ValueType},
+    SdkError,
+};
+
+#[cfg(not(feature = "native"))]
+use js_sys::{Object, Reflect};
+#[cfg(not(feature = "native"))]
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    str::FromStr,
+};
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// A record found in the outputs of a transition which belongs to a view key, together with the
+/// on-chain metadata needed to track and spend it
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone)]
+pub struct OwnedRecord {
+    record: RecordPlaintext,
+    program_id: String,
+    record_name: Option<String>,
+    commitment: String,
+    transition_id: String,
+}
+
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+impl OwnedRecord {
+    /// Get the decrypted record
+    ///
+    /// @returns {RecordPlaintext} Record plaintext
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn record(&self) -> RecordPlaintext {
+        self.record.clone()
+    }
+
+    /// Get the id of the program which created the record
+    ///
+    /// @returns {string} Program id
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = programId))]
+    pub fn program_id(&self) -> String {
+        self.program_id.clone()
+    }
+
+    /// Get the name of the record type. This is only known for credits.aleo records and the
+    /// records of programs whose source was provided when the records were found.
+    ///
+    /// @returns {string | undefined} Record name
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = recordName))]
+    pub fn record_name(&self) -> Option<String> {
+        self.record_name.clone()
+    }
+
+    /// Get the commitment of the record
+    ///
+    /// @returns {string} Record commitment
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
+    pub fn commitment(&self) -> String {
+        self.commitment.clone()
+    }
+
+    /// Get the id of the transition which created the record
+    ///
+    /// @returns {string} Transition id
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = transitionId))]
+    pub fn transition_id(&self) -> String {
+        self.transition_id.clone()
+    }
+}
+
+#[cfg(not(feature = "native"))]
+impl OwnedRecord {
+    /// Decrypt the records owned by a view key in the outputs of a series of transitions
+    ///
+    /// The record names are looked up in the programs which created the records. The source of
+    /// programs other than credits.aleo can be provided in `programs`, keyed by program id.
+    pub(crate) fn find<'a>(
+        transitions: impl Iterator<Item = &'a TransitionNative>,
+        view_key: &ViewKey,
+        programs: Option<Object>,
+    ) -> Result<Vec<OwnedRecord>, SdkError> {
+        let mut cache = HashMap::<ProgramIDNative, Option<ProgramNative>>::new();
+        let mut owned_records = Vec::new();
+        for transition in transitions {
+            for (index, output) in transition.outputs().iter().enumerate() {
+                let (commitment, ciphertext) = match output {
+                    TransitionOutputNative::Record(commitment, _, Some(ciphertext)) => (commitment, ciphertext),
+                    _ => continue,
+                };
+                if !ciphertext.is_owner(view_key) {
+                    continue;
+                }
+                let program = match cache.entry(*transition.program_id()) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => {
+                        entry.insert(Self::lookup_program(transition.program_id(), programs.as_ref())?)
+                    }
+                };
+                let record_name = program
+                    .as_ref()
+                    .and_then(|program| program.get_function_ref(transition.function_name()).ok())
+                    .and_then(|function| function.outputs().get_index(index).cloned())
+                    .and_then(|output| match output.value_type() {
+                        ValueType::Record(name) => Some(name.to_string()),
+                        _ => None,
+                    });
+                owned_records.push(OwnedRecord {
+                    record: RecordPlaintext::from(
+                        ciphertext.decrypt(view_key).map_err(|e| SdkError::Other(e.to_string()))?,
+                    ),
+                    program_id: transition.program_id().to_string(),
+                    record_name,
+                    commitment: commitment.to_string(),
+                    transition_id: transition.id().to_string(),
+                });
+            }
+        }
+        Ok(owned_records)
+    }
+
+    /// Find the definition of a program, either built in or in the provided program sources.
+    /// Programs without a provided source are not known, but a provided source must be valid.
+    fn lookup_program(
+        program_id: &ProgramIDNative,
+        programs: Option<&Object>,
+    ) -> Result<Option<ProgramNative>, SdkError> {
+        if program_id.to_string() == "credits.aleo" {
+            return ProgramNative::credits().map(Some).map_err(|e| SdkError::Other(e.to_string()));
+        }
+        let source = match programs.and_then(|programs| Reflect::get(programs, &program_id.to_string().into()).ok()) {
+            Some(source) if !source.is_undefined() => source,
+            _ => return Ok(None),
+        };
+        let program = source.as_string().and_then(|source| ProgramNative::from_str(&source).ok()).ok_or_else(|| {
+            SdkError::InvalidArgument(format!("The source provided for {program_id} is not a valid program"))
+        })?;
+        Ok(Some(program))
+    }
+}
+
+#[cfg(all(test, not(feature = "native")))]
+mod tests {
+    use super::*;
+    use crate::{
+        account::PrivateKey,
+        types::native::{CurrentAleo, ExecutionNative, PrivateKeyNative, ProcessNative},
+        Execution,
+    };
+
+    use rand::{rngs::StdRng, SeedableRng};
+    use wasm_bindgen_test::*;
+
+    const PRIVATE_KEY: &str = "APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6";
+    const RECORD: &str = "{  owner: aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4.private,  microcredits: 2000000u64.private,  _nonce: 4106205762862305308495708971985748592380064201230396559307556388725936304984group.public}";
+    const STATE_ROOT: &str = "sr1tml46c266j4gzv9qpk0adkt6tkl4mjq7s7supuy8dmv9lq09j5zsd5eqsz";
+    const TOKEN_PROGRAM: &str = r"program token_test.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u64.private;
+    cast self.caller r0 into r1 as token.record;
+    output r1 as token.record;
+";
+
+    fn sample_execution() -> Execution {
+        let process = ProcessNative::load_web().unwrap();
+        let private_key = PrivateKeyNative::from_str(PRIVATE_KEY).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &private_key,
+                "credits.aleo",
+                "split",
+                [RECORD, "1500000u64"].into_iter(),
+                &mut StdRng::from_entropy(),
+            )
+            .unwrap();
+        let execution =
+            ExecutionNative::from(authorization.transitions().values().cloned(), STATE_ROOT.parse().unwrap(), None)
+                .unwrap();
+        Execution::from(execution)
+    }
+
+    #[wasm_bindgen_test]
+    fn test_owned_records_of_execution() {
+        let execution = sample_execution();
+        let view_key = ViewKey::from_private_key(&PrivateKey::from_string(PRIVATE_KEY).unwrap());
+        let records = OwnedRecord::find(execution.transitions(), &view_key, None).unwrap();
+        assert_eq!(execution.owned_records(&view_key, None).unwrap().length(), 2);
+
+        // Both outputs of the split belong to the owner of the input record
+        let transition = execution.transitions().next().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].record().microcredits(), 1500000);
+        assert_eq!(records[1].record().microcredits(), 500000);
+        for record in &records {
+            assert_eq!(record.program_id(), "credits.aleo");
+            assert_eq!(record.record_name(), Some("credits".to_string()));
+            assert_eq!(record.transition_id(), transition.id().to_string());
+            assert_eq!(record.record().commitment("credits.aleo", "credits").unwrap().to_string(), record.commitment());
+        }
+
+        // A view key of another account owns none of the records
+        let other_view_key = ViewKey::from_private_key(&PrivateKey::new());
+        assert_eq!(execution.owned_records(&other_view_key, None).unwrap().length(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_owned_records_of_other_programs() {
+        let mut process = ProcessNative::load_web().unwrap();
+        let program = ProgramNative::from_str(TOKEN_PROGRAM).unwrap();
+        process.add_program(&program).unwrap();
+        let private_key = PrivateKeyNative::from_str(PRIVATE_KEY).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &private_key,
+                "token_test.aleo",
+                "mint",
+                ["100u64"].into_iter(),
+                &mut StdRng::from_entropy(),
+            )
+            .unwrap();
+        let transitions = authorization.transitions().values().cloned().collect::<Vec<_>>();
+        let view_key = ViewKey::from_private_key(&PrivateKey::from_string(PRIVATE_KEY).unwrap());
+
+        // The record name is only known when the source of the program is provided
+        let records = OwnedRecord::find(transitions.iter(), &view_key, None).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].program_id(), "token_test.aleo");
+        assert_eq!(records[0].record_name(), None);
+
+        let programs = Object::new();
+        Reflect::set(&programs, &"token_test.aleo".into(), &TOKEN_PROGRAM.into()).unwrap();
+        let records = OwnedRecord::find(transitions.iter(), &view_key, Some(programs)).unwrap();
+        assert_eq!(records[0].record_name(), Some("token".to_string()));
+
+        // An invalid source is reported instead of being ignored
+        let programs = Object::new();
+        Reflect::set(&programs, &"token_test.aleo".into(), &"not a program".into()).unwrap();
+        let error = OwnedRecord::find(transitions.iter(), &view_key, Some(programs)).unwrap_err();
+        assert_eq!(error.code(), "INVALID_ARGUMENT");
+    }
+}
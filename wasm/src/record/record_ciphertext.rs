@@ -19,20 +19,21 @@ use crate::account::ViewKey;
 
 use crate::types::native::RecordCiphertextNative;
 use std::{ops::Deref, str::FromStr};
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::prelude::*;
 
 /// Encrypted Aleo record
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 #[derive(Clone)]
 pub struct RecordCiphertext(RecordCiphertextNative);
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl RecordCiphertext {
     /// Create a record ciphertext from a string
     ///
     /// @param {string} record String representation of a record ciphertext
     /// @returns {RecordCiphertext} Record ciphertext
-    #[wasm_bindgen(js_name = fromString)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromString))]
     pub fn from_string(record: &str) -> Result<RecordCiphertext, String> {
         Self::from_str(record).map_err(|_| "The record ciphertext string provided was invalid".to_string())
     }
@@ -41,7 +42,7 @@ impl RecordCiphertext {
     ///
     /// @returns {string} String representation of the record ciphertext
     #[allow(clippy::inherent_to_string)]
-    #[wasm_bindgen(js_name = toString)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toString))]
     pub fn to_string(&self) -> String {
         self.0.to_string()
     }
@@ -61,7 +62,7 @@ impl RecordCiphertext {
     ///
     /// @param {ViewKey} view_key View key used to decrypt the ciphertext
     /// @returns {boolean}
-    #[wasm_bindgen(js_name = isOwner)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = isOwner))]
     pub fn is_owner(&self, view_key: &ViewKey) -> bool {
         self.0.is_owner(view_key)
     }
@@ -18,16 +18,17 @@ use crate::{account::PrivateKey, types::Field, Credits};
 
 use crate::types::native::{IdentifierNative, ProgramIDNative, RecordPlaintextNative};
 use std::{ops::Deref, str::FromStr};
+#[cfg(not(feature = "native"))]
 use wasm_bindgen::prelude::*;
 
 /// Plaintext representation of an Aleo record
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 #[derive(Clone)]
 pub struct RecordPlaintext(RecordPlaintextNative);
 
-#[wasm_bindgen]
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
 impl RecordPlaintext {
-    #[wasm_bindgen]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen)]
     pub fn commitment(&self, program_id: &str, record_name: &str) -> Result<Field, String> {
         Ok(Field::from(
             self.to_commitment(
@@ -44,7 +45,7 @@ impl RecordPlaintext {
     ///
     /// @param {string} record String representation of a plaintext representation of an Aleo record
     /// @returns {RecordPlaintext} Record plaintext
-    #[wasm_bindgen(js_name = fromString)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = fromString))]
     pub fn from_string(record: &str) -> Result<RecordPlaintext, String> {
         Self::from_str(record).map_err(|_| "The record plaintext string provided was invalid".into())
     }
@@ -53,7 +54,7 @@ impl RecordPlaintext {
     ///
     /// @returns {string} String representation of the record plaintext
     #[allow(clippy::inherent_to_string)]
-    #[wasm_bindgen(js_name = toString)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = toString))]
     pub fn to_string(&self) -> String {
         self.0.to_string()
     }
@@ -68,7 +69,7 @@ impl RecordPlaintext {
     /// Returns the nonce of the record. This can be used to uniquely identify a record.
     ///
     /// @returns {string} Nonce of the record
-    #[wasm_bindgen(js_name = nonce)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = nonce))]
     pub fn nonce(&self) -> String {
         self.0.nonce().to_string()
     }
@@ -79,7 +80,7 @@ impl RecordPlaintext {
     /// @param {string} program_id Program ID of the program that the record is associated with
     /// @param {string} record_name Name of the record
     /// @returns {string} Serial number of the record
-    #[wasm_bindgen(js_name = serialNumberString)]
+    #[cfg_attr(not(feature = "native"), wasm_bindgen(js_name = serialNumberString))]
     pub fn serial_number_string(
         &self,
         private_key: &PrivateKey,
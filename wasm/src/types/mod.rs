@@ -17,6 +17,9 @@
 pub mod field;
 pub use field::*;
 
+pub mod network;
+pub use network::*;
+
 mod networks;
 
 pub(crate) mod native;
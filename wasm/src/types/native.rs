@@ -20,9 +20,12 @@ pub use snarkvm_console::{
     account::{Address, ComputeKey, PrivateKey, Signature, ViewKey},
     network::{Network, TestnetV0},
     program::{
+        compute_function_id,
+        Argument,
         Ciphertext,
         Entry,
         EntryType,
+        FinalizeGlobalState,
         FromBits,
         FromBytes,
         Group,
@@ -39,15 +42,30 @@ pub use snarkvm_console::{
         ToFields,
         Value,
         ValueType,
+        U16,
         U8,
     },
     types::Field,
 };
-pub use snarkvm_ledger_block::{Execution, Transaction};
+pub use snarkvm_ledger_block::{
+    Block,
+    ConfirmedTransaction,
+    Deployment,
+    Execution,
+    Fee,
+    Input,
+    Output,
+    Rejected,
+    Transaction,
+    Transition,
+};
 pub use snarkvm_ledger_query::Query;
-pub use snarkvm_ledger_store::helpers::memory::BlockMemory;
+pub use snarkvm_ledger_store::{
+    helpers::memory::{BlockMemory, FinalizeMemory},
+    FinalizeStore,
+};
 pub use snarkvm_synthesizer::{
-    process::{cost_in_microcredits, deployment_cost},
+    process::{cost_in_microcredits, deployment_cost, Authorization},
     snark::{ProvingKey, VerifyingKey},
     Process,
     Program,
@@ -58,33 +76,3 @@ pub use snarkvm_wasm::{
     fields::PrimeField,
     utilities::{ToBits, ToBytes, Uniform},
 };
-
-// Account types
-pub type AddressNative = Address<CurrentNetwork>;
-pub type PrivateKeyNative = PrivateKey<CurrentNetwork>;
-pub type SignatureNative = Signature<CurrentNetwork>;
-pub type ViewKeyNative = ViewKey<CurrentNetwork>;
-
-// Algebraic types
-pub type FieldNative = Field<CurrentNetwork>;
-
-// Record types
-pub type CiphertextNative = Ciphertext<CurrentNetwork>;
-pub type PlaintextNative = Plaintext<CurrentNetwork>;
-pub type RecordCiphertextNative = Record<CurrentNetwork, CiphertextNative>;
-pub type RecordPlaintextNative = Record<CurrentNetwork, PlaintextNative>;
-
-// Program types
-type CurrentBlockMemory = BlockMemory<CurrentNetwork>;
-pub type ExecutionNative = Execution<CurrentNetwork>;
-pub type IdentifierNative = Identifier<CurrentNetwork>;
-pub type LiteralNative = Literal<CurrentNetwork>;
-pub type ProcessNative = Process<CurrentNetwork>;
-pub type ProgramIDNative = ProgramID<CurrentNetwork>;
-pub type ProgramNative = Program<CurrentNetwork>;
-pub type ProgramOwnerNative = ProgramOwner<CurrentNetwork>;
-pub type ProvingKeyNative = ProvingKey<CurrentNetwork>;
-pub type QueryNative = Query<CurrentNetwork, CurrentBlockMemory>;
-pub type ResponseNative = Response<CurrentNetwork>;
-pub type TransactionNative = Transaction<CurrentNetwork>;
-pub type VerifyingKeyNative = VerifyingKey<CurrentNetwork>;
@@ -0,0 +1,240 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::native::{
+        canary,
+        mainnet,
+        testnet,
+        FromBytes,
+        Network as NetworkTrait,
+        PrivateKeyNative,
+        ProvingKeyNative,
+        ToBytes,
+        VerifyingKeyNative,
+    },
+    PrivateKey,
+    ProvingKey,
+    VerifyingKey,
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{any::Any, fmt, str::FromStr};
+#[cfg(not(feature = "native"))]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The Aleo network a program manager, transaction, execution or authorization belongs to
+#[cfg_attr(not(feature = "native"), wasm_bindgen)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Network {
+    #[default]
+    MainnetV0 = 0,
+    TestnetV0 = 1,
+    CanaryV0 = 2,
+}
+
+impl Network {
+    /// Get the id of the network as defined by snarkVM
+    pub fn id(&self) -> u16 {
+        match self {
+            Self::MainnetV0 => mainnet::CurrentNetwork::ID,
+            Self::TestnetV0 => testnet::CurrentNetwork::ID,
+            Self::CanaryV0 => canary::CurrentNetwork::ID,
+        }
+    }
+
+    /// Get the network of the native network type `N`
+    pub(crate) fn of<N: NetworkTrait>() -> Self {
+        match N::ID {
+            id if id == testnet::CurrentNetwork::ID => Self::TestnetV0,
+            id if id == canary::CurrentNetwork::ID => Self::CanaryV0,
+            _ => Self::MainnetV0,
+        }
+    }
+
+    /// Get the url the parameters of the network are hosted at
+    pub fn parameters_url(&self) -> &'static str {
+        match self {
+            Self::MainnetV0 => mainnet::BASE_URL,
+            Self::TestnetV0 => testnet::BASE_URL,
+            Self::CanaryV0 => canary::BASE_URL,
+        }
+    }
+
+    /// Ensure a value created on another network is not used on this one
+    pub fn ensure(&self, found: Network) -> Result<(), NetworkMismatch> {
+        match *self == found {
+            true => Ok(()),
+            false => Err(NetworkMismatch { expected: *self, found }),
+        }
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MainnetV0 => write!(f, "mainnet"),
+            Self::TestnetV0 => write!(f, "testnet"),
+            Self::CanaryV0 => write!(f, "canary"),
+        }
+    }
+}
+
+impl FromStr for Network {
+    type Err = String;
+
+    fn from_str(network: &str) -> Result<Self, Self::Err> {
+        match network.to_lowercase().as_str() {
+            "mainnet" | "mainnetv0" => Ok(Self::MainnetV0),
+            "testnet" | "testnetv0" => Ok(Self::TestnetV0),
+            "canary" | "canaryv0" => Ok(Self::CanaryV0),
+            _ => Err(format!("Unknown network '{network}', expected one of mainnet, testnet or canary")),
+        }
+    }
+}
+
+impl Serialize for Network {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Network {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// Error returned when a value created for one network is used with another
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NetworkMismatch {
+    pub expected: Network,
+    pub found: Network,
+}
+
+impl fmt::Display for NetworkMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Network mismatch: expected a {} value but found a {} value", self.expected, self.found)
+    }
+}
+
+impl std::error::Error for NetworkMismatch {}
+
+impl From<NetworkMismatch> for String {
+    fn from(error: NetworkMismatch) -> Self {
+        error.to_string()
+    }
+}
+
+/// Convert a value between the types of two networks. Values whose types already belong to the
+/// target network are moved without copying, other values are converted through their byte
+/// representation, which is identical across networks.
+pub(crate) fn to_network<T: ToBytes + 'static, U: FromBytes + 'static>(value: T) -> Result<U, String> {
+    let value: Box<dyn Any> = Box::new(value);
+    match value.downcast::<U>() {
+        Ok(value) => Ok(*value),
+        Err(value) => {
+            let value = value.downcast::<T>().map_err(|_| "Failed to convert value between networks".to_string())?;
+            let bytes = value.to_bytes_le().map_err(|e| e.to_string())?;
+            U::from_bytes_le(&bytes).map_err(|e| e.to_string())
+        }
+    }
+}
+
+/// A value which is represented with the native type of the default network but is valid on every
+/// network, such as a private key or the keys of a circuit
+pub(crate) trait NetworkAgnostic: Sized {
+    type Native: ToBytes + 'static;
+
+    /// Get the value as the native type of the default network
+    fn into_native(self) -> Self::Native;
+
+    /// Get the value as the native type of the network selected at runtime
+    fn into_network<T: FromBytes + 'static>(self) -> Result<T, String> {
+        to_network(self.into_native())
+    }
+
+    /// Create the value from the native type of the network selected at runtime
+    fn from_network<T: ToBytes + 'static>(value: T) -> Result<Self, String>
+    where
+        Self::Native: FromBytes + Into<Self>,
+    {
+        Ok(to_network::<T, Self::Native>(value)?.into())
+    }
+}
+
+impl NetworkAgnostic for &PrivateKey {
+    type Native = PrivateKeyNative;
+
+    fn into_native(self) -> Self::Native {
+        PrivateKeyNative::from(self)
+    }
+}
+
+impl NetworkAgnostic for ProvingKey {
+    type Native = ProvingKeyNative;
+
+    fn into_native(self) -> Self::Native {
+        ProvingKeyNative::from(self)
+    }
+}
+
+impl NetworkAgnostic for VerifyingKey {
+    type Native = VerifyingKeyNative;
+
+    fn into_native(self) -> Self::Native {
+        VerifyingKeyNative::from(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::native::{PrivateKey, PrivateKeyNative};
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_network_ids_and_names() {
+        for network in [Network::MainnetV0, Network::TestnetV0, Network::CanaryV0] {
+            assert_eq!(network.id(), network as u16);
+            assert_eq!(Network::from_str(&network.to_string()).unwrap(), network);
+        }
+        assert_eq!(Network::of::<mainnet::CurrentNetwork>(), Network::MainnetV0);
+        assert_eq!(Network::of::<testnet::CurrentNetwork>(), Network::TestnetV0);
+        assert_eq!(Network::of::<canary::CurrentNetwork>(), Network::CanaryV0);
+        assert_eq!(Network::default(), Network::MainnetV0);
+        assert!(Network::from_str("devnet").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_network_mismatch() {
+        assert!(Network::TestnetV0.ensure(Network::TestnetV0).is_ok());
+        let error = Network::MainnetV0.ensure(Network::TestnetV0).unwrap_err();
+        assert_eq!(error, NetworkMismatch { expected: Network::MainnetV0, found: Network::TestnetV0 });
+        assert_eq!(String::from(error), "Network mismatch: expected a mainnet value but found a testnet value");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_to_network() {
+        let private_key =
+            PrivateKeyNative::from_str("APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6").unwrap();
+        let testnet_key: PrivateKey<testnet::CurrentNetwork> = to_network(private_key).unwrap();
+        assert_eq!(testnet_key.to_string(), private_key.to_string());
+        let mainnet_key: PrivateKeyNative = to_network(testnet_key).unwrap();
+        assert_eq!(mainnet_key, private_key);
+    }
+}
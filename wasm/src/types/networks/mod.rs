@@ -14,14 +14,65 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-#[cfg(feature = "testnet")]
-mod testnet;
+/// Define the native types of the network selected by `CurrentNetwork` in the invoking module
+macro_rules! native_types {
+    () => {
+        use snarkvm_console::{
+            account::{Address, PrivateKey, Signature, ViewKey},
+            program::{Ciphertext, Identifier, Literal, Plaintext, ProgramID, ProgramOwner, Record, Response, Value},
+            types::Field,
+        };
+        use snarkvm_ledger_block::{Deployment, Execution, Output, Transaction, Transition};
+        use snarkvm_ledger_query::Query;
+        use snarkvm_ledger_store::helpers::memory::BlockMemory;
+        use snarkvm_synthesizer::{
+            process::Authorization,
+            snark::{ProvingKey, VerifyingKey},
+            Process,
+            Program,
+        };
 
-#[cfg(feature = "testnet")]
-pub use testnet::*;
+        // Account types
+        pub type AddressNative = Address<CurrentNetwork>;
+        pub type PrivateKeyNative = PrivateKey<CurrentNetwork>;
+        pub type SignatureNative = Signature<CurrentNetwork>;
+        pub type ViewKeyNative = ViewKey<CurrentNetwork>;
 
-#[cfg(feature = "mainnet")]
-mod mainnet;
+        // Algebraic types
+        pub type FieldNative = Field<CurrentNetwork>;
 
-#[cfg(feature = "mainnet")]
+        // Record types
+        pub type CiphertextNative = Ciphertext<CurrentNetwork>;
+        pub type PlaintextNative = Plaintext<CurrentNetwork>;
+        pub type RecordCiphertextNative = Record<CurrentNetwork, CiphertextNative>;
+        pub type RecordPlaintextNative = Record<CurrentNetwork, PlaintextNative>;
+
+        // Program types
+        pub type AuthorizationNative = Authorization<CurrentNetwork>;
+        type CurrentBlockMemory = BlockMemory<CurrentNetwork>;
+        pub type DeploymentNative = Deployment<CurrentNetwork>;
+        pub type ExecutionNative = Execution<CurrentNetwork>;
+        pub type IdentifierNative = Identifier<CurrentNetwork>;
+        pub type LiteralNative = Literal<CurrentNetwork>;
+        pub type ProcessNative = Process<CurrentNetwork>;
+        pub type ProgramIDNative = ProgramID<CurrentNetwork>;
+        pub type ProgramNative = Program<CurrentNetwork>;
+        pub type ProgramOwnerNative = ProgramOwner<CurrentNetwork>;
+        pub type ProvingKeyNative = ProvingKey<CurrentNetwork>;
+        pub type QueryNative = Query<CurrentNetwork, CurrentBlockMemory>;
+        pub type ResponseNative = Response<CurrentNetwork>;
+        pub type TransactionNative = Transaction<CurrentNetwork>;
+        pub type TransitionNative = Transition<CurrentNetwork>;
+        pub type TransitionOutputNative = Output<CurrentNetwork>;
+        pub type ValueNative = Value<CurrentNetwork>;
+        pub type VerifyingKeyNative = VerifyingKey<CurrentNetwork>;
+    };
+}
+
+pub mod canary;
+pub mod mainnet;
+pub mod testnet;
+
+// Network agnostic values such as accounts, records and programs are represented with the types
+// of the default network and converted to the network selected at runtime when they are used.
 pub use mainnet::*;
@@ -6,10 +6,8 @@ const file = $fs.readFileSync("package.json", { encoding: "utf8" });
 $fs.writeFileSync("package.json", file.replace(/"type": "module",/g, ""));
 
 try {
-    ["testnet", "mainnet"].forEach((network) => {
-        $child.execSync(`wasm-pack test --node --features ${network}`, {
-            stdio: "inherit",
-        });
+    $child.execSync("wasm-pack test --node", {
+        stdio: "inherit",
     });
 
 } finally {
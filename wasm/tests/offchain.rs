@@ -14,10 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use aleo_wasm::{PrivateKey, Program, ProgramManager, ProvingKey, RecordPlaintext, VerifyingKey};
-use js_sys::{Array, Object, Reflect};
-use wasm_bindgen::JsValue;
+#![cfg(all(feature = "browser", not(feature = "native")))]
+
+use aleo_wasm::{
+    Authorization,
+    LocalLedger,
+    Network,
+    PrivateKey,
+    Program,
+    ProgramManager,
+    ProgressStage,
+    ProvingKey,
+    RecordPlaintext,
+    VerifyingKey,
+};
+use js_sys::{Array, Function, Object, Reflect};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
 use wasm_bindgen_test::*;
+use web_sys::AbortController;
 wasm_bindgen_test_configure!(run_in_browser);
 
 pub const HELLO_PROGRAM: &str = r#"program hello.aleo;
@@ -111,7 +125,7 @@ async fn test_cache_functionality() {
     let split_proving_key_clone = split_proving_key.clone();
     let split_verifying_key = VerifyingKey::from_bytes(&split_verifying_key_bytes).unwrap();
     let split_verifying_key_clone = split_verifying_key.clone();
-    let mut program_manager = ProgramManager::new();
+    let program_manager = ProgramManager::new(None).unwrap();
 
     // Ensure the keypair is not in wasm memory if it has not been cached
     assert!(program_manager.get_cached_keypair("credits.aleo", "split").is_err());
@@ -141,15 +155,19 @@ async fn test_cache_functionality() {
     // Ensure program can be executed using the cache after caching an externally provided keypair
     let result = program_manager
         .execute_function_offline(
-            PrivateKey::from_string("APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6").unwrap(),
-            Program::get_credits_program().to_string(),
-            "split".to_string(),
+            &PrivateKey::from_string("APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6").unwrap(),
+            &Program::get_credits_program().to_string(),
+            "split",
             inputs,
             false,
             true,
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -162,24 +180,62 @@ async fn test_cache_functionality() {
 
     // Ensure the keypair can't be overwritten
     assert!(program_manager
-        .cache_keypair_in_wasm_memory("credits.aleo", "split", split_proving_key_clone, split_verifying_key_clone)
+        .cache_keypair_in_wasm_memory(
+            &Program::get_credits_program().to_string(),
+            "split",
+            split_proving_key_clone,
+            split_verifying_key_clone
+        )
         .is_err());
 
+    // Ensure the cached keys are reported in the memory usage
+    assert!(program_manager.memory_usage().unwrap() > split_proving_key_bytes.len() as u64);
+
     // Ensure the cache clears correctly
-    program_manager.clear_key_cache();
+    program_manager.clear_key_cache().unwrap();
     assert!(program_manager.get_cached_keypair("credits.aleo", "split").is_err());
     assert!(!program_manager.key_exists("credits.aleo", "split").unwrap());
 }
 
+#[wasm_bindgen_test]
+fn test_program_cache() {
+    let program_manager = ProgramManager::new(None).unwrap();
+    let imports = Object::new();
+    Reflect::set(&imports, &JsValue::from_str("multiply_test.aleo"), &JsValue::from_str(MULTIPLY_PROGRAM)).unwrap();
+
+    // Ensure programs and their imports are added to the cache
+    assert!(!program_manager.has_program("double_test.aleo").unwrap());
+    program_manager.add_program(MULTIPLY_IMPORT_PROGRAM, Some(imports)).unwrap();
+    assert!(program_manager.has_program("double_test.aleo").unwrap());
+    assert!(program_manager.has_program("multiply_test.aleo").unwrap());
+    assert!(program_manager.has_program("credits.aleo").unwrap());
+
+    // Ensure adding the same program again succeeds but a different program with the same id fails
+    program_manager.add_program(MULTIPLY_PROGRAM, None).unwrap();
+    program_manager.add_program(HELLO_PROGRAM, None).unwrap();
+    assert!(program_manager.add_program(HELLO_PROGRAM_EDIT, None).is_err());
+    let memory_usage = program_manager.memory_usage().unwrap();
+    assert!(memory_usage > 0);
+
+    // Ensure clearing the cache removes the programs
+    program_manager.clear_cache().unwrap();
+    assert!(!program_manager.has_program("double_test.aleo").unwrap());
+    assert!(!program_manager.has_program("multiply_test.aleo").unwrap());
+    assert!(program_manager.memory_usage().unwrap() < memory_usage);
+    program_manager.add_program(HELLO_PROGRAM_EDIT, None).unwrap();
+}
+
 #[wasm_bindgen_test]
 async fn test_key_synthesis() {
+    let program_manager = ProgramManager::new(None).unwrap();
     // Synthesize a keypair for the split program
     let credits = Program::get_credits_program().to_string();
     let inputs = Array::new();
     inputs.set(0u32, JsValue::from_str(RECORD));
     inputs.set(1u32, JsValue::from_str("5u64"));
     let private_key = PrivateKey::from_string("APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6").unwrap();
-    let mut key_pair = ProgramManager::synthesize_keypair(&private_key, &credits, "split", inputs, None).await.unwrap();
+    let mut key_pair =
+        program_manager.synthesize_keypair(&private_key, &credits, "split", inputs, None, None, None).await.unwrap();
     let retrieved_proving_key = key_pair.proving_key().unwrap();
     let retreived_verifying_key = key_pair.verifying_key().unwrap();
 
@@ -187,21 +243,24 @@ async fn test_key_synthesis() {
     let inputs = Array::new();
     inputs.set(0u32, JsValue::from_str(RECORD));
     inputs.set(1u32, JsValue::from_str("5u64"));
-    let mut result = ProgramManager::execute_function_offline(
-        &PrivateKey::from_string("APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6").unwrap(),
-        &credits,
-        "split",
-        inputs,
-        false,
-        true,
-        None,
-        Some(retrieved_proving_key.clone()),
-        Some(retreived_verifying_key.clone()),
-        None,
-        None,
-    )
-    .await
-    .unwrap();
+    let mut result = program_manager
+        .execute_function_offline(
+            &PrivateKey::from_string("APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6").unwrap(),
+            &credits,
+            "split",
+            inputs,
+            false,
+            true,
+            None,
+            Some(retrieved_proving_key.clone()),
+            Some(retreived_verifying_key.clone()),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
 
     let mut keys = result.get_keys().unwrap();
     let proving_key = keys.proving_key().unwrap();
@@ -212,6 +271,7 @@ async fn test_key_synthesis() {
 
 #[wasm_bindgen_test]
 async fn test_fee_validation() {
+    let program_manager = ProgramManager::new(None).unwrap();
     let inputs = Array::new();
     inputs.set(0u32, JsValue::from_str(RECORD));
     inputs.set(1u32, JsValue::from_str("1000000u64"));
@@ -220,96 +280,422 @@ async fn test_fee_validation() {
     let fee_record = RecordPlaintext::from_string(RECORD).unwrap();
 
     // Ensure execution fails when fee amount is greater than the balance available in the record
-    let execution = ProgramManager::execute(
-        &private_key,
-        &Program::get_credits_program().to_string(),
-        "split",
-        inputs,
-        100.0,
-        Some(fee_record.clone()),
-        Some("https://api.explorer.provable.com/v1".to_string()),
-        None,
-        None,
-        None,
-        None,
-        None,
-        None,
-    )
-    .await;
+    let execution = program_manager
+        .execute(
+            &private_key,
+            &Program::get_credits_program().to_string(),
+            "split",
+            inputs,
+            100.0,
+            Some(fee_record.clone()),
+            Some(JsValue::from_str("https://api.explorer.provable.com/v1").unchecked_into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
     assert!(execution.is_err());
 
     // Ensure deployment fails when fee amount is greater than the balance available in the record
-    let deployment = ProgramManager::deploy(
-        &private_key,
-        &Program::get_credits_program().to_string(),
-        100.0,
-        Some(fee_record.clone()),
-        Some("https://api.explorer.provable.com/v1".to_string()),
-        None,
-        None,
-        None,
-        None,
-    )
-    .await;
+    let deployment = program_manager
+        .deploy(
+            &private_key,
+            &Program::get_credits_program().to_string(),
+            100.0,
+            Some(fee_record.clone()),
+            Some(JsValue::from_str("https://api.explorer.provable.com/v1").unchecked_into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
     assert!(deployment.is_err());
 
     // Ensure transfer fails when fee amount or amount is greater than the balance available in the record
-    let transfer = ProgramManager::transfer(
-        &private_key,
-        100.00,
-        "aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4",
-        "private",
-        Some(fee_record.clone()),
-        0.9,
-        Some(fee_record.clone()),
-        Some("https://api.explorer.provable.com/v1".to_string()),
-        None,
-        None,
-        None,
-        None,
-        None,
-    )
-    .await;
+    let transfer = program_manager
+        .transfer(
+            &private_key,
+            100.00,
+            "aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4",
+            "private",
+            Some(fee_record.clone()),
+            0.9,
+            Some(fee_record.clone()),
+            Some(JsValue::from_str("https://api.explorer.provable.com/v1").unchecked_into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
     assert!(transfer.is_err());
 
-    let transfer = ProgramManager::transfer(
-        &private_key,
-        0.5,
-        "aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4",
-        "private",
-        Some(fee_record.clone()),
-        100.00,
-        Some(fee_record.clone()),
-        Some("https://api.explorer.provable.com/v1".to_string()),
-        None,
-        None,
-        None,
-        None,
-        None,
-    )
-    .await;
+    let transfer = program_manager
+        .transfer(
+            &private_key,
+            0.5,
+            "aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4",
+            "private",
+            Some(fee_record.clone()),
+            100.00,
+            Some(fee_record.clone()),
+            Some(JsValue::from_str("https://api.explorer.provable.com/v1").unchecked_into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
     assert!(transfer.is_err());
 
     // Ensure join fails when fee amount is greater than the balance available in the record
-    let join = ProgramManager::join(
-        &private_key,
-        fee_record.clone(),
-        fee_record.clone(),
-        100.00,
-        Some(fee_record.clone()),
-        Some("https://api.explorer.provable.com/v1".to_string()),
-        None,
-        None,
-        None,
-        None,
-        None,
-    )
-    .await;
+    let join = program_manager
+        .join(
+            &private_key,
+            fee_record.clone(),
+            fee_record.clone(),
+            100.00,
+            Some(fee_record.clone()),
+            Some(JsValue::from_str("https://api.explorer.provable.com/v1").unchecked_into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
     assert!(join.is_err());
+
+    // Ensure join fails when the fee record cannot cover both the base and priority fee
+    let join = program_manager
+        .join(
+            &private_key,
+            fee_record.clone(),
+            fee_record.clone(),
+            1.5,
+            Some(fee_record.clone()),
+            Some(JsValue::from_str("https://api.explorer.provable.com/v1").unchecked_into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(1.0),
+            None,
+            None,
+        )
+        .await;
+    assert!(join.is_err());
+}
+
+#[wasm_bindgen_test]
+async fn test_staking_input_validation() {
+    let program_manager = ProgramManager::new(None).unwrap();
+    let private_key = PrivateKey::from_string("APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6").unwrap();
+    let validator = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3";
+    let own_address = "aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4";
+
+    // Ensure bonding fails when the validator address is invalid
+    let bond = program_manager
+        .bond_public(
+            &private_key,
+            "not_an_address",
+            own_address,
+            10.0,
+            0.5,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+    assert_eq!(bond.unwrap_err().code(), "INVALID_ARGUMENT");
+
+    // Ensure bonding fails when the amount is below the minimum bond amount
+    let bond = program_manager
+        .bond_public(
+            &private_key,
+            validator,
+            own_address,
+            0.5,
+            0.5,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+    assert_eq!(bond.unwrap_err().code(), "INVALID_ARGUMENT");
+
+    // Ensure a validator cannot withdraw to its own address
+    let bond = program_manager
+        .bond_validator(
+            &private_key,
+            own_address,
+            10.0,
+            10,
+            0.5,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+    assert_eq!(bond.unwrap_err().code(), "INVALID_ARGUMENT");
+
+    // Ensure the commission cannot exceed 100 percent
+    let bond = program_manager
+        .bond_validator(
+            &private_key,
+            validator,
+            10.0,
+            101,
+            0.5,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+    assert_eq!(bond.unwrap_err().code(), "INVALID_ARGUMENT");
+
+    // Ensure unbonding fails when the amount is zero
+    let unbond = program_manager
+        .unbond_public(&private_key, own_address, 0.0, 0.5, None, None, None, None, None, None, None, None, None, None)
+        .await;
+    assert_eq!(unbond.unwrap_err().code(), "INVALID_ARGUMENT");
+
+    // Ensure unbonding amounts which are not finite or overflow microcredits are rejected
+    for amount in [f64::NAN, f64::INFINITY, 1e20] {
+        let unbond = program_manager
+            .unbond_public(
+                &private_key,
+                own_address,
+                amount,
+                0.5,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+        assert_eq!(unbond.unwrap_err().code(), "INVALID_ARGUMENT");
+    }
+
+    // Ensure claiming fails when the staker address is invalid
+    let claim = program_manager
+        .claim_unbond_public(&private_key, "aleo1", 0.5, None, None, None, None, None, None, None, None, None, None)
+        .await;
+    assert_eq!(claim.unwrap_err().code(), "INVALID_ARGUMENT");
+}
+
+#[wasm_bindgen_test]
+fn test_authorization_export() {
+    let program_manager = ProgramManager::new(None).unwrap();
+    let private_key = PrivateKey::from_string("APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6").unwrap();
+    let inputs = Array::new();
+    inputs.set(0u32, JsValue::from_str(RECORD));
+    inputs.set(1u32, JsValue::from_str("1000000u64"));
+
+    // Authorize the split function and ensure the authorization survives serialization
+    let authorization = program_manager
+        .authorize(&private_key, &Program::get_credits_program().to_string(), "split", inputs, None)
+        .unwrap();
+    let exported = authorization.to_string();
+    let imported = Authorization::from_string(&exported, None).unwrap();
+    assert_eq!(authorization, imported);
+
+    // Authorize a public fee for the execution and ensure it commits to the execution id
+    let execution_id = imported.to_execution_id().unwrap();
+    let fee_authorization = program_manager.authorize_fee(&private_key, 0.5, &execution_id, None, None).unwrap();
+    assert!(fee_authorization.is_fee_public());
+    assert!(!fee_authorization.is_fee_private());
+
+    // Ensure a private fee cannot exceed the balance of the fee record
+    let fee_record = RecordPlaintext::from_string(RECORD).unwrap();
+    assert!(program_manager.authorize_fee(&private_key, 100.0, &execution_id, Some(fee_record.clone()), None).is_err());
+
+    // Ensure a private fee record must also cover the priority fee
+    assert!(
+        program_manager.authorize_fee(&private_key, 1.0, &execution_id, Some(fee_record.clone()), Some(1.5)).is_err()
+    );
+    assert!(program_manager.authorize_fee(&private_key, 1.0, &execution_id, Some(fee_record), Some(-1.0)).is_err());
+}
+
+#[wasm_bindgen_test]
+async fn test_network_selection() {
+    let mainnet_manager = ProgramManager::new(None).unwrap();
+    let testnet_manager = ProgramManager::new(Some(Network::TestnetV0)).unwrap();
+    assert_eq!(mainnet_manager.network(), Network::MainnetV0);
+    assert_eq!(testnet_manager.network(), Network::TestnetV0);
+
+    // Authorizations are tagged with the network of the program manager that created them
+    let private_key = PrivateKey::from_string("APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6").unwrap();
+    let credits = Program::get_credits_program().to_string();
+    let inputs = Array::new();
+    inputs.set(0u32, JsValue::from_str(RECORD));
+    inputs.set(1u32, JsValue::from_str("1000000u64"));
+    let authorization = testnet_manager.authorize(&private_key, &credits, "split", inputs, None).unwrap();
+    assert_eq!(authorization.network(), Network::TestnetV0);
+    let imported = Authorization::from_string(&authorization.to_string(), Some(Network::TestnetV0)).unwrap();
+    assert_eq!(authorization, imported);
+
+    // Ensure an authorization cannot be proven on another network
+    let result = mainnet_manager
+        .prove_authorization(&authorization, &credits, None, None, None, None, None, None, None, None, None, None)
+        .await;
+    let error = result.unwrap_err();
+    assert_eq!(error.code(), "NETWORK_MISMATCH");
+    assert_eq!(error.to_string(), "Network mismatch: expected a mainnet value but found a testnet value");
+
+    // Ensure a fee authorization cannot be used on another network
+    let execution_id = authorization.to_execution_id().unwrap();
+    let fee_authorization = mainnet_manager.authorize_fee(&private_key, 0.5, &execution_id, None, None).unwrap();
+    assert_eq!(fee_authorization.network(), Network::MainnetV0);
+    let result = testnet_manager
+        .prove_authorization(
+            &authorization,
+            &credits,
+            Some(fee_authorization),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+    let error = result.unwrap_err();
+    assert_eq!(error.code(), "NETWORK_MISMATCH");
+    assert_eq!(error.to_string(), "Network mismatch: expected a testnet value but found a mainnet value");
+}
+
+#[wasm_bindgen_test]
+async fn test_progress_and_abort() {
+    let program_manager = ProgramManager::new(None).unwrap();
+    let private_key = PrivateKey::from_string("APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6").unwrap();
+    let inputs = Array::new();
+    inputs.set(0u32, JsValue::from_str("5u32"));
+    inputs.set(1u32, JsValue::from_str("5u32"));
+
+    // Ensure an execution which has already been aborted stops before loading the program
+    let controller = AbortController::new().unwrap();
+    controller.abort();
+    let result = program_manager
+        .execute_function_offline(
+            &private_key,
+            HELLO_PROGRAM,
+            "main",
+            inputs.clone(),
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(controller.signal()),
+        )
+        .await;
+    let error = result.err().unwrap();
+    assert_eq!(error.code(), "ABORTED");
+    assert_eq!(error.to_string(), "The operation was aborted");
+    assert!(!program_manager.has_program("hello.aleo").unwrap());
+
+    // Ensure each stage of the execution is reported in order
+    let stages = Array::new();
+    let recorded = stages.clone();
+    let on_progress = Closure::<dyn Fn(JsValue)>::new(move |stage: JsValue| {
+        recorded.push(&stage);
+    });
+    program_manager
+        .execute_function_offline(
+            &private_key,
+            HELLO_PROGRAM,
+            "main",
+            inputs,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(on_progress.as_ref().unchecked_ref::<Function>().clone()),
+            None,
+        )
+        .await
+        .unwrap();
+    let expected = [
+        ProgressStage::LoadProgram,
+        ProgressStage::ResolveImports,
+        ProgressStage::Synthesize,
+        ProgressStage::Authorize,
+    ];
+    assert_eq!(stages.length(), expected.len() as u32);
+    for (i, stage) in expected.into_iter().enumerate() {
+        assert_eq!(stages.get(i as u32).as_f64(), Some(stage as u32 as f64));
+    }
 }
 
 #[wasm_bindgen_test]
 async fn test_fee_estimation() {
+    let program_manager = ProgramManager::new(None).unwrap();
     let private_key = PrivateKey::new();
 
     let inputs = js_sys::Array::new_with_length(2);
@@ -317,41 +703,76 @@ async fn test_fee_estimation() {
     inputs.set(1, wasm_bindgen::JsValue::from_str("15u64"));
 
     // Ensure the deployment fee is correct and the cache is used
-    let deployment_fee = ProgramManager::estimate_deployment_fee(FINALIZE, None).await.unwrap();
+    let deployment_cost = program_manager.estimate_deployment_fee(FINALIZE, None).await.unwrap();
     let namespace_fee = ProgramManager::program_name_cost("tencharacters.aleo").unwrap();
     assert_eq!(namespace_fee, 1000000);
 
     // Ensure the fee is greater a specific amount
-    assert!(deployment_fee > 1940000);
+    assert!(deployment_cost.total() > 1940000);
+
+    // Ensure the breakdown adds up to the total and matches the namespace cost of the program name
+    assert_eq!(
+        deployment_cost.total(),
+        deployment_cost.storage_cost() + deployment_cost.synthesis_cost() + deployment_cost.namespace_cost()
+    );
+    assert_eq!(deployment_cost.namespace_cost(), ProgramManager::program_name_cost("finalize_test").unwrap());
+
+    // Ensure the circuit sizes of every function are reported
+    assert_eq!(deployment_cost.functions().length(), 1);
+    let function = &deployment_cost.function_costs()[0];
+    assert_eq!(function.name(), "integer_key_mapping_update");
+    assert!(function.num_constraints() > 0);
+    assert!(function.num_variables() > 0);
 
     // Ensure the finalize fee is greater than zero for a program with a finalize scope
-    let finalize_fee = ProgramManager::estimate_finalize_fee(FINALIZE, "integer_key_mapping_update").unwrap();
+    let finalize_fee = program_manager.estimate_finalize_fee(FINALIZE, "integer_key_mapping_update").unwrap();
     assert!(finalize_fee > 0);
 
-    let execution_fee = ProgramManager::estimate_execution_fee(
-        &private_key,
-        FINALIZE,
-        "integer_key_mapping_update",
-        inputs,
-        Some("https://api.explorer.provable.com/v1".to_string()),
-        None,
-        None,
-        None,
-        None,
-    )
-    .await
-    .unwrap();
+    let execution_cost = program_manager
+        .estimate_execution_fee(&private_key, FINALIZE, "integer_key_mapping_update", inputs.clone(), None, Some(0.5))
+        .unwrap();
 
     // Ensure the fee is greater a specific amount
-    console_log!("execute fee for finalize: {:?}", execution_fee);
-    assert!(execution_fee > 1001000);
+    console_log!("execute fee for finalize: {:?}", execution_cost);
+    assert!(execution_cost.base_fee() > 1001000);
+
+    // Ensure the breakdown reuses the finalize cost and includes the priority fee
+    assert_eq!(execution_cost.finalize_cost(), finalize_fee);
+    assert!(execution_cost.storage_cost() > 0);
+    assert_eq!(execution_cost.priority_fee(), 500000);
+    assert_eq!(execution_cost.total(), execution_cost.base_fee() + 500000);
 
-    // Ensure the total fee is greater than the finalize fee
-    assert!(execution_fee > finalize_fee);
+    // Ensure a negative priority fee is rejected
+    assert!(program_manager
+        .estimate_execution_fee(&private_key, FINALIZE, "integer_key_mapping_update", inputs, None, Some(-1.0))
+        .is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_local_ledger_finalize() {
+    let mut ledger = LocalLedger::new(None).unwrap();
+    let private_key = PrivateKey::new();
+    ledger.add_program(FINALIZE).unwrap();
+
+    let inputs = Array::of2(&JsValue::from_str("7u64"), &JsValue::from_str("15u64"));
+    let transaction = ledger.execute(&private_key, "finalize_test.aleo", "integer_key_mapping_update", inputs).unwrap();
+
+    // Ensure the finalize block of the execution updated the mapping
+    assert_eq!(
+        ledger.get_mapping_value("finalize_test.aleo", "integer_key_mapping", "7u64").unwrap(),
+        Some("15u64".to_string())
+    );
+    assert_eq!(ledger.get_mapping_value("finalize_test.aleo", "integer_key_mapping", "8u64").unwrap(), None);
+    assert_eq!(ledger.height(), 1);
+
+    // Ensure a transaction cannot be applied twice
+    let error: JsValue = ledger.apply_transaction(&transaction).unwrap_err().into();
+    assert_eq!(Reflect::get(&error, &"code".into()).unwrap().as_string().unwrap(), "REJECTED");
 }
 
 #[wasm_bindgen_test]
 async fn test_import_resolution() {
+    let program_manager = ProgramManager::new(None).unwrap();
     let imports = Object::new();
     Reflect::set(&imports, &JsValue::from_str("multiply_test.aleo"), &JsValue::from_str(MULTIPLY_PROGRAM)).unwrap();
     Reflect::set(&imports, &JsValue::from_str("addition_test.aleo"), &JsValue::from_str(ADDITION_PROGRAM)).unwrap();
@@ -363,21 +784,24 @@ async fn test_import_resolution() {
     inputs.set(0, JsValue::from_str("5u32"));
     inputs.set(1, JsValue::from_str("10u32"));
 
-    let result = ProgramManager::execute_function_offline(
-        &private_key,
-        NESTED_IMPORT_PROGRAM,
-        "add_and_double",
-        inputs,
-        false,
-        false,
-        Some(imports),
-        None,
-        None,
-        None,
-        None,
-    )
-    .await
-    .unwrap();
+    let result = program_manager
+        .execute_function_offline(
+            &private_key,
+            NESTED_IMPORT_PROGRAM,
+            "add_and_double",
+            inputs,
+            false,
+            false,
+            Some(imports),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
 
     let outputs = result.get_outputs().to_vec();
     console_log!("outputs: {:?}", outputs);
@@ -11,9 +11,11 @@ export const DecryptAccount = () => {
 
     const onCiphertextChange = (event) => {
         try {
-            let ciphertext = aleo.PrivateKeyCiphertext.fromString(
-                event.target.value,
-            );
+            // Accept either a JSON keystore or a bare private key ciphertext
+            const input = event.target.value.trim();
+            let ciphertext = input.startsWith("{")
+                ? aleo.Keystore.fromString(input)
+                : aleo.PrivateKeyCiphertext.fromString(input);
             setInputCiphertext(ciphertext);
             setAccountFromCiphertext(
                 ciphertext.decryptToPrivateKey(inputPassword),
@@ -65,11 +67,14 @@ export const DecryptAccount = () => {
                 style={{ width: "100%" }}
             >
                 <Form {...layout}>
-                    <Form.Item label="Private Key Ciphertext" colon={false}>
+                    <Form.Item
+                        label="Private Key Ciphertext or Keystore"
+                        colon={false}
+                    >
                         <Input
                             name="privateKeyCiphertext"
                             size="large"
-                            placeholder="Private Key Ciphertext"
+                            placeholder="Private Key Ciphertext or Keystore JSON"
                             allowClear
                             onChange={onCiphertextChange}
                         />
@@ -22,13 +22,23 @@ export const EncryptAccount = () => {
         console.log("password is: " + password);
         if (password && account) {
             try {
-                setEncryptedAccount(account.toCiphertext(passwordString()));
+                setEncryptedAccount(
+                    aleo.Keystore.fromPrivateKey(account, passwordString()),
+                );
                 setPassword(null);
             } catch (error) {
                 console.error(error);
             }
         }
     };
+    const downloadKeystore = () => {
+        const blob = new Blob([keystore()], { type: "application/json" });
+        const link = document.createElement("a");
+        link.href = URL.createObjectURL(blob);
+        link.download = "account-ciphertext.json";
+        link.click();
+        URL.revokeObjectURL(link.href);
+    };
     const clear = () => {
         setAccount(null);
         setPassword(null);
@@ -48,6 +58,10 @@ export const EncryptAccount = () => {
     const address = () =>
         account !== null ? account.to_address().to_string() : "";
     const encryptedPrivateKey = () =>
+        encryptedAccount !== null
+            ? encryptedAccount.toPrivateKeyCiphertext().toString()
+            : "";
+    const keystore = () =>
         encryptedAccount !== null ? encryptedAccount.toString() : "";
     const passwordString = () => (password !== null ? password : "");
 
@@ -145,6 +159,27 @@ export const EncryptAccount = () => {
                                 />
                             </Form.Item>
                         )}
+                        {encryptedAccount && (
+                            <Form.Item label="Keystore" colon={false}>
+                                <Input.TextArea
+                                    size="large"
+                                    placeholder="Keystore"
+                                    value={keystore()}
+                                    autoSize={{ minRows: 4, maxRows: 16 }}
+                                    disabled
+                                />
+                                <Row justify="center" style={{ marginTop: 8 }}>
+                                    <Col>
+                                        <CopyButton data={keystore()} />
+                                    </Col>
+                                    <Col offset="1">
+                                        <Button onClick={downloadKeystore}>
+                                            Download Keystore
+                                        </Button>
+                                    </Col>
+                                </Row>
+                            </Form.Item>
+                        )}
                     </Form>
                 )}
             </Card>